@@ -13,6 +13,7 @@ mod fast_trap;
 mod hart;
 mod hart_csr_utils;
 mod hsm_cell;
+mod pmu;
 mod qemu_test;
 mod rfence;
 mod riscv_spec;
@@ -133,6 +134,7 @@ extern "C" fn rust_main(hart_id: usize, opaque: usize) {
             hsm: Hsm,
             reset: qemu_test::get(),
             dbcn: dbcn::get(),
+            pmu: pmu::Pmu,
         });
         // Release 发布 BSS 清零、BoardInfo、设备指针和 RustSBI；secondary 的 Acquire
         // 在访问任一全局对象前消费这些写。
@@ -176,13 +178,14 @@ extern "C" fn rust_main(hart_id: usize, opaque: usize) {
     clint::clear();
     // 准备启动调度
     // SAFETY: cold boot runs in M-mode after DTB validation; delegated masks intentionally cover
-    // only S-mode consumers and counter access exposes only the time CSR.
+    // only S-mode consumers and counter access exposes only the time CSR until PMU start.
     unsafe {
         // 只委托 S-mode 实际消费的中断与来自 U-mode 的异常；S-mode ecall 必须留在 M-mode 作为 SBI。
         asm!("csrw mideleg,    {}", in(reg) DELEGATED_S_INTERRUPTS);
         asm!("csrw medeleg,    {}", in(reg) DELEGATED_U_EXCEPTIONS);
-        // kernel 单调时钟只需要 `time` CSR，不向 S-mode 暴露未使用的硬件计数器。
+        // kernel 单调时钟只需要 `time` CSR；cycle/instret 停止且不可见，直到 SBI PMU start。
         asm!("csrw mcounteren, {}", in(reg) COUNTER_TIME);
+        asm!("csrw mcountinhibit, {}", in(reg) pmu::HARDWARE_COUNTERS);
         use riscv::register::mtvec;
         mtvec::write(
             trap_vec::trap_vec as *const () as usize,
@@ -399,6 +402,7 @@ struct FixedRustSBI<'a> {
     hsm: Hsm,
     reset: &'a qemu_test::QemuTest,
     dbcn: &'a dbcn::Dbcn,
+    pmu: pmu::Pmu,
 }
 
 struct Hsm;
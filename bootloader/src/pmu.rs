@@ -0,0 +1,186 @@
+use core::arch::asm;
+
+use rustsbi::SbiRet;
+
+/// `mcycle`/`minstret` 在 mcounteren 与 mcountinhibit 中的 bit，也是 SBI logical counter index。
+const CYCLE_COUNTER: usize = 0;
+const INSTRET_COUNTER: usize = 2;
+/// 冷启动时两个 counter 的 inhibit/expose mask。
+pub(crate) const HARDWARE_COUNTERS: usize = (1 << CYCLE_COUNTER) | (1 << INSTRET_COUNTER);
+
+// SBI PMU event_idx：type 位于 [19:16]，hardware general event 为 type 0。
+const EVENT_TYPE_SHIFT: usize = 16;
+const EVENT_HW_CPU_CYCLES: usize = 1;
+const EVENT_HW_INSTRUCTIONS: usize = 2;
+// counter_get_info 的 hardware counter 编码：[11:0] CSR number，[17:12] width - 1。
+const COUNTER_WIDTH_SHIFT: usize = 12;
+const COUNTER_WIDTH_64: usize = 63;
+const CSR_CYCLE: usize = 0xc00;
+
+const CONFIG_SKIP_MATCH: usize = 1 << 0;
+const CONFIG_CLEAR_VALUE: usize = 1 << 1;
+const CONFIG_AUTO_START: usize = 1 << 2;
+/// `SBI_PMU_CFG_FLAG_SET_*INH` mode filters；无 Sscofpmf 时 firmware 只能忽略。
+const CONFIG_MODE_FILTERS: usize = 0x3f << 3;
+const START_SET_INIT_VALUE: usize = 1 << 0;
+const STOP_RESET: usize = 1 << 0;
+
+/// @description SBI PMU 的固定 counter 实现：只开放架构 `mcycle`/`minstret`。
+///
+/// 每个 hart 的 counter 状态完全由该 hart 的 mcounteren/mcountinhibit CSR 表达，firmware
+/// 不保存跨 hart 状态；QEMU 未实现 Sscofpmf 与 programmable event，其他事件一律不支持。
+pub(crate) struct Pmu;
+
+/// @description 把 base/mask 展开为 counter 位图；任一 index 不是固定 counter 时拒绝。
+fn selected_counters(base: usize, mask: usize) -> Option<usize> {
+    let mut selected = 0;
+    for bit in 0..usize::BITS as usize {
+        if mask & (1 << bit) == 0 {
+            continue;
+        }
+        match base.checked_add(bit) {
+            Some(index @ (CYCLE_COUNTER | INSTRET_COUNTER)) => selected |= 1 << index,
+            _ => return None,
+        }
+    }
+    Some(selected)
+}
+
+fn started_counters() -> usize {
+    let enabled: usize;
+    // SAFETY: mcounteren is an M-mode CSR owned by this hart's firmware context.
+    unsafe { asm!("csrr {}, mcounteren", out(reg) enabled) };
+    enabled & HARDWARE_COUNTERS
+}
+
+fn write_counter(counter: usize, value: u64) {
+    // SAFETY: firmware owns the M-mode counter CSRs; S-mode only reads the shadow CSR.
+    unsafe {
+        match counter {
+            CYCLE_COUNTER => asm!("csrw mcycle, {}", in(reg) value),
+            _ => asm!("csrw minstret, {}", in(reg) value),
+        }
+    }
+}
+
+/// @description 解除 inhibit 并向 S-mode 暴露 counter；S-mode 由此直接读取 cycle/instret。
+fn start(counters: usize) {
+    // SAFETY: only the fixed hardware-counter bits are changed on the calling hart.
+    unsafe {
+        asm!("csrc mcountinhibit, {}", in(reg) counters);
+        asm!("csrs mcounteren, {}", in(reg) counters);
+    }
+}
+
+fn stop(counters: usize) {
+    // SAFETY: only the fixed hardware-counter bits are changed on the calling hart.
+    unsafe {
+        asm!("csrc mcounteren, {}", in(reg) counters);
+        asm!("csrs mcountinhibit, {}", in(reg) counters);
+    }
+}
+
+impl rustsbi::Pmu for Pmu {
+    /// logical index 与 CSR bit 一一对应，因此返回值覆盖 0..=2；index 1 是 `time`，
+    /// `counter_get_info` 对它返回 invalid_param。SBI 不要求 logical index 连续，Linux
+    /// `pmu_sbi_get_ctrinfo` 同样跳过无 info 的 index，实际可用 counter 只有两个。
+    fn num_counters(&self) -> usize {
+        INSTRET_COUNTER + 1
+    }
+
+    fn counter_get_info(&self, counter_idx: usize) -> SbiRet {
+        match counter_idx {
+            CYCLE_COUNTER | INSTRET_COUNTER => SbiRet::success(
+                (COUNTER_WIDTH_64 << COUNTER_WIDTH_SHIFT) | (CSR_CYCLE + counter_idx),
+            ),
+            // index 1 是 `time`，由 TIME extension 拥有，不能绑定 PMU 事件。
+            _ => SbiRet::invalid_param(),
+        }
+    }
+
+    fn counter_config_matching(
+        &self,
+        counter_idx_base: usize,
+        counter_idx_mask: usize,
+        config_flags: usize,
+        event_idx: usize,
+        _event_data: u64,
+    ) -> SbiRet {
+        let known =
+            CONFIG_SKIP_MATCH | CONFIG_CLEAR_VALUE | CONFIG_AUTO_START | CONFIG_MODE_FILTERS;
+        if config_flags & !known != 0 {
+            return SbiRet::invalid_param();
+        }
+        let Some(selected) = selected_counters(counter_idx_base, counter_idx_mask) else {
+            return SbiRet::invalid_param();
+        };
+        let counter = match (event_idx >> EVENT_TYPE_SHIFT, event_idx & 0xffff) {
+            (0, EVENT_HW_CPU_CYCLES) => CYCLE_COUNTER,
+            (0, EVENT_HW_INSTRUCTIONS) => INSTRET_COUNTER,
+            _ => return SbiRet::not_supported(),
+        };
+        // SKIP_MATCH 表示 caller 已持有该 counter；否则必须在 mask 内找到固定映射的 counter。
+        if selected & (1 << counter) == 0 {
+            return SbiRet::not_supported();
+        }
+        if config_flags & CONFIG_CLEAR_VALUE != 0 {
+            write_counter(counter, 0);
+        }
+        if config_flags & CONFIG_AUTO_START != 0 {
+            start(1 << counter);
+        }
+        SbiRet::success(counter)
+    }
+
+    fn counter_start(
+        &self,
+        counter_idx_base: usize,
+        counter_idx_mask: usize,
+        start_flags: usize,
+        initial_value: u64,
+    ) -> SbiRet {
+        if start_flags & !START_SET_INIT_VALUE != 0 {
+            return SbiRet::invalid_param();
+        }
+        let Some(selected) = selected_counters(counter_idx_base, counter_idx_mask) else {
+            return SbiRet::invalid_param();
+        };
+        if started_counters() & selected != 0 {
+            return SbiRet::already_started();
+        }
+        if start_flags & START_SET_INIT_VALUE != 0 {
+            for counter in [CYCLE_COUNTER, INSTRET_COUNTER] {
+                if selected & (1 << counter) != 0 {
+                    write_counter(counter, initial_value);
+                }
+            }
+        }
+        start(selected);
+        SbiRet::success(0)
+    }
+
+    fn counter_stop(
+        &self,
+        counter_idx_base: usize,
+        counter_idx_mask: usize,
+        stop_flags: usize,
+    ) -> SbiRet {
+        // RESET 只解除 event 映射；固定 counter 的映射不可变，因此与普通 stop 相同。
+        if stop_flags & !STOP_RESET != 0 {
+            return SbiRet::invalid_param();
+        }
+        let Some(selected) = selected_counters(counter_idx_base, counter_idx_mask) else {
+            return SbiRet::invalid_param();
+        };
+        if started_counters() & selected != selected {
+            return SbiRet::already_stopped();
+        }
+        stop(selected);
+        SbiRet::success(0)
+    }
+
+    fn counter_fw_read(&self, _counter_idx: usize) -> SbiRet {
+        // 没有 firmware counter；全部 logical counter 都是 hardware CSR。
+        SbiRet::invalid_param()
+    }
+}
@@ -188,6 +188,12 @@ impl<Page: TablePage> PageTable<Page> {
         AddressSpaceToken::from_root_page(self.root_page, self.address_space_id)
     }
 
+    /// @description 返回可能缓存本 address space translation 的 logical CPU native bitmap。
+    /// @return 全部 bit；inner-shareable TLBI 广播到每个 PE，不需要 per-ASID CPU tracking。
+    pub(crate) fn translation_cpus(&self) -> usize {
+        usize::MAX
+    }
+
     /// @description 把 kernel root 安装到 TTBR1；user root 只经 token 写 TTBR0。
     pub(crate) fn activate_kernel(&self) {
         assert_eq!(self.kind, AddressSpaceKind::Kernel);
@@ -0,0 +1,74 @@
+const PMU_VERSION_SHIFT: u64 = 8;
+const PMU_VERSION_MASK: u64 = 0xf;
+const PMU_VERSION_IMPLEMENTATION_DEFINED: u64 = 0xf;
+const PMCR_ENABLE: u64 = 1 << 0;
+const PMCR_CYCLE_RESET: u64 = 1 << 2;
+const PMCR_LONG_CYCLE: u64 = 1 << 6;
+const ENABLE_CYCLE_COUNTER: u64 = 1 << 31;
+
+/// AArch64 exposes only the PMUv3 cycle counter.
+///
+/// QEMU implements INST_RETIRED only under precise icount and HVF emulates only the cycle
+/// counter registers, so touching PMEVTYPER/PMEVCNTR could raise UNDEF on the product machine.
+pub(crate) const COUNTS_INSTRUCTIONS: bool = false;
+
+/// Snapshot of the calling CPU's PMUv3 cycle counter.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CounterSample {
+    cycles: u64,
+}
+
+impl CounterSample {
+    /// Return `[cycles, instructions]` elapsed since an earlier sample on the same CPU.
+    ///
+    /// The instruction slot is always zero because [`COUNTS_INSTRUCTIONS`] is false.
+    pub(crate) fn since(self, earlier: Self) -> [u64; 2] {
+        [self.cycles.wrapping_sub(earlier.cycles), 0]
+    }
+}
+
+/// Enable the PMUv3 64-bit cycle counter on the calling CPU.
+///
+/// Returns false when ID_AA64DFR0_EL1 reports no architected PMUv3. EL0 access stays disabled;
+/// a zero PMCCFILTR counts EL0 and EL1 of the running Thread.
+pub(crate) fn start() -> bool {
+    let features: u64;
+    // SAFETY: ID_AA64DFR0_EL1 is a read-only identification register at EL1.
+    unsafe {
+        core::arch::asm!("mrs {value}, id_aa64dfr0_el1", value = out(reg) features, options(nomem, nostack, preserves_flags))
+    };
+    let version = (features >> PMU_VERSION_SHIFT) & PMU_VERSION_MASK;
+    if version == 0 || version == PMU_VERSION_IMPLEMENTATION_DEFINED {
+        return false;
+    }
+    // SAFETY: each CPU exclusively owns its PMU registers; PMUSERENR=0 keeps them EL1-only.
+    // The trailing ISB publishes the enable before the first sample.
+    unsafe {
+        core::arch::asm!(
+            "msr pmuserenr_el0, xzr",
+            "msr pmccfiltr_el0, xzr",
+            "msr pmcntenset_el0, {enable}",
+            "msr pmcr_el0, {control}",
+            "isb",
+            enable = in(reg) ENABLE_CYCLE_COUNTER,
+            control = in(reg) PMCR_ENABLE | PMCR_CYCLE_RESET | PMCR_LONG_CYCLE,
+            options(nomem, nostack, preserves_flags)
+        )
+    };
+    true
+}
+
+/// Read the calling CPU's cycle counter started by [`start`].
+#[inline(always)]
+pub(crate) fn sample() -> CounterSample {
+    let cycles: u64;
+    // SAFETY: callers only sample after `start` succeeded on every CPU.
+    unsafe {
+        core::arch::asm!(
+            "mrs {cycles}, pmccntr_el0",
+            cycles = out(reg) cycles,
+            options(nomem, nostack, preserves_flags)
+        )
+    };
+    CounterSample { cycles }
+}
@@ -124,6 +124,16 @@ pub(crate) mod time {
     pub(crate) use super::selected::{counter_frequency, program_virtual_timer};
 }
 
+/// Hardware cycle/instruction counters selected at compile time.
+pub(crate) mod performance {
+    #[cfg(target_arch = "aarch64")]
+    pub(crate) use super::selected::start_performance_counters as start;
+    pub(crate) use super::selected::{
+        COUNTS_INSTRUCTIONS, PerformanceSample as CounterSample,
+        sample_performance_counters as sample,
+    };
+}
+
 /// MMU mechanism selected at compile time.
 pub(crate) mod mmu {
     #[cfg(target_arch = "aarch64")]
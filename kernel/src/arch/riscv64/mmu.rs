@@ -1,6 +1,6 @@
 use core::{
     arch::asm,
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::atomic::{AtomicUsize, Ordering, fence},
 };
 
 const PHYSICAL_ADDRESS_WIDTH: usize = 56;
@@ -19,9 +19,9 @@ static ADDRESS_SPACE_ID_CAPACITY: AtomicUsize = AtomicUsize::new(0);
 static ADDRESS_SPACE_IDS: [AtomicUsize; ASID_BITMAP_WORDS] =
     [const { AtomicUsize::new(0) }; ASID_BITMAP_WORDS];
 // OWNER: one native CPU-seen bitmap per ASID. First activation on each CPU executes an ASID-scoped
-// fence that orders page-table publication before implicit reads. Retirement clears all CPU TLBs
-// before the allocator resets this word; omitting it would skip the ordering fence on a CPU that
-// has never observed the page-table writes.
+// fence that orders page-table publication before implicit reads, and revoke shootdowns target only
+// CPUs in this word. Retirement clears all CPU TLBs before the allocator resets it; omitting it would
+// skip the ordering fence, or the remote fence, on a CPU holding this ASID's translations.
 static ADDRESS_SPACE_ID_CPUS: [AtomicUsize; MAX_ADDRESS_SPACE_IDS] =
     [const { AtomicUsize::new(0) }; MAX_ADDRESS_SPACE_IDS];
 
@@ -190,10 +190,21 @@ fn prepare_local_activation(token: AddressSpaceToken, logical_cpu: usize) {
     if seen.load(Ordering::Acquire) & cpu_bit != 0 {
         return;
     }
+    // Publish before fencing: a revoking CPU writes PTEs, fences and then reads this word. Either
+    // it observes this bit and targets the CPU remotely, or this CPU's fence observes its writes.
+    seen.fetch_or(cpu_bit, Ordering::SeqCst);
+    fence(Ordering::SeqCst);
     // SAFETY: rs1=x0 selects every virtual address for this nonzero ASID; the current CPU owns its
-    // activation bit and executes the fence before publishing that the ASID has been observed.
+    // activation bit and no user translation for this ASID is used before the fence completes.
     unsafe { asm!("sfence.vma x0, {asid}", asid = in(reg) identifier, options(nostack)) };
-    seen.fetch_or(cpu_bit, Ordering::Release);
+}
+
+/// @description 读取曾激活指定 ASID 的 logical CPU native bitmap。
+/// @param identifier 当前已分配的非零 ASID。
+/// @return 未出现在结果中的 CPU 在首次激活时的 ASID-scoped fence 必然观察到 caller 已写入的 PTE。
+pub(super) fn address_space_cpus(identifier: usize) -> usize {
+    fence(Ordering::SeqCst);
+    ADDRESS_SPACE_ID_CPUS[identifier].load(Ordering::SeqCst)
 }
 
 /// @description 为 trampoline 的下一次 user `satp` 切换准备 CPU-local ASID state。
@@ -7,6 +7,7 @@ mod io;
 mod kernel_context;
 mod mmu;
 mod page_table;
+mod performance;
 mod pte;
 mod signal_frame;
 mod start;
@@ -33,6 +34,9 @@ pub(crate) use page_table::{
     AddressSpaceKind, PageTable as ArchitecturePageTable,
     PageTableEntry as ArchitecturePageTableEntry, PageTableError, TablePage,
 };
+pub(crate) use performance::{
+    COUNTS_INSTRUCTIONS, CounterSample as PerformanceSample, sample as sample_performance_counters,
+};
 pub(crate) use pte::PagePermissions;
 pub(crate) use signal_frame::{MIN_SIGNAL_STACK_SIZE, SIGNAL_FRAME_SIZE, SignalFrame, SignalStack};
 pub(crate) use start::entry_address as secondary_entry;
@@ -182,6 +182,16 @@ impl<Page: TablePage> PageTable<Page> {
         AddressSpaceToken::from_root_page(self.root_page, self.address_space_id)
     }
 
+    /// @description 返回可能缓存本 address space translation 的 logical CPU native bitmap。
+    /// @return user root 返回其 ASID 的 CPU-seen bitmap；kernel root 的 global mapping 返回全部 bit。
+    /// @note caller 必须在对应 PTE 写入之后调用。
+    pub(crate) fn translation_cpus(&self) -> usize {
+        match self.kind {
+            AddressSpaceKind::Kernel => usize::MAX,
+            AddressSpaceKind::User => super::mmu::address_space_cpus(self.address_space_id),
+        }
+    }
+
     /// @description 激活 RISC-V kernel Sv39 root；该 backend 保持单 root 契约。
     pub(crate) fn activate_kernel(&self) {
         assert_eq!(self.kind, AddressSpaceKind::Kernel);
@@ -0,0 +1,31 @@
+/// @description SBI PMU 同时启动 `cycle` 与 `instret`，两者要么都可读要么都不可读。
+pub(crate) const COUNTS_INSTRUCTIONS: bool = true;
+
+/// @description 当前 hart 的 `cycle`/`instret` 快照；两者都是 64-bit free-running counter。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CounterSample {
+    cycles: u64,
+    instructions: u64,
+}
+
+impl CounterSample {
+    /// @description 计算同一 hart 上两个快照之间的 cycle/instruction 增量。
+    /// @param earlier 同一 CPU 较早读取的快照。
+    /// @return `[cycles, instructions]`；64-bit counter 以 wrapping 差值处理回绕。
+    pub(crate) fn since(self, earlier: Self) -> [u64; 2] {
+        [
+            self.cycles.wrapping_sub(earlier.cycles),
+            self.instructions.wrapping_sub(earlier.instructions),
+        ]
+    }
+}
+
+/// @description 读取当前 hart 的 cycle/instret counter。
+/// @return 当前快照；调用方必须已确认 SBI PMU 在本 hart 启动并开放了两个 counter。
+#[inline(always)]
+pub(crate) fn sample() -> CounterSample {
+    CounterSample {
+        cycles: riscv::register::cycle::read64(),
+        instructions: riscv::register::instret::read64(),
+    }
+}
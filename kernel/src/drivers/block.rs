@@ -1,6 +1,13 @@
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 use spin::Mutex;
 
+#[path = "block/device_name.rs"]
+mod device_name;
+#[path = "block/partition.rs"]
+mod partition;
+pub(crate) use device_name::{BlockDeviceName, parse as parse_device_name};
+pub(crate) use partition::{Partition, PartitionDevice, scan as scan_partitions};
+
 /// 启动块设备错误。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum BlockError {
@@ -8,10 +15,33 @@ pub(crate) enum BlockError {
     IoError,
     DeviceError,
     OutOfMemory,
-    AlreadyRegistered,
+    TooManyDevices,
+}
+
+/// @description `submit_read`/`submit_write` 返回的 outstanding request；必须交回同一设备的 `complete` 恰好一次。
+#[must_use = "submitted block requests must be completed to release their device slot"]
+#[derive(Debug)]
+pub(crate) struct BlockRequest {
+    slot: u16,
+    generation: u64,
+}
+
+impl BlockRequest {
+    /// @description 由 concrete adapter 把固定 slot identity 包装为 filesystem 可持有的 token。
+    pub(crate) fn new(slot: u16, generation: u64) -> Self {
+        Self { slot, generation }
+    }
+
+    pub(crate) fn slot(&self) -> u16 {
+        self.slot
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
-/// @description 为文件系统提供同步固定块读写与持久化屏障。
+/// @description 为文件系统提供固定块读写、可流水写入与持久化屏障。
 pub(crate) trait BlockDevice: Send + Sync {
     /// 读取一个完整逻辑块。
     ///
@@ -37,6 +67,41 @@ pub(crate) trait BlockDevice: Send + Sync {
     /// @errors 块号越界、缓冲区长度错误或设备 I/O 失败时返回错误。
     fn write_block(&self, block_id: usize, buf: &[u8]) -> Result<usize, BlockError>;
 
+    /// @description 提交一个完整逻辑块写入，不等待 device completion。
+    ///
+    /// @param block_id 从零开始的逻辑块号。
+    /// @param buf 长度必须等于 `block_size()`；返回前已复制进 device-owned DMA buffer。
+    /// @return 已发布到 device 的 request token。
+    /// @errors 块号越界、缓冲区长度错误或设备已失败时返回错误，且不占用 slot。
+    fn submit_write(&self, block_id: usize, buf: &[u8]) -> Result<BlockRequest, BlockError>;
+
+    /// @description 提交一个完整逻辑块读取，不等待 device completion。
+    ///
+    /// @param block_id 从零开始的逻辑块号。
+    /// @return 已发布到 device 的 request token；数据只在 `complete_read` 时交付。
+    /// @errors 块号越界或设备已失败时返回错误，且不占用 slot。
+    fn submit_read(&self, block_id: usize) -> Result<BlockRequest, BlockError>;
+
+    /// @description 睡眠等待读 request completion，复制数据并释放其 slot。
+    ///
+    /// @param request 本设备 `submit_read` 返回、尚未 complete 的 token。
+    /// @param buf 长度必须等于 `block_size()` 的目标缓冲区；错误时内容未定义。
+    /// @errors device 报告 I/O 错误、reset 终止 request 或缓冲区长度错误时返回错误。
+    fn complete_read(&self, request: BlockRequest, buf: &mut [u8]) -> Result<(), BlockError>;
+
+    /// @description 睡眠等待写 request completion 并释放其 slot。
+    ///
+    /// @param request 本设备 `submit_write` 返回、尚未 complete 的 token。
+    /// @return device 报告写入成功时返回 unit。
+    /// @errors device 报告 I/O 错误或 reset 终止 request 时返回错误。
+    fn complete(&self, request: BlockRequest) -> Result<(), BlockError>;
+
+    /// @description 所有 caller 共享的 device-wide outstanding request slot 数。
+    ///
+    /// slot 全部被占用时 submit 等待任一 token complete。流水 caller 只能取其中一小份，并在
+    /// 等待新 slot 前 complete 自己最旧的 token；否则多个 caller 各持部分 slot 时互相等待。
+    fn queue_depth(&self) -> usize;
+
     /// @description 把设备已接受的写入推进到稳定存储能力边界。
     ///
     /// @return flush 完成或设备明确不需要额外 flush 时返回成功。
@@ -46,35 +111,109 @@ pub(crate) trait BlockDevice: Send + Sync {
     /// 返回逻辑块字节数。
     fn block_size(&self) -> usize;
 
+    /// 返回设备 logical block 总数，即 `block_size` 单位的容量。
+    fn block_count(&self) -> usize;
+
     /// Reclaim a bounded completion batch outside hardirq context.
     fn dispatch_completions(&self) -> bool;
 }
 
-// OWNER: block layer owns the single root-device binding; platform discovery sets it once.
-static PRIMARY_BLOCK_DEVICE: spin::Once<Mutex<Option<Arc<dyn BlockDevice>>>> = spin::Once::new();
+/// 可注册的 whole-disk 设备数，对应 Linux `vda`..`vdz`。
+pub(crate) const MAX_BLOCK_DEVICES: usize = 26;
 
-fn primary_slot() -> &'static Mutex<Option<Arc<dyn BlockDevice>>> {
-    PRIMARY_BLOCK_DEVICE.call_once(|| Mutex::new(None))
-}
+// OWNER: block layer owns disk indices; platform registers devices once in device-tree order.
+static BLOCK_DEVICES: Mutex<[Option<Arc<dyn BlockDevice>>; MAX_BLOCK_DEVICES]> =
+    Mutex::new([const { None }; MAX_BLOCK_DEVICES]);
 
-/// 注册唯一启动块设备。
+/// @description 按 platform discovery 顺序注册一个 whole-disk 设备。
+/// @return 稳定 disk index；0 即 `vda`。
+/// @errors 已注册 `MAX_BLOCK_DEVICES` 个设备时返回 TooManyDevices。
 pub(crate) fn register_block_device(device: Arc<dyn BlockDevice>) -> Result<usize, BlockError> {
-    let mut slot = primary_slot().lock();
-    if slot.is_some() {
-        return Err(BlockError::AlreadyRegistered);
+    let mut devices = BLOCK_DEVICES.lock();
+    let index = devices
+        .iter()
+        .position(Option::is_none)
+        .ok_or(BlockError::TooManyDevices)?;
+    devices[index] = Some(device);
+    Ok(index)
+}
+
+/// @description 取得指定 disk index 的 whole-disk 设备。
+pub(crate) fn block_device(index: usize) -> Option<Arc<dyn BlockDevice>> {
+    BLOCK_DEVICES.lock().get(index)?.clone()
+}
+
+/// 每个 disk 可发布的分区数；Linux virtio-blk 每盘 16 个 minor，minor 0 为整盘。
+pub(crate) const MAX_PARTITIONS: usize = 15;
+
+/// 一个 disk 的分区 view；下标为一基分区号减一。
+type PartitionViews = [Option<Arc<dyn BlockDevice>>; MAX_PARTITIONS];
+
+// OWNER: block layer owns partition views; boot registers each disk's table once before mounting root.
+static PARTITION_DEVICES: Mutex<[PartitionViews; MAX_BLOCK_DEVICES]> =
+    Mutex::new([const { [const { None }; MAX_PARTITIONS] }; MAX_BLOCK_DEVICES]);
+
+/// @description 扫描一个已注册 disk 的分区表，并为每个分区发布 `PartitionDevice`。
+///
+/// @param disk 注册顺序中的 disk index。
+/// @return 分区表全部表项；分区号超过 `MAX_PARTITIONS` 的表项不发布 block view。
+/// @errors disk 未注册时返回 InvalidBlock；分区表读取、校验或分配失败时返回对应错误且不发布任何分区。
+pub(crate) fn register_partitions(disk: usize) -> Result<Vec<Partition>, BlockError> {
+    let device = block_device(disk).ok_or(BlockError::InvalidBlock)?;
+    let partitions = scan_partitions(device.as_ref())?;
+    let mut views: PartitionViews = [const { None }; MAX_PARTITIONS];
+    for partition in partitions
+        .iter()
+        .filter(|partition| partition.number <= MAX_PARTITIONS)
+    {
+        views[partition.number - 1] = Some(
+            Arc::try_new(PartitionDevice::new(device.clone(), partition))
+                .map_err(|_| BlockError::OutOfMemory)?,
+        );
     }
-    *slot = Some(device);
-    Ok(0)
+    PARTITION_DEVICES.lock()[disk] = views;
+    Ok(partitions)
 }
 
-/// 取得唯一启动块设备。
-pub(crate) fn get_primary_block_device() -> Option<Arc<dyn BlockDevice>> {
-    primary_slot().lock().clone()
+/// @description 按 Linux `vdXN` 名称取得 whole disk 或已发布的分区设备。
+pub(crate) fn named_block_device(name: BlockDeviceName) -> Option<Arc<dyn BlockDevice>> {
+    match name.partition {
+        None => block_device(name.disk),
+        Some(number) => PARTITION_DEVICES
+            .lock()
+            .get(name.disk)?
+            .get(number.checked_sub(1)?)?
+            .clone(),
+    }
 }
 
-/// Dispatch primary-device completion work at a task/idle safe point.
+/// @description 提交每个已注册 disk 的 volatile write cache；失败 disk 记录错误后继续。
+pub(crate) fn flush_all() {
+    for index in 0..MAX_BLOCK_DEVICES {
+        let Some(device) = block_device(index) else {
+            break;
+        };
+        if let Err(error) = device.flush() {
+            error!(
+                "vd{}: write cache flush failed: {:?}",
+                char::from(b'a' + index as u8),
+                error
+            );
+        }
+    }
+}
+
+/// Dispatch every disk's completion work at a task/idle safe point.
 pub(crate) fn dispatch_completion_work() -> bool {
-    get_primary_block_device().is_some_and(|device| device.dispatch_completions())
+    // 先克隆 owner 再逐个 dispatch；completion wake 不得在 registry lock 内执行。
+    let mut backlog = false;
+    for index in 0..MAX_BLOCK_DEVICES {
+        let Some(device) = block_device(index) else {
+            break;
+        };
+        backlog |= device.dispatch_completions();
+    }
+    backlog
 }
 
 pub(crate) const BLOCK_SIZE: usize = 4096;
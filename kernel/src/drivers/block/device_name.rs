@@ -0,0 +1,74 @@
+//! @description Linux `vdXN` block device name 到 disk/partition index 的投影。
+
+/// @description `root=` 等参数选定的 whole disk 或其一基分区。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BlockDeviceName {
+    /// 注册顺序中的 disk index；`vda` 为 0。
+    pub(crate) disk: usize,
+    /// `vda2` 中的 2；None 表示 whole disk。
+    pub(crate) partition: Option<usize>,
+}
+
+/// @description 解析可带 `/dev/` 前缀的 `vd<letter>[<number>]` 名称。
+///
+/// @param name 原始名称 bytes。
+/// @return 合法名称的 disk/partition；分区号为零、带前导零或溢出时返回 None。
+pub(crate) fn parse(name: &[u8]) -> Option<BlockDeviceName> {
+    let name = name.strip_prefix(b"/dev/").unwrap_or(name);
+    let (&letter, number) = name.strip_prefix(b"vd")?.split_first()?;
+    if !letter.is_ascii_lowercase() {
+        return None;
+    }
+    let disk = usize::from(letter - b'a');
+    if number.is_empty() {
+        return Some(BlockDeviceName {
+            disk,
+            partition: None,
+        });
+    }
+    if number[0] == b'0' || !number.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let partition = number.iter().try_fold(0usize, |value, digit| {
+        value
+            .checked_mul(10)?
+            .checked_add(usize::from(digit - b'0'))
+    })?;
+    Some(BlockDeviceName {
+        disk,
+        partition: Some(partition),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_map_to_disk_and_partition_indices() {
+        assert_eq!(
+            parse(b"/dev/vda"),
+            Some(BlockDeviceName {
+                disk: 0,
+                partition: None,
+            })
+        );
+        assert_eq!(
+            parse(b"vdb12"),
+            Some(BlockDeviceName {
+                disk: 1,
+                partition: Some(12),
+            })
+        );
+        for invalid in [
+            &b"/dev/sda1"[..],
+            b"vd",
+            b"vdA",
+            b"vda0",
+            b"vda01",
+            b"vda1x",
+        ] {
+            assert_eq!(parse(invalid), None);
+        }
+    }
+}
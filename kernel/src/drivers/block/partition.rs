@@ -0,0 +1,522 @@
+//! @description MBR/GPT 分区表扫描与以父设备偏移实现的 partition 块设备视图。
+
+use alloc::{sync::Arc, vec::Vec};
+
+use super::{BlockDevice, BlockError, BlockRequest};
+
+/// 分区表 LBA 的固定单位；与 device logical block size 无关。
+const SECTOR_SIZE: usize = 512;
+const MBR_ENTRY_OFFSET: usize = 446;
+const MBR_ENTRY_SIZE: usize = 16;
+const MBR_SIGNATURE: [u8; 2] = [0x55, 0xaa];
+const MBR_TYPE_LINUX: u8 = 0x83;
+const MBR_TYPE_GPT_PROTECTIVE: u8 = 0xee;
+const GPT_SIGNATURE: &[u8; 8] = b"EFI PART";
+const GPT_HEADER_MIN_SIZE: usize = 92;
+const GPT_ENTRY_SIZE: usize = 128;
+/// UEFI 规定的最小 entry array 容量，也是本扫描接受的上限。
+const GPT_MAX_ENTRIES: usize = 128;
+/// `0FC63DAF-8483-4772-8E79-3D69D8477DE4` 的 on-disk mixed-endian 编码。
+const GPT_TYPE_LINUX_FILESYSTEM: [u8; 16] = [
+    0xaf, 0x3d, 0xc6, 0x0f, 0x83, 0x84, 0x72, 0x47, 0x8e, 0x79, 0x3d, 0x69, 0xd8, 0x47, 0x7d, 0xe4,
+];
+
+/// @description 分区表中一个可按 device block 对齐访问的分区。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Partition {
+    /// Linux 命名使用的一基分区号，即 `vda1` 中的 1。
+    pub(crate) number: usize,
+    /// 父设备上的首个 logical block。
+    pub(crate) first_block: usize,
+    /// 分区包含的 logical block 数。
+    pub(crate) blocks: usize,
+    /// MBR type 0x83 或 GPT Linux filesystem type。
+    pub(crate) linux: bool,
+}
+
+/// @description 读取父设备的 MBR，必要时继续解析 protective MBR 之后的 GPT。
+///
+/// @param device 整盘块设备。
+/// @return 按表项顺序排列的分区；无 0x55AA 签名的整盘 filesystem 返回空表。
+/// @errors 读取失败、GPT header/entry CRC 不一致、usable 区间越出设备或分配失败时返回错误。
+/// @note 只解析四个 MBR primary entry，不遍历 extended/logical 链；起点或长度不按
+/// device block 对齐的分区无法以 block view 表达，被跳过。与 Linux `add_partition` 一致，
+/// 起点越出设备的表项被跳过，尾部越出设备的表项截断到设备末尾。
+pub(crate) fn scan(device: &dyn BlockDevice) -> Result<Vec<Partition>, BlockError> {
+    let mut mbr = [0u8; SECTOR_SIZE];
+    read_bytes(device, 0, &mut mbr)?;
+    let mut partitions = Vec::new();
+    if mbr[SECTOR_SIZE - 2..] != MBR_SIGNATURE {
+        return Ok(partitions);
+    }
+    let entries = mbr[MBR_ENTRY_OFFSET..MBR_ENTRY_OFFSET + 4 * MBR_ENTRY_SIZE]
+        .as_chunks::<MBR_ENTRY_SIZE>()
+        .0
+        .iter()
+        .map(|entry| {
+            (
+                entry[4],
+                le32(&entry[8..]) as u64,
+                le32(&entry[12..]) as u64,
+            )
+        });
+    if entries
+        .clone()
+        .any(|(kind, _, _)| kind == MBR_TYPE_GPT_PROTECTIVE)
+    {
+        return scan_gpt(device);
+    }
+    partitions
+        .try_reserve_exact(4)
+        .map_err(|_| BlockError::OutOfMemory)?;
+    for (index, (kind, first, sectors)) in entries.enumerate() {
+        if kind != 0 && sectors != 0 {
+            push_aligned(
+                &mut partitions,
+                device,
+                index + 1,
+                first,
+                sectors,
+                kind == MBR_TYPE_LINUX,
+            );
+        }
+    }
+    Ok(partitions)
+}
+
+fn scan_gpt(device: &dyn BlockDevice) -> Result<Vec<Partition>, BlockError> {
+    let mut header = [0u8; SECTOR_SIZE];
+    read_bytes(device, SECTOR_SIZE as u64, &mut header)?;
+    let header_size = le32(&header[12..]) as usize;
+    if &header[..8] != GPT_SIGNATURE || !(GPT_HEADER_MIN_SIZE..=SECTOR_SIZE).contains(&header_size)
+    {
+        return Err(BlockError::InvalidBlock);
+    }
+    let header_crc = le32(&header[16..]);
+    header[16..20].fill(0);
+    let (first_usable, last_usable) = (le64(&header[40..]), le64(&header[48..]));
+    let entries_lba = le64(&header[72..]);
+    let count = le32(&header[80..]) as usize;
+    // Linux `is_gpt_valid`：usable 区间必须非空且落在设备最后一个 LBA 之内。
+    let last_lba = (device.block_count() as u64)
+        .saturating_mul((device.block_size() / SECTOR_SIZE) as u64)
+        .checked_sub(1);
+    if crc32(&header[..header_size]) != header_crc
+        || count > GPT_MAX_ENTRIES
+        || le32(&header[84..]) as usize != GPT_ENTRY_SIZE
+        || first_usable > last_usable
+        || last_lba.is_none_or(|last_lba| last_usable > last_lba)
+    {
+        return Err(BlockError::InvalidBlock);
+    }
+    let mut entries = Vec::new();
+    entries
+        .try_reserve_exact(count * GPT_ENTRY_SIZE)
+        .map_err(|_| BlockError::OutOfMemory)?;
+    entries.resize(count * GPT_ENTRY_SIZE, 0);
+    let offset = entries_lba
+        .checked_mul(SECTOR_SIZE as u64)
+        .ok_or(BlockError::InvalidBlock)?;
+    read_bytes(device, offset, &mut entries)?;
+    if crc32(&entries) != le32(&header[88..]) {
+        return Err(BlockError::InvalidBlock);
+    }
+    let mut partitions = Vec::new();
+    partitions
+        .try_reserve_exact(count)
+        .map_err(|_| BlockError::OutOfMemory)?;
+    for (index, entry) in entries.as_chunks::<GPT_ENTRY_SIZE>().0.iter().enumerate() {
+        let (first, last) = (le64(&entry[32..]), le64(&entry[40..]));
+        // Linux `is_pte_valid`：越出 usable 区间或首尾倒置的表项被跳过，而非使整表失效。
+        let sectors = last.checked_sub(first).and_then(|span| span.checked_add(1));
+        if let Some(sectors) = sectors
+            && entry[..16].iter().any(|byte| *byte != 0)
+            && first >= first_usable
+            && last <= last_usable
+        {
+            let linux = entry[..16] == GPT_TYPE_LINUX_FILESYSTEM;
+            push_aligned(&mut partitions, device, index + 1, first, sectors, linux);
+        }
+    }
+    Ok(partitions)
+}
+
+fn push_aligned(
+    partitions: &mut Vec<Partition>,
+    device: &dyn BlockDevice,
+    number: usize,
+    first_sector: u64,
+    sectors: u64,
+    linux: bool,
+) {
+    let per_block = (device.block_size() / SECTOR_SIZE) as u64;
+    if per_block == 0
+        || !first_sector.is_multiple_of(per_block)
+        || !sectors.is_multiple_of(per_block)
+    {
+        return;
+    }
+    let (Ok(first_block), Ok(blocks)) = (
+        usize::try_from(first_sector / per_block),
+        usize::try_from(sectors / per_block),
+    ) else {
+        return;
+    };
+    // MBR 没有 usable 区间约束；按父设备容量截断，PartitionDevice 才不会越出整盘。
+    let Some(available) = device.block_count().checked_sub(first_block) else {
+        return;
+    };
+    let blocks = blocks.min(available);
+    if blocks == 0 {
+        return;
+    }
+    // capacity 已由 caller 按表项数预留，push 不会重新分配。
+    partitions.push(Partition {
+        number,
+        first_block,
+        blocks,
+        linux,
+    });
+}
+
+fn read_bytes(device: &dyn BlockDevice, offset: u64, output: &mut [u8]) -> Result<(), BlockError> {
+    let block_size = device.block_size();
+    let mut block = Vec::new();
+    block
+        .try_reserve_exact(block_size)
+        .map_err(|_| BlockError::OutOfMemory)?;
+    block.resize(block_size, 0);
+    let mut done = 0;
+    while done < output.len() {
+        let current = offset + done as u64;
+        let block_id =
+            usize::try_from(current / block_size as u64).map_err(|_| BlockError::InvalidBlock)?;
+        let start = (current % block_size as u64) as usize;
+        let count = (block_size - start).min(output.len() - done);
+        device.read_block(block_id, &mut block)?;
+        output[done..done + count].copy_from_slice(&block[start..start + count]);
+        done += count;
+    }
+    Ok(())
+}
+
+fn le32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes[..4].try_into().unwrap())
+}
+
+fn le64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap())
+}
+
+/// GPT 使用的 IEEE 802.3 reflected CRC32。
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        (0..u8::BITS).fold(crc ^ u32::from(*byte), |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+/// @description 把 block id 平移到父设备分区区间的块设备视图。
+pub(crate) struct PartitionDevice {
+    parent: Arc<dyn BlockDevice>,
+    first_block: usize,
+    blocks: usize,
+}
+
+impl PartitionDevice {
+    pub(crate) fn new(parent: Arc<dyn BlockDevice>, partition: &Partition) -> Self {
+        Self {
+            parent,
+            first_block: partition.first_block,
+            blocks: partition.blocks,
+        }
+    }
+
+    fn parent_block(&self, block_id: usize) -> Result<usize, BlockError> {
+        if block_id >= self.blocks {
+            return Err(BlockError::InvalidBlock);
+        }
+        Ok(self.first_block + block_id)
+    }
+}
+
+impl BlockDevice for PartitionDevice {
+    fn read_block(&self, block_id: usize, buf: &mut [u8]) -> Result<usize, BlockError> {
+        self.parent.read_block(self.parent_block(block_id)?, buf)
+    }
+
+    fn write_block(&self, block_id: usize, buf: &[u8]) -> Result<usize, BlockError> {
+        self.parent.write_block(self.parent_block(block_id)?, buf)
+    }
+
+    fn submit_write(&self, block_id: usize, buf: &[u8]) -> Result<BlockRequest, BlockError> {
+        self.parent.submit_write(self.parent_block(block_id)?, buf)
+    }
+
+    fn submit_read(&self, block_id: usize) -> Result<BlockRequest, BlockError> {
+        self.parent.submit_read(self.parent_block(block_id)?)
+    }
+
+    fn complete_read(&self, request: BlockRequest, buf: &mut [u8]) -> Result<(), BlockError> {
+        self.parent.complete_read(request, buf)
+    }
+
+    fn complete(&self, request: BlockRequest) -> Result<(), BlockError> {
+        self.parent.complete(request)
+    }
+
+    fn queue_depth(&self) -> usize {
+        self.parent.queue_depth()
+    }
+
+    fn flush(&self) -> Result<(), BlockError> {
+        // FLUSH 作用于整盘 volatile cache；分区视图无法缩小其范围。
+        self.parent.flush()
+    }
+
+    fn block_size(&self) -> usize {
+        self.parent.block_size()
+    }
+
+    fn block_count(&self) -> usize {
+        self.blocks
+    }
+
+    fn dispatch_completions(&self) -> bool {
+        self.parent.dispatch_completions()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    const BLOCK: usize = 4096;
+
+    struct MemoryDisk(Mutex<Vec<u8>>);
+
+    impl BlockDevice for MemoryDisk {
+        fn read_block(&self, block_id: usize, buf: &mut [u8]) -> Result<usize, BlockError> {
+            let disk = self.0.lock().unwrap();
+            let bytes = disk
+                .get(block_id * BLOCK..(block_id + 1) * BLOCK)
+                .ok_or(BlockError::InvalidBlock)?;
+            buf.copy_from_slice(bytes);
+            Ok(BLOCK)
+        }
+
+        fn write_block(&self, _block_id: usize, _buf: &[u8]) -> Result<usize, BlockError> {
+            Err(BlockError::IoError)
+        }
+
+        fn submit_write(&self, _block_id: usize, _buf: &[u8]) -> Result<BlockRequest, BlockError> {
+            Err(BlockError::IoError)
+        }
+
+        fn submit_read(&self, _block_id: usize) -> Result<BlockRequest, BlockError> {
+            Err(BlockError::IoError)
+        }
+
+        fn complete_read(&self, _request: BlockRequest, _buf: &mut [u8]) -> Result<(), BlockError> {
+            Err(BlockError::IoError)
+        }
+
+        fn complete(&self, _request: BlockRequest) -> Result<(), BlockError> {
+            Err(BlockError::IoError)
+        }
+
+        fn queue_depth(&self) -> usize {
+            1
+        }
+
+        fn flush(&self) -> Result<(), BlockError> {
+            Ok(())
+        }
+
+        fn block_size(&self) -> usize {
+            BLOCK
+        }
+
+        fn block_count(&self) -> usize {
+            self.0.lock().unwrap().len() / BLOCK
+        }
+
+        fn dispatch_completions(&self) -> bool {
+            false
+        }
+    }
+
+    fn mbr_entry(disk: &mut [u8], index: usize, kind: u8, first: u32, sectors: u32) {
+        let entry = &mut disk[MBR_ENTRY_OFFSET + index * MBR_ENTRY_SIZE..];
+        entry[4] = kind;
+        entry[8..12].copy_from_slice(&first.to_le_bytes());
+        entry[12..16].copy_from_slice(&sectors.to_le_bytes());
+        disk[SECTOR_SIZE - 2..SECTOR_SIZE].copy_from_slice(&MBR_SIGNATURE);
+    }
+
+    #[test]
+    fn unsigned_sector_zero_is_a_whole_disk_filesystem() {
+        let disk = MemoryDisk(Mutex::new(vec![0; 4 * BLOCK]));
+        assert!(scan(&disk).unwrap().is_empty());
+    }
+
+    #[test]
+    fn mbr_primary_entries_become_block_aligned_partitions() {
+        let mut bytes = vec![0; 4 * BLOCK];
+        mbr_entry(&mut bytes, 0, 0x0c, 8, 8);
+        mbr_entry(&mut bytes, 1, MBR_TYPE_LINUX, 16, 16);
+        mbr_entry(&mut bytes, 2, MBR_TYPE_LINUX, 3, 8);
+        let disk = Arc::new(MemoryDisk(Mutex::new(bytes)));
+        let partitions = scan(disk.as_ref()).unwrap();
+        assert_eq!(
+            partitions,
+            [
+                Partition {
+                    number: 1,
+                    first_block: 1,
+                    blocks: 1,
+                    linux: false,
+                },
+                Partition {
+                    number: 2,
+                    first_block: 2,
+                    blocks: 2,
+                    linux: true,
+                },
+            ]
+        );
+        let view = PartitionDevice::new(disk, &partitions[1]);
+        let mut block = [0u8; BLOCK];
+        assert_eq!(view.read_block(1, &mut block), Ok(BLOCK));
+        assert_eq!(
+            view.read_block(2, &mut block),
+            Err(BlockError::InvalidBlock)
+        );
+    }
+
+    #[test]
+    fn mbr_entries_past_the_disk_end_are_truncated_or_skipped() {
+        let mut bytes = vec![0; 4 * BLOCK];
+        mbr_entry(&mut bytes, 0, MBR_TYPE_LINUX, 16, 64);
+        mbr_entry(&mut bytes, 1, MBR_TYPE_LINUX, 32, 8);
+        mbr_entry(&mut bytes, 2, MBR_TYPE_LINUX, 8, u32::MAX - 7);
+        let disk = Arc::new(MemoryDisk(Mutex::new(bytes)));
+        let partitions = scan(disk.as_ref()).unwrap();
+        assert_eq!(
+            partitions,
+            [
+                Partition {
+                    number: 1,
+                    first_block: 2,
+                    blocks: 2,
+                    linux: true,
+                },
+                Partition {
+                    number: 3,
+                    first_block: 1,
+                    blocks: 3,
+                    linux: true,
+                },
+            ]
+        );
+        let view = PartitionDevice::new(disk, &partitions[0]);
+        let mut block = [0u8; BLOCK];
+        assert_eq!(view.block_count(), 2);
+        assert_eq!(
+            view.read_block(2, &mut block),
+            Err(BlockError::InvalidBlock)
+        );
+    }
+
+    #[test]
+    fn registered_partitions_resolve_by_linux_name() {
+        let mut bytes = vec![0; 4 * BLOCK];
+        mbr_entry(&mut bytes, 0, MBR_TYPE_LINUX, 8, 8);
+        mbr_entry(&mut bytes, 1, MBR_TYPE_LINUX, 16, 16);
+        bytes[2 * BLOCK] = 0x5a;
+        let disk =
+            super::super::register_block_device(Arc::new(MemoryDisk(Mutex::new(bytes)))).unwrap();
+        assert_eq!(super::super::register_partitions(disk).unwrap().len(), 2);
+        let name = |partition| super::super::BlockDeviceName { disk, partition };
+        let second = super::super::named_block_device(name(Some(2))).unwrap();
+        let mut block = [0u8; BLOCK];
+        assert_eq!(second.read_block(0, &mut block), Ok(BLOCK));
+        assert_eq!((block[0], second.block_count()), (0x5a, 2));
+        assert!(super::super::named_block_device(name(None)).is_some());
+        assert!(super::super::named_block_device(name(Some(3))).is_none());
+    }
+
+    fn gpt_entry(disk: &mut [u8], index: usize, first: u64, last: u64) {
+        let entry = &mut disk[2 * SECTOR_SIZE + index * GPT_ENTRY_SIZE..];
+        entry[..16].copy_from_slice(&GPT_TYPE_LINUX_FILESYSTEM);
+        entry[32..40].copy_from_slice(&first.to_le_bytes());
+        entry[40..48].copy_from_slice(&last.to_le_bytes());
+    }
+
+    /// 在 LBA 1 写入四表项 GPT header；usable 区间与 CRC 按当前 entry array 计算。
+    fn gpt_header(disk: &mut [u8], first_usable: u64, last_usable: u64) {
+        let entries_crc = crc32(&disk[2 * SECTOR_SIZE..2 * SECTOR_SIZE + 4 * GPT_ENTRY_SIZE]);
+        let header = &mut disk[SECTOR_SIZE..2 * SECTOR_SIZE];
+        header[..8].copy_from_slice(GPT_SIGNATURE);
+        header[12..16].copy_from_slice(&(GPT_HEADER_MIN_SIZE as u32).to_le_bytes());
+        header[16..20].fill(0);
+        header[40..48].copy_from_slice(&first_usable.to_le_bytes());
+        header[48..56].copy_from_slice(&last_usable.to_le_bytes());
+        header[72..80].copy_from_slice(&2u64.to_le_bytes());
+        header[80..84].copy_from_slice(&4u32.to_le_bytes());
+        header[84..88].copy_from_slice(&(GPT_ENTRY_SIZE as u32).to_le_bytes());
+        header[88..92].copy_from_slice(&entries_crc.to_le_bytes());
+        let header_crc = crc32(&header[..GPT_HEADER_MIN_SIZE]);
+        header[16..20].copy_from_slice(&header_crc.to_le_bytes());
+    }
+
+    #[test]
+    fn gpt_entries_are_accepted_only_with_matching_crcs() {
+        let mut bytes = vec![0; 8 * BLOCK];
+        mbr_entry(&mut bytes, 0, MBR_TYPE_GPT_PROTECTIVE, 1, u32::MAX);
+        gpt_entry(&mut bytes, 0, 8, 23);
+        gpt_header(&mut bytes, 3, 63);
+        let disk = MemoryDisk(Mutex::new(bytes));
+        assert_eq!(
+            scan(&disk).unwrap(),
+            [Partition {
+                number: 1,
+                first_block: 1,
+                blocks: 2,
+                linux: true,
+            }]
+        );
+        disk.0.lock().unwrap()[2 * SECTOR_SIZE + 40] ^= 1;
+        assert_eq!(scan(&disk), Err(BlockError::InvalidBlock));
+    }
+
+    #[test]
+    fn malformed_gpt_entries_are_skipped_without_overflow() {
+        let mut bytes = vec![0; 8 * BLOCK];
+        mbr_entry(&mut bytes, 0, MBR_TYPE_GPT_PROTECTIVE, 1, u32::MAX);
+        gpt_entry(&mut bytes, 0, 0, u64::MAX);
+        gpt_entry(&mut bytes, 1, 16, 8);
+        gpt_entry(&mut bytes, 2, 56, 71);
+        gpt_entry(&mut bytes, 3, 8, 15);
+        gpt_header(&mut bytes, 3, 63);
+        let disk = MemoryDisk(Mutex::new(bytes));
+        assert_eq!(
+            scan(&disk).unwrap(),
+            [Partition {
+                number: 4,
+                first_block: 1,
+                blocks: 1,
+                linux: true,
+            }]
+        );
+        gpt_header(&mut disk.0.lock().unwrap(), 3, 64);
+        assert_eq!(scan(&disk), Err(BlockError::InvalidBlock));
+    }
+
+    #[test]
+    fn crc32_matches_the_ieee_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+}
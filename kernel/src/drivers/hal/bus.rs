@@ -5,6 +5,7 @@ pub(crate) enum BusError {
 }
 
 /// @description 提供有边界和对齐检查、并由静态 arch façade 固定指令形态的 MMIO 访问。
+#[derive(Clone, Copy)]
 pub(crate) struct MmioBus {
     base_addr: usize,
     size: usize,
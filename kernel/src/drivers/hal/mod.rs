@@ -7,4 +7,5 @@ pub(crate) use interrupt::{InterruptError, InterruptHandler, InterruptVector};
 pub(super) use virtio::{
     VIRTIO_CONFIG_S_DRIVER_OK, VIRTIO_CONFIG_S_FEATURES_OK, VIRTIO_F_VERSION_1,
     VIRTIO_MMIO_INT_CONFIG, VIRTIO_MMIO_INT_VRING, VirtIODevice, VirtQueueAddresses,
+    reset_live_transports,
 };
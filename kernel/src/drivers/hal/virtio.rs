@@ -1,3 +1,5 @@
+use spin::Mutex;
+
 use super::bus::{BusError, MmioBus};
 
 const MAGIC: usize = 0x000;
@@ -30,6 +32,29 @@ pub(in crate::drivers) const VIRTIO_MMIO_INT_VRING: u32 = 1;
 pub(in crate::drivers) const VIRTIO_MMIO_INT_CONFIG: u32 = 2;
 
 const VIRTIO_MMIO_MAGIC: u32 = 0x7472_6976;
+/// QEMU `virt` 最多暴露 32 个 virtio-mmio slot。
+const MAX_LIVE_TRANSPORTS: usize = 32;
+
+// OWNER: VirtIO transport 层独占已进入 driver 协商的 MMIO window 清单，只供 shutdown 复位。
+// 各 driver 私有持有自己的 VirtIODevice，无法从全局枚举；缺少该清单时 reboot 只能让仍在
+// DMA 与投递 IRQ 的 device 直接进入 firmware reset。
+static LIVE_TRANSPORTS: Mutex<[Option<MmioBus>; MAX_LIVE_TRANSPORTS]> =
+    Mutex::new([None; MAX_LIVE_TRANSPORTS]);
+
+/// @description 复位每个已进入 driver 协商的 transport；device 随后停止 DMA、used ring 更新与 IRQ。
+///
+/// 只在 reboot/poweroff 交给 firmware 前调用；复位后原 driver 的 queue 不再 live。
+/// @return 全部 transport 读回 status 0 后返回 unit。
+/// @errors 任一 MMIO window 无效时返回 `InvalidAddress`，其余 transport 仍被复位。
+pub(in crate::drivers) fn reset_live_transports() -> Result<(), BusError> {
+    let transports = *LIVE_TRANSPORTS.lock();
+    let mut result = Ok(());
+    for bus in transports.into_iter().flatten() {
+        let transport = VirtIODevice { bus, device_id: 0 };
+        result = result.and(transport.reset());
+    }
+    result
+}
 
 /// @description VirtIO MMIO v2 split-queue 的三段物理地址。
 #[derive(Clone, Copy)]
@@ -78,7 +103,14 @@ impl VirtIODevice {
         }
         self.reset()?;
         self.set_status(VIRTIO_CONFIG_S_ACKNOWLEDGE)?;
-        self.set_status(VIRTIO_CONFIG_S_ACKNOWLEDGE | VIRTIO_CONFIG_S_DRIVER)
+        self.set_status(VIRTIO_CONFIG_S_ACKNOWLEDGE | VIRTIO_CONFIG_S_DRIVER)?;
+        let mut transports = LIVE_TRANSPORTS.lock();
+        let slot = transports
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(BusError::InvalidAddress)?;
+        *slot = Some(self.bus);
+        Ok(())
     }
 
     /// @description 发起 device reset，并等待 transport 读回完成状态。
@@ -5,6 +5,7 @@ mod input;
 pub(crate) mod io_completion;
 pub(crate) mod network;
 mod uart;
+mod virtio_balloon;
 mod virtio_blk;
 mod virtio_completion_irq;
 mod virtio_gpu;
@@ -23,6 +24,7 @@ use hal::{
 };
 pub(crate) use input::{InputAbsInfo, InputDevice, InputDeviceError, InputId, RawInputEvent};
 pub(crate) use input::{device as input_device, device_count as input_device_count};
+pub(crate) use virtio_balloon::VirtIOBalloonDevice;
 pub(crate) use virtio_blk::VirtIOBlockDevice;
 pub(crate) use virtio_gpu::VirtIOGpuDevice;
 pub(crate) use virtio_input::VirtIOInputDevice;
@@ -52,7 +54,34 @@ pub(crate) fn register_entropy_device(device: alloc::sync::Arc<VirtIORngDevice>)
 ///
 /// @return 任一设备仍有 backlog 时返回 `true`，caller 必须重新发布 `DriverIo` work。
 pub(crate) fn dispatch_io_completion_work() -> bool {
-    block::dispatch_completion_work() | virtio_rng::dispatch_completion_work()
+    block::dispatch_completion_work()
+        | virtio_rng::dispatch_completion_work()
+        | virtio_balloon::dispatch_work()
+}
+
+/// @description reboot/poweroff 前按 Linux `device_shutdown` 语义静默全部 driver。
+///
+/// 1. 每个已注册 disk 提交 volatile write cache，覆盖 root filesystem 以外的 disk；
+/// 2. 复位每个已驱动的 VirtIO transport，device 停止 DMA 与 IRQ 后才交给 firmware reset。
+///
+/// 任一步失败只记录错误且不跳过其余步骤：caller 已决定 reset，拒绝它会让部分 device
+/// 已复位的系统继续运行。
+pub(crate) fn shutdown_devices() {
+    block::flush_all();
+    if hal::reset_live_transports().is_err() {
+        error!("VirtIO transport reset before shutdown failed");
+    }
+}
+
+/// @description `/proc/vmstat` 读取的累计 balloon inflate/deflate 页数。
+pub(crate) fn balloon_statistics() -> virtio_balloon::BalloonStatistics {
+    virtio_balloon::statistics()
+}
+
+pub(crate) fn register_balloon_device(
+    device: alloc::sync::Arc<VirtIOBalloonDevice>,
+) -> Result<(), ()> {
+    virtio_balloon::register(device)
 }
 
 pub(crate) fn register_display_device(
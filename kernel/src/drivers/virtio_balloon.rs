@@ -0,0 +1,310 @@
+//! @description VirtIO memory balloon adapter；safe point 按 host target 单批 inflate/deflate。
+
+use alloc::{sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicU64, Ordering};
+use spin::{Mutex, Once};
+
+#[path = "virtio_balloon/policy.rs"]
+mod policy;
+use policy::{BalloonStep, PFNS_PER_REQUEST, next_step};
+
+use super::{
+    InterruptError, InterruptHandler, InterruptVector, VIRTIO_CONFIG_S_DRIVER_OK,
+    VIRTIO_CONFIG_S_FEATURES_OK, VIRTIO_F_VERSION_1, VirtIODevice,
+    virtio_completion_irq::VirtIoCompletionIrq,
+    virtio_queue::{DmaBuffer, VirtQueue},
+};
+use crate::memory::{FrameTracker, PAGE_SIZE, WithdrawnFrame, alloc_frame, withdraw_frame};
+
+const BALLOON_DEVICE_ID: u32 = 5;
+const INFLATE_QUEUE: u32 = 0;
+const DEFLATE_QUEUE: u32 = 1;
+const CONFIG_NUM_PAGES: usize = 0;
+const CONFIG_ACTUAL: usize = 4;
+const VIRTIO_BALLOON_PFN_SHIFT: usize = 12;
+const PFN_BUFFER_BYTES: usize = PFNS_PER_REQUEST * core::mem::size_of::<u32>();
+
+// balloon PFN 固定以 4 KiB 为单位；kernel page 与之相同时每个 FrameTracker 对应一个 PFN。
+const _: () = assert!(PAGE_SIZE == 1 << VIRTIO_BALLOON_PFN_SHIFT);
+
+/// OWNER: virtio-balloon driver owns the only kernel balloon device binding.
+static BALLOON_DEVICE: Once<Arc<VirtIOBalloonDevice>> = Once::new();
+// OWNER: virtio-balloon completion path owns Linux vmstat `balloon_inflate/balloon_deflate` page counts.
+static INFLATED_PAGES: AtomicU64 = AtomicU64::new(0);
+static DEFLATED_PAGES: AtomicU64 = AtomicU64::new(0);
+
+/// @description host 确认后的累计 inflate/deflate 页数，对应 Linux `/proc/vmstat` 同名计数。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BalloonStatistics {
+    pub(crate) inflated_pages: u64,
+    pub(crate) deflated_pages: u64,
+}
+
+enum InFlight {
+    Inflate {
+        head: u16,
+        frames: Vec<FrameTracker>,
+    },
+    Deflate {
+        head: u16,
+        frames: Vec<WithdrawnFrame>,
+    },
+}
+
+struct BalloonState {
+    inflate: VirtQueue,
+    deflate: VirtQueue,
+    pfns: DmaBuffer<PFN_BUFFER_BYTES>,
+    in_flight: Option<InFlight>,
+    withdrawn: Vec<WithdrawnFrame>,
+    failed: bool,
+}
+
+/// @description Modern VirtIO balloon adapter；任一时刻最多一个 request 占用共享 PFN buffer。
+pub(crate) struct VirtIOBalloonDevice {
+    device: VirtIODevice,
+    state: Mutex<BalloonState>,
+    completion_irq: VirtIoCompletionIrq,
+}
+
+impl VirtIOBalloonDevice {
+    pub(crate) fn new(base_addr: usize) -> Option<Arc<Self>> {
+        let mut device = VirtIODevice::new(base_addr, 0x1000).ok()?;
+        if device.device_id() != BALLOON_DEVICE_ID {
+            return None;
+        }
+        device.initialize().ok()?;
+        if device.device_features().ok()? & VIRTIO_F_VERSION_1 == 0 {
+            return None;
+        }
+        // 不协商 MUST_TELL_HOST/DEFLATE_ON_OOM/stats；deflate 总是等 completion 后才释放页。
+        device.set_driver_features(VIRTIO_F_VERSION_1).ok()?;
+        let status = device.get_status().ok()?;
+        device
+            .set_status(status | VIRTIO_CONFIG_S_FEATURES_OK)
+            .ok()?;
+        if device.get_status().ok()? & VIRTIO_CONFIG_S_FEATURES_OK == 0 {
+            return None;
+        }
+        let inflate = Self::configure_queue(&device, INFLATE_QUEUE)?;
+        let deflate = Self::configure_queue(&device, DEFLATE_QUEUE)?;
+        let pfns = DmaBuffer::try_zeroed().ok()?;
+        let status = device.get_status().ok()?;
+        device.set_status(status | VIRTIO_CONFIG_S_DRIVER_OK).ok()?;
+        Arc::try_new(Self {
+            device,
+            state: Mutex::new(BalloonState {
+                inflate,
+                deflate,
+                pfns,
+                in_flight: None,
+                withdrawn: Vec::new(),
+                failed: false,
+            }),
+            completion_irq: VirtIoCompletionIrq::new(),
+        })
+        .ok()
+    }
+
+    fn configure_queue(device: &VirtIODevice, index: u32) -> Option<VirtQueue> {
+        let queue_size = device.queue_max_size(index).ok()?;
+        let queue = VirtQueue::new(queue_size)?;
+        device
+            .configure_queue(index, queue_size, queue.addresses())
+            .ok()?;
+        Some(queue)
+    }
+
+    /// @description 回收已完成批次，并在空闲时按最新 host target 提交下一批。
+    ///
+    /// @return backlog 始终由下一次 completion/config IRQ 重新发布，因此返回 false。
+    fn process(&self) -> bool {
+        if self.completion_irq.take_transport_error() {
+            self.fail_device();
+            return false;
+        }
+        let mut state = self.state.lock();
+        if state.failed {
+            return false;
+        }
+        let result = match self.reclaim(&mut state) {
+            Ok(true) => self.submit_next(&mut state),
+            Ok(false) => Ok(()),
+            Err(()) => Err(()),
+        };
+        drop(state);
+        if result.is_err() {
+            self.fail_device();
+        }
+        false
+    }
+
+    /// @description 消费唯一 in-flight request 的 completion。
+    /// @return 已无 in-flight request 时返回 true；仍等待 device 时返回 false。
+    /// @errors used ring identity 与 in-flight head 不一致时返回 Err，caller 必须 reset。
+    fn reclaim(&self, state: &mut BalloonState) -> Result<bool, ()> {
+        let Some(in_flight) = state.in_flight.take() else {
+            return Ok(true);
+        };
+        let (queue, head) = match &in_flight {
+            InFlight::Inflate { head, .. } => (&mut state.inflate, *head),
+            InFlight::Deflate { head, .. } => (&mut state.deflate, *head),
+        };
+        let completion = match queue.used()? {
+            Some(completion) => completion,
+            None => {
+                state.in_flight = Some(in_flight);
+                return Ok(false);
+            }
+        };
+        if completion.head() != head {
+            state.in_flight = Some(in_flight);
+            return Err(());
+        }
+        queue.recycle_used(completion)?;
+        match in_flight {
+            InFlight::Inflate { frames, .. } => {
+                INFLATED_PAGES.fetch_add(frames.len() as u64, Ordering::Relaxed);
+                // capacity 已在提交前 reserve；withdraw 只在 host 确认后扣除 guest capacity。
+                state
+                    .withdrawn
+                    .extend(frames.into_iter().map(withdraw_frame));
+            }
+            // deflate completion 后 host 不再拥有这些页，Drop 归还 allocator。
+            InFlight::Deflate { frames, .. } => {
+                DEFLATED_PAGES.fetch_add(frames.len() as u64, Ordering::Relaxed);
+                drop(frames);
+            }
+        }
+        self.device
+            .write_config_u32(CONFIG_ACTUAL, state.withdrawn.len() as u32)
+            .map_err(|_| ())?;
+        Ok(true)
+    }
+
+    fn submit_next(&self, state: &mut BalloonState) -> Result<(), ()> {
+        let target = self
+            .device
+            .read_config_u32(CONFIG_NUM_PAGES)
+            .map_err(|_| ())?;
+        match next_step(target, state.withdrawn.len()) {
+            BalloonStep::Idle => Ok(()),
+            BalloonStep::Inflate(count) => {
+                let mut frames = Vec::new();
+                if frames.try_reserve_exact(count).is_err()
+                    || state.withdrawn.try_reserve(count).is_err()
+                {
+                    return Ok(());
+                }
+                // 只取 Reclaimable 页：低水位时停止 inflate，balloon 不消耗 kernel progress reserve。
+                while frames.len() < count
+                    && let Some(frame) = alloc_frame()
+                {
+                    frames.push(frame);
+                }
+                if frames.is_empty() {
+                    return Ok(());
+                }
+                let head = Self::publish(
+                    &mut state.inflate,
+                    &mut state.pfns,
+                    frames.iter().map(|frame| frame.ppn.as_usize()),
+                )?;
+                state.in_flight = Some(InFlight::Inflate { head, frames });
+                self.device.notify_queue(INFLATE_QUEUE).map_err(|_| ())
+            }
+            BalloonStep::Deflate(count) => {
+                let start = state.withdrawn.len() - count;
+                let mut frames = Vec::new();
+                frames.try_reserve_exact(count).map_err(|_| ())?;
+                frames.extend(state.withdrawn.drain(start..));
+                let head = Self::publish(
+                    &mut state.deflate,
+                    &mut state.pfns,
+                    frames.iter().map(|frame| frame.ppn().as_usize()),
+                )?;
+                state.in_flight = Some(InFlight::Deflate { head, frames });
+                self.device.notify_queue(DEFLATE_QUEUE).map_err(|_| ())
+            }
+        }
+    }
+
+    fn publish(
+        queue: &mut VirtQueue,
+        pfns: &mut DmaBuffer<PFN_BUFFER_BYTES>,
+        ppns: impl Iterator<Item = usize>,
+    ) -> Result<u16, ()> {
+        let mut length = 0;
+        for (ppn, bytes) in ppns.zip(pfns.as_mut_slice().as_chunks_mut::<4>().0) {
+            *bytes = u32::try_from(ppn).map_err(|_| ())?.to_le_bytes();
+            length += bytes.len();
+        }
+        let readable = pfns.readable(0..length).map_err(|_| ())?;
+        let head = queue.add_dma(&[readable]).map_err(|_| ())?;
+        queue.add_to_avail(head);
+        Ok(head)
+    }
+
+    fn fail_device(&self) {
+        let mut state = self.state.lock();
+        if state.failed {
+            return;
+        }
+        state.failed = true;
+        // Reset 撤销 host 对所有 balloon 页的回收；此后 guest 访问会重新得到 host backing。
+        let _ = self.device.reset();
+        state.in_flight = None;
+        state.withdrawn = Vec::new();
+    }
+
+    pub(crate) fn irq_handler_for(self: &Arc<Self>) -> Arc<dyn InterruptHandler> {
+        Arc::try_new(VirtIOBalloonIrqHandler {
+            device: self.clone(),
+        })
+        .expect("VirtIO balloon IRQ handler allocation failed")
+    }
+}
+
+impl Drop for VirtIOBalloonDevice {
+    fn drop(&mut self) {
+        // Reset 先于 PFN buffer 与 withdrawn frame drop，host 不再引用任何已释放页。
+        let _ = self.device.reset();
+    }
+}
+
+struct VirtIOBalloonIrqHandler {
+    device: Arc<VirtIOBalloonDevice>,
+}
+
+impl InterruptHandler for VirtIOBalloonIrqHandler {
+    fn handle_interrupt(&self, _vector: InterruptVector) -> Result<(), InterruptError> {
+        // config change（新 target）与 used ring completion 都收敛到同一 safe-point pass。
+        self.device
+            .completion_irq
+            .acknowledge_and_defer(&self.device.device);
+        Ok(())
+    }
+}
+
+pub(super) fn register(device: Arc<VirtIOBalloonDevice>) -> Result<(), ()> {
+    if BALLOON_DEVICE.get().is_some() {
+        return Err(());
+    }
+    BALLOON_DEVICE.call_once(|| device);
+    // probe 前 host 可能已设置非零 target；发布一次 pass，避免等待下一次 config IRQ。
+    crate::cpu::raise_deferred(crate::cpu::DeferredWork::DriverIo);
+    Ok(())
+}
+
+/// @description 读取 host 已确认的累计 balloon 页数；无 balloon device 时均为零。
+pub(super) fn statistics() -> BalloonStatistics {
+    BalloonStatistics {
+        inflated_pages: INFLATED_PAGES.load(Ordering::Relaxed),
+        deflated_pages: DEFLATED_PAGES.load(Ordering::Relaxed),
+    }
+}
+
+/// @description 在 safe point 推进唯一 balloon device 的 target 收敛。
+pub(super) fn dispatch_work() -> bool {
+    BALLOON_DEVICE.get().is_some_and(|device| device.process())
+}
@@ -0,0 +1,41 @@
+//! @description VirtIO balloon host target 到单批 inflate/deflate 的收敛 policy。
+
+/// 单个 inflate/deflate request 携带的最大 PFN 数，与 Linux driver 的批量上限一致。
+pub(super) const PFNS_PER_REQUEST: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BalloonStep {
+    Idle,
+    Inflate(usize),
+    Deflate(usize),
+}
+
+/// @description 按 host target 与已确认 withdrawn 页数选择下一批。
+///
+/// @param target_pages device config `num_pages`。
+/// @param actual_pages driver 已收到 inflate completion 的页数。
+/// @return 不超过 `PFNS_PER_REQUEST` 的单向批次；相等时 Idle。
+pub(super) fn next_step(target_pages: u32, actual_pages: usize) -> BalloonStep {
+    let target_pages = target_pages as usize;
+    if target_pages > actual_pages {
+        BalloonStep::Inflate((target_pages - actual_pages).min(PFNS_PER_REQUEST))
+    } else if target_pages < actual_pages {
+        BalloonStep::Deflate((actual_pages - target_pages).min(PFNS_PER_REQUEST))
+    } else {
+        BalloonStep::Idle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_step_moves_toward_target_by_at_most_one_batch() {
+        assert_eq!(next_step(10, 0), BalloonStep::Inflate(10));
+        assert_eq!(next_step(1000, 100), BalloonStep::Inflate(PFNS_PER_REQUEST));
+        assert_eq!(next_step(0, 1000), BalloonStep::Deflate(PFNS_PER_REQUEST));
+        assert_eq!(next_step(90, 100), BalloonStep::Deflate(10));
+        assert_eq!(next_step(100, 100), BalloonStep::Idle);
+    }
+}
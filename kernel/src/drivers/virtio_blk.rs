@@ -10,7 +10,7 @@ use policy::{
 use super::{
     InterruptError, InterruptHandler, InterruptVector, VIRTIO_CONFIG_S_DRIVER_OK,
     VIRTIO_CONFIG_S_FEATURES_OK, VIRTIO_F_VERSION_1, VirtIODevice,
-    block::{BLOCK_SIZE, BlockDevice, BlockError},
+    block::{BLOCK_SIZE, BlockDevice, BlockError, BlockRequest},
     io_completion::request_owner::{
         CommitOrWait, PreparedCapacityWait, RequestIdentity, RequestOwner, RequestOwnerError,
         ReserveOrWait,
@@ -143,6 +143,13 @@ impl VirtIOBlockDevice {
         Ok(())
     }
 
+    fn request_identity(request: BlockRequest) -> RequestIdentity {
+        RequestIdentity {
+            slot: request.slot(),
+            generation: request.generation(),
+        }
+    }
+
     fn decode_status(status: u8) -> Result<(), BlockError> {
         match decode_status(status) {
             CompletionStatus::Ok => Ok(()),
@@ -466,12 +473,49 @@ impl BlockDevice for VirtIOBlockDevice {
         BLOCK_SIZE
     }
 
+    fn block_count(&self) -> usize {
+        usize::try_from(self.capacity / (BLOCK_SIZE / 512) as u64).unwrap_or(usize::MAX)
+    }
+
     fn write_block(&self, block_id: usize, buf: &[u8]) -> Result<usize, BlockError> {
         self.validate_block(block_id, buf.len())?;
         self.execute(RequestOperation::Write, block_id, Some(buf), None)?;
         Ok(buf.len())
     }
 
+    fn submit_write(&self, block_id: usize, buf: &[u8]) -> Result<BlockRequest, BlockError> {
+        self.validate_block(block_id, buf.len())?;
+        let identity = self.submit(RequestOperation::Write, block_id, Some(buf))?;
+        Ok(BlockRequest::new(identity.slot, identity.generation))
+    }
+
+    fn submit_read(&self, block_id: usize) -> Result<BlockRequest, BlockError> {
+        self.validate_block(block_id, BLOCK_SIZE)?;
+        let identity = self.submit(RequestOperation::Read, block_id, None)?;
+        Ok(BlockRequest::new(identity.slot, identity.generation))
+    }
+
+    fn complete_read(&self, request: BlockRequest, buf: &mut [u8]) -> Result<(), BlockError> {
+        let identity = Self::request_identity(request);
+        self.wait(identity);
+        if buf.len() != BLOCK_SIZE {
+            // 长度错误仍须归还 slot；否则 caller 的错误会永久占用一个 request slot。
+            self.finish(identity, None)?;
+            return Err(BlockError::InvalidBlock);
+        }
+        self.finish(identity, Some(buf))
+    }
+
+    fn complete(&self, request: BlockRequest) -> Result<(), BlockError> {
+        let identity = Self::request_identity(request);
+        self.wait(identity);
+        self.finish(identity, None)
+    }
+
+    fn queue_depth(&self) -> usize {
+        BLOCK_REQUEST_SLOTS
+    }
+
     fn flush(&self) -> Result<(), BlockError> {
         if self.supports_flush {
             self.execute(RequestOperation::Flush, 0, None, None)
@@ -6,6 +6,9 @@ use super::{
     FileSystemStatistics, IndexedDirectory, Inode, InodeMetadata, InodeType,
 };
 
+#[path = "devfs/block.rs"]
+mod block;
+
 const DEVICE_FILESYSTEM_ID: usize = 2;
 
 // OWNER: devfs module 唯一拥有 synthetic device filesystem；缺失会产生重复 st_dev/inode identity。
@@ -113,7 +116,9 @@ impl DevInode {
             (DevNode::Device(_) | DevNode::Link(_), _) | (DevNode::Dri | DevNode::Pts, _) => {
                 return Err(FileSystemError::NotFound);
             }
-            (DevNode::Root, _) => return Err(FileSystemError::NotFound),
+            (DevNode::Root, name) => {
+                DevNode::Device(block::lookup(name).ok_or(FileSystemError::NotFound)?)
+            }
         };
         Ok(Self::new(self.filesystem_id, node)?)
     }
@@ -194,6 +199,7 @@ impl Inode for DevInode {
     fn inode_type(&self) -> InodeType {
         match self.node {
             DevNode::Root | DevNode::Dri | DevNode::Input | DevNode::Pts => InodeType::Directory,
+            DevNode::Device(DeviceKind::Block { .. }) => InodeType::BlockDevice,
             DevNode::Device(_) => InodeType::CharacterDevice,
             DevNode::Link(_) => InodeType::SymLink,
         }
@@ -202,6 +208,9 @@ impl Inode for DevInode {
     fn size(&self) -> u64 {
         match self.node {
             DevNode::Link(link) => link.target().len() as u64,
+            DevNode::Device(device @ DeviceKind::Block { .. }) => {
+                block::device(device).map_or(0, |device| block::capacity(device.as_ref()))
+            }
             DevNode::Root | DevNode::Dri | DevNode::Input | DevNode::Pts | DevNode::Device(_) => 0,
         }
     }
@@ -210,6 +219,10 @@ impl Inode for DevInode {
         false
     }
 
+    fn is_volatile(&self) -> bool {
+        matches!(self.node, DevNode::Device(DeviceKind::Block { .. }))
+    }
+
     fn is_read_only(&self) -> bool {
         true
     }
@@ -238,8 +251,13 @@ impl Inode for DevInode {
         }
     }
 
-    fn read_storage(&self, _offset: u64, _buf: &mut [u8]) -> Result<usize, FileSystemError> {
-        Err(FileSystemError::InvalidOperation)
+    fn read_storage(&self, offset: u64, buf: &mut [u8]) -> Result<usize, FileSystemError> {
+        match self.node {
+            DevNode::Device(device @ DeviceKind::Block { .. }) => {
+                block::read(block::device(device)?.as_ref(), offset, buf)
+            }
+            _ => Err(FileSystemError::InvalidOperation),
+        }
     }
 
     fn write_storage(&self, _offset: u64, _buf: &[u8]) -> Result<usize, FileSystemError> {
@@ -288,7 +306,35 @@ impl Inode for DevInode {
             (13, InodeType::CharacterDevice, &b"card0"[..]),
         ];
         let specifications: &[_] = match self.node {
-            DevNode::Root => &root,
+            DevNode::Root => {
+                let mut stream = IndexedDirectory::new(cursor, visitor);
+                for (index, &(inode, kind, name)) in
+                    root.iter().enumerate().skip(stream.start_index())
+                {
+                    if !stream.emit(index, DirectoryEntry { inode, kind, name })? {
+                        return Ok(stream.finish());
+                    }
+                }
+                // block nodes 跟随 registry 动态出现，排在固定 entry 之后。
+                let mut ordinal = stream.start_index().saturating_sub(root.len());
+                while let Some(device @ DeviceKind::Block { disk, partition }) = block::nth(ordinal)
+                {
+                    let mut name = [0u8; 5];
+                    let length = block::name(disk, partition, &mut name);
+                    if !stream.emit(
+                        root.len() + ordinal,
+                        DirectoryEntry {
+                            inode: device.inode(),
+                            kind: InodeType::BlockDevice,
+                            name: &name[..length],
+                        },
+                    )? {
+                        break;
+                    }
+                    ordinal += 1;
+                }
+                return Ok(stream.finish());
+            }
             DevNode::Dri => &dri,
             DevNode::Input => {
                 let count = crate::input::device_count();
@@ -0,0 +1,107 @@
+//! @description devfs `vdX[N]` block node 到 block registry 的只读字节视图。
+
+use alloc::{sync::Arc, vec::Vec};
+
+use crate::drivers::block::{
+    BlockDevice, BlockDeviceName, MAX_BLOCK_DEVICES, MAX_PARTITIONS, named_block_device,
+    parse_device_name,
+};
+use crate::fs::{DeviceKind, FileSystemError};
+
+/// @description 把已发布的 `vdX[N]` 名称解析为 block device identity。
+/// @return registry 中不存在的 disk 或分区返回 None。
+pub(super) fn lookup(name: &[u8]) -> Option<DeviceKind> {
+    let parsed = parse_device_name(name)?;
+    named_block_device(parsed)?;
+    Some(DeviceKind::Block {
+        disk: u8::try_from(parsed.disk).ok()?,
+        partition: u8::try_from(parsed.partition.unwrap_or(0)).ok()?,
+    })
+}
+
+/// @description 按 disk 注册顺序、整盘先于分区枚举已发布的 block node。
+/// @param ordinal `/dev` 目录中 block entry 的零基序号。
+/// @return 第 `ordinal` 个 node；越过末尾返回 None。
+pub(super) fn nth(ordinal: usize) -> Option<DeviceKind> {
+    (0..MAX_BLOCK_DEVICES)
+        .flat_map(|disk| (0..=MAX_PARTITIONS).map(move |partition| (disk, partition)))
+        .filter_map(|(disk, partition)| {
+            let kind = DeviceKind::Block {
+                disk: disk as u8,
+                partition: partition as u8,
+            };
+            device(kind).ok().map(|_| kind)
+        })
+        .nth(ordinal)
+}
+
+/// @description 写出 Linux 风格节点名，如 `vda` 或 `vdb12`。
+/// @return 写入 `output` 的字节数。
+pub(super) fn name(disk: u8, partition: u8, output: &mut [u8; 5]) -> usize {
+    output[..2].copy_from_slice(b"vd");
+    output[2] = b'a' + disk;
+    match partition {
+        0 => 3,
+        1..=9 => {
+            output[3] = b'0' + partition;
+            4
+        }
+        _ => {
+            output[3] = b'0' + partition / 10;
+            output[4] = b'0' + partition % 10;
+            5
+        }
+    }
+}
+
+/// @description 取得 block node 当前指向的 registry 设备。
+/// @errors 非 block identity 或设备未发布时返回 NotFound。
+pub(super) fn device(kind: DeviceKind) -> Result<Arc<dyn BlockDevice>, FileSystemError> {
+    let DeviceKind::Block { disk, partition } = kind else {
+        return Err(FileSystemError::NotFound);
+    };
+    named_block_device(BlockDeviceName {
+        disk: usize::from(disk),
+        partition: (partition != 0).then_some(usize::from(partition)),
+    })
+    .ok_or(FileSystemError::NotFound)
+}
+
+/// @description 返回设备字节容量，供 `SEEK_END` 定位。
+pub(super) fn capacity(device: &dyn BlockDevice) -> u64 {
+    device.block_count() as u64 * device.block_size() as u64
+}
+
+/// @description 从任意 byte offset 读取，跨 logical block 时逐块复制，直到 buffer 满或设备末尾。
+/// @return 实际读取字节数；offset 位于末尾或之后时返回零。
+/// @errors bounce buffer 分配失败返回 OutOfMemory，设备读取失败返回 IoError。
+pub(super) fn read(
+    device: &dyn BlockDevice,
+    offset: u64,
+    output: &mut [u8],
+) -> Result<usize, FileSystemError> {
+    let block_size = device.block_size();
+    let count = usize::try_from(capacity(device).saturating_sub(offset))
+        .unwrap_or(usize::MAX)
+        .min(output.len());
+    if count == 0 {
+        return Ok(0);
+    }
+    let mut block = Vec::new();
+    block
+        .try_reserve_exact(block_size)
+        .map_err(|_| FileSystemError::OutOfMemory)?;
+    block.resize(block_size, 0);
+    let mut done = 0;
+    while done < count {
+        let position = offset + done as u64;
+        let within = (position % block_size as u64) as usize;
+        device
+            .read_block((position / block_size as u64) as usize, &mut block)
+            .map_err(|_| FileSystemError::IoError)?;
+        let length = (block_size - within).min(count - done);
+        output[done..done + length].copy_from_slice(&block[within..within + length]);
+        done += length;
+    }
+    Ok(done)
+}
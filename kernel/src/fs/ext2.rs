@@ -2,7 +2,10 @@ use alloc::{
     sync::{Arc, Weak},
     vec::Vec,
 };
-use core::{cmp, mem};
+use core::{
+    cmp, mem,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use spin::Mutex;
 
 use super::{
@@ -10,7 +13,7 @@ use super::{
     Inode, InodeMetadata, InodeType, OwnerModeChange, StorageWriter,
 };
 use crate::{
-    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError},
+    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError, BlockRequest},
     fallible_tree::FallibleMap,
     sync::TaskMutex,
 };
@@ -55,29 +58,33 @@ mod metadata;
 mod metadata_cache;
 #[path = "ext2/mount.rs"]
 mod mount;
+#[path = "ext2/name_cache.rs"]
+mod name_cache;
 #[path = "ext2/orphan.rs"]
 mod orphan;
 #[path = "ext2/storage_mutation.rs"]
 mod storage_mutation;
 #[cfg(test)]
 pub(crate) use cost_test_support::{
-    TestMappedInode, arm_test_orphan_drop, clear_test_metadata_cache,
-    fail_next_test_metadata_owner, release_test_orphan_drop, reset_test_allocation_attempts,
-    reset_test_stage_capacity, reset_test_write_costs, set_test_stage_capacity,
-    test_allocation_attempts, test_mount_allocation_state, test_write_costs,
+    TestMappedInode, arm_test_orphan_drop, clear_test_metadata_cache, drop_test_read_pipeline,
+    drop_test_write_pipeline, fail_next_test_metadata_owner, release_test_orphan_drop,
+    reset_test_allocation_attempts, reset_test_name_lookups, reset_test_stage_capacity,
+    reset_test_write_costs, set_test_stage_capacity, test_allocation_attempts,
+    test_mount_allocation_state, test_name_lookups, test_pipeline_depths, test_write_costs,
     wait_test_orphan_drop_admission,
 };
 #[cfg(test)]
 use cost_test_support::{
     fail_test_metadata_owner, record_test_allocation_attempt,
     record_test_allocation_materialization, record_test_allocation_metadata_bytes,
-    record_test_home_write, record_test_journal_write, record_test_transaction,
-    test_orphan_drop_admission, test_stage_capacity,
+    record_test_home_write, record_test_journal_write, record_test_name_lookup,
+    record_test_transaction, test_orphan_drop_admission, test_stage_capacity,
 };
 use directory_cursor::{DirectoryCursor, RecordPosition};
 use inode::Ext2Inode;
 use journal::{Journal, JournalOwner, MutationGuard};
 use metadata_cache::MetadataBlockCache;
+use name_cache::{NameCache, NameLookup};
 
 fn link_count_error(error: link_count::LinkCountError) -> FileSystemError {
     match error {
@@ -138,6 +145,8 @@ fn record_test_journal_write() {}
 #[cfg(not(test))]
 fn record_test_transaction() {}
 #[cfg(not(test))]
+fn record_test_name_lookup(_: bool) {}
+#[cfg(not(test))]
 fn test_orphan_drop_admission(_: u32) {}
 #[cfg(not(test))]
 const fn test_stage_capacity(capacity: usize) -> usize {
@@ -332,7 +341,15 @@ pub(crate) struct Ext2FileSystem {
     // bytes. Writes update an existing identity; free/abort invalidate it, preventing block reuse
     // from observing an old object's metadata.
     metadata_cache: Mutex<MetadataBlockCache>,
+    // OWNER: ext2 directory entry writers alone invalidate names after the block write is visible;
+    // lookup admits a scan result only if no invalidation intervened, so a racing scan of the old
+    // directory image cannot republish a removed name or hide a created one.
+    name_cache: Mutex<NameCache>,
     inode_cache: Mutex<FallibleMap<u32, Weak<Ext2Inode>>>,
+    // OWNER: 本 filesystem 的 read/home-write 流水共享该 device slot 预约；raw devfs 读只发同步
+    // request。计数为全部流水等待 slot 时可能持有的 token 总数，保持小于 `queue_depth`，
+    // 否则多条流水各持部分 slot 并同时等待时，没有任何 token 会被 complete。
+    pipeline_slots: AtomicUsize,
     self_ref: spin::Mutex<Weak<Ext2FileSystem>>,
 }
 
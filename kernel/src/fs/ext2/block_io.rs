@@ -1,5 +1,223 @@
 use super::*;
 
+/// 单条 read/home-write 流水最多持有的 device request token 数。
+const BLOCK_PIPELINE_DEPTH: usize = 4;
+/// 单条流水最多占用 device-wide slot 的 1/BLOCK_PIPELINE_SHARE。
+const BLOCK_PIPELINE_SHARE: usize = 4;
+
+impl Ext2FileSystem {
+    /// @description 为一条流水预约 device slot，返回其可同时持有的 token 数。
+    ///
+    /// 流水提交第 depth+1 个 request 前先 complete 最旧 token，因此在 submit 中等待 slot 时至多
+    /// 持有 depth-1 个。1. 份额只取 device-wide `queue_depth` 的一小份，其余留给同步 block I/O；
+    /// 2. 全部流水预约的 depth-1 之和小于 `queue_depth`，任一时刻至少一个 slot 空闲或被不会再等待
+    /// 的 request 占用；预约耗尽时 depth 退化为 1，提交前不持有 token，与同步 I/O 等价。
+    /// @return 1..=BLOCK_PIPELINE_DEPTH；流水 Drop 时以同值调用 `release_pipeline`。
+    fn reserve_pipeline(&self) -> usize {
+        let slots = self.device.queue_depth();
+        let share = (slots / BLOCK_PIPELINE_SHARE).clamp(1, BLOCK_PIPELINE_DEPTH) - 1;
+        let limit = slots.saturating_sub(1);
+        let grant = |held: usize| share.min(limit.saturating_sub(held));
+        match self
+            .pipeline_slots
+            .try_update(Ordering::Relaxed, Ordering::Relaxed, |held| {
+                let extra = grant(held);
+                (extra != 0).then_some(held + extra)
+            }) {
+            Ok(previous) => grant(previous) + 1,
+            Err(_) => 1,
+        }
+    }
+
+    fn release_pipeline(&self, depth: usize) {
+        self.pipeline_slots.fetch_sub(depth - 1, Ordering::Relaxed);
+    }
+}
+
+/// @description 以 per-caller slot 份额流水提交互不排序的 home block 写入。
+///
+/// caller 只在 `finish` 之后以 FLUSH 建立持久化边界；token ring 在提交第 depth+1 个
+/// request 前 complete 最旧 token，避免等待自己持有的 device slot。
+pub(super) struct HomeWritePipeline<'fs> {
+    fs: &'fs Ext2FileSystem,
+    depth: usize,
+    pending: [Option<BlockRequest>; BLOCK_PIPELINE_DEPTH],
+    next: usize,
+    result: Result<(), FileSystemError>,
+}
+
+impl<'fs> HomeWritePipeline<'fs> {
+    pub(super) fn new(fs: &'fs Ext2FileSystem) -> Self {
+        Self {
+            fs,
+            depth: fs.reserve_pipeline(),
+            pending: core::array::from_fn(|_| None),
+            next: 0,
+            result: Ok(()),
+        }
+    }
+
+    /// @description 提交一个完整 filesystem block；首个错误后不再提交。
+    ///
+    /// @param fs_block_id 目标 home block。
+    /// @param buf 完整 block image；返回前已复制进 device DMA buffer，caller 可立即复用。
+    pub(super) fn submit(&mut self, fs_block_id: u32, buf: &[u8]) {
+        if self.result.is_ok() {
+            self.result = self.try_submit(fs_block_id, buf);
+        }
+    }
+
+    fn try_submit(&mut self, fs_block_id: u32, buf: &[u8]) -> Result<(), FileSystemError> {
+        let fs = self.fs;
+        let device_block_size = fs.device.block_size();
+        if fs.block_size < device_block_size {
+            // 小于 device block 的 image 需要 read-modify-write，只能同步完成。
+            return fs.write_fs_block_home(fs_block_id, buf);
+        }
+        record_test_home_write();
+        if fs_block_id >= fs.superblock.lock().s_blocks_count {
+            return Err(FileSystemError::InvalidFileSystem);
+        }
+        if buf.len() != fs.block_size {
+            return Err(FileSystemError::IoError);
+        }
+        let count = fs.block_size / device_block_size;
+        let first = fs_block_id as usize * count;
+        for (index, bytes) in buf.chunks_exact(device_block_size).enumerate() {
+            if let Some(oldest) = self.pending[self.next].take() {
+                fs.device.complete(oldest).map_err(block_error)?;
+            }
+            self.pending[self.next] = Some(
+                fs.device
+                    .submit_write(first + index, bytes)
+                    .map_err(block_error)?,
+            );
+            self.next = (self.next + 1) % self.depth;
+        }
+        fs.metadata_cache.lock().update_if_present(fs_block_id, buf);
+        Ok(())
+    }
+
+    /// @description complete 全部仍持有的 token，并返回流水中的首个错误。
+    pub(super) fn finish(mut self) -> Result<(), FileSystemError> {
+        self.complete_pending();
+        core::mem::replace(&mut self.result, Ok(()))
+    }
+
+    fn complete_pending(&mut self) {
+        for request in self.pending.iter_mut().filter_map(Option::take) {
+            let completed = self.fs.device.complete(request).map_err(block_error);
+            if self.result.is_ok() {
+                self.result = completed;
+            }
+        }
+    }
+}
+
+impl Drop for HomeWritePipeline<'_> {
+    fn drop(&mut self) {
+        // caller 因编码错误提前返回时仍须归还 device slot；否则后续 submit 会永久等待 capacity。
+        self.complete_pending();
+        self.fs.release_pipeline(self.depth);
+    }
+}
+
+/// @description 以 per-caller slot 份额流水读取完整 data block 到 caller 缓冲区。
+///
+/// 每个 token 持有自己的目标 slice；ring 复用 slot 前 complete 最旧 token，Drop 归还剩余 slot。
+/// caller 在流水存活期间不得发起同步 device I/O（block map、partial block），应先全部解析。
+pub(super) struct ReadPipeline<'fs, 'buf> {
+    fs: &'fs Ext2FileSystem,
+    depth: usize,
+    pending: [Option<(BlockRequest, &'buf mut [u8])>; BLOCK_PIPELINE_DEPTH],
+    next: usize,
+    result: Result<(), FileSystemError>,
+}
+
+impl<'fs, 'buf> ReadPipeline<'fs, 'buf> {
+    pub(super) fn new(fs: &'fs Ext2FileSystem) -> Self {
+        Self {
+            fs,
+            depth: fs.reserve_pipeline(),
+            pending: core::array::from_fn(|_| None),
+            next: 0,
+            result: Ok(()),
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// @description 提交一个完整 filesystem block 读取；首个错误后不再提交。
+    ///
+    /// @param fs_block_id 源 block；journal 中已 staged 的 image 优先于 home location。
+    /// @param buf 完整 block 目标；只在 `finish` 成功后保证已填充。
+    pub(super) fn submit(&mut self, fs_block_id: u32, buf: &'buf mut [u8]) {
+        if self.result.is_ok() {
+            self.result = self.try_submit(fs_block_id, buf);
+        }
+    }
+
+    fn try_submit(&mut self, fs_block_id: u32, buf: &'buf mut [u8]) -> Result<(), FileSystemError> {
+        let fs = self.fs;
+        let device_block_size = fs.device.block_size();
+        if fs_block_id >= fs.superblock.lock().s_blocks_count {
+            return Err(FileSystemError::InvalidFileSystem);
+        }
+        if buf.len() != fs.block_size {
+            return Err(FileSystemError::IoError);
+        }
+        if fs.journal.lock().copy_staged(fs_block_id, buf) {
+            return Ok(());
+        }
+        if fs.block_size < device_block_size {
+            // 小于 device block 的 image 需要 bounce buffer 截取，只能同步完成。
+            return fs.read_fs_block_home(fs_block_id, buf);
+        }
+        let first = fs_block_id as usize * (fs.block_size / device_block_size);
+        for (index, bytes) in buf.chunks_exact_mut(device_block_size).enumerate() {
+            if let Some((oldest, target)) = self.pending[self.next].take() {
+                fs.device
+                    .complete_read(oldest, target)
+                    .map_err(block_error)?;
+            }
+            let request = fs.device.submit_read(first + index).map_err(block_error)?;
+            self.pending[self.next] = Some((request, bytes));
+            self.next = (self.next + 1) % self.depth;
+        }
+        Ok(())
+    }
+
+    /// @description complete 全部仍持有的 token，并返回流水中的首个错误。
+    pub(super) fn finish(mut self) -> Result<(), FileSystemError> {
+        self.complete_pending();
+        core::mem::replace(&mut self.result, Ok(()))
+    }
+
+    fn complete_pending(&mut self) {
+        for (request, target) in self.pending.iter_mut().filter_map(Option::take) {
+            let completed = self
+                .fs
+                .device
+                .complete_read(request, target)
+                .map_err(block_error);
+            if self.result.is_ok() {
+                self.result = completed;
+            }
+        }
+    }
+}
+
+impl Drop for ReadPipeline<'_, '_> {
+    fn drop(&mut self) {
+        // mapping 错误提前返回时仍须归还 device slot；目标 slice 的内容此时不再被使用。
+        self.complete_pending();
+        self.fs.release_pipeline(self.depth);
+    }
+}
+
 impl Ext2FileSystem {
     /// Return one immutable directory/pointer metadata block under the filesystem-wide identity.
     pub(super) fn read_metadata_block(
@@ -22,6 +22,12 @@ static ALLOCATION_MATERIALIZATIONS: AtomicUsize = AtomicUsize::new(0);
 // OWNER: serialized host write-cost tests own the metadata-byte counter.
 #[cfg(test)]
 static ALLOCATION_METADATA_BYTES: AtomicUsize = AtomicUsize::new(0);
+// OWNER: serialized host lookup-cost tests own the name-cache hit counter.
+#[cfg(test)]
+static NAME_CACHE_HITS: AtomicUsize = AtomicUsize::new(0);
+// OWNER: serialized host lookup-cost tests own the name-cache miss counter.
+#[cfg(test)]
+static NAME_CACHE_MISSES: AtomicUsize = AtomicUsize::new(0);
 // OWNER: serialized host ENOSPC test owns this journal capacity override.
 #[cfg(test)]
 static STAGE_CAPACITY: AtomicUsize = AtomicUsize::new(usize::MAX);
@@ -131,6 +137,31 @@ pub(super) fn fail_test_metadata_owner() -> bool {
     FAIL_NEXT_METADATA_OWNER.swap(false, Ordering::Relaxed)
 }
 
+#[cfg(test)]
+pub(super) fn record_test_name_lookup(hit: bool) {
+    let counter = if hit {
+        &NAME_CACHE_HITS
+    } else {
+        &NAME_CACHE_MISSES
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+pub(crate) fn reset_test_name_lookups() {
+    NAME_CACHE_HITS.store(0, Ordering::Relaxed);
+    NAME_CACHE_MISSES.store(0, Ordering::Relaxed);
+}
+
+/// @description 返回自上次 reset 以来 name cache 的 `(hits, misses)`。
+#[cfg(test)]
+pub(crate) fn test_name_lookups() -> (usize, usize) {
+    (
+        NAME_CACHE_HITS.load(Ordering::Relaxed),
+        NAME_CACHE_MISSES.load(Ordering::Relaxed),
+    )
+}
+
 #[cfg(test)]
 pub(crate) fn clear_test_metadata_cache(fs: &Ext2FileSystem) {
     fs.metadata_cache.lock().clear();
@@ -209,3 +240,40 @@ impl TestMappedInode {
         Ok(block)
     }
 }
+
+/// @description 提交 `blocks` 的读取后不 finish 直接 drop 流水，返回 Drop 完成的 block image。
+#[cfg(test)]
+pub(crate) fn drop_test_read_pipeline(
+    fs: &Ext2FileSystem,
+    blocks: &[u32],
+) -> Result<Vec<Vec<u8>>, FileSystemError> {
+    let mut images = Vec::new();
+    for _ in blocks {
+        images.push(try_zeroed(fs.block_size)?);
+    }
+    let mut pipeline = super::block_io::ReadPipeline::new(fs);
+    for (block, image) in blocks.iter().zip(images.iter_mut()) {
+        pipeline.submit(*block, image);
+    }
+    drop(pipeline);
+    Ok(images)
+}
+
+/// @description 提交 home block 写入后不 finish 直接 drop 流水。
+#[cfg(test)]
+pub(crate) fn drop_test_write_pipeline(fs: &Ext2FileSystem, writes: &[(u32, Vec<u8>)]) {
+    let mut pipeline = super::block_io::HomeWritePipeline::new(fs);
+    for (block, image) in writes {
+        pipeline.submit(*block, image);
+    }
+    drop(pipeline);
+}
+
+/// @description 同时持有 `count` 条读流水，返回各自预约到的 depth。
+#[cfg(test)]
+pub(crate) fn test_pipeline_depths(fs: &Ext2FileSystem, count: usize) -> Vec<usize> {
+    let pipelines = (0..count)
+        .map(|_| super::block_io::ReadPipeline::new(fs))
+        .collect::<Vec<_>>();
+    pipelines.iter().map(|pipeline| pipeline.depth()).collect()
+}
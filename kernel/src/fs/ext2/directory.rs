@@ -25,6 +25,19 @@ impl Ext2Inode {
         child: u32,
         name: &[u8],
         kind: InodeType,
+    ) -> Result<(), FileSystemError> {
+        let result = self.insert_dir_entry_locked(mutation, child, name, kind);
+        // 目录块写入可见后才撤销名称；先撤销会让并发扫描把旧 negative 结果重新发布。
+        self.fs.name_cache.lock().invalidate(self.inode_num, name);
+        result
+    }
+
+    fn insert_dir_entry_locked(
+        &self,
+        mutation: &mut MutationGuard<'_>,
+        child: u32,
+        name: &[u8],
+        kind: InodeType,
     ) -> Result<(), FileSystemError> {
         let needed = align_up(Ext2DirEntry2Header::SIZE + name.len(), 4);
         let blocks = ceil_div(self.size() as usize, self.fs.block_size);
@@ -110,6 +123,16 @@ impl Ext2Inode {
     /// @return 被删除 entry 的 inode number，directory size 不收缩。
     /// @errors entry 不存在、record layout、block mapping、journal 或 I/O 错误。
     pub(super) fn remove_dir_entry_locked(
+        &self,
+        mutation: &mut MutationGuard<'_>,
+        name: &[u8],
+    ) -> Result<u32, FileSystemError> {
+        let result = self.erase_dir_entry_locked(mutation, name);
+        self.fs.name_cache.lock().invalidate(self.inode_num, name);
+        result
+    }
+
+    fn erase_dir_entry_locked(
         &self,
         _mutation: &mut MutationGuard<'_>,
         name: &[u8],
@@ -233,6 +233,13 @@ impl Ext2Inode {
         *disk = Ext2InodeDisk::default();
         self.fs.write_inode_disk(self.inode_num, &disk)?;
         drop(disk);
+        if directory {
+            // inode number 复用为新目录前，撤销旧目录下残留的 negative entry。
+            self.fs
+                .name_cache
+                .lock()
+                .invalidate_directory(self.inode_num);
+        }
         self.fs.free_inode(self.inode_num, directory)
     }
 }
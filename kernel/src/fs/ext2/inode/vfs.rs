@@ -1,4 +1,5 @@
 use super::*;
+use crate::fs::ext2::block_io::ReadPipeline;
 
 impl Inode for Ext2Inode {
     fn filesystem_id(&self) -> usize {
@@ -41,38 +42,64 @@ impl Inode for Ext2Inode {
     }
 
     fn read_storage(&self, offset: u64, buf: &mut [u8]) -> Result<usize, FileSystemError> {
-        let mut done = 0usize;
+        self.read_storage_pages(offset, &mut [buf])
+    }
+
+    fn read_storage_pages(
+        &self,
+        offset: u64,
+        pages: &mut [&mut [u8]],
+    ) -> Result<usize, FileSystemError> {
         let ino = self.disk.lock();
         let size = usize::try_from(Self::disk_size(&ino))
             .map_err(|_| FileSystemError::InvalidOperation)?;
         drop(ino);
         let offset = usize::try_from(offset).map_err(|_| FileSystemError::InvalidOperation)?;
-        if offset >= size || buf.is_empty() {
+        let requested = pages.iter().map(|page| page.len()).sum::<usize>();
+        if offset >= size || requested == 0 {
             return Ok(0);
         }
-        let to_read = cmp::min(buf.len(), size - offset);
+        let to_read = cmp::min(requested, size - offset);
         let bs = self.fs.block_size;
-        let mut cur_off = offset;
-        while done < to_read {
-            let blk_index = (cur_off / bs) as u32;
-            let blk_off = cur_off % bs;
-            let blk = self.map_block_sparse(blk_index)?;
-            let n = cmp::min(bs - blk_off, to_read - done);
-            if blk == 0 {
-                // This is a hole - fill with zeros
-                buf[done..done + n].fill(0);
-            } else if blk_off == 0 && n == bs {
-                // 完整对齐块直接读入 caller，避免 page-cache miss 为每个块分配并复制 Vec。
-                self.fs.read_fs_block(blk, &mut buf[done..done + n])?;
-            } else {
-                // Read from actual block
-                let mut b = try_zeroed(bs)?;
-                self.fs.read_fs_block(blk, &mut b)?;
-                buf[done..done + n].copy_from_slice(&b[blk_off..blk_off + n]);
+        let mut done = 0usize;
+        // 1. block map 与 partial block 的同步读取必须先于首个 submit_read 完成：流水持有的
+        //    token 只有本 task 的 finish 才会归还，持有期间的同步 I/O 会等待自己占用的 slot。
+        let mut full_blocks = Vec::new();
+        full_blocks
+            .try_reserve_exact(to_read / bs)
+            .map_err(|_| FileSystemError::OutOfMemory)?;
+        for page in pages.iter_mut() {
+            let length = cmp::min(page.len(), to_read - done);
+            let mut rest = &mut page[..length];
+            while !rest.is_empty() {
+                let cur_off = offset + done;
+                let blk_off = cur_off % bs;
+                let n = cmp::min(bs - blk_off, rest.len());
+                let (part, tail) = core::mem::take(&mut rest).split_at_mut(n);
+                rest = tail;
+                let blk = self.map_block_sparse((cur_off / bs) as u32)?;
+                if blk == 0 {
+                    // This is a hole - fill with zeros
+                    part.fill(0);
+                } else if blk_off == 0 && n == bs {
+                    full_blocks.push((blk, part));
+                } else {
+                    let mut b = try_zeroed(bs)?;
+                    self.fs.read_fs_block(blk, &mut b)?;
+                    part.copy_from_slice(&b[blk_off..blk_off + n]);
+                }
+                done += n;
             }
-            done += n;
-            cur_off += n;
+            if done == to_read {
+                break;
+            }
+        }
+        // 2. 完整对齐块进入同一流水，device 并发服务多个 request；首错后 finish 归还 slot。
+        let mut pipeline = ReadPipeline::new(&self.fs);
+        for (blk, part) in full_blocks {
+            pipeline.submit(blk, part);
         }
+        pipeline.finish()?;
         // 1. Linux relatime avoids a journal transaction on every page-cache miss.
         let now = Self::now();
         let inode = self.disk.lock();
@@ -170,6 +197,7 @@ impl Inode for Ext2Inode {
                 2 => InodeType::Directory,
                 7 => InodeType::SymLink,
                 3 => InodeType::CharacterDevice,
+                4 => InodeType::BlockDevice,
                 5 => InodeType::Fifo,
                 6 => InodeType::Socket,
                 _ => InodeType::File,
@@ -189,6 +217,18 @@ impl Inode for Ext2Inode {
         if !matches!(self.inode_type(), InodeType::Directory) {
             return Err(FileSystemError::NotDirectory);
         }
+        let generation = match self.fs.name_cache.lock().lookup(self.inode_num, name) {
+            NameLookup::Found(number) => {
+                record_test_name_lookup(true);
+                return Ext2Inode::load(self.fs.clone(), number).map(|x| x as Arc<dyn Inode>);
+            }
+            NameLookup::Missing => {
+                record_test_name_lookup(true);
+                return Err(FileSystemError::NotFound);
+            }
+            NameLookup::Uncached(generation) => generation,
+        };
+        record_test_name_lookup(false);
         let mut found: Option<u32> = None;
         self.dir_iterate_from(0, |_next_cursor, hdr, name_bytes| {
             if hdr.inode != 0 && name_bytes == name {
@@ -197,6 +237,10 @@ impl Inode for Ext2Inode {
             }
             Ok(DirectoryVisit::Continue)
         })?;
+        self.fs
+            .name_cache
+            .lock()
+            .insert_if_unchanged(generation, self.inode_num, name, found);
         if let Some(ino) = found {
             return Ext2Inode::load(self.fs.clone(), ino).map(|x| x as Arc<dyn Inode>);
         }
@@ -215,7 +259,7 @@ impl Inode for Ext2Inode {
         Self::validate_name(name)?;
         if !matches!(
             kind,
-            InodeType::File | InodeType::Directory | InodeType::Socket
+            InodeType::File | InodeType::Directory | InodeType::Socket | InodeType::Fifo
         ) {
             return Err(FileSystemError::InvalidOperation);
         }
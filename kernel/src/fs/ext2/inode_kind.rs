@@ -8,6 +8,7 @@ pub(super) fn from_mode(mode: u16) -> InodeType {
         0x1000 => InodeType::Fifo,
         0x2000 => InodeType::CharacterDevice,
         0x4000 => InodeType::Directory,
+        0x6000 => InodeType::BlockDevice,
         0xA000 => InodeType::SymLink,
         0xC000 => InodeType::Socket,
         _ => InodeType::File,
@@ -22,6 +23,7 @@ pub(super) fn file_type(kind: InodeType) -> u8 {
         InodeType::Fifo => 5,
         InodeType::CharacterDevice => 3,
         InodeType::Directory => 2,
+        InodeType::BlockDevice => 4,
         InodeType::File => 1,
         InodeType::SymLink => 7,
         InodeType::Socket => 6,
@@ -29,13 +31,14 @@ pub(super) fn file_type(kind: InodeType) -> u8 {
 }
 
 /// @description 编码 create transaction 的 inode type 与 permission bits。
-/// @param kind 已由 caller 限制为 regular、directory 或 socket。
+/// @param kind 已由 caller 限制为 regular、directory、socket 或 FIFO。
 /// @param permissions VFS 已应用 umask/setgid 的 mode。
 /// @return ext2 packed `i_mode`。
 pub(super) fn create_mode(kind: InodeType, permissions: u32) -> u16 {
     let kind = match kind {
         InodeType::Directory => 0x4000,
         InodeType::Socket => 0xC000,
+        InodeType::Fifo => 0x1000,
         InodeType::File => 0x8000,
         _ => unreachable!("unsupported ext2 create kind crossed validation"),
     };
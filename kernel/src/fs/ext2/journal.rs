@@ -1,6 +1,7 @@
 use alloc::{sync::Arc, vec::Vec};
 
 use super::allocation_dirty::AllocationDirty;
+use super::block_io::HomeWritePipeline;
 use super::journal_layout::JournalLayout;
 use super::*;
 use crate::fallible_tree::FallibleMap;
@@ -196,6 +197,9 @@ impl Journal {
             for (block, _) in &writes.writes {
                 cache.invalidate(*block);
             }
+            drop(cache);
+            // 并发 lookup 可能已观察并缓存未提交的目录项；rollback 后只能整体丢弃。
+            fs.name_cache.lock().clear();
         }
         self.active = None;
     }
@@ -224,6 +228,18 @@ impl Journal {
         fs.write_fs_block_home(block, bytes)
     }
 
+    fn journal_submit(
+        &self,
+        pipeline: &mut HomeWritePipeline<'_>,
+        logical: usize,
+        bytes: &[u8],
+    ) -> Result<(), FileSystemError> {
+        record_test_journal_write();
+        let block = *self.blocks.get(logical).ok_or(FileSystemError::NoSpace)?;
+        pipeline.submit(block, bytes);
+        Ok(())
+    }
+
     fn write_state(
         &mut self,
         fs: &Ext2FileSystem,
@@ -287,9 +303,11 @@ impl Journal {
             }
         };
         if committed {
-            for (block, bytes) in replay {
-                fs.write_fs_block_home(block, &bytes)?;
+            let mut pipeline = HomeWritePipeline::new(fs);
+            for (block, bytes) in &replay {
+                pipeline.submit(*block, bytes);
             }
+            pipeline.finish()?;
             fs.device.flush().map_err(block_error)?;
         }
         self.sequence = sequence.wrapping_add(1);
@@ -320,6 +338,8 @@ impl Journal {
         // 若在 durable state publication 后才尝试 heap allocation，OOM 会留下无法安全重试的半事务。
         let mut scratch_storage = [0u8; PAGE_SIZE];
         let scratch = &mut scratch_storage[..fs.block_size];
+        // descriptor/data 之间无序；submit 已把 scratch 复制进 DMA buffer，可立即复用。
+        let mut pipeline = HomeWritePipeline::new(fs);
         let mut next_block = writes.first_key_value().map(|(&block, _)| block);
         while let Some(first_block) = next_block {
             scratch.fill(0);
@@ -349,7 +369,7 @@ impl Journal {
                 }
                 last_block = *block;
             }
-            self.journal_write(fs, cursor, scratch)?;
+            self.journal_submit(&mut pipeline, cursor, scratch)?;
             cursor += 1;
             for (_, bytes) in writes.iter_from(&first_block).take(count) {
                 let journal_bytes: &[u8] = if bytes[..4] == JBD2_MAGIC.to_be_bytes() {
@@ -359,11 +379,12 @@ impl Journal {
                 } else {
                     bytes
                 };
-                self.journal_write(fs, cursor, journal_bytes)?;
+                self.journal_submit(&mut pipeline, cursor, journal_bytes)?;
                 cursor += 1;
             }
             next_block = writes.successor(&last_block).map(|(&block, _)| block);
         }
+        pipeline.finish()?;
         // Descriptor 和全部 data image 必须先于 commit record 到达稳定存储。否则断电可留下
         // durable commit 与旧 journal slot data 的组合，recovery 会把旧 image 当成新事务 replay。
         fs.device.flush().map_err(block_error)?;
@@ -371,9 +392,11 @@ impl Journal {
         put_header(&mut *scratch, JBD2_COMMIT_BLOCK, sequence)?;
         self.journal_write(fs, cursor, scratch)?;
         fs.device.flush().map_err(block_error)?;
+        let mut pipeline = HomeWritePipeline::new(fs);
         for (block, bytes) in writes {
-            fs.write_fs_block_home(*block, bytes)?;
+            pipeline.submit(*block, bytes);
         }
+        pipeline.finish()?;
         fs.device.flush().map_err(block_error)?;
         self.sequence = sequence.wrapping_add(1);
         self.write_state(fs, 0, self.sequence)?;
@@ -401,8 +401,9 @@ impl Ext2FileSystem {
         }
 
         // Journal inode mapping may have populated caches before replay. Mount is still
-        // single-threaded here, so clear both identities before publishing the recovered owners.
+        // single-threaded here, so clear every identity before publishing the recovered owners.
         self.metadata_cache.lock().clear();
+        self.name_cache.lock().clear();
         self.inode_cache.lock().clear();
         *self.superblock.lock() = recovered;
         *self.groups.lock() = groups;
@@ -524,7 +525,9 @@ impl Ext2FileSystem {
             mutation: TaskMutex::new(()),
             journal: Mutex::new(JournalOwner::unavailable()),
             metadata_cache: Mutex::new(MetadataBlockCache::new()),
+            name_cache: Mutex::new(NameCache::new()),
             inode_cache: Mutex::new(FallibleMap::new()),
+            pipeline_slots: AtomicUsize::new(0),
             self_ref: spin::Mutex::new(Weak::new()),
         })
         .map_err(|_| FileSystemError::OutOfMemory)?;
@@ -0,0 +1,190 @@
+/// 固定容量；覆盖 shell PATH search 的多目录 negative 名称与常用 positive 名称。
+const CAPACITY: usize = 128;
+/// 与 Linux `DNAME_INLINE_LEN` 同量级；更长的名称直接走目录扫描，不占用 cache。
+const NAME_CAPACITY: usize = 32;
+
+#[derive(Clone, Copy)]
+struct Entry {
+    directory: u32,
+    name_length: u8,
+    name: [u8; NAME_CAPACITY],
+    /// 零表示 negative entry：名称在该目录中不存在。
+    inode: u32,
+    last_use: u64,
+}
+
+impl Entry {
+    fn matches(&self, directory: u32, name: &[u8]) -> bool {
+        self.directory == directory && self.name[..usize::from(self.name_length)] == *name
+    }
+}
+
+/// @description 目录 lookup 的查询结果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum NameLookup {
+    /// 名称映射到该 inode number。
+    Found(u32),
+    /// 缓存的 negative entry。
+    Missing,
+    /// 未缓存；携带扫描目录前的 generation，供 `insert_if_unchanged` 拒绝过期结果。
+    Uncached(u64),
+}
+
+/// Filesystem-owned `(directory inode, name) → inode number` lookup cache with negative entries.
+///
+/// entry 只保存 inode number，不保活 `Ext2Inode`，因此不影响 unlink 的 external-owner 判定。
+pub(super) struct NameCache {
+    entries: [Option<Entry>; CAPACITY],
+    clock: u64,
+    generation: u64,
+}
+
+impl NameCache {
+    pub(super) const fn new() -> Self {
+        Self {
+            entries: [None; CAPACITY],
+            clock: 0,
+            generation: 0,
+        }
+    }
+
+    /// @description 查询 `directory` 中的 `name`。
+    pub(super) fn lookup(&mut self, directory: u32, name: &[u8]) -> NameLookup {
+        self.clock = self.clock.wrapping_add(1);
+        let clock = self.clock;
+        match self
+            .entries
+            .iter_mut()
+            .flatten()
+            .find(|entry| entry.matches(directory, name))
+        {
+            Some(entry) => {
+                entry.last_use = clock;
+                match entry.inode {
+                    0 => NameLookup::Missing,
+                    inode => NameLookup::Found(inode),
+                }
+            }
+            None => NameLookup::Uncached(self.generation),
+        }
+    }
+
+    /// @description 发布一次目录扫描结果；扫描期间发生过目录修改时丢弃。
+    /// @param inode 找到的 inode number；None 记录 negative entry。
+    pub(super) fn insert_if_unchanged(
+        &mut self,
+        generation: u64,
+        directory: u32,
+        name: &[u8],
+        inode: Option<u32>,
+    ) {
+        if self.generation != generation || name.len() > NAME_CAPACITY {
+            return;
+        }
+        self.clock = self.clock.wrapping_add(1);
+        let slot_index = self
+            .entries
+            .iter()
+            .position(|entry| entry.is_some_and(|entry| entry.matches(directory, name)))
+            .or_else(|| self.entries.iter().position(Option::is_none))
+            .or_else(|| {
+                self.entries
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| entry.map_or(0, |entry| entry.last_use))
+                    .map(|(index, _)| index)
+            })
+            .expect("name cache has a non-zero fixed capacity");
+        let mut bytes = [0; NAME_CAPACITY];
+        bytes[..name.len()].copy_from_slice(name);
+        self.entries[slot_index] = Some(Entry {
+            directory,
+            name_length: name.len() as u8,
+            name: bytes,
+            inode: inode.unwrap_or(0),
+            last_use: self.clock,
+        });
+    }
+
+    /// @description 目录项已被写入或删除后撤销该名称，并使进行中的扫描结果失效。
+    pub(super) fn invalidate(&mut self, directory: u32, name: &[u8]) {
+        self.generation = self.generation.wrapping_add(1);
+        if let Some(slot) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.is_some_and(|entry| entry.matches(directory, name)))
+        {
+            *slot = None;
+        }
+    }
+
+    /// @description 目录 inode 被释放时撤销其全部名称，避免 inode number 复用后命中旧 negative entry。
+    pub(super) fn invalidate_directory(&mut self, directory: u32) {
+        self.generation = self.generation.wrapping_add(1);
+        for slot in &mut self.entries {
+            if slot.is_some_and(|entry| entry.directory == directory) {
+                *slot = None;
+            }
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.entries = [None; CAPACITY];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(cache: &mut NameCache, directory: u32, name: &[u8], inode: Option<u32>) {
+        let NameLookup::Uncached(generation) = cache.lookup(directory, name) else {
+            panic!("expected a cache miss");
+        };
+        cache.insert_if_unchanged(generation, directory, name, inode);
+    }
+
+    #[test]
+    fn positive_and_negative_entries_hit_until_invalidated() {
+        let mut cache = NameCache::new();
+        scan(&mut cache, 2, b"bin", Some(12));
+        scan(&mut cache, 12, b"sh", None);
+        assert_eq!(cache.lookup(2, b"bin"), NameLookup::Found(12));
+        assert_eq!(cache.lookup(12, b"sh"), NameLookup::Missing);
+        cache.invalidate(12, b"sh");
+        assert!(matches!(cache.lookup(12, b"sh"), NameLookup::Uncached(_)));
+        scan(&mut cache, 12, b"ls", None);
+        cache.invalidate_directory(12);
+        assert!(matches!(cache.lookup(12, b"ls"), NameLookup::Uncached(_)));
+        assert_eq!(cache.lookup(2, b"bin"), NameLookup::Found(12));
+    }
+
+    #[test]
+    fn mutation_during_scan_rejects_stale_result() {
+        let mut cache = NameCache::new();
+        let NameLookup::Uncached(generation) = cache.lookup(2, b"new") else {
+            panic!("expected a cache miss");
+        };
+        cache.invalidate(2, b"new");
+        cache.insert_if_unchanged(generation, 2, b"new", None);
+        assert!(matches!(cache.lookup(2, b"new"), NameLookup::Uncached(_)));
+    }
+
+    #[test]
+    fn capacity_evicts_least_recent_and_skips_long_names() {
+        let mut cache = NameCache::new();
+        for directory in 0..CAPACITY as u32 {
+            scan(&mut cache, directory, b"x", None);
+        }
+        assert_eq!(cache.lookup(0, b"x"), NameLookup::Missing);
+        scan(&mut cache, CAPACITY as u32, b"x", Some(5));
+        assert!(matches!(cache.lookup(1, b"x"), NameLookup::Uncached(_)));
+        assert_eq!(cache.lookup(0, b"x"), NameLookup::Missing);
+        scan(&mut cache, 0, &[b'n'; NAME_CAPACITY + 1], None);
+        assert!(matches!(
+            cache.lookup(0, &[b'n'; NAME_CAPACITY + 1]),
+            NameLookup::Uncached(_)
+        ));
+    }
+}
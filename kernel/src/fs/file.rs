@@ -25,7 +25,8 @@ use position::FilePosition;
 
 use super::{
     AccessIdentity, DeviceKind, Epoll, EpollMemberships, FileSystemError, FileSystemStatistics,
-    Inode, OpenedFile, ReadinessSource, ReadinessSources, vfs,
+    Inode, InodeType, OpenedFile, ReadinessSource, ReadinessSources, page_cache::ReadaheadWindow,
+    vfs,
 };
 use crate::{
     ipc::{EventFd, PipeEnd},
@@ -67,9 +68,27 @@ pub(crate) enum OpenFileKind {
     Socket(Arc<Socket>),
     Epoll(Arc<Epoll>),
     EventFd(Arc<EventFd>),
+    PerfEvent(Arc<dyn PerfEvent>),
     Inode(Arc<OpenedFile>),
 }
 
+/// @description `perf_event_open` 计数器的 OFD 控制命令；与 Linux `PERF_EVENT_IOC_*` 一一对应。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PerfEventControl {
+    Enable,
+    Disable,
+    Reset,
+}
+
+/// @description perf counter 文件后端 seam；计数 owner 属于 task 调度域，fs 只转发 OFD 操作。
+pub(crate) trait PerfEvent: Send + Sync {
+    /// @description 读取 enable 期间累计的事件数，包含调用线程尚未提交的 active slice。
+    fn read(&self) -> u64;
+
+    /// @description 执行一次 enable/disable/reset；重复 enable 或 disable 为幂等操作。
+    fn control(&self, control: PerfEventControl);
+}
+
 /// @description console 文件后端 seam；具体 platform adapter 只在 composition root 装配。
 pub(crate) trait Console: Send + Sync {
     /// @description 非阻塞读取当前 IRQ ring 中已有 console bytes。
@@ -96,6 +115,9 @@ pub(crate) trait Console: Send + Sync {
 pub(crate) struct OpenFileDescription {
     pub(crate) kind: OpenFileKind,
     position: FilePosition,
+    // OWNER: OFD 拥有顺序访问检测；dup/fork 共享 OFD 时与 Linux file_ra_state 一样共享 window。
+    // 只有 regular-file OFD 持有 window；pipe、socket、epoll 等没有 page-cache 顺序读。
+    pub(crate) readahead: Option<Mutex<ReadaheadWindow>>,
     pub(crate) flags: Mutex<u32>,
     character_opened: Option<Arc<OpenedFile>>,
     pub(super) epoll_memberships: EpollMemberships,
@@ -158,18 +180,20 @@ impl OpenFileDescription {
             OpenFileKind::Inode(_) => result = events & (INPUT | OUTPUT),
             OpenFileKind::Character(device) => result = device.poll_events(events),
             OpenFileKind::Pipe(endpoint) => {
-                let state = endpoint.pipe().poll_state(endpoint.direction());
-                if events & INPUT != 0 && state.readable {
-                    result |= INPUT;
-                }
-                if events & OUTPUT != 0 && state.writable {
-                    result |= OUTPUT;
-                }
-                if state.error {
-                    result |= ERROR;
-                }
-                if state.hangup {
-                    result |= HANGUP;
+                for &direction in endpoint.directions() {
+                    let state = endpoint.pipe().poll_state(direction);
+                    if events & INPUT != 0 && state.readable {
+                        result |= INPUT;
+                    }
+                    if events & OUTPUT != 0 && state.writable {
+                        result |= OUTPUT;
+                    }
+                    if state.error {
+                        result |= ERROR;
+                    }
+                    if state.hangup {
+                        result |= HANGUP;
+                    }
                 }
             }
             OpenFileKind::Socket(socket) => {
@@ -203,6 +227,8 @@ impl OpenFileDescription {
                     result |= OUTPUT;
                 }
             }
+            // Linux perf_poll 在未 mmap ring buffer 时固定报告 POLLHUP。
+            OpenFileKind::PerfEvent(_) => result = HANGUP,
         }
         result
     }
@@ -214,19 +240,22 @@ impl OpenFileDescription {
     pub(crate) fn readiness_generation(&self, events: i16) -> u64 {
         match &self.kind {
             OpenFileKind::Character(device) => device.readiness_generation(),
-            OpenFileKind::Pipe(endpoint) => {
-                endpoint.pipe().readiness_generation(endpoint.direction())
-            }
+            OpenFileKind::Pipe(endpoint) => endpoint
+                .directions()
+                .iter()
+                .map(|&direction| endpoint.pipe().readiness_generation(direction))
+                .max()
+                .unwrap_or(0),
             OpenFileKind::Socket(socket) => socket.readiness_generation(events),
             OpenFileKind::Epoll(epoll) => epoll.readiness_generation(),
             OpenFileKind::EventFd(event) => event.readiness_generation(events),
-            OpenFileKind::Inode(_) => 0,
+            OpenFileKind::PerfEvent(_) | OpenFileKind::Inode(_) => 0,
         }
     }
 
     /// @description 判断 backend 是否提供可注册 wait source，而非仅提供同步 poll 结果。
     ///
-    /// @return 可加入 epoll 返回 true；regular inode/null/zero 返回 false 并映射 EPERM。
+    /// @return 可加入 epoll 返回 true；regular inode/null/zero/perf counter 返回 false 并映射 EPERM。
     pub(crate) fn epoll_pollable(&self) -> bool {
         match &self.kind {
             OpenFileKind::Character(device) => device.epoll_pollable(),
@@ -234,7 +263,7 @@ impl OpenFileDescription {
             | OpenFileKind::Socket(_)
             | OpenFileKind::Epoll(_)
             | OpenFileKind::EventFd(_) => true,
-            OpenFileKind::Inode(_) => false,
+            OpenFileKind::PerfEvent(_) | OpenFileKind::Inode(_) => false,
         }
     }
 
@@ -280,10 +309,11 @@ impl OpenFileDescription {
                     crate::ipc::PipeDirection::Read,
                 ));
             }
-            OpenFileKind::Pipe(endpoint) => sources.push(ReadinessSource::pipe(
-                &endpoint.pipe(),
-                endpoint.direction(),
-            )),
+            OpenFileKind::Pipe(endpoint) => {
+                for &direction in endpoint.directions() {
+                    sources.push(ReadinessSource::pipe(&endpoint.pipe(), direction));
+                }
+            }
             OpenFileKind::Socket(socket) => {
                 let (socket_sources, _) = socket.wait_sources(events);
                 for source in socket_sources.into_iter().flatten() {
@@ -338,6 +368,7 @@ impl OpenFileDescription {
                 pty: None,
             }),
             position: FilePosition::new(),
+            readahead: None,
             flags: Mutex::new(flags),
             character_opened: Some(backing_opened),
             epoll_memberships: EpollMemberships::new(),
@@ -364,6 +395,7 @@ impl OpenFileDescription {
         Arc::try_new(Self {
             kind: OpenFileKind::Character(device),
             position: FilePosition::new(),
+            readahead: None,
             flags: Mutex::new(flags),
             character_opened: Some(backing_opened),
             epoll_memberships: EpollMemberships::new(),
@@ -373,9 +405,12 @@ impl OpenFileDescription {
     }
 
     pub(crate) fn inode(opened: Arc<OpenedFile>, flags: u32) -> Result<Arc<Self>, ()> {
+        let readahead = (opened.inode().inode_type() == InodeType::File)
+            .then(|| Mutex::new(ReadaheadWindow::new()));
         Arc::try_new(Self {
             kind: OpenFileKind::Inode(opened),
             position: FilePosition::new(),
+            readahead,
             flags: Mutex::new(flags),
             character_opened: None,
             epoll_memberships: EpollMemberships::new(),
@@ -388,6 +423,7 @@ impl OpenFileDescription {
         Arc::try_new(Self {
             kind: OpenFileKind::Pipe(endpoint),
             position: FilePosition::new(),
+            readahead: None,
             flags: Mutex::new(flags),
             character_opened: None,
             epoll_memberships: EpollMemberships::new(),
@@ -400,6 +436,7 @@ impl OpenFileDescription {
         let ofd = Arc::try_new(Self {
             kind: OpenFileKind::Socket(socket.clone()),
             position: FilePosition::new(),
+            readahead: None,
             flags: Mutex::new(flags),
             character_opened: None,
             epoll_memberships: EpollMemberships::new(),
@@ -415,6 +452,7 @@ impl OpenFileDescription {
         Arc::try_new(Self {
             kind: OpenFileKind::Epoll(epoll),
             position: FilePosition::new(),
+            readahead: None,
             flags: Mutex::new(O_RDWR),
             character_opened: None,
             epoll_memberships: EpollMemberships::new(),
@@ -427,6 +465,20 @@ impl OpenFileDescription {
         Arc::try_new(Self {
             kind: OpenFileKind::EventFd(event),
             position: FilePosition::new(),
+            readahead: None,
+            flags: Mutex::new(O_RDWR | flags),
+            character_opened: None,
+            epoll_memberships: EpollMemberships::new(),
+            descriptor_refs: AtomicUsize::new(0),
+        })
+        .map_err(|_| ())
+    }
+
+    pub(crate) fn perf_event(event: Arc<dyn PerfEvent>, flags: u32) -> Result<Arc<Self>, ()> {
+        Arc::try_new(Self {
+            kind: OpenFileKind::PerfEvent(event),
+            position: FilePosition::new(),
+            readahead: None,
             flags: Mutex::new(O_RDWR | flags),
             character_opened: None,
             epoll_memberships: EpollMemberships::new(),
@@ -442,7 +494,8 @@ impl OpenFileDescription {
             OpenFileKind::Pipe(_)
             | OpenFileKind::Socket(_)
             | OpenFileKind::Epoll(_)
-            | OpenFileKind::EventFd(_) => None,
+            | OpenFileKind::EventFd(_)
+            | OpenFileKind::PerfEvent(_) => None,
         }
     }
 
@@ -455,7 +508,8 @@ impl OpenFileDescription {
             OpenFileKind::Pipe(_)
             | OpenFileKind::Socket(_)
             | OpenFileKind::Epoll(_)
-            | OpenFileKind::EventFd(_) => None,
+            | OpenFileKind::EventFd(_)
+            | OpenFileKind::PerfEvent(_) => None,
         }
     }
 
@@ -486,7 +540,7 @@ impl OpenFileDescription {
                 fragment_size: 4096,
                 flags: 0x20,
             }),
-            OpenFileKind::Epoll(_) | OpenFileKind::EventFd(_) => {
+            OpenFileKind::Epoll(_) | OpenFileKind::EventFd(_) | OpenFileKind::PerfEvent(_) => {
                 Err(FileSystemError::InvalidFileSystem)
             }
         }
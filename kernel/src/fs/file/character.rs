@@ -95,6 +95,8 @@ impl CharacterDevice {
                 file: crate::input::open(usize::from(index))
                     .map_err(|_| FileSystemError::OutOfMemory)?,
             },
+            // block node 以 inode OFD 打开，不经过 character backend。
+            DeviceKind::Block { .. } => return Err(FileSystemError::InvalidOperation),
         })
     }
 
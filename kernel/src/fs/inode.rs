@@ -13,9 +13,10 @@ pub(crate) enum InodeType {
     CharacterDevice = 3,
     Fifo = 4,
     Socket = 5,
+    BlockDevice = 6,
 }
 
-/// @description devfs inode 与打开后的 character OFD 共享的标准设备 identity。
+/// @description devfs inode 与打开后的 device OFD 共享的标准设备 identity。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum DeviceKind {
     Null,
@@ -29,10 +30,15 @@ pub(crate) enum DeviceKind {
     PtySlave(u32),
     DriCard0,
     InputEvent(u16),
+    /// VirtIO block disk index 与分区号；分区号零为整盘。
+    Block {
+        disk: u8,
+        partition: u8,
+    },
 }
 
 impl DeviceKind {
-    /// @description 返回 Linux conventional character-device major/minor。
+    /// @description 返回 Linux conventional device major/minor。
     pub(crate) fn numbers(self) -> (u32, u32) {
         match self {
             Self::Null => (1, 3),
@@ -46,6 +52,8 @@ impl DeviceKind {
             Self::PtySlave(index) => (136 + index / 256, index % 256),
             Self::DriCard0 => (226, 0),
             Self::InputEvent(index) => (13, 64 + u32::from(index)),
+            // virtio-blk 的动态 major 在 QEMU virt 上固定为首个空闲值 254，每盘 16 个 minor。
+            Self::Block { disk, partition } => (254, u32::from(disk) * 16 + u32::from(partition)),
         }
     }
 
@@ -62,6 +70,9 @@ impl DeviceKind {
             Self::PtySlave(index) => 0x1_0000 + u64::from(index),
             Self::DriCard0 => 13,
             Self::InputEvent(index) => 0x100 + u64::from(index),
+            Self::Block { disk, partition } => {
+                0x2_0000 + u64::from(disk) * 16 + u64::from(partition)
+            }
         }
     }
 
@@ -75,6 +86,7 @@ impl DeviceKind {
             | Self::Tty
             | Self::Ptmx
             | Self::DriCard0 => 0o020666,
+            Self::Block { .. } => 0o060660,
         }
     }
 }
@@ -146,6 +158,27 @@ pub(crate) trait Inode: Send + Sync {
 
     fn read_storage(&self, offset: u64, buf: &mut [u8]) -> Result<usize, FileSystemError>;
 
+    /// @description 把从 offset 开始的连续 storage bytes 依次读入多个互不相邻的 page buffer。
+    /// @param offset 首个 segment 对应的文件 byte offset。
+    /// @param pages 按文件顺序排列的目标 segment。
+    /// @return 实际读取的总字节数；短于 segment 总长表示到达 EOF。
+    /// @note 默认逐段调用 read_storage；block-backed adapter 覆盖为一次流水提交全部 block。
+    fn read_storage_pages(
+        &self,
+        offset: u64,
+        pages: &mut [&mut [u8]],
+    ) -> Result<usize, FileSystemError> {
+        let mut done = 0usize;
+        for page in pages.iter_mut() {
+            let read = self.read_storage(offset + done as u64, page)?;
+            done += read;
+            if read < page.len() {
+                break;
+            }
+        }
+        Ok(done)
+    }
+
     /// @description 读取 symbolic-link 的原始 target bytes，不追加 NUL。
     /// @return symbolic-link 返回完整 target；其他 inode 默认返回 InvalidOperation。
     fn read_link(&self) -> Result<Vec<u8>, FileSystemError> {
@@ -29,12 +29,13 @@ pub(crate) use file::{
     CancelledFileReservation, CharacterDevice, Console, DetachedFileDescriptor,
     FileDescriptorError, FileDescriptorTable, KmsgDeviceRead, MAX_FILE_DESCRIPTORS, O_ACCMODE,
     O_APPEND, O_CLOEXEC, O_NONBLOCK, O_RDONLY, O_RDWR, O_WRONLY, OpenFileDescription, OpenFileKind,
-    Terminal, TerminalAccess, TerminalRead, TerminalReadMode, character_write_chunk,
+    PerfEvent, PerfEventControl, Terminal, TerminalAccess, TerminalRead, TerminalReadMode,
+    character_write_chunk,
 };
 pub(crate) use inode::{DeviceKind, Inode, InodeMetadata, InodeType, StorageWriter};
 pub(crate) use page_cache::{
-    RegularFile, RegularFileWrite, allocate, mapping, statistics as page_cache_statistics,
-    sync_all, sync_inode, truncate,
+    ReadaheadWindow, RegularFile, RegularFileWrite, allocate, mapping,
+    statistics as page_cache_statistics, sync_all, sync_inode, truncate,
 };
 pub(crate) use permission::{AccessIdentity, CreateMetadata, OwnerModeChange};
 pub(crate) use procfs::{
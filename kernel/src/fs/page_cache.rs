@@ -11,10 +11,13 @@ use crate::sync::{TaskMutex, TaskMutexGuard, TaskMutexWaitPreparation};
 
 use super::{FileSystemError, Inode, InodeType};
 
+mod readahead;
+mod readahead_fill;
 mod reclaim;
 mod regular_write;
 mod writeback;
 mod writeback_batch;
+pub(crate) use readahead::ReadaheadWindow;
 use reclaim::CachedPages;
 use writeback_batch::WRITEBACK_BATCH_PAGES;
 
@@ -200,7 +203,11 @@ impl CachedFile {
         Ok((page, available))
     }
 
-    fn page_with_storage(&self, index: u64) -> Result<(Arc<CachedPage>, usize), FileSystemError> {
+    fn page_with_storage(
+        &self,
+        index: u64,
+        readahead_pages: usize,
+    ) -> Result<(Arc<CachedPage>, usize), FileSystemError> {
         if let Some(page) = self.pages.lock().entries.get(&index).cloned() {
             return Ok((page, 0));
         }
@@ -209,7 +216,7 @@ impl CachedFile {
             .operation
             .lock()
             .map_err(|_| FileSystemError::OutOfMemory)?;
-        self.page_after_operation_lock(index, &operation)
+        self.fill_window(index, readahead_pages, &operation)
     }
 
     fn fault_page(&self, index: u64) -> Result<Arc<CachedPage>, FileSystemError> {
@@ -347,14 +354,16 @@ pub(crate) struct RegularFileWrite<'a> {
 
 impl RegularFile {
     /// @description 将 regular inode 解析为持久 page-cache owner 或只读动态快照。
-    /// @param inode 目标 regular inode。
+    /// @param inode 目标 regular inode，或按 byte offset 直读设备的 devfs block node。
     /// @return 可在本次 I/O 内复用的 facade；volatile inode 不注册全局 cache entry。
-    /// @error `InvalidOperation` 表示 inode 不是 regular file。
+    /// @error `InvalidOperation` 表示 inode 既不是 regular file 也不是 volatile block node。
     /// @error inode metadata 读取失败时透传 filesystem error。
     /// @error 首次注册 memory reclaimer 失败时返回 `OutOfMemory`。
     pub(crate) fn from_inode(inode: Arc<dyn Inode>) -> Result<Self, FileSystemError> {
-        if inode.inode_type() != InodeType::File {
-            return Err(FileSystemError::InvalidOperation);
+        match inode.inode_type() {
+            InodeType::File => {}
+            InodeType::BlockDevice if inode.is_volatile() => {}
+            _ => return Err(FileSystemError::InvalidOperation),
         }
         if inode.is_volatile() {
             return Ok(Self(RegularFileBackend::Volatile(inode)));
@@ -383,6 +392,7 @@ impl RegularFile {
     /// @description 从持久 page cache 或只读动态 inode 读取 regular-file bytes。
     /// @param offset 文件 byte offset。
     /// @param output kernel-owned 输出缓冲区。
+    /// @param readahead OFD 顺序访问状态；None 表示 loader 等一次性读取，不做 readahead。
     /// @return 实际读取字节数；EOF 返回零。
     /// @error cache fill 分配失败时返回 `OutOfMemory`。
     /// @error size snapshot 后并发 truncate 越过当前 page 时返回 `InvalidOperation`。
@@ -391,6 +401,7 @@ impl RegularFile {
         &self,
         offset: u64,
         output: &mut [u8],
+        readahead: Option<&Mutex<ReadaheadWindow>>,
     ) -> Result<RegularFileRead, FileSystemError> {
         let file = match &self.0 {
             RegularFileBackend::Cached(file) => file,
@@ -407,11 +418,14 @@ impl RegularFile {
         let count = usize::try_from(size.saturating_sub(offset))
             .unwrap_or(usize::MAX)
             .min(output.len());
+        let first = offset / PAGE_SIZE as u64;
+        let last = (offset + count.saturating_sub(1) as u64) / PAGE_SIZE as u64;
+        let window = readahead.map_or(1, |state| state.lock().advance(first, last));
         let mut done = 0;
         let mut storage_bytes = 0;
         while done < count {
             let current = offset + done as u64;
-            let (page, filled) = file.page_with_storage(current / PAGE_SIZE as u64)?;
+            let (page, filled) = file.page_with_storage(current / PAGE_SIZE as u64, window)?;
             storage_bytes += filled;
             let page_offset = current as usize % PAGE_SIZE;
             let part = (PAGE_SIZE - page_offset).min(count - done);
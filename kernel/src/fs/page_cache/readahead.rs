@@ -0,0 +1,62 @@
+//! @description OFD-local sequential read 检测与 readahead window 增长 policy。
+
+/// 首次判定为顺序访问时，一次 cache miss 批量填充的 page 数。
+const INITIAL_READAHEAD_PAGES: usize = 4;
+/// 单次 cache-miss fill 的最大 page 数；4 KiB block 时恰好两轮 16-slot device queue。
+pub(crate) const MAX_READAHEAD_PAGES: usize = 32;
+
+/// @description 单个 open file description 的顺序访问状态，对应 Linux `file_ra_state`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ReadaheadWindow {
+    next: u64,
+    pages: usize,
+}
+
+impl ReadaheadWindow {
+    /// @description 创建从文件头开始视为顺序访问的初始状态。
+    pub(crate) const fn new() -> Self {
+        Self { next: 0, pages: 0 }
+    }
+
+    /// @description 记录一次覆盖 `[first, last]` page 的 read，并返回 cache miss 的批量 fill 页数。
+    ///
+    /// @param first 本次 read 的首个 page index。
+    /// @param last 本次 read 的末个 page index。
+    /// @return 随机访问返回 1；连续顺序访问从 `INITIAL_READAHEAD_PAGES` 倍增至上限。
+    pub(crate) fn advance(&mut self, first: u64, last: u64) -> usize {
+        // 非 page 对齐的连续 read 会从上次末页继续，也属于顺序访问。
+        let sequential = first == self.next || first.checked_add(1) == Some(self.next);
+        self.pages = if !sequential {
+            1
+        } else if self.pages < INITIAL_READAHEAD_PAGES {
+            INITIAL_READAHEAD_PAGES
+        } else {
+            (self.pages * 2).min(MAX_READAHEAD_PAGES)
+        };
+        self.next = last.saturating_add(1);
+        self.pages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequential_reads_grow_window_and_random_reads_reset_it() {
+        let mut window = ReadaheadWindow::new();
+        assert_eq!(window.advance(0, 0), INITIAL_READAHEAD_PAGES);
+        assert_eq!(window.advance(1, 1), INITIAL_READAHEAD_PAGES * 2);
+        // 未对齐 read 从上次末页继续仍是顺序访问。
+        assert_eq!(window.advance(1, 2), INITIAL_READAHEAD_PAGES * 4);
+        assert_eq!(window.advance(3, 3), MAX_READAHEAD_PAGES);
+        assert_eq!(window.advance(4, 4), MAX_READAHEAD_PAGES);
+        assert_eq!(window.advance(100, 100), 1);
+        assert_eq!(window.advance(101, 101), INITIAL_READAHEAD_PAGES);
+    }
+
+    #[test]
+    fn first_read_away_from_file_start_is_random() {
+        assert_eq!(ReadaheadWindow::new().advance(7, 7), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::fallible_tree::NodeSlot;
+
+use super::readahead::MAX_READAHEAD_PAGES;
+use super::*;
+
+type PageFill = (u64, NodeSlot<u64, Arc<CachedPage>>, SharedFrame, usize);
+
+impl CachedFile {
+    /// @description 以一次 storage 流水填充从 `index` 开始的连续未缓存 page。
+    ///
+    /// window 遇到 EOF 或已缓存 page 即截断；首页之外的 node/frame/page 分配失败只缩短
+    /// window，因此 readahead 不会把可单页完成的 cache miss 变成 ENOMEM。
+    /// @param index 本次 read 实际需要的 page。
+    /// @param pages 由 OFD 顺序检测给出的批量上限；1 退化为单页 fill。
+    /// @return 首页以及本次从 storage 填充的全部 bytes。
+    /// @errors 首页 EOF、分配失败或 storage 短读时返回对应错误，window 内 page 都不发布。
+    pub(super) fn fill_window(
+        &self,
+        index: u64,
+        pages: usize,
+        operation: &TaskMutexGuard<'_, ()>,
+    ) -> Result<(Arc<CachedPage>, usize), FileSystemError> {
+        if pages <= 1 {
+            return self.page_after_operation_lock(index, operation);
+        }
+        let size = self.inode.size();
+        let offset = index
+            .checked_mul(PAGE_SIZE as u64)
+            .ok_or(FileSystemError::InvalidOperation)?;
+        if offset >= size {
+            return Err(FileSystemError::InvalidOperation);
+        }
+        let end = {
+            let resident = self.pages.lock();
+            if let Some(page) = resident.entries.get(&index).cloned() {
+                return Ok((page, 0));
+            }
+            let limit = index.saturating_add(pages.min(MAX_READAHEAD_PAGES) as u64);
+            let mut end = index + 1;
+            while end < limit
+                && end * (PAGE_SIZE as u64) < size
+                && !resident.entries.contains_key(&end)
+            {
+                end += 1;
+            }
+            end
+        };
+        let mut fills: [Option<PageFill>; MAX_READAHEAD_PAGES] = core::array::from_fn(|_| None);
+        for (slot, page_index) in fills.iter_mut().zip(index..end) {
+            let reserved = FallibleMap::<u64, Arc<CachedPage>>::try_reserve_node()
+                .map_err(|_| FileSystemError::OutOfMemory)
+                .and_then(|node| Ok((node, SharedFrame::allocate().map_err(shared_error)?)));
+            match reserved {
+                Ok((node, frame)) => {
+                    let start = page_index * PAGE_SIZE as u64;
+                    let available = usize::try_from(size - start)
+                        .unwrap_or(usize::MAX)
+                        .min(PAGE_SIZE);
+                    *slot = Some((page_index, node, frame, available));
+                }
+                Err(error) if page_index == index => return Err(error),
+                Err(_) => break,
+            }
+        }
+        let expected = fills.iter().flatten().map(|fill| fill.3).sum::<usize>();
+        let mut segments: [&mut [u8]; MAX_READAHEAD_PAGES] = core::array::from_fn(|_| &mut [][..]);
+        let mut count = 0;
+        for ((_, _, frame, available), segment) in fills.iter_mut().flatten().zip(&mut segments) {
+            *segment = &mut frame.bytes_mut()[..*available];
+            count += 1;
+        }
+        // 全部 frame 尚未发布且保持独占；adapter 一次提交整个 window 的 block request。
+        if self
+            .inode
+            .read_storage_pages(offset, &mut segments[..count])?
+            != expected
+        {
+            return Err(FileSystemError::IoError);
+        }
+        let mut first = None;
+        let mut pages = self.pages.lock();
+        for (page_index, node, frame, _) in fills.into_iter().flatten() {
+            let Ok(page) = Arc::try_new(CachedPage {
+                frame,
+                state: AtomicUsize::new(0),
+            }) else {
+                if page_index == index {
+                    return Err(FileSystemError::OutOfMemory);
+                }
+                break;
+            };
+            assert!(!pages.entries.contains_key(&page_index));
+            pages
+                .entries
+                .commit_vacant(node.fill(page_index, page.clone()));
+            first.get_or_insert(page);
+        }
+        let first = first.expect("readahead window always contains the demanded page");
+        Ok((first, expected))
+    }
+}
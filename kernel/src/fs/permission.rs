@@ -1,13 +1,14 @@
 use alloc::{sync::Arc, vec::Vec};
 
-use super::{FileSystemError, InodeMetadata, InodeType};
+use super::{FileSystemError, InodeMetadata, InodeType, OpenedFile};
 
-/// @description VFS permission evaluator 消费的不可变调用身份；状态仍由 Process 独占。
+/// @description VFS pathname lookup 与 permission evaluator 消费的不可变调用身份；状态仍由 Process 独占。
 #[derive(Clone)]
 pub(crate) struct AccessIdentity {
     uid: u32,
     gid: u32,
     groups: Option<Arc<Vec<u32>>>,
+    root: Option<Arc<OpenedFile>>,
 }
 
 impl AccessIdentity {
@@ -17,7 +18,25 @@ impl AccessIdentity {
     /// @param groups Process 不可变 supplementary group snapshot；空集合不分配。
     /// @return 不持有 Process lock 的权限输入。
     pub(crate) fn new(uid: u32, gid: u32, groups: Option<Arc<Vec<u32>>>) -> Self {
-        Self { uid, gid, groups }
+        Self {
+            uid,
+            gid,
+            groups,
+            root: None,
+        }
+    }
+
+    /// @description 附加 Process `chroot` 目录作为本次 lookup 的 `/` 与 `..` 边界。
+    /// @param root Process root opened entry；None 表示 VFS namespace root。
+    /// @return 同一 credentials、不同 lookup root 的快照。
+    pub(crate) fn with_root(mut self, root: Option<Arc<OpenedFile>>) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// @description 返回本次 lookup 的 Process root；None 时由 VFS 使用 namespace root。
+    pub(super) fn lookup_root(&self) -> Option<&Arc<OpenedFile>> {
+        self.root.as_ref()
     }
 
     pub(crate) fn root() -> Self {
@@ -17,8 +17,8 @@ pub(crate) use snapshot::{
     ProcProcessSnapshot, ProcSnapshot, ProcThreadSnapshot,
 };
 use system::{
-    format_buddyinfo, format_cpu_stat, format_loadavg, format_meminfo, format_network_devices,
-    format_network_routes, format_uptime, format_vmstat,
+    format_bootlog, format_buddyinfo, format_cpu_stat, format_loadavg, format_meminfo,
+    format_network_devices, format_network_routes, format_uptime, format_vmstat,
 };
 
 use super::{
@@ -94,6 +94,9 @@ impl ProcInode {
         if matches!(self.node, ProcNode::Mounts) {
             return vfs().mount_table();
         }
+        if matches!(self.node, ProcNode::BootLog) {
+            return format_bootlog();
+        }
         if let ProcNode::ProcessCmdline(pid) = self.node {
             return self
                 .source
@@ -119,7 +122,9 @@ impl ProcInode {
             ProcNode::Uptime => format_uptime(&snapshot),
             ProcNode::NetDev => format_network_devices(snapshot.network),
             ProcNode::NetRoute => format_network_routes(snapshot.network),
-            ProcNode::Mounts => unreachable!("mount table handled before task snapshot"),
+            ProcNode::Mounts | ProcNode::BootLog => {
+                unreachable!("mount table and boot timeline handled before task snapshot")
+            }
             ProcNode::ProcessStat(pid) => format_process_stat(find_process(&snapshot, pid)?),
             ProcNode::ProcessStatus(pid) => format_process_status(find_process(&snapshot, pid)?),
             ProcNode::ProcessComm(pid) => format_process_comm(find_process(&snapshot, pid)?),
@@ -315,6 +320,7 @@ impl Inode for ProcInode {
                     (12, InodeType::File, &b"vmstat"[..]),
                     (4, InodeType::File, &b"loadavg"[..]),
                     (5, InodeType::File, &b"uptime"[..]),
+                    (13, InodeType::File, &b"bootlog"[..]),
                     (6, InodeType::File, &b"mounts"[..]),
                     (7, InodeType::Directory, &b"net"[..]),
                     (10, InodeType::SymLink, &b"self"[..]),
@@ -427,6 +433,7 @@ impl Inode for ProcInode {
                 b"vmstat" => ProcNode::VmStat,
                 b"loadavg" => ProcNode::LoadAvg,
                 b"uptime" => ProcNode::Uptime,
+                b"bootlog" => ProcNode::BootLog,
                 b"mounts" => ProcNode::Mounts,
                 b"net" => ProcNode::NetDir,
                 b"self" => ProcNode::SelfLink,
@@ -9,6 +9,7 @@ pub(super) enum ProcNode {
     VmStat,
     LoadAvg,
     Uptime,
+    BootLog,
     Mounts,
     NetDir,
     NetDev,
@@ -48,6 +49,7 @@ impl ProcNode {
             Self::SelfLink => 10,
             Self::BuddyInfo => 11,
             Self::VmStat => 12,
+            Self::BootLog => 13,
             Self::ProcessDir(pid) => 0x1000_0000_0000_0000 | (pid as u64) << 4,
             Self::ProcessStat(pid) => 0x1000_0000_0000_0001 | (pid as u64) << 4,
             Self::ProcessStatus(pid) => 0x1000_0000_0000_0002 | (pid as u64) << 4,
@@ -85,10 +85,13 @@ pub(crate) struct ProcSnapshot {
     pub(crate) direct_reclaim_attempts: u64,
     pub(crate) direct_reclaim_scanned_pages: u64,
     pub(crate) direct_reclaim_reclaimed_pages: u64,
+    pub(crate) balloon_inflated_pages: u64,
+    pub(crate) balloon_deflated_pages: u64,
     pub(crate) cached_pages: usize,
     pub(crate) dirty_pages: usize,
     pub(crate) reclaimable_cached_pages: usize,
     pub(crate) heap_pages: usize,
+    pub(crate) kernel_stack_pages: usize,
     pub(crate) runnable_tasks: usize,
     pub(crate) total_tasks: usize,
     pub(crate) processes_created: u64,
@@ -55,13 +55,14 @@ pub(super) fn format_meminfo(snapshot: &ProcSnapshot) -> Result<Vec<u8>, FileSys
         .saturating_add(snapshot.reclaimable_cached_pages)
         .min(snapshot.total_pages);
     proc_text(format_args!(
-        "MemTotal:       {} kB\nMemFree:        {} kB\nMemAvailable:   {} kB\nBuffers:        0 kB\nCached:         {} kB\nSwapCached:     0 kB\nActive:         0 kB\nInactive:       0 kB\nSwapTotal:      0 kB\nSwapFree:       0 kB\nDirty:          {} kB\nWriteback:      0 kB\nAnonPages:      0 kB\nMapped:         0 kB\nShmem:          0 kB\nSlab:           {} kB\n",
+        "MemTotal:       {} kB\nMemFree:        {} kB\nMemAvailable:   {} kB\nBuffers:        0 kB\nCached:         {} kB\nSwapCached:     0 kB\nActive:         0 kB\nInactive:       0 kB\nSwapTotal:      0 kB\nSwapFree:       0 kB\nDirty:          {} kB\nWriteback:      0 kB\nAnonPages:      0 kB\nMapped:         0 kB\nShmem:          0 kB\nSlab:           {} kB\nKernelStack:    {} kB\n",
         snapshot.total_pages * 4,
         snapshot.free_pages * 4,
         available_pages * 4,
         snapshot.cached_pages * 4,
         snapshot.dirty_pages * 4,
         snapshot.heap_pages * 4,
+        snapshot.kernel_stack_pages * 4,
     ))
 }
 
@@ -82,10 +83,12 @@ pub(super) fn format_buddyinfo(snapshot: &ProcSnapshot) -> Result<Vec<u8>, FileS
 
 pub(super) fn format_vmstat(snapshot: &ProcSnapshot) -> Result<Vec<u8>, FileSystemError> {
     proc_text(format_args!(
-        "allocstall {}\npgscan_direct {}\npgsteal_direct {}\n",
+        "allocstall {}\npgscan_direct {}\npgsteal_direct {}\nballoon_inflate {}\nballoon_deflate {}\n",
         snapshot.direct_reclaim_attempts,
         snapshot.direct_reclaim_scanned_pages,
         snapshot.direct_reclaim_reclaimed_pages,
+        snapshot.balloon_inflated_pages,
+        snapshot.balloon_deflated_pages,
     ))
 }
 
@@ -123,6 +126,15 @@ pub(super) fn format_uptime(snapshot: &ProcSnapshot) -> Result<Vec<u8>, FileSyst
     ))
 }
 
+/// `/proc/bootlog` 每行为 `<stage> <microseconds>`，按 boot 顺序只列出已完成 stage。
+pub(super) fn format_bootlog() -> Result<Vec<u8>, FileSystemError> {
+    let mut output = ProcText::new();
+    for (stage, timestamp_us) in crate::log::boot_timeline() {
+        writeln!(output, "{stage} {timestamp_us}").map_err(|_| FileSystemError::OutOfMemory)?;
+    }
+    Ok(output.finish())
+}
+
 pub(super) fn format_network_devices(
     network: Option<ProcNetworkSnapshot>,
 ) -> Result<Vec<u8>, FileSystemError> {
@@ -17,6 +17,8 @@ pub(crate) use opened::OpenedFile;
 use opened_index::OpenedIndex;
 #[path = "vfs/advisory_lock.rs"]
 mod advisory_lock;
+#[path = "vfs/fifo.rs"]
+mod fifo;
 #[path = "vfs/record_lock.rs"]
 mod record_lock;
 pub(crate) use advisory_lock::{
@@ -41,6 +43,9 @@ pub(crate) struct VirtualFileSystem {
     // OWNER: VFS inode identity → process-owned POSIX byte-range locks；若归 fd/OFD 所有，dup、fork
     // 与任一 descriptor close 会产生错误的锁生命周期。
     record_locks: Mutex<Vec<record_lock::RecordLock>>,
+    // OWNER: VFS inode identity → named pipe 的共享 ring；只保存 Weak，最后 endpoint 关闭即释放。
+    // 缺失时同一 FIFO 的两次 open 得到互不相通的 pipe，reader 永远等不到 writer。
+    fifos: Mutex<Vec<fifo::FifoPipe>>,
     // 唯一反向 adapter 只投递 key，不保存 task 状态；缺失时最后 descriptor close 无法唤醒 waiter。
     advisory_lock_notifier: Mutex<Option<Arc<dyn AdvisoryLockNotifier>>>,
 }
@@ -134,7 +139,10 @@ impl VirtualFileSystem {
         followed_links: usize,
     ) -> Result<Arc<OpenedFile>, FileSystemError> {
         const MAX_SYMLINKS: usize = 40;
-        let root = self.root_opened()?;
+        let root = match identity.lookup_root() {
+            Some(root) => root.clone(),
+            None => self.root_opened()?,
+        };
         let mut opened = if path.first() == Some(&b'/') {
             root.clone()
         } else {
@@ -151,10 +159,12 @@ impl VirtualFileSystem {
         {
             identity.require(opened.inode().metadata()?, 1)?;
             match component {
+                // chroot 目录可以是 mount root；先判断 lookup root，否则 `..` 会经 mount parent 逃逸。
+                b".." if opened.same_inode(&root) => {}
                 b".." => {
                     if let Some(parent) = self.leave_mount(&opened) {
                         opened = parent;
-                    } else if !opened.same_inode(&root) {
+                    } else {
                         opened = opened.parent().ok_or(FileSystemError::InvalidFileSystem)?;
                     }
                 }
@@ -290,6 +300,7 @@ impl VirtualFileSystem {
             opened: OpenedIndex::new(),
             advisory_locks: Mutex::new(Vec::new()),
             record_locks: Mutex::new(Vec::new()),
+            fifos: Mutex::new(Vec::new()),
             advisory_lock_notifier: Mutex::new(None),
         }
     }
@@ -432,7 +443,7 @@ impl VirtualFileSystem {
         let mut output = Vec::new();
         write_mount_record(&mut output, root.0, b"/", &root.1.statistics()?)?;
         for (source, point, filesystem) in mounts {
-            let target = self.absolute_path(point)?;
+            let target = self.absolute_path(point, None)?;
             write_mount_record(&mut output, source, &target, &filesystem.statistics()?)?;
         }
         Ok(output)
@@ -510,16 +521,18 @@ impl VirtualFileSystem {
     /// @description 从目录 inode identity 反向解析当前 namespace 中的 raw absolute path。
     ///
     /// @param inode 必须属于当前 root filesystem 且为目录。
-    /// @return root 返回 `/`；其他目录返回当前目录项关系对应的 absolute path。
-    /// @errors inode 已不可达、目录关系损坏、跨 filesystem 或底层 I/O 失败时返回明确错误。
+    /// @param root Process `chroot` 目录；None 表示 namespace root。
+    /// @return root 返回 `/`；其他目录返回相对 root 的目录项关系对应的 absolute path。
+    /// @errors inode 已删除或不在 root 之下时返回 `NotFound`；目录关系损坏或 I/O 失败时返回明确错误。
     pub(crate) fn absolute_path(
         &self,
         opened: Arc<OpenedFile>,
+        root: Option<&Arc<OpenedFile>>,
     ) -> Result<Vec<u8>, FileSystemError> {
         if opened.inode().inode_type() != InodeType::Directory {
             return Err(FileSystemError::NotDirectory);
         }
-        opened.path(false)
+        opened.path(false, root)
     }
 
     /// @description 投影 procfs fd symlink 使用的 opened pathname。
@@ -527,7 +540,7 @@ impl VirtualFileSystem {
     /// @return 当前路径；任一祖先已删除时追加 Linux ` (deleted)` 后缀。
     /// @errors opened-entry 链损坏或内存不足时返回明确错误。
     pub(crate) fn opened_path(&self, opened: &Arc<OpenedFile>) -> Result<Vec<u8>, FileSystemError> {
-        opened.path(true)
+        opened.path(true, None)
     }
 }
 
@@ -0,0 +1,45 @@
+use alloc::sync::{Arc, Weak};
+
+use super::VirtualFileSystem;
+use crate::fs::{FileSystemError, Inode};
+use crate::ipc::Pipe;
+
+/// @description 一个 FIFO inode 当前共享的 Pipe；Linux `inode->i_pipe` 的 VFS 投影。
+pub(super) struct FifoPipe {
+    identity: (usize, u64),
+    pipe: Weak<Pipe>,
+}
+
+impl VirtualFileSystem {
+    /// @description 取得 FIFO inode 的共享 Pipe；尚无 live endpoint 时用 `create` 新建。
+    ///
+    /// @param inode 已解析的 FIFO inode。
+    /// @param create 构造绑定 task wait registry 的空 FIFO Pipe。
+    /// @return 同一 mounted inode 的所有 open 共享的 Pipe。
+    /// @errors metadata 错误透传；表项或 Pipe 分配失败返回 `OutOfMemory`。
+    pub(crate) fn fifo_pipe(
+        &self,
+        inode: &Arc<dyn Inode>,
+        create: impl FnOnce() -> Result<Arc<Pipe>, ()>,
+    ) -> Result<Arc<Pipe>, FileSystemError> {
+        let identity = Self::identity(inode)?;
+        let mut fifos = self.fifos.lock();
+        fifos.retain(|fifo| fifo.pipe.strong_count() != 0);
+        if let Some(pipe) = fifos
+            .iter()
+            .find(|fifo| fifo.identity == identity)
+            .and_then(|fifo| fifo.pipe.upgrade())
+        {
+            return Ok(pipe);
+        }
+        fifos
+            .try_reserve(1)
+            .map_err(|_| FileSystemError::OutOfMemory)?;
+        let pipe = create().map_err(|()| FileSystemError::OutOfMemory)?;
+        fifos.push(FifoPipe {
+            identity,
+            pipe: Arc::downgrade(&pipe),
+        });
+        Ok(pipe)
+    }
+}
@@ -155,23 +155,38 @@ impl OpenedFile {
     ///
     /// @param deleted_suffix 为 true 时按 procfs 规则为已删除链追加 ` (deleted)`；
     /// false 时已删除链返回 `NotFound`，供 getcwd 使用。
+    /// @param root Process `chroot` 目录；链在该 inode 处截止。None 时走到 namespace root。
     /// @return 当前绝对路径。
-    /// @errors opened-entry 链损坏、形成环或内存不足时返回明确错误。
-    pub(super) fn path(&self, deleted_suffix: bool) -> Result<Vec<u8>, FileSystemError> {
+    /// @errors 链不经过 `root` 时返回 `NotFound`；opened-entry 链损坏、形成环或内存不足时返回明确错误。
+    pub(super) fn path(
+        &self,
+        deleted_suffix: bool,
+        root: Option<&Arc<OpenedFile>>,
+    ) -> Result<Vec<u8>, FileSystemError> {
+        let at_root = |entry: &OpenedFile| root.is_some_and(|root| entry.same_inode(root));
         let mut components = Vec::new();
-        let mut current = self.location.lock().parent.clone();
         let own = self.location.lock();
         let mut deleted = own.deleted;
-        if own.parent.is_some() {
+        let mut current = own.parent.clone();
+        let own_name = own.name;
+        drop(own);
+        let mut reached = root.is_none();
+        if at_root(self) {
+            reached = true;
+            current = None;
+        } else if current.is_some() {
             components
                 .try_reserve(1)
                 .map_err(|_| FileSystemError::OutOfMemory)?;
-            components.push(own.name);
+            components.push(own_name);
         }
-        drop(own);
 
         let mut visited = Vec::new();
         while let Some(entry) = current {
+            if at_root(&entry) {
+                reached = true;
+                break;
+            }
             let identity = Arc::as_ptr(&entry) as usize;
             if visited.contains(&identity) {
                 return Err(FileSystemError::InvalidFileSystem);
@@ -190,6 +205,9 @@ impl OpenedFile {
             }
             current = location.parent.clone();
         }
+        if !reached {
+            return Err(FileSystemError::NotFound);
+        }
         if deleted && !deleted_suffix {
             return Err(FileSystemError::NotFound);
         }
@@ -9,6 +9,10 @@ pub(crate) use receive_buffer::ReceiveBuffer;
 mod eventfd;
 pub(crate) use eventfd::{EventFd, EventFdRead, EventFdWrite};
 
+#[path = "ipc/fifo.rs"]
+mod fifo;
+pub(crate) use fifo::FifoOpenError;
+
 pub(crate) const PIPE_BUF: usize = 4096;
 const PIPE_CAPACITY: NonZeroUsize = NonZeroUsize::new(64 * 1024).unwrap();
 const NOTIFICATION_CAPACITY: NonZeroUsize = NonZeroUsize::MIN;
@@ -24,7 +28,14 @@ pub(crate) enum PipeDirection {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum PipeWaitCondition {
     Readable,
-    Writable { minimum: usize },
+    Writable {
+        minimum: usize,
+    },
+    /// FIFO open 等待对端；`opens` 是登记自身 endpoint 时观察到的对端 open counter。
+    Partner {
+        direction: PipeDirection,
+        opens: u64,
+    },
 }
 
 impl PipeWaitCondition {
@@ -38,6 +49,7 @@ impl PipeWaitCondition {
                 assert!((1..=PIPE_BUF).contains(&minimum));
                 PipeDirection::Write
             }
+            Self::Partner { direction, .. } => direction,
         }
     }
 }
@@ -56,6 +68,14 @@ pub(crate) enum PipeWrite {
     Broken,
 }
 
+/// @description `F_SETPIPE_SZ` 无法替换 ring 的原因。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PipeResizeError {
+    /// 已缓冲数据超过新 capacity。
+    Busy,
+    OutOfMemory,
+}
+
 /// @description byte ring 写入语义；匿名 pipe 保证 `PIPE_BUF` 原子性，stream socket 允许短写。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PipeWriteMode {
@@ -70,6 +90,8 @@ pub(crate) struct PipePollState {
     pub(crate) hangup: bool,
     pub(crate) error: bool,
     pub(crate) write_capacity: usize,
+    /// 对端 endpoint 的累计 open 次数；Linux `r_counter/w_counter`。
+    pub(crate) partner_opens: u64,
 }
 
 impl PipePollState {
@@ -81,6 +103,7 @@ impl PipePollState {
         match condition {
             PipeWaitCondition::Readable => self.readable,
             PipeWaitCondition::Writable { minimum } => self.error || self.write_capacity >= minimum,
+            PipeWaitCondition::Partner { opens, .. } => self.partner_opens != opens,
         }
     }
 }
@@ -93,8 +116,12 @@ struct PipeState {
     bytes: Vec<u8>,
     head: usize,
     length: usize,
+    /// splice 已 peek 但尚未提交的前缀长度；非零时其他 reader 视为 empty 以保持 FIFO 顺序。
+    held: usize,
     readers: usize,
     writers: usize,
+    reader_opens: u64,
+    writer_opens: u64,
     read_generation: u64,
     write_generation: u64,
 }
@@ -142,8 +169,11 @@ impl Pipe {
                 bytes,
                 head: 0,
                 length: 0,
+                held: 0,
                 readers: 1,
                 writers: 1,
+                reader_opens: 1,
+                writer_opens: 1,
                 read_generation: crate::sync::next_readiness_generation(),
                 write_generation: crate::sync::next_readiness_generation(),
             }),
@@ -153,11 +183,13 @@ impl Pipe {
         let read = Arc::try_new(PipeEnd {
             pipe: pipe.clone(),
             direction: PipeDirection::Read,
+            duplex: false,
         })
         .map_err(|_| ())?;
         let write = Arc::try_new(PipeEnd {
             pipe,
             direction: PipeDirection::Write,
+            duplex: false,
         })
         .map_err(|_| ())?;
         Ok((read, write))
@@ -183,11 +215,12 @@ impl Pipe {
         let state = self.state.lock();
         match direction {
             PipeDirection::Read => PipePollState {
-                readable: state.length != 0 || state.writers == 0,
+                readable: state.held == 0 && (state.length != 0 || state.writers == 0),
                 writable: false,
                 hangup: state.writers == 0,
                 error: false,
                 write_capacity: 0,
+                partner_opens: state.writer_opens,
             },
             PipeDirection::Write => PipePollState {
                 readable: false,
@@ -195,10 +228,50 @@ impl Pipe {
                 hangup: false,
                 error: state.readers == 0,
                 write_capacity: state.bytes.len() - state.length,
+                partner_opens: state.reader_opens,
             },
         }
     }
 
+    /// @description 返回当前 ring capacity，即 Linux `F_GETPIPE_SZ` 的 byte 值。
+    pub(crate) fn capacity(&self) -> usize {
+        self.state.lock().bytes.len()
+    }
+
+    /// @description 以保持 FIFO 顺序的新 ring 替换 capacity，并唤醒等待空间的 writer。
+    ///
+    /// @param capacity 已按 Linux `round_pipe_size` 规整、不小于 `PIPE_BUF` 的新容量。
+    /// @return 替换成功返回 Ok。
+    /// @errors 已缓冲数据超过新容量返回 `Busy`；新 ring 分配失败返回 `OutOfMemory`。
+    pub(crate) fn resize(self: &Arc<Self>, capacity: usize) -> Result<(), PipeResizeError> {
+        assert!(
+            capacity >= PIPE_BUF,
+            "pipe capacity must keep PIPE_BUF atomicity"
+        );
+        let mut bytes = Vec::new();
+        bytes
+            .try_reserve_exact(capacity)
+            .map_err(|_| PipeResizeError::OutOfMemory)?;
+        bytes.resize(capacity, 0);
+        let retired = {
+            let mut state = self.state.lock();
+            if state.length > capacity {
+                return Err(PipeResizeError::Busy);
+            }
+            let head = state.head;
+            let length = state.length;
+            let first = length.min(state.bytes.len() - head);
+            bytes[..first].copy_from_slice(&state.bytes[head..head + first]);
+            bytes[first..length].copy_from_slice(&state.bytes[..length - first]);
+            state.head = 0;
+            state.write_generation = crate::sync::next_readiness_generation();
+            core::mem::replace(&mut state.bytes, bytes)
+        };
+        drop(retired);
+        self.notifier.notify(self);
+        Ok(())
+    }
+
     /// @description 返回指定 endpoint 最近一次可观察状态变化的全局 generation。
     ///
     /// @param direction read 侧跟踪 data/EOF，write 侧跟踪 space/broken-pipe。
@@ -211,7 +284,13 @@ impl Pipe {
         }
     }
 
-    fn read(self: &Arc<Self>, output: &mut ReceiveBuffer<'_>, maximum: usize) -> PipeRead {
+    /// @description 复制 ring 前缀；`hold` 时保留这些字节，直至 `commit_held` 决定实际消费量。
+    fn read(
+        self: &Arc<Self>,
+        output: &mut ReceiveBuffer<'_>,
+        maximum: usize,
+        hold: bool,
+    ) -> PipeRead {
         let result = {
             let mut state = self.state.lock();
             if state.length == 0 {
@@ -220,6 +299,17 @@ impl Pipe {
                 } else {
                     PipeRead::Empty
                 }
+            } else if state.held != 0 {
+                PipeRead::Empty
+            } else if hold {
+                let count = output.remaining().min(maximum).min(state.length);
+                let capacity = state.bytes.len();
+                let head = state.head;
+                let first = count.min(capacity - head);
+                assert_eq!(output.append(&state.bytes[head..head + first]), first);
+                assert_eq!(output.append(&state.bytes[..count - first]), count - first);
+                state.held = count;
+                return PipeRead::Bytes(count);
             } else {
                 let count = output.remaining().min(maximum).min(state.length);
                 if count != 0 {
@@ -249,6 +339,25 @@ impl Pipe {
         result
     }
 
+    /// @description 结束 held read：只消费实际写出的前缀，其余字节留在 ring 头部供后续读取。
+    fn commit_held(self: &Arc<Self>, consumed: usize) {
+        {
+            let mut state = self.state.lock();
+            assert!(consumed <= state.held, "pipe held read over-commit");
+            let next = state.head + consumed;
+            state.head = if next >= state.bytes.len() {
+                next - state.bytes.len()
+            } else {
+                next
+            };
+            state.length -= consumed;
+            state.held = 0;
+            state.read_generation = crate::sync::next_readiness_generation();
+            state.write_generation = crate::sync::next_readiness_generation();
+        }
+        self.notifier.notify(self);
+    }
+
     fn write(self: &Arc<Self>, input: &[u8], mode: PipeWriteMode) -> PipeWrite {
         let result = {
             let mut state = self.state.lock();
@@ -355,11 +464,25 @@ impl Pipe {
 pub(crate) struct PipeEnd {
     pipe: Arc<Pipe>,
     direction: PipeDirection,
+    /// O_RDWR FIFO 同时计入 reader 与 writer；`direction` 固定为 Read。
+    duplex: bool,
 }
 
 impl PipeEnd {
-    pub(crate) fn direction(&self) -> PipeDirection {
-        self.direction
+    /// @description 返回该 endpoint 在 Pipe 上登记的全部方向。
+    ///
+    /// @return 普通 endpoint 只含 `direction`；O_RDWR FIFO 依次含 Read 与 Write。
+    pub(crate) fn directions(&self) -> &'static [PipeDirection] {
+        match (self.duplex, self.direction) {
+            (true, _) => &[PipeDirection::Read, PipeDirection::Write],
+            (false, PipeDirection::Read) => &[PipeDirection::Read],
+            (false, PipeDirection::Write) => &[PipeDirection::Write],
+        }
+    }
+
+    /// @description 判断该 endpoint 是否登记了指定方向，即是否允许对应的 read/write。
+    pub(crate) fn allows(&self, direction: PipeDirection) -> bool {
+        self.directions().contains(&direction)
     }
 
     pub(crate) fn pipe(&self) -> Arc<Pipe> {
@@ -368,7 +491,7 @@ impl PipeEnd {
 
     pub(crate) fn read(&self, output: &mut ReceiveBuffer<'_>) -> PipeRead {
         let maximum = output.remaining();
-        self.pipe.read(output, maximum)
+        self.pipe.read(output, maximum, false)
     }
 
     /// @description 从 pipe 读取至 receive sink，但不越过 protocol/control barrier。
@@ -376,7 +499,25 @@ impl PipeEnd {
     /// @param maximum 本次最多追加的 byte count。
     /// @return byte count、empty 或 EOF。
     pub(crate) fn read_bounded(&self, output: &mut ReceiveBuffer<'_>, maximum: usize) -> PipeRead {
-        self.pipe.read(output, maximum)
+        self.pipe.read(output, maximum, false)
+    }
+
+    /// @description 复制 ring 前缀但暂不消费，对应 Linux splice 在 pipe lock 下先写出再释放 buffer。
+    ///
+    /// 返回 `Bytes` 后 caller 必须调用 `commit_held`；期间其他 reader 观察到 empty。
+    /// @param output initialized-prefix owner。
+    /// @param maximum 本次最多保留的 byte count。
+    /// @return 保留的 byte count、empty 或 EOF。
+    pub(crate) fn peek_held(&self, output: &mut ReceiveBuffer<'_>, maximum: usize) -> PipeRead {
+        self.pipe.read(output, maximum, true)
+    }
+
+    /// @description 提交 `peek_held` 的结果，只消费实际写出的字节。
+    ///
+    /// @param consumed 不超过保留长度的已写出 byte count；零表示全部留在 pipe。
+    /// @errors 超过保留长度表示 caller 破坏 peek/commit 配对并 fail-stop。
+    pub(crate) fn commit_held(&self, consumed: usize) {
+        self.pipe.commit_held(consumed);
     }
 
     pub(crate) fn write(&self, input: &[u8]) -> PipeWrite {
@@ -412,6 +553,8 @@ impl PipeEnd {
 
 impl Drop for PipeEnd {
     fn drop(&mut self) {
-        self.pipe.close(self.direction);
+        for &direction in self.directions() {
+            self.pipe.close(direction);
+        }
     }
 }
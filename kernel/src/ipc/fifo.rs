@@ -0,0 +1,121 @@
+use super::*;
+
+/// @description FIFO endpoint 无法登记的原因。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FifoOpenError {
+    /// O_NONBLOCK writer 打开时没有 reader；Linux 返回 ENXIO。
+    NoReader,
+    OutOfMemory,
+}
+
+impl Pipe {
+    /// @description 创建尚无 endpoint 的 named-pipe ring；endpoint 由 `open_fifo_end` 逐个登记。
+    ///
+    /// @param notifier 与 anonymous pipe 共用的 task wait-registry 通知 seam。
+    /// @return readers/writers 都为零的 Pipe owner。
+    /// @errors kernel heap 不足返回 unit。
+    pub(crate) fn fifo(notifier: Arc<dyn PipeNotifier>) -> Result<Arc<Self>, ()> {
+        let mut bytes = Vec::new();
+        bytes
+            .try_reserve_exact(PIPE_CAPACITY.get())
+            .map_err(|_| ())?;
+        bytes.resize(PIPE_CAPACITY.get(), 0);
+        Arc::try_new(Self {
+            object_id: crate::id::next_runtime_object_id(),
+            state: Mutex::new(PipeState {
+                bytes,
+                head: 0,
+                length: 0,
+                held: 0,
+                readers: 0,
+                writers: 0,
+                reader_opens: 0,
+                writer_opens: 0,
+                read_generation: crate::sync::next_readiness_generation(),
+                write_generation: crate::sync::next_readiness_generation(),
+            }),
+            notifier,
+        })
+        .map_err(|_| ())
+    }
+
+    /// @description 按 Linux `fifo_open` 登记一个 read 或 write endpoint，并唤醒等待对端的 opener。
+    ///
+    /// 两侧都已关闭后重新打开的 FIFO 从空 ring 开始，与 Linux 释放 `pipe_inode_info` 一致。
+    /// @param direction 新 endpoint 的方向。
+    /// @param require_reader O_NONBLOCK writer 要求已有 reader。
+    /// @return endpoint，以及对端缺席时登记前的对端 open counter；caller 可等待其变化。
+    /// @errors 无 reader 的非阻塞 writer 返回 `NoReader`；endpoint 分配失败返回 `OutOfMemory`。
+    pub(crate) fn open_fifo_end(
+        self: &Arc<Self>,
+        direction: PipeDirection,
+        require_reader: bool,
+    ) -> Result<(Arc<PipeEnd>, Option<u64>), FifoOpenError> {
+        let partner = {
+            let mut state = self.state.lock();
+            if require_reader && state.readers == 0 {
+                return Err(FifoOpenError::NoReader);
+            }
+            if state.readers == 0 && state.writers == 0 {
+                state.head = 0;
+                state.length = 0;
+            }
+            match direction {
+                PipeDirection::Read => {
+                    state.readers += 1;
+                    state.reader_opens += 1;
+                    state.write_generation = crate::sync::next_readiness_generation();
+                    (state.writers == 0).then_some(state.writer_opens)
+                }
+                PipeDirection::Write => {
+                    state.writers += 1;
+                    state.writer_opens += 1;
+                    state.read_generation = crate::sync::next_readiness_generation();
+                    (state.readers == 0).then_some(state.reader_opens)
+                }
+            }
+        };
+        let Ok(endpoint) = Arc::try_new(PipeEnd {
+            pipe: self.clone(),
+            direction,
+            duplex: false,
+        }) else {
+            self.close(direction);
+            return Err(FifoOpenError::OutOfMemory);
+        };
+        self.notifier.notify(self);
+        Ok((endpoint, partner))
+    }
+
+    /// @description 按 Linux `fifo_open` 的 FMODE_READ|FMODE_WRITE 分支同时登记 reader 与 writer。
+    ///
+    /// 该 opener 自身即是对端，因此从不等待，且两侧等待者都会被唤醒。
+    /// @return 可读可写的 duplex endpoint。
+    /// @errors endpoint 分配失败返回 `OutOfMemory`。
+    pub(crate) fn open_fifo_duplex(self: &Arc<Self>) -> Result<Arc<PipeEnd>, FifoOpenError> {
+        {
+            let mut state = self.state.lock();
+            if state.readers == 0 && state.writers == 0 {
+                state.head = 0;
+                state.length = 0;
+            }
+            state.readers += 1;
+            state.reader_opens += 1;
+            state.writers += 1;
+            state.writer_opens += 1;
+            state.read_generation = crate::sync::next_readiness_generation();
+            state.write_generation = crate::sync::next_readiness_generation();
+        }
+        let Ok(endpoint) = Arc::try_new(PipeEnd {
+            pipe: self.clone(),
+            direction: PipeDirection::Read,
+            duplex: true,
+        }) else {
+            self.close(PipeDirection::Read);
+            self.close(PipeDirection::Write);
+            return Err(FifoOpenError::OutOfMemory);
+        };
+        self.notifier.notify(self);
+        Ok(endpoint)
+    }
+}
@@ -0,0 +1,16 @@
+//! @description Linux keyrings 的 per-UID `user` key 存储，供 session secret 不落盘地跨进程传递。
+
+#[path = "keys/store.rs"]
+mod store;
+
+pub(crate) use store::{KeyError, KeyStore, SecretBytes, USER_PAYLOAD_MAX};
+
+// OWNER: 全系统唯一的 key serial 空间与 per-UID user keyring 集合；内部 lock 只保护内存状态，
+// user copy 均在锁外完成。若每个 Process 各持一份，同 UID 的 display server 与 login manager
+// 将无法通过 user keyring 共享 session token。
+static KEYS: KeyStore = KeyStore::new();
+
+/// @description 返回唯一的 kernel key store。
+pub(crate) fn keys() -> &'static KeyStore {
+    &KEYS
+}
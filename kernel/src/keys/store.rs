@@ -0,0 +1,456 @@
+use alloc::vec::Vec;
+use spin::Mutex;
+
+/// Linux `user` key type 的 payload 上限。
+pub(crate) const USER_PAYLOAD_MAX: usize = 32767;
+/// 按 Linux `key_serial_t` 编码的 keyring read 单项宽度。
+const SERIAL_SIZE: usize = core::mem::size_of::<i32>();
+/// Linux `key_quota_maxkeys` / `key_quota_maxbytes` 默认值；root 使用独立 root quota。
+const USER_QUOTA: (usize, usize) = (200, 20_000);
+const ROOT_QUOTA: (usize, usize) = (1_000_000, 25_000_000);
+
+/// @description key 操作失败原因；syscall 层按 Linux keyctl errno 投影。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyError {
+    /// serial 不存在或 search 未命中；`ENOKEY`。
+    NotFound,
+    /// key 已被 revoke；`EKEYREVOKED`。
+    Revoked,
+    /// key 属于其他 UID，或操作对象是不可撤销的 per-UID keyring；`EACCES`。
+    Access,
+    /// keyring 参数指向普通 key；`ENOTDIR`。
+    NotKeyring,
+    /// key 未链接在指定 keyring；`ENOENT`。
+    NotLinked,
+    /// 超过 per-UID key 数或 byte quota；`EDQUOT`。
+    Quota,
+    OutOfMemory,
+}
+
+/// @description 释放前清零的 secret bytes，对应 Linux `kfree_sensitive`。
+pub(crate) struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    /// @description 分配已清零的 `length` 字节 staging。
+    /// @errors kernel heap 不足返回 `OutOfMemory`。
+    pub(crate) fn try_new(length: usize) -> Result<Self, KeyError> {
+        let mut bytes = Vec::new();
+        bytes
+            .try_reserve_exact(length)
+            .map_err(|_| KeyError::OutOfMemory)?;
+        bytes.resize(length, 0);
+        Ok(Self(bytes))
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        for byte in &mut self.0 {
+            // SAFETY: `byte` 是独占的有效 `&mut u8`；volatile 防止编译器删除释放前的清零，
+            // 否则 session token 会残留在随后复用的 heap block 中。
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+    }
+}
+
+struct Key {
+    serial: i32,
+    description: Vec<u8>,
+    payload: SecretBytes,
+    revoked: bool,
+}
+
+impl Key {
+    /// Linux `key_alloc` 按 description 含 NUL 加 payload 计入 quota。
+    fn quota_bytes(&self) -> usize {
+        self.description.len() + 1 + self.payload.as_slice().len()
+    }
+}
+
+struct UserKeyring {
+    uid: u32,
+    serial: i32,
+    keys: Vec<Key>,
+    bytes: usize,
+}
+
+impl UserKeyring {
+    fn quota(&self) -> (usize, usize) {
+        if self.uid == 0 {
+            ROOT_QUOTA
+        } else {
+            USER_QUOTA
+        }
+    }
+
+    fn position(&self, serial: i32) -> Option<usize> {
+        self.keys.iter().position(|key| key.serial == serial)
+    }
+
+    fn remove(&mut self, index: usize) {
+        let key = self.keys.swap_remove(index);
+        self.bytes -= key.quota_bytes();
+    }
+}
+
+/// serial 在 store 中解析出的对象。
+enum Object {
+    Keyring(usize),
+    Key(usize, usize),
+}
+
+struct State {
+    keyrings: Vec<UserKeyring>,
+    next_serial: i32,
+}
+
+impl State {
+    fn find(&self, serial: i32) -> Option<Object> {
+        self.keyrings
+            .iter()
+            .enumerate()
+            .find_map(|(ring, keyring)| {
+                if keyring.serial == serial {
+                    Some(Object::Keyring(ring))
+                } else {
+                    keyring.position(serial).map(|key| Object::Key(ring, key))
+                }
+            })
+    }
+
+    /// Linux user key 的默认 permission 只授予 possessor；同 UID 的 process 经 user keyring
+    /// 持有它，其他 UID 只能得到 `EACCES`。
+    fn owned(&self, uid: u32, serial: i32) -> Result<Object, KeyError> {
+        let object = self.find(serial).ok_or(KeyError::NotFound)?;
+        let ring = match object {
+            Object::Keyring(ring) | Object::Key(ring, _) => ring,
+        };
+        if self.keyrings[ring].uid == uid {
+            Ok(object)
+        } else {
+            Err(KeyError::Access)
+        }
+    }
+
+    fn owned_keyring(&self, uid: u32, serial: i32) -> Result<usize, KeyError> {
+        match self.owned(uid, serial)? {
+            Object::Keyring(ring) => Ok(ring),
+            Object::Key(..) => Err(KeyError::NotKeyring),
+        }
+    }
+
+    fn owned_key(&self, uid: u32, serial: i32) -> Result<(usize, usize), KeyError> {
+        match self.owned(uid, serial)? {
+            Object::Keyring(_) => Err(KeyError::Access),
+            Object::Key(ring, key) => Ok((ring, key)),
+        }
+    }
+
+    fn allocate_serial(&mut self) -> Result<i32, KeyError> {
+        let serial = self.next_serial;
+        self.next_serial = serial.checked_add(1).ok_or(KeyError::OutOfMemory)?;
+        Ok(serial)
+    }
+
+    fn user_keyring(&mut self, uid: u32) -> Result<usize, KeyError> {
+        if let Some(ring) = self.keyrings.iter().position(|keyring| keyring.uid == uid) {
+            return Ok(ring);
+        }
+        self.keyrings
+            .try_reserve(1)
+            .map_err(|_| KeyError::OutOfMemory)?;
+        let serial = self.allocate_serial()?;
+        self.keyrings.push(UserKeyring {
+            uid,
+            serial,
+            keys: Vec::new(),
+            bytes: 0,
+        });
+        Ok(self.keyrings.len() - 1)
+    }
+}
+
+/// @description Linux keyrings 的 per-UID `user` key 子集；每个 UID 只有一个持久 user keyring。
+///
+/// key 只存在于其 owner 的 user keyring 中，因此 unlink 即释放；payload 在释放或 revoke 时清零。
+pub(crate) struct KeyStore {
+    state: Mutex<State>,
+}
+
+impl KeyStore {
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: Mutex::new(State {
+                keyrings: Vec::new(),
+                next_serial: 3,
+            }),
+        }
+    }
+
+    /// @description 返回 UID 的 user keyring serial，首次使用时创建，对应 `KEY_SPEC_USER_KEYRING`。
+    /// @errors serial 耗尽或 heap 不足返回 `OutOfMemory`。
+    pub(crate) fn user_keyring(&self, uid: u32) -> Result<i32, KeyError> {
+        let mut state = self.state.lock();
+        let ring = state.user_keyring(uid)?;
+        Ok(state.keyrings[ring].serial)
+    }
+
+    /// @description 按 Linux `key_create_or_update` 创建或更新 `user` key。
+    ///
+    /// 同 description 的 live key 原地替换 payload 并保留 serial；已 revoke 的同名 key 被新 key 取代。
+    /// @param keyring None 表示 caller 的 user keyring；Some 必须是其 serial。
+    /// @return key serial。
+    /// @errors keyring 解析错误透传；超过 quota 返回 `Quota`；heap 或 serial 耗尽返回 `OutOfMemory`。
+    pub(crate) fn add(
+        &self,
+        uid: u32,
+        keyring: Option<i32>,
+        description: Vec<u8>,
+        payload: SecretBytes,
+    ) -> Result<i32, KeyError> {
+        let mut state = self.state.lock();
+        let ring = match keyring {
+            Some(serial) => state.owned_keyring(uid, serial)?,
+            None => state.user_keyring(uid)?,
+        };
+        let keyring = &mut state.keyrings[ring];
+        let (maximum_keys, maximum_bytes) = keyring.quota();
+        let charge = description.len() + 1 + payload.as_slice().len();
+        if let Some(index) = keyring
+            .keys
+            .iter()
+            .position(|key| key.description == description)
+        {
+            if !keyring.keys[index].revoked {
+                let key = &mut keyring.keys[index];
+                if keyring.bytes - key.quota_bytes() + charge > maximum_bytes {
+                    return Err(KeyError::Quota);
+                }
+                keyring.bytes = keyring.bytes - key.quota_bytes() + charge;
+                key.payload = payload;
+                return Ok(key.serial);
+            }
+            keyring.remove(index);
+        }
+        if keyring.keys.len() >= maximum_keys || keyring.bytes + charge > maximum_bytes {
+            return Err(KeyError::Quota);
+        }
+        keyring
+            .keys
+            .try_reserve(1)
+            .map_err(|_| KeyError::OutOfMemory)?;
+        let serial = state.allocate_serial()?;
+        let keyring = &mut state.keyrings[ring];
+        keyring.bytes += charge;
+        keyring.keys.push(Key {
+            serial,
+            description,
+            payload,
+            revoked: false,
+        });
+        Ok(serial)
+    }
+
+    /// @description 在 caller 的 user keyring 中按 description 查找 key。
+    /// @errors 未命中返回 `NotFound`；命中已 revoke 的 key 返回 `Revoked`。
+    pub(crate) fn search(&self, uid: u32, description: &[u8]) -> Result<i32, KeyError> {
+        let state = self.state.lock();
+        let key = state
+            .keyrings
+            .iter()
+            .find(|keyring| keyring.uid == uid)
+            .and_then(|keyring| {
+                keyring
+                    .keys
+                    .iter()
+                    .find(|key| key.description == description)
+            })
+            .ok_or(KeyError::NotFound)?;
+        if key.revoked {
+            return Err(KeyError::Revoked);
+        }
+        Ok(key.serial)
+    }
+
+    /// @description 按 Linux `KEYCTL_READ` 复制 key payload 或 keyring 的 serial 列表前缀。
+    /// @param output 接收前缀的 staging；可为空以只查询长度。
+    /// @return 完整 payload 长度，可能大于 `output.len()`。
+    /// @errors 不存在、非 owner 或已 revoke 分别返回 `NotFound`、`Access`、`Revoked`。
+    pub(crate) fn read(&self, uid: u32, serial: i32, output: &mut [u8]) -> Result<usize, KeyError> {
+        let state = self.state.lock();
+        match state.owned(uid, serial)? {
+            Object::Keyring(ring) => {
+                let keys = &state.keyrings[ring].keys;
+                let serials = keys.iter().flat_map(|key| key.serial.to_ne_bytes());
+                for (slot, byte) in output.iter_mut().zip(serials) {
+                    *slot = byte;
+                }
+                Ok(keys.len() * SERIAL_SIZE)
+            }
+            Object::Key(ring, key) => {
+                let key = &state.keyrings[ring].keys[key];
+                if key.revoked {
+                    return Err(KeyError::Revoked);
+                }
+                let payload = key.payload.as_slice();
+                let count = payload.len().min(output.len());
+                output[..count].copy_from_slice(&payload[..count]);
+                Ok(payload.len())
+            }
+        }
+    }
+
+    /// @description 按 Linux `KEYCTL_REVOKE` 立即清除 payload；serial 保留到 unlink/invalidate。
+    /// @errors 解析错误透传；user keyring 返回 `Access`。
+    pub(crate) fn revoke(&self, uid: u32, serial: i32) -> Result<(), KeyError> {
+        let mut state = self.state.lock();
+        let (ring, index) = state.owned_key(uid, serial)?;
+        let keyring = &mut state.keyrings[ring];
+        let key = &mut keyring.keys[index];
+        keyring.bytes -= key.payload.as_slice().len();
+        key.payload = SecretBytes(Vec::new());
+        key.revoked = true;
+        Ok(())
+    }
+
+    /// @description 按 Linux `KEYCTL_INVALIDATE` 立即移除并释放 key。
+    /// @errors 解析错误透传；user keyring 返回 `Access`。
+    pub(crate) fn invalidate(&self, uid: u32, serial: i32) -> Result<(), KeyError> {
+        let mut state = self.state.lock();
+        let (ring, index) = state.owned_key(uid, serial)?;
+        state.keyrings[ring].remove(index);
+        Ok(())
+    }
+
+    /// @description 按 Linux `KEYCTL_UNLINK` 从 keyring 移除 key；key 没有其他 link，因此同时释放。
+    /// @param keyring None 表示 caller 的 user keyring。
+    /// @errors 解析错误透传；key 不在该 keyring 返回 `NotLinked`。
+    pub(crate) fn unlink(
+        &self,
+        uid: u32,
+        serial: i32,
+        keyring: Option<i32>,
+    ) -> Result<(), KeyError> {
+        let mut state = self.state.lock();
+        let target = match keyring {
+            Some(keyring) => state.owned_keyring(uid, keyring)?,
+            None => state.user_keyring(uid)?,
+        };
+        let (ring, index) = state.owned_key(uid, serial)?;
+        if ring != target {
+            return Err(KeyError::NotLinked);
+        }
+        state.keyrings[ring].remove(index);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(bytes: &[u8]) -> SecretBytes {
+        let mut secret = SecretBytes::try_new(bytes.len()).unwrap();
+        secret.as_mut_slice().copy_from_slice(bytes);
+        secret
+    }
+
+    fn read(store: &KeyStore, uid: u32, serial: i32) -> Result<Vec<u8>, KeyError> {
+        let mut output = [0u8; 64];
+        let length = store.read(uid, serial, &mut output)?;
+        Ok(output[..length].to_vec())
+    }
+
+    #[test]
+    fn add_updates_in_place_and_read_then_invalidate_is_read_once() {
+        let store = KeyStore::new();
+        let token = store
+            .add(1000, None, b"session".to_vec(), secret(b"first"))
+            .unwrap();
+        assert_eq!(
+            store.add(1000, None, b"session".to_vec(), secret(b"second")),
+            Ok(token)
+        );
+        assert_eq!(store.search(1000, b"session"), Ok(token));
+        assert_eq!(read(&store, 1000, token).unwrap(), b"second");
+        let keyring = store.user_keyring(1000).unwrap();
+        assert_eq!(read(&store, 1000, keyring).unwrap(), token.to_ne_bytes());
+        store.invalidate(1000, token).unwrap();
+        assert_eq!(store.search(1000, b"session"), Err(KeyError::NotFound));
+        assert_eq!(read(&store, 1000, token), Err(KeyError::NotFound));
+    }
+
+    #[test]
+    fn keys_are_private_to_their_uid_namespace() {
+        let store = KeyStore::new();
+        let token = store
+            .add(1000, None, b"session".to_vec(), secret(b"x"))
+            .unwrap();
+        let other = store.user_keyring(1001).unwrap();
+        assert_eq!(store.search(1001, b"session"), Err(KeyError::NotFound));
+        assert_eq!(read(&store, 1001, token), Err(KeyError::Access));
+        assert_eq!(store.invalidate(1001, token), Err(KeyError::Access));
+        assert_eq!(
+            store.add(1000, Some(other), b"a".to_vec(), secret(b"x")),
+            Err(KeyError::Access)
+        );
+        assert_eq!(
+            store.add(1000, Some(token), b"a".to_vec(), secret(b"x")),
+            Err(KeyError::NotKeyring)
+        );
+        assert_eq!(
+            store.unlink(1000, token, Some(other)),
+            Err(KeyError::Access)
+        );
+        assert_eq!(store.revoke(1000, other), Err(KeyError::Access));
+    }
+
+    #[test]
+    fn revoke_clears_payload_until_replaced() {
+        let store = KeyStore::new();
+        let token = store
+            .add(1000, None, b"session".to_vec(), secret(b"x"))
+            .unwrap();
+        store.revoke(1000, token).unwrap();
+        assert_eq!(read(&store, 1000, token), Err(KeyError::Revoked));
+        assert_eq!(store.search(1000, b"session"), Err(KeyError::Revoked));
+        let replacement = store
+            .add(1000, None, b"session".to_vec(), secret(b"y"))
+            .unwrap();
+        assert_ne!(replacement, token);
+        assert_eq!(read(&store, 1000, token), Err(KeyError::NotFound));
+        assert_eq!(store.unlink(1000, replacement, None), Ok(()));
+        assert_eq!(
+            store.unlink(1000, replacement, None),
+            Err(KeyError::NotFound)
+        );
+    }
+
+    #[test]
+    fn non_root_quota_limits_key_count_and_bytes() {
+        let store = KeyStore::new();
+        for index in 0..USER_QUOTA.0 {
+            let description = alloc::format!("k{index}").into_bytes();
+            store.add(1000, None, description, secret(b"v")).unwrap();
+        }
+        assert_eq!(
+            store.add(1000, None, b"extra".to_vec(), secret(b"v")),
+            Err(KeyError::Quota)
+        );
+        let large = SecretBytes::try_new(USER_PAYLOAD_MAX).unwrap();
+        assert_eq!(
+            store.add(1001, None, b"large".to_vec(), large),
+            Err(KeyError::Quota)
+        );
+        let large = SecretBytes::try_new(USER_PAYLOAD_MAX).unwrap();
+        assert!(store.add(0, None, b"large".to_vec(), large).is_ok());
+    }
+}
@@ -1,5 +1,5 @@
 use core::fmt::{self, Write};
-use core::sync::atomic::{AtomicU8, Ordering};
+use core::sync::atomic::{AtomicU8, AtomicU64, Ordering};
 
 use crate::{println, sync::IrqMutex};
 
@@ -165,6 +165,77 @@ impl KmsgReader {
     }
 }
 
+/// @description `kernel_main` 按完成顺序发布的 boot stage；顺序即 `/proc/bootlog` 行序。
+#[derive(Clone, Copy)]
+#[repr(usize)]
+pub(crate) enum BootStage {
+    Board,
+    Memory,
+    Drivers,
+    Fs,
+    Task,
+    Smp,
+}
+
+impl BootStage {
+    const ALL: [Self; 6] = [
+        Self::Board,
+        Self::Memory,
+        Self::Drivers,
+        Self::Fs,
+        Self::Task,
+        Self::Smp,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Board => "board",
+            Self::Memory => "memory",
+            Self::Drivers => "drivers",
+            Self::Fs => "fs",
+            Self::Task => "task",
+            Self::Smp => "smp",
+        }
+    }
+}
+
+const BOOT_STAGE_PENDING: u64 = u64::MAX;
+
+// OWNER: logging module 独占每个 boot stage 的完成 timestamp；只有 boot CPU 在 `kernel_main` 与
+// 首次进入 scheduler 时各写一次。它独立于 kmsg ring 与 LOG_LEVEL：ring 覆盖或 `quiet` 过滤
+// Info record 后，`/proc/bootlog` 仍能投影完整 boot timeline。
+static BOOT_TIMELINE: [AtomicU64; BootStage::ALL.len()] =
+    [const { AtomicU64::new(BOOT_STAGE_PENDING) }; BootStage::ALL.len()];
+
+/// @description 记录 boot stage 完成 timestamp，并输出一条受 log level 约束的 Info record。
+/// @param stage 刚完成的 boot stage；重复记录表示 boot 顺序损坏。
+pub(crate) fn record_boot_stage(stage: BootStage) {
+    let timestamp_us = crate::timer::get_time_us();
+    let previous = BOOT_TIMELINE[stage as usize].swap(timestamp_us, Ordering::Release);
+    assert_eq!(
+        previous,
+        BOOT_STAGE_PENDING,
+        "boot stage {} recorded twice",
+        stage.name()
+    );
+    if enabled(LogLevel::Info) {
+        __log(
+            LogLevel::Info,
+            module_path!(),
+            format_args!("boot stage {} ready", stage.name()),
+        );
+    }
+}
+
+/// @description 按 boot 顺序遍历已完成 stage，供 `/proc/bootlog` 投影。
+/// @return `(stage name, 自 timebase 零点起的 microseconds)`；尚未完成的 stage 不出现。
+pub(crate) fn boot_timeline() -> impl Iterator<Item = (&'static str, u64)> {
+    BootStage::ALL.into_iter().filter_map(|stage| {
+        let timestamp_us = BOOT_TIMELINE[stage as usize].load(Ordering::Acquire);
+        (timestamp_us != BOOT_STAGE_PENDING).then_some((stage.name(), timestamp_us))
+    })
+}
+
 /// Module filter entry
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ModuleFilter {
@@ -295,6 +366,23 @@ fn set_log_level(level: LogLevel) {
     LOG_LEVEL.store(level as u8, Ordering::Release);
 }
 
+/// @description 按 Linux `console_loglevel` 语义设置阈值：只保留 syslog priority 小于该值的 record。
+///
+/// 本 module 最严重的 level 是 Error，因此 0..=3 仍保留 Error；阈值同时约束 kmsg ring。
+/// @param console_loglevel `loglevel=`、`debug`（10）或 `quiet`（4）给出的值。
+pub(crate) fn set_console_loglevel(console_loglevel: u8) {
+    let level = [
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ]
+    .into_iter()
+    .find(|level| level.syslog_priority() < console_loglevel)
+    .unwrap_or(LogLevel::Error);
+    set_log_level(level);
+}
+
 /// @description 在构造 format arguments 前判断 severity threshold。
 pub(crate) fn enabled(level: LogLevel) -> bool {
     level as u8 >= LOG_LEVEL.load(Ordering::Acquire)
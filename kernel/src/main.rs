@@ -28,6 +28,7 @@ mod lang_item;
 mod id;
 mod input;
 mod ipc;
+mod keys;
 mod memory;
 mod random;
 mod socket;
@@ -53,15 +54,26 @@ fn kernel_main(context: entry::BootContext) -> ! {
     memory::init_allocator();
     platform::initialize(context.platform());
     platform::verify_firmware();
-    cpu::initialize(platform::hardware_cpu_ids(), context.hardware_cpu());
+    let command_line = platform::command_line();
+    apply_log_parameters(command_line);
+    // Linux `nosmp`：只登记 boot CPU，secondary 不进入 topology，也不会被 firmware 启动。
+    let nosmp = command_line.flag(b"nosmp");
+    cpu::initialize(
+        platform::hardware_cpu_ids()
+            .filter(|hardware| !nosmp || *hardware == context.hardware_cpu()),
+        context.hardware_cpu(),
+    );
     task::initialize_interrupt_state();
     info!(
         "logical CPU topology initialized: count={}, boot={:?}",
         cpu::count(),
         cpu::boot_id()
     );
+    // 各 stage 完成 timestamp 进入 log 独占的 boot timeline，经 `/proc/bootlog` 投影。
+    log::record_boot_stage(log::BootStage::Board);
     memory::init();
     timer::init_rtc();
+    log::record_boot_stage(log::BootStage::Memory);
     fs::init_vfs();
     platform::initialize_devices();
     if let Some(display) = drivers::primary_display() {
@@ -79,12 +91,16 @@ fn kernel_main(context: entry::BootContext) -> ! {
     )
     .expect("Unix98 PTY initialization failed");
     socket::init();
+    log::record_boot_stage(log::BootStage::Drivers);
     mount_root_filesystem();
+    log::record_boot_stage(log::BootStage::Fs);
     task::init(
         arch::trap::user_entry(),
         trap::trap_return,
         Arc::try_new(PlatformConsole).expect("platform console allocation failed"),
+        command_line.value(b"init").unwrap_or(b"/bin/init"),
     );
+    log::record_boot_stage(log::BootStage::Task);
     // Release 发布页表、设备、文件系统和首个任务；secondary 在进入任何共享子系统前消费它。
     INIT_READY.store(true, Ordering::Release);
     for target in cpu::possible().iter() {
@@ -101,9 +117,8 @@ fn kernel_main(context: entry::BootContext) -> ! {
 }
 
 fn mount_root_filesystem() {
-    let device =
-        drivers::block::get_primary_block_device().expect("boot requires one primary block device");
-    let filesystem = fs::Ext2FileSystem::new(device).expect("invalid ext2 root filesystem");
+    let filesystem =
+        fs::Ext2FileSystem::new(root_block_device()).expect("invalid ext2 root filesystem");
     fs::vfs()
         .mount_root(b"root", filesystem)
         .expect("root filesystem mounted more than once");
@@ -141,6 +156,94 @@ fn mount_root_filesystem() {
     info!("sysfs mounted at /sys");
 }
 
+/// 每个 disk 的分区都发布为 `vdXN` block view；`root=/dev/vdX[N]` 按 DTB 注册顺序选择
+/// disk 与分区，缺省时 vda 的首个 Linux 分区为 root，无分区表时整盘即 root filesystem。
+fn root_block_device() -> Arc<dyn drivers::block::BlockDevice> {
+    let requested = platform::command_line().value(b"root").map(|name| {
+        drivers::block::parse_device_name(name).unwrap_or_else(|| {
+            panic!(
+                "unsupported root= device {:?}",
+                core::str::from_utf8(name).unwrap_or("<non-UTF-8>")
+            )
+        })
+    });
+    let name = requested.unwrap_or(drivers::block::BlockDeviceName {
+        disk: 0,
+        partition: None,
+    });
+    // 指定整盘的 root 不依赖分区表；其余 root 选择须读出合法表项。
+    let table_required = requested.is_none() || name.partition.is_some();
+    let mut root_partitions = alloc::vec::Vec::new();
+    for index in 0..drivers::block::MAX_BLOCK_DEVICES {
+        let Some(disk) = drivers::block::block_device(index) else {
+            break;
+        };
+        let letter = disk_letter(index);
+        info!("vd{}: block_size={}", letter, disk.block_size());
+        let partitions = match drivers::block::register_partitions(index) {
+            Ok(partitions) => partitions,
+            Err(error) if index == name.disk && table_required => {
+                panic!("invalid vd{} partition table: {:?}", letter, error)
+            }
+            Err(error) => {
+                warn!("vd{}: invalid partition table: {:?}", letter, error);
+                continue;
+            }
+        };
+        for partition in &partitions {
+            info!(
+                "vd{}{}: first_block={} blocks={} linux={}",
+                letter, partition.number, partition.first_block, partition.blocks, partition.linux
+            );
+        }
+        if index == name.disk {
+            root_partitions = partitions;
+        }
+    }
+    let letter = disk_letter(name.disk);
+    let name = match (
+        requested,
+        root_partitions.iter().find(|partition| partition.linux),
+    ) {
+        (None, Some(root)) => drivers::block::BlockDeviceName {
+            disk: 0,
+            partition: Some(root.number),
+        },
+        _ => name,
+    };
+    let root = drivers::block::named_block_device(name).unwrap_or_else(|| match name.partition {
+        Some(number) => panic!("root partition vd{}{} is not present", letter, number),
+        None => panic!("root disk vd{} is not present", letter),
+    });
+    match name.partition {
+        Some(number) => info!("root filesystem on vd{}{}", letter, number),
+        None => info!("root filesystem on vd{}", letter),
+    }
+    root
+}
+
+/// Linux virtio-blk 以注册顺序命名 vda..vdz。
+fn disk_letter(index: usize) -> char {
+    char::from(b'a' + index as u8)
+}
+
+/// Linux 按出现顺序处理 `loglevel=`、`debug` 与 `quiet`，最后一个生效；非法数值被忽略。
+fn apply_log_parameters(command_line: platform::CommandLine<'_>) {
+    for parameter in command_line.parameters() {
+        let level = match parameter {
+            (b"debug", None) => Some(10),
+            (b"quiet", None) => Some(4),
+            (b"loglevel", Some(value)) => core::str::from_utf8(value)
+                .ok()
+                .and_then(|value| value.parse().ok()),
+            _ => None,
+        };
+        if let Some(level) = level {
+            log::set_console_loglevel(level);
+        }
+    }
+}
+
 struct PlatformConsole;
 
 impl fs::Console for PlatformConsole {
@@ -189,6 +292,8 @@ fn init_local_arch(hardware_cpu: cpu::HardwareCpuId) {
 }
 
 fn enter_scheduler() -> ! {
+    // counter 必须先于 online 发布启动；否则 Thread 可被调度到尚未启动 counter 的 CPU。
+    task::start_hardware_counters();
     timer::enable_timer_interrupt();
     // SAFETY: local trap state and platform interrupt controllers are initialized before the
     // architecture enables scheduler interrupt delivery for this CPU.
@@ -204,6 +309,7 @@ fn enter_scheduler() -> ! {
             cpu::count(),
             cpu::online().native_word()
         );
+        log::record_boot_stage(log::BootStage::Smp);
     }
     // 每个 CPU 在发布 online 后只同步自己的共享 kernel translations；尚未 online 的 CPU
     // 不可作为 remote-fence target，已 online CPU 已在各自 activation 路径完成本地 fence。
@@ -30,7 +30,7 @@ pub(crate) enum FrameAllocationClass {
 /// @description frame allocator 唯一 owner 的瞬时容量与碎片快照。
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct FrameStatistics {
-    /// allocator 管辖的总页数。
+    /// allocator 管辖且未交还 hypervisor 的 guest 可用总页数。
     pub(crate) capacity_pages: usize,
     /// 所有 order 合计的当前空闲页数。
     pub(crate) free_pages: usize,
@@ -104,6 +104,24 @@ impl Drop for FrameTracker {
     }
 }
 
+/// @description 已由 hypervisor 确认回收的单页 owner；Drop 时恢复 guest 容量并归还 allocator。
+pub(crate) struct WithdrawnFrame(FrameTracker);
+
+impl WithdrawnFrame {
+    /// @description 返回交给 hypervisor 的物理页号。
+    pub(crate) fn ppn(&self) -> PhysicalPageNumber {
+        self.0.ppn
+    }
+}
+
+impl Drop for WithdrawnFrame {
+    fn drop(&mut self) {
+        // 先恢复 capacity 再由内部 FrameTracker 归还 free list，两步各自持锁。
+        let mut allocator = FRAME_ALLOCATOR.wait().lock();
+        allocator.withdrawn_pages -= self.0.pages;
+    }
+}
+
 impl Debug for FrameTracker {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
@@ -141,6 +159,9 @@ struct FrameAllocator {
     // OWNER: free_pages 是上述 buddy metadata 的同锁 projection，只用于低水位
     // 快速判定。缺失同 transaction 加减会使 kernel reserve 被误放行或永久拒绝。
     free_pages: usize,
+    // OWNER: withdrawn_pages 是 WithdrawnFrame 的同锁计数；仅从 statistics 的 guest
+    // capacity 中扣除。若不计数，balloon 回收的页仍会计入 MemTotal 与 dirty limit。
+    withdrawn_pages: usize,
 }
 
 impl FrameAllocator {
@@ -161,6 +182,7 @@ impl FrameAllocator {
             block_state,
             free_blocks: [0; ORDER_COUNT],
             free_pages: capacity,
+            withdrawn_pages: 0,
         };
 
         // 将任意起点/长度区间分解为最大的 absolute-PPN-aligned buddy blocks。
@@ -379,7 +401,7 @@ impl FrameAllocator {
 
     fn statistics(&self) -> FrameStatistics {
         FrameStatistics {
-            capacity_pages: self.capacity(),
+            capacity_pages: self.capacity() - self.withdrawn_pages,
             free_pages: self.free_pages,
             free_blocks: self.free_blocks,
         }
@@ -516,3 +538,13 @@ fn alloc_contiguous_uninitialized(
 pub(crate) fn statistics() -> FrameStatistics {
     FRAME_ALLOCATOR.wait().lock().statistics()
 }
+
+/// @description 把 hypervisor 已确认回收的单页从 guest capacity 中扣除。
+///
+/// @param frame balloon inflate 已完成的唯一单页 owner。
+/// @return 保持该页 allocated 且计入 withdrawn 的 owner。
+pub(crate) fn withdraw(frame: FrameTracker) -> WithdrawnFrame {
+    assert_eq!(frame.pages, 1, "withdrawn frames are single pages");
+    FRAME_ALLOCATOR.wait().lock().withdrawn_pages += 1;
+    WithdrawnFrame(frame)
+}
@@ -16,6 +16,14 @@ use super::{
 };
 use crate::sync::LocalIrqGuard;
 
+mod sanitizer;
+mod size_class;
+
+use sanitizer::{
+    HEAP_SANITIZER, Quarantine, poison_free_block, sanitize_allocation, sanitize_free,
+};
+use size_class::{MIN_CLASS_SIZE, SLAB_MAX_SIZE, class_layout, class_size, slab_layout};
+
 struct HeapStorage(UnsafeCell<[u8; config::BOOTSTRAP_HEAP_SIZE]>);
 
 // SAFETY: bootstrap storage 只由 BOOTSTRAP_OFFSET 在 IRQ-off lock 下分配；发布给调用方后
@@ -27,9 +35,7 @@ static KERNEL_HEAP_MEMORY: HeapStorage =
     HeapStorage(UnsafeCell::new([0; config::BOOTSTRAP_HEAP_SIZE]));
 static BOOTSTRAP_OFFSET: Mutex<usize> = Mutex::new(0);
 
-const MIN_CLASS_SIZE: usize = core::mem::size_of::<usize>();
 const CACHE_MAX_SIZE: usize = 256;
-const SLAB_MAX_SIZE: usize = 1024;
 const CACHE_CLASS_COUNT: usize =
     (CACHE_MAX_SIZE.trailing_zeros() - MIN_CLASS_SIZE.trailing_zeros() + 1) as usize;
 const SLAB_CLASS_COUNT: usize =
@@ -224,6 +230,10 @@ unsafe impl Sync for CpuHeapCaches {}
 // OWNER: 唯一 per-CPU small-block cache；cache 持有的 block 仍计入所属 slab allocated。
 static CPU_HEAP_CACHES: Once<CpuHeapCaches> = Once::new();
 
+// OWNER: debug build 的已释放 slab block 在交还 cache/slab 前唯一停留于此；IRQ-off leaf lock，
+// 不与 HEAP_STATE 或 per-CPU cache 嵌套。
+static HEAP_QUARANTINE: Mutex<Quarantine> = Mutex::new(Quarantine::new());
+
 // OWNER: 该 release/acquire flag 是 bootstrap -> frame-backed allocation 的唯一切换点。
 static FRAME_BACKED_GROWTH: AtomicBool = AtomicBool::new(false);
 
@@ -233,23 +243,6 @@ fn align_up(value: usize, alignment: usize) -> Option<usize> {
         .map(|value| value & !(alignment - 1))
 }
 
-fn class_layout(layout: Layout, maximum: usize) -> Option<(usize, Layout)> {
-    let size = layout
-        .size()
-        .checked_next_power_of_two()?
-        .max(layout.align())
-        .max(MIN_CLASS_SIZE);
-    if size > maximum {
-        return None;
-    }
-    let class = size.trailing_zeros() as usize - MIN_CLASS_SIZE.trailing_zeros() as usize;
-    Some((class, Layout::from_size_align(size, size).ok()?))
-}
-
-fn class_size(class: usize) -> usize {
-    MIN_CLASS_SIZE << class
-}
-
 fn prepare_slab(address: usize, class: usize) {
     let block_size = class_size(class);
     let first =
@@ -265,6 +258,7 @@ fn prepare_slab(address: usize, class: usize) {
         };
         // SAFETY: candidate FrameTracker uniquely owns the page; blocks are disjoint and unpublished.
         unsafe { (block as *mut usize).write(next) };
+        poison_free_block(block, block_size);
     }
     // SAFETY: address is page-aligned and the header fits before first block.
     unsafe {
@@ -284,10 +278,6 @@ fn cache_layout(layout: Layout) -> Option<(usize, Layout)> {
     class_layout(layout, CACHE_MAX_SIZE)
 }
 
-fn slab_layout(layout: Layout) -> Option<(usize, Layout)> {
-    class_layout(layout, SLAB_MAX_SIZE)
-}
-
 fn current_cpu_cache() -> Option<&'static UnsafeCell<CpuHeapCache>> {
     CPU_HEAP_CACHES
         .get()?
@@ -407,6 +397,14 @@ fn grow_and_allocate(layout: Layout) -> Option<NonNull<u8>> {
     allocate_direct(layout)
 }
 
+fn quarantine(block: NonNull<u8>, layout: Layout) -> Option<(NonNull<u8>, Layout)> {
+    if !HEAP_SANITIZER {
+        return Some((block, layout));
+    }
+    let _irq = LocalIrqGuard::disable();
+    HEAP_QUARANTINE.lock().admit(block, layout)
+}
+
 fn deallocate_backend(ptr: NonNull<u8>, layout: Layout) {
     let address = ptr.as_ptr() as usize;
     if let Some((class, _)) = slab_layout(layout) {
@@ -465,9 +463,11 @@ unsafe impl GlobalAlloc for KernelAllocator {
         if !FRAME_BACKED_GROWTH.load(Ordering::Acquire) {
             return bootstrap_allocate(layout).map_or(core::ptr::null_mut(), NonNull::as_ptr);
         }
-        try_allocate_slab(layout)
-            .or_else(|| grow_and_allocate(layout))
-            .map_or(core::ptr::null_mut(), NonNull::as_ptr)
+        let block = try_allocate_slab(layout).or_else(|| grow_and_allocate(layout));
+        if let Some(block) = block {
+            sanitize_allocation(block, layout);
+        }
+        block.map_or(core::ptr::null_mut(), NonNull::as_ptr)
     }
 
     // SAFETY: caller must return the exact pointer/layout pair produced by this allocator.
@@ -483,6 +483,10 @@ unsafe impl GlobalAlloc for KernelAllocator {
             return;
         }
         let block = NonNull::new(ptr).expect("non-null heap pointer");
+        sanitize_free(block, layout);
+        let Some((block, layout)) = quarantine(block, layout) else {
+            return;
+        };
         let _irq = LocalIrqGuard::disable();
         if let Some((class, _)) = cache_layout(layout)
             && let Some(cache) = current_cpu_cache()
@@ -0,0 +1,141 @@
+//! Debug-build slab poison and redzone checks.
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use super::{MIN_CLASS_SIZE, class_size, slab_layout};
+
+// debug build 以 slab class 尾部 slack 作 redzone，并以 poison 标记 free block；release build
+// 常量折叠为空，slab layout 与 release 完全一致。
+pub(super) const HEAP_SANITIZER: bool = cfg!(debug_assertions);
+const FREE_POISON: u8 = 0x6b;
+const REDZONE_POISON: u8 = 0xfc;
+// 释放后延迟复用的 block 数；越大越能拉开 use-after-free 写与下一次复用之间的窗口。
+const QUARANTINE_BLOCKS: usize = 64;
+
+/// @description debug build 中已释放、尚未交还 cache/slab 的 slab block FIFO。
+pub(super) struct Quarantine {
+    blocks: [(usize, Layout); QUARANTINE_BLOCKS],
+    oldest: usize,
+    len: usize,
+}
+
+impl Quarantine {
+    pub(super) const fn new() -> Self {
+        Self {
+            blocks: [(0, Layout::new::<u8>()); QUARANTINE_BLOCKS],
+            oldest: 0,
+            len: 0,
+        }
+    }
+
+    /// @description 收容刚通过 sanitize_free 的 block；满时按 FIFO 逐出最早进入的 block。
+    /// @param block 已写入 free poison 的 block。
+    /// @param layout block 分配时的原始 layout。
+    /// @return 需要真正交还 allocator 的 block；收容未满时为 None，非 slab layout 原样返回。
+    /// @errors 被逐出 block 的 poison 在 quarantine 期间被改写时 panic。
+    pub(super) fn admit(
+        &mut self,
+        block: NonNull<u8>,
+        layout: Layout,
+    ) -> Option<(NonNull<u8>, Layout)> {
+        if slab_layout(layout).is_none() {
+            return Some((block, layout));
+        }
+        let entry = (block.as_ptr() as usize, layout);
+        if self.len < QUARANTINE_BLOCKS {
+            self.blocks[(self.oldest + self.len) % QUARANTINE_BLOCKS] = entry;
+            self.len += 1;
+            return None;
+        }
+        let (address, evicted_layout) = core::mem::replace(&mut self.blocks[self.oldest], entry);
+        self.oldest = (self.oldest + 1) % QUARANTINE_BLOCKS;
+        let evicted = NonNull::new(address as *mut u8).expect("quarantined block is null");
+        let (evicted_class, _) = slab_layout(evicted_layout).expect("quarantined slab layout");
+        check_free_poison(evicted, class_size(evicted_class));
+        Some((evicted, evicted_layout))
+    }
+}
+
+/// @description 校验 free block 中 free-link word 之后的 poison 未被改写。
+/// @errors 任一字节不是 free poison 时 panic，报告 use-after-free 的地址与 offset。
+fn check_free_poison(block: NonNull<u8>, size: usize) {
+    // SAFETY: free block 的 [MIN_CLASS_SIZE, size) 由 prepare_slab/sanitize_free 写入 poison，
+    // caller 持有 allocator 对该 block 的独占权。
+    let free = unsafe {
+        core::slice::from_raw_parts(block.as_ptr().add(MIN_CLASS_SIZE), size - MIN_CLASS_SIZE)
+    };
+    if let Some(offset) = free.iter().position(|&byte| byte != FREE_POISON) {
+        panic!(
+            "heap use-after-free: {size}-byte block {:#x} modified at offset {} while free",
+            block.as_ptr() as usize,
+            MIN_CLASS_SIZE + offset
+        );
+    }
+}
+
+/// @description 以 free poison 覆盖 slab block 中 free-link word 之后的字节。
+/// @param block caller 独占的 block 起始地址。
+/// @param size block 所属 class 的字节数。
+pub(super) fn poison_free_block(block: usize, size: usize) {
+    if HEAP_SANITIZER {
+        // SAFETY: caller 独占完整 class-size block；首个 usize 留给 free link。
+        unsafe {
+            core::ptr::write_bytes(
+                (block + MIN_CLASS_SIZE) as *mut u8,
+                FREE_POISON,
+                size - MIN_CLASS_SIZE,
+            )
+        };
+    }
+}
+
+/// @description 交付 slab block 前校验 free poison，并把 payload 之后的 class slack 写为 redzone。
+/// @param block 刚从 per-CPU cache 或 slab free list 取出的 block。
+/// @param layout caller 请求的原始 layout。
+/// @errors block 在 free 期间被改写时 panic，报告 use-after-free 的地址与 offset。
+pub(super) fn sanitize_allocation(block: NonNull<u8>, layout: Layout) {
+    if !HEAP_SANITIZER {
+        return;
+    }
+    let Some((class, _)) = slab_layout(layout) else {
+        return;
+    };
+    let size = class_size(class);
+    // 本函数返回前 block 仍由 allocator 独占。
+    check_free_poison(block, size);
+    // SAFETY: [layout.size(), size) 是 canonical class 内不属于 payload 的 slack。
+    unsafe {
+        core::ptr::write_bytes(
+            block.as_ptr().add(layout.size()),
+            REDZONE_POISON,
+            size - layout.size(),
+        )
+    };
+}
+
+/// @description 回收 slab block 前校验 redzone，再以 free poison 覆盖整个 block。
+/// @param block caller 按 GlobalAlloc 契约交还的 block。
+/// @param layout 分配时的原始 layout。
+/// @errors payload 之后的 redzone 被改写时 panic，报告越界写的地址与 offset。
+pub(super) fn sanitize_free(block: NonNull<u8>, layout: Layout) {
+    if !HEAP_SANITIZER {
+        return;
+    }
+    let Some((class, _)) = slab_layout(layout) else {
+        return;
+    };
+    let size = class_size(class);
+    // SAFETY: sanitize_allocation 在交付时写入 [layout.size(), size) redzone，payload 不含该区间。
+    let redzone = unsafe {
+        core::slice::from_raw_parts(block.as_ptr().add(layout.size()), size - layout.size())
+    };
+    if let Some(offset) = redzone.iter().position(|&byte| byte != REDZONE_POISON) {
+        panic!(
+            "heap redzone overwritten: {}-byte allocation {:#x} written at offset {}",
+            layout.size(),
+            block.as_ptr() as usize,
+            layout.size() + offset
+        );
+    }
+    poison_free_block(block.as_ptr() as usize, size);
+}
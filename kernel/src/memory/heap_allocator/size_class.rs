@@ -0,0 +1,29 @@
+//! Power-of-two size classes shared by the per-CPU cache, slab pages and the sanitizer.
+
+use core::alloc::Layout;
+
+pub(super) const MIN_CLASS_SIZE: usize = core::mem::size_of::<usize>();
+pub(super) const SLAB_MAX_SIZE: usize = 1024;
+
+/// @description 把 layout 向上取整到不超过 `maximum` 的 power-of-two class。
+/// @return class index 与按 class size 自然对齐的 block layout；超出上限或溢出返回 `None`。
+pub(super) fn class_layout(layout: Layout, maximum: usize) -> Option<(usize, Layout)> {
+    let size = layout
+        .size()
+        .checked_next_power_of_two()?
+        .max(layout.align())
+        .max(MIN_CLASS_SIZE);
+    if size > maximum {
+        return None;
+    }
+    let class = size.trailing_zeros() as usize - MIN_CLASS_SIZE.trailing_zeros() as usize;
+    Some((class, Layout::from_size_align(size, size).ok()?))
+}
+
+pub(super) fn class_size(class: usize) -> usize {
+    MIN_CLASS_SIZE << class
+}
+
+pub(super) fn slab_layout(layout: Layout) -> Option<(usize, Layout)> {
+    class_layout(layout, SLAB_MAX_SIZE)
+}
@@ -2,8 +2,12 @@ use super::{
     KERNEL_SPACE, KERNEL_STACK_SIZE, MapPermission, MemoryError, PAGE_SIZE, address::VirtualAddress,
 };
 use crate::id::IdAllocator;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use spin::Mutex;
 
+/// guard page 之外每个 kernel stack 实际映射的页数。
+const MAPPED_PAGES: usize = KERNEL_STACK_SIZE / PAGE_SIZE - 1;
+
 #[derive(Debug)]
 pub(crate) struct KernelStack {
     handle: KernelStackHandle,
@@ -31,6 +35,7 @@ impl KernelStack {
             top.into(),
             MapPermission::R | MapPermission::W,
         )?;
+        KERNEL_STACK_PAGES.fetch_add(MAPPED_PAGES, Ordering::Relaxed);
 
         Ok(Self { handle })
     }
@@ -65,9 +70,16 @@ impl Drop for KernelStack {
             .wait()
             .lock()
             .remove_area_with_start_vpn(VirtualAddress::from(mapped_bottom).into());
+        KERNEL_STACK_PAGES.fetch_sub(MAPPED_PAGES, Ordering::Relaxed);
     }
 }
 
+/// @description 读取全部 live kernel stack 映射页数的常数时间 projection。
+/// @return 不含 guard page 的 resident 页数，供 `/proc/meminfo` 的 `KernelStack` 统计。
+pub(crate) fn resident_pages() -> usize {
+    KERNEL_STACK_PAGES.load(Ordering::Relaxed)
+}
+
 /// 获取应用内核栈的地址范围，返回 (bottom, top)
 fn kernel_stack_position(app_id: usize) -> (usize, usize) {
     // architecture façade owns the TTBR-visible stack window. AArch64 uses the canonical TTBR1
@@ -99,5 +111,8 @@ impl Drop for KernelStackHandle {
     }
 }
 
+// OWNER: live KernelStack mapping 的唯一页数 projection，与 KERNEL_SPACE area 插入/移除同生命周期
+// 以 Relaxed 原子提交；只提供瞬时统计，不参与 lifetime 判定。
+static KERNEL_STACK_PAGES: AtomicUsize = AtomicUsize::new(0);
 // OWNER: kernel-stack module exclusively allocates virtual stack handles.
 static KERNEL_STACK_HANDLE_ALLOCATOR: Mutex<IdAllocator> = Mutex::new(IdAllocator::new(1));
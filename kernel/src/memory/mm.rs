@@ -89,6 +89,9 @@ pub(crate) struct MemorySet {
     program_break: Option<ProgramBreak>,
     // OWNER: Linux mm 的 arg_start/arg_end；缺失时 procfs 只能伪造静态 argv，无法反映用户栈修改。
     argument_range: Range<usize>,
+    // OWNER: Linux `MMF_HAS_MDWE`；与 VMA 权限同属 MemorySet lock，exec 与 fork 继承且不可撤销。
+    // 放在 Process 会让 vfork/CLONE_VM 共享同一 mm 的 caller 看到分裂的 W^X policy。
+    deny_write_exec: bool,
 }
 
 impl MemorySet {
@@ -102,6 +105,7 @@ impl MemorySet {
             code_range: 0..0,
             program_break: None,
             argument_range: 0..0,
+            deny_write_exec: false,
         }
     }
 
@@ -114,6 +118,7 @@ impl MemorySet {
             code_range: 0..0,
             program_break: None,
             argument_range: 0..0,
+            deny_write_exec: false,
         })
     }
 
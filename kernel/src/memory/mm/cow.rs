@@ -103,6 +103,7 @@ impl MemorySet {
         cloned.code_range = self.code_range.clone();
         cloned.program_break = self.program_break;
         cloned.argument_range = self.argument_range.clone();
+        cloned.deny_write_exec = self.deny_write_exec;
         cloned.map_trampoline()?;
         let page_table = &mut self.page_table;
         let mut parent_commit = TranslationCommit::new();
@@ -110,6 +110,21 @@ impl MemorySet {
     /// @param load_bias ET_EXEC 为零，PIE 或 interpreter 使用固定非零基址。
     /// @return entry、auxv program-header facts 与最高 segment end。
     /// @errors 地址、权限、映射、资源或 source 读取失败；调用方丢弃新 MemorySet。
+    /// @description exec 继承旧地址空间的 MDWE policy，在新映像发布前复查 ELF VMA。
+    /// @param enable 旧地址空间是否已开启 MDWE。
+    /// @errors 开启时新映像含 RWX `PT_LOAD` 返回 `InvalidElf`，与 mmap/mprotect 拒绝同一组合。
+    pub(crate) fn inherit_deny_write_exec(&mut self, enable: bool) -> Result<(), ElfLoadError> {
+        self.deny_write_exec = enable;
+        if self
+            .areas
+            .values()
+            .any(|area| self.denies_write_exec(area.map_permission, area.map_permission))
+        {
+            return Err(ElfLoadError::InvalidElf);
+        }
+        Ok(())
+    }
+
     pub(super) fn map_elf_image(
         &mut self,
         image: &ParsedElf,
@@ -1,6 +1,27 @@
 use super::*;
 
 impl MemorySet {
+    /// @description 查询或开启 Linux `PR_MDWE_REFUSE_EXEC_GAIN` policy。
+    /// @param enable 为 true 时开启；policy 一经开启不可撤销。
+    /// @return 调用后 policy 是否生效。
+    pub(crate) fn deny_write_exec(&mut self, enable: bool) -> bool {
+        self.deny_write_exec |= enable;
+        self.deny_write_exec
+    }
+
+    /// @description Linux `map_deny_write_exec`：MDWE 下拒绝 WRITE|EXECUTE 与新增 EXECUTE。
+    /// @param current VMA 当前权限；新建 mapping 传入与 `requested` 相同的权限。
+    /// @param requested 目标权限。
+    pub(crate) fn denies_write_exec(
+        &self,
+        current: MapPermission,
+        requested: MapPermission,
+    ) -> bool {
+        self.deny_write_exec
+            && requested.contains(MapPermission::X)
+            && (requested.contains(MapPermission::W) || !current.contains(MapPermission::X))
+    }
+
     /// @description 修改完整 anonymous/file/ELF 区间权限，并按 VMA 边界原子拆分。
     pub(crate) fn protect_user_mapping(
         &mut self,
@@ -19,6 +40,7 @@ impl MemorySet {
                     area.kind,
                     VmaKind::Anonymous | VmaKind::Elf | VmaKind::File | VmaKind::Device
                 ) || area.device.is_some() && permission.contains(MapPermission::X)
+                    || self.denies_write_exec(area.map_permission, permission)
                 {
                     return Err(MemoryError::PermissionDenied);
                 }
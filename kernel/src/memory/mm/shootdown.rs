@@ -38,6 +38,10 @@ pub(in crate::memory) struct TranslationCommit {
     strength: FenceStrength,
     instruction_first_physical_page: usize,
     instruction_end_physical_page: usize,
+    // OWNER: native logical-CPU bitmap of every CPU that may cache a revoked translation. Each
+    // revoke record ORs the address-space seen mask read after its PTE write; recording no mask
+    // would let the remote fence skip a CPU that still holds the stale entry.
+    remote_cpus: usize,
     // OWNER: detached table frames remain alive until every required hardware walker fence
     // completes. Dropping them at PTE clear would let a stale parent PTE walk reused memory.
     retired_table_pages: FallibleMap<usize, FrameTracker>,
@@ -52,6 +56,7 @@ impl TranslationCommit {
             strength: FenceStrength::None,
             instruction_first_physical_page: usize::MAX,
             instruction_end_physical_page: 0,
+            remote_cpus: 0,
             retired_table_pages: FallibleMap::new(),
         }
     }
@@ -71,11 +76,24 @@ impl TranslationCommit {
     }
 
     /// @description 合并一个 contiguous leaf span；huge leaf revoke 必须覆盖完整 translation。
+    /// @note 未给出 CPU 集合的 revoke 保守地以全部 CPU 为远端 target。
     pub(in crate::memory) fn record_range(
         &mut self,
         first_page: usize,
         page_count: usize,
         transition: TranslationTransition,
+    ) {
+        self.record_range_on_cpus(first_page, page_count, transition, usize::MAX);
+    }
+
+    /// @description 合并一个 contiguous leaf span，并限定 revoke 需要同步的远端 CPU。
+    /// @param cpus address space 的 CPU-seen native bitmap；caller 必须在 PTE 写入之后读取。
+    pub(in crate::memory) fn record_range_on_cpus(
+        &mut self,
+        first_page: usize,
+        page_count: usize,
+        transition: TranslationTransition,
+        cpus: usize,
     ) {
         assert_ne!(page_count, 0, "translation range must not be empty");
         let end_page = first_page
@@ -87,6 +105,9 @@ impl TranslationCommit {
             TranslationTransition::Publish | TranslationTransition::Relax => FenceStrength::Local,
             TranslationTransition::Revoke => FenceStrength::Remote,
         });
+        if transition == TranslationTransition::Revoke {
+            self.remote_cpus |= cpus;
+        }
     }
 
     /// @description 记录本次 PTE transaction 发布 executable view 的物理页范围。
@@ -167,7 +188,7 @@ impl TranslationCommit {
         }
     }
 
-    /// @description 提交最小 local range fence，并只为 revoke/replace 同步远端 CPU。
+    /// @description 提交最小 local range fence，并只为 revoke/replace 同步曾缓存该 address space 的远端 CPU。
     /// @return 所有必需 target 完成 fence 后成功；firmware 失败时返回原错误。
     #[cfg(not(test))]
     pub(super) fn synchronize(&mut self) -> Result<(), TranslationSynchronizationError> {
@@ -186,10 +207,14 @@ impl TranslationCommit {
         if let Some((start, size)) = remote_range
             && plan.remote_targets != 0
         {
-            let mut targets = crate::cpu::online() & crate::cpu::possible();
+            let mut targets = crate::cpu::online()
+                & crate::cpu::possible()
+                & crate::cpu::CpuSet::from_native_word(self.remote_cpus);
             targets.remove(crate::cpu::current_id());
-            crate::platform::synchronize_tlb(targets, start, size)
-                .map_err(TranslationSynchronizationError::Translation)?;
+            if !targets.is_empty() {
+                crate::platform::synchronize_tlb(targets, start, size)
+                    .map_err(TranslationSynchronizationError::Translation)?;
+            }
         }
         if plan.local_instruction_fence {
             let start = self
@@ -354,6 +379,19 @@ mod tests {
         assert_eq!(plan.remote_targets, 7);
     }
 
+    #[test]
+    fn revoke_targets_union_of_recorded_address_space_cpus() {
+        let mut commit = TranslationCommit::new();
+        commit.record(3, TranslationTransition::Publish);
+        assert_eq!(commit.remote_cpus, 0);
+        commit.record_range_on_cpus(4, 1, TranslationTransition::Revoke, 0b0101);
+        commit.record_range_on_cpus(5, 1, TranslationTransition::Relax, 0b1000);
+        commit.record_range_on_cpus(6, 1, TranslationTransition::Revoke, 0b0010);
+        assert_eq!(commit.remote_cpus, 0b0111);
+        commit.record(7, TranslationTransition::Revoke);
+        assert_eq!(commit.remote_cpus, usize::MAX);
+    }
+
     #[test]
     fn huge_leaf_revoke_fences_the_complete_span() {
         let mut commit = TranslationCommit::new();
@@ -33,10 +33,11 @@ pub(crate) use executable::{
     ExecutableImage, ExecutableParseError, ExecutableSource, parse_interpreter_elf, parse_main_elf,
 };
 pub(crate) use frame_allocator::{
-    FrameAllocationClass, FrameTracker, alloc_contiguous, statistics as frame_statistics,
+    FrameAllocationClass, FrameTracker, WithdrawnFrame, alloc as alloc_frame, alloc_contiguous,
+    statistics as frame_statistics, withdraw as withdraw_frame,
 };
 pub(crate) use heap_allocator::statistics as heap_statistics;
-pub(crate) use kernel_stack::KernelStack;
+pub(crate) use kernel_stack::{KernelStack, resident_pages as kernel_stack_pages};
 pub(crate) use mm::{
     DeviceMappingSource, ElfLoadError, FileMappingError, FileMappingSource, FutexKey,
     MappingResourceLimits, MemoryAdvice, MemoryError, MemorySet, PageFaultAccess, PageFaultOutcome,
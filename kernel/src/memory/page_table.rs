@@ -121,7 +121,12 @@ impl PageTable {
         let unmapped = self.0.unmap(usize::from(vpn))?;
         let (first_page, page_count, retired) = unmapped.into_parts();
         commit.retain_table_pages(retired);
-        commit.record_range(first_page, page_count, TranslationTransition::Revoke);
+        commit.record_range_on_cpus(
+            first_page,
+            page_count,
+            TranslationTransition::Revoke,
+            self.0.translation_cpus(),
+        );
         Ok(())
     }
 
@@ -135,13 +140,15 @@ impl PageTable {
         let old = old_entry.permissions();
         self.0.set_flags(usize::from(vpn), permissions)?;
         if permissions != old {
-            commit.record(
+            commit.record_range_on_cpus(
                 vpn.as_usize(),
+                1,
                 if permissions.contains(old) {
                     TranslationTransition::Relax
                 } else {
                     TranslationTransition::Revoke
                 },
+                self.0.translation_cpus(),
             );
             if permissions.contains(PagePermissions::EXECUTE)
                 && !old.contains(PagePermissions::EXECUTE)
@@ -0,0 +1,85 @@
+//! @description firmware `/chosen/bootargs` 的 Linux kernel command-line 只读 key/value 视图。
+
+/// @description 按 Linux `next_arg` 语义切分的 command line；不复制 firmware bytes。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CommandLine<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> CommandLine<'a> {
+    /// @description 包装 NUL 终止符之前的 bootargs bytes。
+    pub(crate) const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// @description 依次产生 `(key, value)`；无 `=` 的 flag value 为 None。
+    ///
+    /// 空白分隔参数，双引号内空白不分隔且 value 外层引号被剥离；`--` 之后的参数属于 init，
+    /// kernel 不再解析。
+    pub(crate) fn parameters(self) -> impl Iterator<Item = (&'a [u8], Option<&'a [u8]>)> {
+        let mut rest = self.bytes;
+        core::iter::from_fn(move || {
+            rest = rest.trim_ascii_start();
+            if rest.is_empty() {
+                return None;
+            }
+            let mut quoted = false;
+            let end = rest
+                .iter()
+                .position(|&byte| {
+                    quoted ^= byte == b'"';
+                    !quoted && byte.is_ascii_whitespace()
+                })
+                .unwrap_or(rest.len());
+            let (argument, remaining) = rest.split_at(end);
+            rest = remaining;
+            if argument == b"--" {
+                rest = &[];
+                return None;
+            }
+            Some(match argument.iter().position(|&byte| byte == b'=') {
+                Some(split) => {
+                    let value = &argument[split + 1..];
+                    let value = value
+                        .strip_prefix(b"\"")
+                        .and_then(|inner| inner.strip_suffix(b"\""))
+                        .unwrap_or(value);
+                    (&argument[..split], Some(value))
+                }
+                None => (argument, None),
+            })
+        })
+    }
+
+    /// @description 查询 `key=value` 参数；重复出现时最后一个生效，与 Linux setup handler 覆盖顺序一致。
+    pub(crate) fn value(self, key: &[u8]) -> Option<&'a [u8]> {
+        self.parameters()
+            .filter(|(name, _)| *name == key)
+            .filter_map(|(_, value)| value)
+            .last()
+    }
+
+    /// @description 查询无 value 的 boolean 参数是否出现。
+    pub(crate) fn flag(self, key: &[u8]) -> bool {
+        self.parameters()
+            .any(|(name, value)| name == key && value.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameters_follow_linux_quoting_and_last_value_wins() {
+        let line =
+            CommandLine::new(b" root=/dev/vda  nosmp init=\"/bin/a b\" root=/dev/vdb2 -- quiet");
+        assert_eq!(line.value(b"root"), Some(&b"/dev/vdb2"[..]));
+        assert_eq!(line.value(b"init"), Some(&b"/bin/a b"[..]));
+        assert!(line.flag(b"nosmp"));
+        assert!(!line.flag(b"root"));
+        // `--` 之后的参数交给 init。
+        assert!(!line.flag(b"quiet"));
+        assert_eq!(CommandLine::new(b"").parameters().count(), 0);
+    }
+}
@@ -7,13 +7,24 @@ mod qemu_virt;
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use qemu_virt as selected;
 
+mod command_line;
+
+pub(crate) use command_line::CommandLine;
+
 #[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
 compile_error!("LiteOS currently has no platform implementation for this target architecture");
 
 pub(crate) use selected::{
-    BootInfo, ClaimedInterrupt, InstructionFenceError, ResetError, TlbShootdownError, arm_timer,
+    BootInfo, ClaimedInterrupt, InstructionFenceError, TlbShootdownError, arm_timer,
     claim_interrupt, complete_interrupt, console, debug_console_write, hardware_cpu_ids,
     initialize, initialize_devices, kernel_mmio_regions, notify_self, physical_memory_end,
-    read_realtime_ns, reset_system, send_ipi, start_cpu, synchronize_instruction_cache,
-    synchronize_tlb, timebase_frequency, validate_boot_info, verify_firmware,
+    read_realtime_ns, reset_system, send_ipi, start_cpu, start_performance_counters,
+    synchronize_instruction_cache, synchronize_tlb, timebase_frequency, validate_boot_info,
+    verify_firmware,
 };
+
+/// @description 投影 firmware 传入的 kernel command line。
+/// @return DTB `/chosen/bootargs` 的 key/value 视图；缺失时为空。
+pub(crate) fn command_line() -> CommandLine<'static> {
+    CommandLine::new(selected::boot_arguments())
+}
@@ -2,8 +2,8 @@
 
 use super::{discovery, gicv3, pl011};
 use crate::drivers::{
-    DisplayDevice, InputDevice, MmioBus, VirtIOBlockDevice, VirtIOGpuDevice, VirtIOInputDevice,
-    VirtIONetworkDevice, VirtIORngDevice,
+    DisplayDevice, InputDevice, MmioBus, VirtIOBalloonDevice, VirtIOBlockDevice, VirtIOGpuDevice,
+    VirtIOInputDevice, VirtIONetworkDevice, VirtIORngDevice,
 };
 use crate::{error, info, warn};
 
@@ -52,6 +52,7 @@ fn initialize_virtio_devices() {
             1 => initialize_network(device),
             2 => initialize_block(device),
             4 => initialize_rng(device),
+            5 => initialize_balloon(device),
             16 => initialize_gpu(device),
             18 => initialize_input(device),
             _ => info!(
@@ -94,6 +95,15 @@ fn initialize_rng(resource: &discovery::MmioDevice) {
     info!("[Platform] VirtIO RNG at {:#x}", resource.base_addr);
 }
 
+fn initialize_balloon(resource: &discovery::MmioDevice) {
+    let device = VirtIOBalloonDevice::new(mapped_base(resource.base_addr))
+        .expect("virtio-balloon init failed");
+    register_irq(resource.irq, device.irq_handler_for(), "virtio-balloon");
+    crate::drivers::register_balloon_device(device)
+        .expect("only one virtio-balloon device is supported");
+    info!("[Platform] VirtIO balloon at {:#x}", resource.base_addr);
+}
+
 fn initialize_gpu(resource: &discovery::MmioDevice) {
     let device =
         VirtIOGpuDevice::new(mapped_base(resource.base_addr)).expect("virtio-gpu init failed");
@@ -63,6 +63,8 @@ pub(crate) struct PlatformInfo {
     pub(crate) gic: GicV3Info,
     pub(crate) virtio_devices: [Option<MmioDevice>; MAX_VIRTIO_DEVICES],
     pub(crate) virtio_count: usize,
+    /// `/chosen/bootargs` without the NUL terminator; empty when absent.
+    pub(crate) bootargs: Vec<u8>,
 }
 
 impl fmt::Display for PlatformInfo {
@@ -150,6 +152,7 @@ impl PlatformInfo {
         let mut psci_compatible = false;
         let mut psci_hvc = false;
         let mut coherent_dma = false;
+        let mut bootargs = Vec::new();
 
         let dtb_pointer = crate::arch::mmu::physical_to_virtual(dtb_address) as *const u8;
         // SAFETY: x0 follows the Linux arm64 boot ABI and the static TTBR1 direct map covers DTB;
@@ -259,6 +262,12 @@ impl PlatformInfo {
                 let node = context.name();
                 if name == Str::from("method") && is_psci_node(node) {
                     psci_hvc = contains_string(value, "hvc");
+                } else if name == Str::from("bootargs") && node == Str::from("chosen") {
+                    let value = value.split(|byte| *byte == 0).next().unwrap_or(&[]);
+                    bootargs
+                        .try_reserve_exact(value.len())
+                        .expect("bootargs discovery allocation failed");
+                    bootargs.extend_from_slice(value);
                 } else if name == Str::from("interrupts") {
                     if is_uart_node(node) {
                         uart_irq = decode_first_gic_interrupt(value);
@@ -317,6 +326,7 @@ impl PlatformInfo {
             gic,
             virtio_devices,
             virtio_count,
+            bootargs,
         }
     }
 }
@@ -324,6 +334,7 @@ impl PlatformInfo {
 fn interesting_root_node(name: Str<'_>) -> bool {
     name == Str::from("cpus")
         || name == Str::from("soc")
+        || name == Str::from("chosen")
         || name.starts_with("memory")
         || interesting_device_node(name)
         || is_psci_node(name)
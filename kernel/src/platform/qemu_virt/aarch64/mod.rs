@@ -13,7 +13,7 @@ mod psci;
 pub(crate) use devices::initialize as initialize_devices;
 pub(crate) use discovery::{BootInfo, hardware_cpu_ids};
 pub(crate) use gicv3::{claim_interrupt, complete_interrupt, notify_self, send_ipi};
-pub(crate) use psci::{ResetError, reset_system, start_cpu};
+pub(crate) use psci::{reset_system, start_cpu};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct TimerArmError;
@@ -51,6 +51,10 @@ pub(crate) fn verify_firmware() {
     psci::verify();
 }
 
+pub(crate) fn start_performance_counters() -> bool {
+    crate::arch::performance::start()
+}
+
 pub(crate) fn debug_console_write(byte: u8) -> Result<(), console::ConsoleError> {
     console::write_byte(byte)
 }
@@ -59,6 +63,10 @@ pub(crate) fn physical_memory_end() -> usize {
     discovery::info().memory.end
 }
 
+pub(crate) fn boot_arguments() -> &'static [u8] {
+    &discovery::info().bootargs
+}
+
 pub(crate) fn timebase_frequency() -> u64 {
     crate::arch::time::counter_frequency()
 }
@@ -4,8 +4,8 @@ use super::uart;
 #[cfg(debug_assertions)]
 use crate::debug;
 use crate::drivers::{
-    DisplayDevice, InputDevice, InterruptHandler, MmioBus, VirtIOBlockDevice, VirtIOGpuDevice,
-    VirtIOInputDevice, VirtIONetworkDevice, VirtIORngDevice,
+    DisplayDevice, InputDevice, InterruptHandler, MmioBus, VirtIOBalloonDevice, VirtIOBlockDevice,
+    VirtIOGpuDevice, VirtIOInputDevice, VirtIONetworkDevice, VirtIORngDevice,
 };
 use crate::sync::IrqMutex;
 use crate::{error, info, warn};
@@ -81,6 +81,7 @@ fn init_virtio_devices(board_info: &PlatformInfo) {
                 1 => init_virtio_net_device(board_info, virtio_dev.irq, base_addr),
                 2 => init_virtio_blk_device(board_info, virtio_dev.irq, base_addr),
                 4 => init_virtio_rng_device(board_info, virtio_dev.irq, base_addr),
+                5 => init_virtio_balloon_device(board_info, virtio_dev.irq, base_addr),
                 16 => init_virtio_gpu_device(board_info, virtio_dev.irq, base_addr),
                 18 => init_virtio_input_device(board_info, virtio_dev.irq, base_addr),
                 _ => info!(
@@ -136,6 +137,17 @@ fn init_virtio_rng_device(board_info: &PlatformInfo, irq: u32, base_addr: usize)
     info!("[Platform] VirtIO RNG registered at {:#x}", base_addr);
 }
 
+fn init_virtio_balloon_device(board_info: &PlatformInfo, irq: u32, base_addr: usize) {
+    let device = VirtIOBalloonDevice::new(base_addr).expect("DTB virtio-balloon must initialize");
+    assert!(
+        maybe_register_irq(board_info, irq, device.irq_handler_for(), "balloon"),
+        "virtio-balloon requires a registered IRQ"
+    );
+    crate::drivers::register_balloon_device(device)
+        .expect("only one virtio-balloon device is supported");
+    info!("[Platform] VirtIO balloon registered at {:#x}", base_addr);
+}
+
 fn init_virtio_gpu_device(board_info: &PlatformInfo, irq: u32, base_addr: usize) {
     let device = VirtIOGpuDevice::new(base_addr).expect("DTB virtio-gpu must initialize");
     let mode = device.mode();
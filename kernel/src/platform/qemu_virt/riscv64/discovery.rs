@@ -112,6 +112,8 @@ pub(crate) struct PlatformInfo {
     pub(crate) virtio_count: usize,
     pub(crate) rtc_device: Option<RTCDevice>,
     pub(crate) plic_device: Option<PLICDevice>,
+    /// `/chosen/bootargs` 去掉 NUL 终止符后的 bytes；缺失时为空。
+    pub(crate) bootargs: Vec<u8>,
 }
 
 impl<const N: usize> Display for StringInLine<N> {
@@ -177,6 +179,7 @@ impl PlatformInfo {
         const VIRTIO: &str = "virtio_mmio";
         const RTC: &str = "rtc";
         const PLIC: &str = "plic";
+        const CHOSEN: &str = "chosen";
 
         let mut ans = PlatformInfo {
             dtb: dtb_addr..dtb_addr,
@@ -192,6 +195,7 @@ impl PlatformInfo {
             virtio_count: 0,
             rtc_device: None,
             plic_device: None,
+            bootargs: Vec::new(),
         };
 
         // 用于临时存储当前 VirtIO 设备的信息
@@ -222,7 +226,11 @@ impl PlatformInfo {
             DtbObj::SubNode { name, .. } => {
                 let current = ctx.name();
                 if ctx.is_root() {
-                    if name == Str::from(CPUS) || name == Str::from(SOC) || name.starts_with(MEM) {
+                    if name == Str::from(CPUS)
+                        || name == Str::from(SOC)
+                        || name == Str::from(CHOSEN)
+                        || name.starts_with(MEM)
+                    {
                         WalkOperation::StepInto
                     } else if name.starts_with(VIRTIO) {
                         // 遇到 VirtIO 设备节点，准备解析
@@ -355,6 +363,12 @@ impl PlatformInfo {
                 let node = ctx.name();
                 if name == Str::from("timebase-frequency") {
                     ans.time_base_freq = bytes_to_usize(value) as u64;
+                } else if name == Str::from("bootargs") && node == Str::from(CHOSEN) {
+                    let value = value.split(|byte| *byte == 0).next().unwrap_or(&[]);
+                    ans.bootargs
+                        .try_reserve_exact(value.len())
+                        .expect("bootargs discovery allocation failed");
+                    ans.bootargs.extend_from_slice(value);
                 } else if name == Str::from("interrupts")
                     && (node.starts_with(UART) || node.starts_with(SERIAL))
                 {
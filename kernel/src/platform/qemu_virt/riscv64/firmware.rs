@@ -4,6 +4,7 @@ const EID_RFENCE: usize = 0x5246_4e43;
 const EID_SYSTEM_RESET: usize = 0x5352_5354;
 const EID_DEBUG_CONSOLE: usize = 0x4442_434e;
 const EID_HSM: usize = 0x0048_534d;
+const EID_PMU: usize = 0x0050_4d55;
 const EID_BASE: usize = 0x10;
 
 const FID_SET_TIMER: usize = 0;
@@ -15,6 +16,17 @@ const FID_CONSOLE_WRITE: usize = 0;
 const FID_CONSOLE_WRITE_BYTE: usize = 2;
 const FID_HART_START: usize = 0;
 const FID_PROBE_EXTENSION: usize = 3;
+const FID_COUNTER_CONFIG_MATCHING: usize = 2;
+
+// SBI PMU hardware general event code（event type 0）。
+const PMU_HW_CPU_CYCLES: usize = 1;
+const PMU_HW_INSTRUCTIONS: usize = 2;
+// mask 只允许 logical counter 0/2，firmware 必须把事件绑定到 `cycle`/`instret` CSR；
+// 否则 kernel 需要按 counter_get_info 动态选择 CSR，读取路径无法保持静态。
+const PMU_CYCLE_COUNTER: usize = 0;
+const PMU_INSTRET_COUNTER: usize = 2;
+const PMU_CONFIG_CLEAR_VALUE: usize = 1 << 1;
+const PMU_CONFIG_AUTO_START: usize = 1 << 2;
 
 /// @description SBI operation failure retained only inside the platform implementation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -144,6 +156,36 @@ pub(crate) fn verify_firmware() {
     }
 }
 
+/// @description 通过 SBI PMU 在当前 hart 清零并启动 cycle/instret counter，使 S-mode 可直接读取。
+///
+/// @return firmware 实现 PMU 且两个事件都绑定到预期固定 counter 时返回 true；否则返回 false，
+/// 此时即使部分 counter 已启动，kernel 也不得读取任一 counter CSR。
+pub(crate) fn start_performance_counters() -> bool {
+    if !probe_extension(EID_PMU).unwrap_or(false) {
+        return false;
+    }
+    [
+        (PMU_HW_CPU_CYCLES, PMU_CYCLE_COUNTER),
+        (PMU_HW_INSTRUCTIONS, PMU_INSTRET_COUNTER),
+    ]
+    .into_iter()
+    .all(|(event, counter)| {
+        let (error, value) = sbi_call(
+            EID_PMU,
+            FID_COUNTER_CONFIG_MATCHING,
+            [
+                counter,
+                1,
+                PMU_CONFIG_CLEAR_VALUE | PMU_CONFIG_AUTO_START,
+                event,
+                0,
+                0,
+            ],
+        );
+        value_or_error(error, value) == Ok(counter)
+    })
+}
+
 /// @description 通过 SBI HSM 启动一个 DTB secondary hart。
 ///
 /// @param hardware_cpu_id 目标 RISC-V hart identity。
@@ -13,9 +13,9 @@ mod uart;
 pub(crate) use devices::{handle_external_interrupt, initialize as initialize_devices};
 pub(crate) use discovery::{BootInfo, hardware_cpu_ids, initialize, validate_boot_info};
 pub(crate) use firmware::{
-    InstructionFenceError, ResetError, TlbShootdownError, arm_timer, debug_console_write,
-    debug_console_write_bytes, reset_system, send_ipi, start_cpu, synchronize_instruction_cache,
-    synchronize_tlb, verify_firmware,
+    InstructionFenceError, TlbShootdownError, arm_timer, debug_console_write,
+    debug_console_write_bytes, reset_system, send_ipi, start_cpu, start_performance_counters,
+    synchronize_instruction_cache, synchronize_tlb, verify_firmware,
 };
 
 /// @description claim 并处理当前 RISC-V external interrupt batch。
@@ -44,6 +44,12 @@ pub(crate) fn physical_memory_end() -> usize {
     discovery::info().mem.end
 }
 
+/// @description 投影 firmware 传入的 kernel command line。
+/// @return DTB `/chosen/bootargs` bytes；缺失时为空。
+pub(crate) fn boot_arguments() -> &'static [u8] {
+    &discovery::info().bootargs
+}
+
 /// @description 投影 architecture counter 的 platform frequency。
 /// @return DTB `timebase-frequency`，零值由 timer owner fail-stop。
 pub(crate) fn timebase_frequency() -> u64 {
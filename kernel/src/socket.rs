@@ -4,6 +4,8 @@ use core::net::Ipv4Addr;
 use crate::ipc::ReceiveBuffer;
 use crate::ipc::{Pipe, PipeDirection, PipeEnd};
 
+#[path = "socket/audit.rs"]
+mod audit;
 #[path = "socket/inet.rs"]
 mod inet;
 #[path = "socket/kobject.rs"]
@@ -23,11 +25,13 @@ mod send;
 #[path = "socket/unix.rs"]
 mod unix;
 
+use audit::AuditSocket;
+pub(crate) use audit::{AuditSyscall, audit_active, record_syscall_exit};
 use inet::InetSocket;
 use kobject::KobjectSocket;
 pub(crate) use kobject::publish_drm_hotplug;
 use packet::PacketSocket;
-pub(crate) use send::{SocketSendBlocker, SocketSendError, SocketWaitGuard};
+pub(crate) use send::{SocketSendBlocker, SocketSendError, SocketSender, SocketWaitGuard};
 use unix::UnixSocket;
 pub(crate) use unix::{
     SCM_MAX_FD, UnixAddress, UnixNode, UnixPassedFile, UnixPathIdentity, UnixRights,
@@ -132,6 +136,8 @@ pub(crate) enum SocketError {
     WrongType,
     /// real-UID SCM_RIGHTS inflight 超过 sender RLIMIT_NOFILE。
     TooManyReferences,
+    /// netlink receive queue 溢出后丢弃过 reply。
+    NoBufferSpace,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -158,6 +164,7 @@ enum SocketBackend {
     Inet(Arc<InetSocket>),
     Packet(Arc<PacketSocket>),
     Kobject(Arc<KobjectSocket>),
+    Audit(Arc<AuditSocket>),
     /// AF_INET raw control fd；data plane 未开放时不复制 NetworkStack 协议状态。
     InterfaceControl,
 }
@@ -234,6 +241,9 @@ impl Socket {
             (SocketDomain::Netlink, SocketType::Datagram, 15) => {
                 SocketBackend::Kobject(KobjectSocket::new(notify)?)
             }
+            (SocketDomain::Netlink, SocketType::Datagram | SocketType::Raw, 9) => {
+                SocketBackend::Audit(AuditSocket::new(notify)?)
+            }
             (SocketDomain::Inet, SocketType::Raw, 255) => SocketBackend::InterfaceControl,
             _ => return Err(SocketError::ProtocolNotSupported),
         };
@@ -273,6 +283,7 @@ impl Socket {
             (SocketBackend::Kobject(socket), SocketAddress::Netlink(address)) => {
                 socket.bind(address)
             }
+            (SocketBackend::Audit(socket), SocketAddress::Netlink(address)) => socket.bind(address),
             (SocketBackend::InterfaceControl, _) => Err(SocketError::OperationNotSupported),
             _ => Err(SocketError::Invalid),
         }
@@ -299,7 +310,9 @@ impl Socket {
             SocketBackend::Unix(socket) => socket.listen(backlog),
             SocketBackend::Inet(socket) => socket.listen(backlog),
             SocketBackend::Packet(_) => Err(SocketError::OperationNotSupported),
-            SocketBackend::Kobject(_) => Err(SocketError::OperationNotSupported),
+            SocketBackend::Kobject(_) | SocketBackend::Audit(_) => {
+                Err(SocketError::OperationNotSupported)
+            }
             SocketBackend::InterfaceControl => Err(SocketError::OperationNotSupported),
         }
     }
@@ -336,7 +349,9 @@ impl Socket {
                 )
             }
             (SocketBackend::InterfaceControl, _) => Err(SocketError::OperationNotSupported),
-            (SocketBackend::Kobject(_), _) => Err(SocketError::OperationNotSupported),
+            (SocketBackend::Kobject(_) | SocketBackend::Audit(_), _) => {
+                Err(SocketError::OperationNotSupported)
+            }
             _ => Err(SocketError::Invalid),
         }
     }
@@ -376,7 +391,9 @@ impl Socket {
                 .map_err(|_| SocketError::NoMemory)
             }
             SocketBackend::Packet(_) => Err(SocketError::OperationNotSupported),
-            SocketBackend::Kobject(_) => Err(SocketError::OperationNotSupported),
+            SocketBackend::Kobject(_) | SocketBackend::Audit(_) => {
+                Err(SocketError::OperationNotSupported)
+            }
             SocketBackend::InterfaceControl => Err(SocketError::OperationNotSupported),
         }
     }
@@ -394,7 +411,9 @@ impl Socket {
             SocketBackend::Inet(socket) => socket.connection_result(),
             SocketBackend::Unix(_) => Ok(()),
             SocketBackend::Packet(_) => Err(SocketError::OperationNotSupported),
-            SocketBackend::Kobject(_) => Err(SocketError::OperationNotSupported),
+            SocketBackend::Kobject(_) | SocketBackend::Audit(_) => {
+                Err(SocketError::OperationNotSupported)
+            }
             SocketBackend::InterfaceControl => Ok(()),
         }
     }
@@ -407,7 +426,7 @@ impl Socket {
             SocketBackend::Inet(socket) => socket.take_error(),
             SocketBackend::Unix(_) => None,
             SocketBackend::Packet(_) => None,
-            SocketBackend::Kobject(_) => None,
+            SocketBackend::Kobject(_) | SocketBackend::Audit(_) => None,
             SocketBackend::InterfaceControl => None,
         }
     }
@@ -466,7 +485,10 @@ impl Socket {
                     })
             }
             SocketBackend::Kobject(socket) if !peek => socket.receive(output),
-            SocketBackend::Kobject(_) => Err(SocketError::OperationNotSupported),
+            SocketBackend::Audit(socket) if !peek => socket.receive(output),
+            SocketBackend::Kobject(_) | SocketBackend::Audit(_) => {
+                Err(SocketError::OperationNotSupported)
+            }
             SocketBackend::InterfaceControl => Err(SocketError::OperationNotSupported),
         }
     }
@@ -501,13 +523,18 @@ impl Socket {
         )
     }
 
-    pub(crate) fn write(&self, input: &[u8]) -> Result<usize, SocketSendError> {
+    pub(crate) fn write(
+        &self,
+        input: &[u8],
+        sender: SocketSender,
+    ) -> Result<usize, SocketSendError> {
         self.validate_send_length(input.len())
             .map_err(SocketSendError::from)?;
         match &self.backend {
             SocketBackend::Unix(socket) => socket.write(input),
             SocketBackend::Inet(socket) => socket.send_to(input, None).map_err(Into::into),
             SocketBackend::Packet(socket) => socket.send_to(input, None).map_err(Into::into),
+            SocketBackend::Audit(socket) => socket.send(input, None, sender),
             SocketBackend::Kobject(_) => Err(SocketError::OperationNotSupported.into()),
             SocketBackend::InterfaceControl => Err(SocketError::OperationNotSupported.into()),
         }
@@ -517,12 +544,14 @@ impl Socket {
     /// @param input 本次 byte payload。
     /// @param target 可选显式 socket address。
     /// @param rights 尚未提交的 passed-file capability 集合。
+    /// @param sender NETLINK_AUDIT request 鉴权使用的 caller 快照。
     /// @return 实际 byte count；失败时 rights 不被消费。
     pub(crate) fn send_to_with_rights(
         &self,
         input: &[u8],
         target: Option<SocketAddress>,
         rights: &mut Option<UnixRights>,
+        sender: SocketSender,
     ) -> Result<usize, SocketSendError> {
         self.validate_send_length(input.len())
             .map_err(SocketSendError::from)?;
@@ -543,6 +572,7 @@ impl Socket {
             (SocketBackend::Packet(socket), None) => {
                 socket.send_to(input, None).map_err(Into::into)
             }
+            (SocketBackend::Audit(socket), target) => socket.send(input, target, sender),
             (SocketBackend::Kobject(_), _) => Err(SocketError::OperationNotSupported.into()),
             (SocketBackend::InterfaceControl, _) => Err(SocketError::OperationNotSupported.into()),
             _ => Err(SocketError::Invalid.into()),
@@ -554,7 +584,9 @@ impl Socket {
             SocketBackend::Unix(socket) => socket.shutdown(how),
             SocketBackend::Inet(socket) => socket.shutdown(how),
             SocketBackend::Packet(_) => Err(SocketError::OperationNotSupported),
-            SocketBackend::Kobject(_) => Err(SocketError::OperationNotSupported),
+            SocketBackend::Kobject(_) | SocketBackend::Audit(_) => {
+                Err(SocketError::OperationNotSupported)
+            }
             SocketBackend::InterfaceControl => Err(SocketError::OperationNotSupported),
         }
     }
@@ -0,0 +1,553 @@
+use alloc::sync::{Arc, Weak};
+use core::sync::atomic::{AtomicBool, Ordering};
+use spin::Mutex;
+
+use crate::ipc::{Pipe, PipeDirection, PipeEnd, ReceiveBuffer};
+
+use super::{
+    NetlinkAddress, ReceivedMessage, SocketAddress, SocketError, SocketPollState, SocketSendError,
+    SocketSender,
+};
+
+#[path = "audit/wire.rs"]
+mod wire;
+
+use wire::{
+    AUDIT_ADD_RULE, AUDIT_DEL_RULE, AUDIT_GET, AUDIT_LIST_RULES, AUDIT_SET, AUDIT_SYSCALL,
+    AuditError, AuditRecord, AuditRule, AuditStatus, BACKLOG_CAPACITY, NLM_F_CAPPED, NLM_F_MULTI,
+    NLMSG_DONE, NLMSG_ERROR, NLMSG_HEADER_BYTES, NetlinkHeader, RULE_BYTES, RuleSubject,
+    STATUS_BYTES, SyscallFacts,
+};
+
+const REPLY_CAPACITY: usize = 16;
+const MAX_RULES: usize = 16;
+/// Linux `audit_backlog_limit` 默认值。
+const DEFAULT_BACKLOG_LIMIT: u32 = 64;
+
+/// @description syscall exit 向 audit owner 提交的一次调用事实。
+pub(crate) struct AuditSyscall {
+    /// Linux `AUDIT_ARCH_*`。
+    pub(crate) arch: u32,
+    pub(crate) syscall: usize,
+    /// 写回用户态的返回值或负 errno。
+    pub(crate) result: isize,
+    /// Linux record 只记录前四个原始参数。
+    pub(crate) arguments: [usize; 4],
+    pub(crate) pid: u32,
+    pub(crate) uid: u32,
+    pub(crate) euid: u32,
+    pub(crate) gid: u32,
+    /// realtime 秒与毫秒，用作 record 的 `audit(sec.ms:serial)` 时间戳。
+    pub(crate) seconds: u64,
+    pub(crate) milliseconds: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Reply {
+    Ack {
+        request: NetlinkHeader,
+        error: i32,
+    },
+    Status {
+        sequence: u32,
+        status: [u8; STATUS_BYTES],
+    },
+    /// LIST_RULES 按 cursor 逐条读取当前 rule 表，最后发送 NLMSG_DONE。
+    Rules {
+        sequence: u32,
+        next: usize,
+    },
+}
+
+struct AuditSocketState {
+    address: Option<NetlinkAddress>,
+    replies: [Option<Reply>; REPLY_CAPACITY],
+    head: usize,
+    length: usize,
+    /// reply 队列溢出后按 Linux `sk_err = ENOBUFS` 在下一次 receive 报告一次。
+    overrun: bool,
+}
+
+/// @description NETLINK_AUDIT 的 request/reply endpoint；注册为 daemon 后同时排空 record backlog。
+pub(super) struct AuditSocket {
+    state: Mutex<AuditSocketState>,
+    notify_read: Arc<PipeEnd>,
+    notify_write: Arc<PipeEnd>,
+}
+
+struct AuditDaemon {
+    socket: Weak<AuditSocket>,
+    tgid: u32,
+}
+
+struct AuditOwner {
+    enabled: bool,
+    backlog_limit: u32,
+    lost: u32,
+    serial: u32,
+    daemon: Option<AuditDaemon>,
+    rules: [Option<AuditRule>; MAX_RULES],
+    rule_count: usize,
+    backlog: [AuditRecord; BACKLOG_CAPACITY],
+    head: usize,
+    length: usize,
+}
+
+// OWNER: audit owner 唯一拥有 enabled、rule 表、daemon 与 record backlog；拆分后 syscall exit
+// 会在 rule 与 backlog limit 之间观察到撕裂的配置。锁序为 endpoint state -> OWNER，request
+// 处理先释放 OWNER 再写 reply；OWNER 内只 signal Pipe，不获取任何 endpoint state。
+static OWNER: Mutex<AuditOwner> = Mutex::new(AuditOwner {
+    enabled: false,
+    backlog_limit: DEFAULT_BACKLOG_LIMIT,
+    lost: 0,
+    serial: 0,
+    daemon: None,
+    rules: [None; MAX_RULES],
+    rule_count: 0,
+    backlog: [AuditRecord::EMPTY; BACKLOG_CAPACITY],
+    head: 0,
+    length: 0,
+});
+
+// OWNER: 只是 OWNER.enabled && rule_count > 0 的无锁投影，由 OWNER 临界区内发布；缺失它
+// 每个 syscall 都要获取全局锁。Relaxed 过期只影响配置变更后紧随的一次 syscall 是否被记录。
+static AUDIT_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// @description syscall exit 快速判断是否需要构造 audit 事实。
+pub(crate) fn audit_active() -> bool {
+    AUDIT_ACTIVE.load(Ordering::Relaxed)
+}
+
+/// @description 按 exit filter rule 判定并把命中的 syscall 追加到 record backlog。
+/// @param event syscall exit 事实。
+/// @return 无返回值；backlog 达到 limit 时丢弃并计入 `lost`，与 Linux 非阻塞 failure 路径一致。
+pub(crate) fn record_syscall_exit(event: &AuditSyscall) {
+    let mut owner = OWNER.lock();
+    if !owner.enabled {
+        return;
+    }
+    let subject = RuleSubject {
+        arch: event.arch,
+        syscall: event.syscall,
+        pid: event.pid,
+        uid: event.uid,
+    };
+    let decision = owner.rules[..owner.rule_count]
+        .iter()
+        .flatten()
+        .find_map(|rule| rule.decide(subject));
+    if decision != Some(true) {
+        return;
+    }
+    owner.serial = owner.serial.wrapping_add(1).max(1);
+    if owner.length >= owner.backlog_limit as usize {
+        owner.lost = owner.lost.saturating_add(1);
+        return;
+    }
+    let record = AuditRecord::syscall(SyscallFacts {
+        seconds: event.seconds,
+        milliseconds: event.milliseconds,
+        serial: owner.serial,
+        arch: event.arch,
+        syscall: event.syscall,
+        result: event.result,
+        arguments: event.arguments,
+        pid: event.pid,
+        uid: event.uid,
+        euid: event.euid,
+        gid: event.gid,
+    });
+    let index = (owner.head + owner.length) % BACKLOG_CAPACITY;
+    owner.backlog[index] = record;
+    owner.length += 1;
+    let daemon = (owner.length == 1)
+        .then(|| {
+            owner
+                .daemon
+                .as_ref()
+                .and_then(|daemon| daemon.socket.upgrade())
+        })
+        .flatten();
+    drop(owner);
+    if let Some(daemon) = daemon {
+        daemon.notify_write.signal_readiness();
+    }
+}
+
+impl AuditOwner {
+    fn live_daemon(&self) -> Option<&AuditDaemon> {
+        self.daemon
+            .as_ref()
+            .filter(|daemon| daemon.socket.strong_count() != 0)
+    }
+
+    fn status(&self) -> AuditStatus {
+        AuditStatus {
+            enabled: self.enabled,
+            daemon: self.live_daemon().map_or(0, |daemon| daemon.tgid),
+            backlog_limit: self.backlog_limit,
+            lost: self.lost,
+            backlog: self.length as u32,
+        }
+    }
+
+    fn publish_active(&self) {
+        AUDIT_ACTIVE.store(self.enabled && self.rule_count != 0, Ordering::Relaxed);
+    }
+
+    fn rule_index(&self, rule: &AuditRule) -> Option<usize> {
+        self.rules[..self.rule_count]
+            .iter()
+            .position(|existing| existing.as_ref() == Some(rule))
+    }
+}
+
+impl AuditSocket {
+    pub(super) fn new(notify: (Arc<PipeEnd>, Arc<PipeEnd>)) -> Result<Arc<Self>, SocketError> {
+        Arc::try_new(Self {
+            state: Mutex::new(AuditSocketState {
+                address: None,
+                replies: [None; REPLY_CAPACITY],
+                head: 0,
+                length: 0,
+                overrun: false,
+            }),
+            notify_read: notify.0,
+            notify_write: notify.1,
+        })
+        .map_err(|_| SocketError::NoMemory)
+    }
+
+    /// @description 记录本地 port；audit multicast group 需要 CAP_AUDIT_READ 语义，尚未开放。
+    pub(super) fn bind(&self, address: NetlinkAddress) -> Result<(), SocketError> {
+        if address.groups != 0 {
+            return Err(SocketError::Invalid);
+        }
+        let mut state = self.state.lock();
+        if state.address.is_some() {
+            return Err(SocketError::Invalid);
+        }
+        state.address = Some(address);
+        Ok(())
+    }
+
+    pub(super) fn address(&self) -> NetlinkAddress {
+        self.state.lock().address.unwrap_or(NetlinkAddress {
+            port_id: 0,
+            groups: 0,
+        })
+    }
+
+    /// @description 逐条处理 datagram 中的 audit request，并把 reply/ack 排入本 endpoint。
+    /// @param input 一次 sendmsg 的完整 payload。
+    /// @param target 可选显式目的地址；audit request 只能发往 kernel port 0。
+    /// @param sender sendmsg caller 的 credential 快照。
+    /// @return 整个 datagram 长度；单条 request 的错误只经 NLMSG_ERROR reply 报告。
+    /// @errors 目的地址不是 kernel port 时返回 `Invalid`。
+    pub(super) fn send(
+        self: &Arc<Self>,
+        input: &[u8],
+        target: Option<SocketAddress>,
+        sender: SocketSender,
+    ) -> Result<usize, SocketSendError> {
+        match target {
+            None | Some(SocketAddress::Netlink(NetlinkAddress { port_id: 0, .. })) => {}
+            Some(_) => return Err(SocketError::Invalid.into()),
+        }
+        let mut offset = 0;
+        while let Some((header, payload, next)) = wire::next_message(input, offset) {
+            offset = next;
+            let result = if header.dispatches() {
+                self.handle(header, payload, sender)
+            } else {
+                Ok(None)
+            };
+            if let Ok(Some(reply)) = result {
+                self.push_reply(reply);
+            }
+            match result {
+                Err(error) => self.push_reply(Reply::Ack {
+                    request: header,
+                    error: error.errno(),
+                }),
+                Ok(_) if header.wants_ack() => self.push_reply(Reply::Ack {
+                    request: header,
+                    error: 0,
+                }),
+                Ok(_) => {}
+            }
+        }
+        Ok(input.len())
+    }
+
+    fn handle(
+        self: &Arc<Self>,
+        header: NetlinkHeader,
+        payload: &[u8],
+        sender: SocketSender,
+    ) -> Result<Option<Reply>, AuditError> {
+        // Linux 先拒绝非初始 PID namespace，再区分未知 type 与 CAP_AUDIT_CONTROL。
+        if !sender.initial_pid_namespace {
+            return Err(AuditError::Permission);
+        }
+        if !matches!(
+            header.kind,
+            AUDIT_GET | AUDIT_SET | AUDIT_ADD_RULE | AUDIT_DEL_RULE | AUDIT_LIST_RULES
+        ) {
+            return Err(AuditError::Invalid);
+        }
+        if !sender.privileged {
+            return Err(AuditError::Permission);
+        }
+        match header.kind {
+            AUDIT_GET => Ok(Some(Reply::Status {
+                sequence: header.sequence,
+                status: OWNER.lock().status().encode(),
+            })),
+            AUDIT_SET => self.set_status(payload, sender.tgid).map(|()| None),
+            AUDIT_LIST_RULES => Ok(Some(Reply::Rules {
+                sequence: header.sequence,
+                next: 0,
+            })),
+            kind => change_rules(payload, kind == AUDIT_ADD_RULE).map(|()| None),
+        }
+    }
+
+    fn set_status(self: &Arc<Self>, payload: &[u8], tgid: u32) -> Result<(), AuditError> {
+        let change = wire::parse_status_change(payload)?;
+        let mut owner = OWNER.lock();
+        if let Some(daemon) = change.daemon {
+            if daemon != 0 && daemon != tgid {
+                return Err(AuditError::Invalid);
+            }
+            if let Some(live) = owner.live_daemon() {
+                // 不允许替换健康的 daemon；只有当前 daemon 自己可以注销。
+                if daemon != 0 {
+                    return Err(AuditError::Exists);
+                }
+                if live.tgid != tgid {
+                    return Err(AuditError::Access);
+                }
+            }
+        }
+        if let Some(enabled) = change.enabled {
+            owner.enabled = enabled;
+        }
+        if let Some(limit) = change.backlog_limit {
+            owner.backlog_limit = limit;
+        }
+        let notify = match change.daemon {
+            Some(0) => {
+                owner.daemon = None;
+                false
+            }
+            Some(daemon) => {
+                owner.daemon = Some(AuditDaemon {
+                    socket: Arc::downgrade(self),
+                    tgid: daemon,
+                });
+                owner.length != 0
+            }
+            None => false,
+        };
+        owner.publish_active();
+        drop(owner);
+        if notify {
+            self.notify_write.signal_readiness();
+        }
+        Ok(())
+    }
+
+    fn push_reply(&self, reply: Reply) {
+        let mut state = self.state.lock();
+        if state.length == REPLY_CAPACITY {
+            state.overrun = true;
+        } else {
+            let index = (state.head + state.length) % REPLY_CAPACITY;
+            state.replies[index] = Some(reply);
+            state.length += 1;
+        }
+        drop(state);
+        self.notify_write.signal_readiness();
+    }
+
+    pub(super) fn receive(
+        &self,
+        output: &mut ReceiveBuffer<'_>,
+    ) -> Result<ReceivedMessage, SocketError> {
+        let mut message = [0u8; NLMSG_HEADER_BYTES + RULE_BYTES];
+        let full_length = {
+            let mut state = self.state.lock();
+            if core::mem::take(&mut state.overrun) {
+                return Err(SocketError::NoBufferSpace);
+            }
+            if state.length != 0 {
+                let port_id = state.address.map_or(0, |address| address.port_id);
+                let head = state.head;
+                let reply = state.replies[head].expect("queued audit reply slot is empty");
+                let (length, advanced) = encode_reply(reply, port_id, &mut message);
+                match advanced {
+                    Some(next) => state.replies[head] = Some(next),
+                    None => {
+                        state.replies[head] = None;
+                        state.head = (head + 1) % REPLY_CAPACITY;
+                        state.length -= 1;
+                    }
+                }
+                length
+            } else {
+                drop(state);
+                self.take_record(&mut message)?
+            }
+        };
+        let count = output.append(&message[..full_length]);
+        Ok(ReceivedMessage {
+            count,
+            full_length,
+            source: Some(SocketAddress::Netlink(NetlinkAddress {
+                port_id: 0,
+                groups: 0,
+            })),
+            local_address: None,
+            rights: None,
+        })
+    }
+
+    fn take_record(&self, message: &mut [u8]) -> Result<usize, SocketError> {
+        let mut owner = OWNER.lock();
+        if owner.length == 0 || !owner.live_daemon().is_some_and(|daemon| self.is(daemon)) {
+            return Err(SocketError::Again);
+        }
+        let record = owner.backlog[owner.head];
+        owner.head = (owner.head + 1) % BACKLOG_CAPACITY;
+        owner.length -= 1;
+        drop(owner);
+        let text = record.text();
+        let header = NetlinkHeader::reply(AUDIT_SYSCALL, 0, 0, text.len());
+        message[..NLMSG_HEADER_BYTES].copy_from_slice(&header.encode());
+        message[NLMSG_HEADER_BYTES..NLMSG_HEADER_BYTES + text.len()].copy_from_slice(text);
+        Ok(NLMSG_HEADER_BYTES + text.len())
+    }
+
+    fn is(&self, daemon: &AuditDaemon) -> bool {
+        core::ptr::eq(daemon.socket.as_ptr(), self)
+    }
+
+    pub(super) fn poll_state(&self) -> SocketPollState {
+        let (replies, overrun) = {
+            let state = self.state.lock();
+            (state.length != 0, state.overrun)
+        };
+        let records = !replies && {
+            let owner = OWNER.lock();
+            owner.length != 0 && owner.live_daemon().is_some_and(|daemon| self.is(daemon))
+        };
+        SocketPollState {
+            readable: replies || overrun || records,
+            writable: true,
+            hangup: false,
+            error: overrun,
+        }
+    }
+
+    pub(super) fn readiness_generation(&self) -> u64 {
+        self.notify_read
+            .pipe()
+            .readiness_generation(PipeDirection::Read)
+    }
+
+    pub(super) fn wait_source(&self) -> Arc<Pipe> {
+        self.notify_read.pipe()
+    }
+
+    pub(super) fn consume_wait_notification(&self) {
+        self.notify_read.drain_readiness();
+    }
+}
+
+fn change_rules(payload: &[u8], add: bool) -> Result<(), AuditError> {
+    let (rule, prepend) = wire::parse_rule(payload)?;
+    let mut owner = OWNER.lock();
+    let existing = owner.rule_index(&rule);
+    let count = owner.rule_count;
+    match (add, existing) {
+        (true, Some(_)) => return Err(AuditError::Exists),
+        (true, None) if count == MAX_RULES => return Err(AuditError::NoSpace),
+        (true, None) if prepend => {
+            owner.rules[..=count].rotate_right(1);
+            owner.rules[0] = Some(rule);
+            owner.rule_count += 1;
+        }
+        (true, None) => {
+            owner.rules[count] = Some(rule);
+            owner.rule_count += 1;
+        }
+        (false, None) => return Err(AuditError::NotFound),
+        (false, Some(index)) => {
+            owner.rules[index..count].rotate_left(1);
+            owner.rules[count - 1] = None;
+            owner.rule_count -= 1;
+        }
+    }
+    owner.publish_active();
+    Ok(())
+}
+
+/// @description 把队首 reply 编码为一个 netlink message。
+/// @return message 长度，以及 LIST_RULES 尚未结束时应替换队首的后继 reply。
+fn encode_reply(reply: Reply, port_id: u32, message: &mut [u8]) -> (usize, Option<Reply>) {
+    let mut put = |header: NetlinkHeader, payload: &[&[u8]]| {
+        message[..NLMSG_HEADER_BYTES].copy_from_slice(&header.encode());
+        let mut offset = NLMSG_HEADER_BYTES;
+        for part in payload {
+            message[offset..offset + part.len()].copy_from_slice(part);
+            offset += part.len();
+        }
+        offset
+    };
+    match reply {
+        // 与 NETLINK_CAP_ACK 一致：ack/error 只回显原 request header。
+        Reply::Ack { request, error } => {
+            let header = NetlinkHeader {
+                port_id,
+                ..NetlinkHeader::reply(NLMSG_ERROR, NLM_F_CAPPED, request.sequence, 20)
+            };
+            (
+                put(header, &[&error.to_ne_bytes(), &request.encode()]),
+                None,
+            )
+        }
+        Reply::Status { sequence, status } => (
+            put(
+                NetlinkHeader::reply(AUDIT_GET, 0, sequence, STATUS_BYTES),
+                &[&status],
+            ),
+            None,
+        ),
+        Reply::Rules { sequence, next } => {
+            let rule = {
+                let owner = OWNER.lock();
+                owner.rules[..owner.rule_count].get(next).copied().flatten()
+            };
+            match rule {
+                Some(rule) => (
+                    put(
+                        NetlinkHeader::reply(AUDIT_LIST_RULES, NLM_F_MULTI, sequence, RULE_BYTES),
+                        &[&rule.encode()],
+                    ),
+                    Some(Reply::Rules {
+                        sequence,
+                        next: next + 1,
+                    }),
+                ),
+                None => (
+                    put(
+                        NetlinkHeader::reply(NLMSG_DONE, NLM_F_MULTI, sequence, 0),
+                        &[],
+                    ),
+                    None,
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! @description NETLINK_AUDIT 的纯 wire codec：nlmsghdr、`audit_status`、exit-filter rule 与
+//! AUDIT_SYSCALL record 文本；不持有任何 owner 状态，可在 host 上直接测试。
+
+pub(super) const NLMSG_HEADER_BYTES: usize = 16;
+pub(super) const NLMSG_ERROR: u16 = 2;
+pub(super) const NLMSG_DONE: u16 = 3;
+const NLMSG_MIN_TYPE: u16 = 0x10;
+const NLM_F_REQUEST: u16 = 0x1;
+pub(super) const NLM_F_MULTI: u16 = 0x2;
+const NLM_F_ACK: u16 = 0x4;
+pub(super) const NLM_F_CAPPED: u16 = 0x100;
+
+pub(super) const AUDIT_GET: u16 = 1000;
+pub(super) const AUDIT_SET: u16 = 1001;
+pub(super) const AUDIT_ADD_RULE: u16 = 1011;
+pub(super) const AUDIT_DEL_RULE: u16 = 1012;
+pub(super) const AUDIT_LIST_RULES: u16 = 1013;
+pub(super) const AUDIT_SYSCALL: u16 = 1300;
+
+const AUDIT_STATUS_ENABLED: u32 = 0x1;
+const AUDIT_STATUS_PID: u32 = 0x4;
+const AUDIT_STATUS_BACKLOG_LIMIT: u32 = 0x10;
+/// Linux `AUDIT_FAIL_PRINTK`；本实现只计数 lost，不提供 panic failure mode。
+const AUDIT_FAIL_PRINTK: u32 = 1;
+pub(super) const STATUS_BYTES: usize = 44;
+
+const AUDIT_FILTER_EXIT: u32 = 0x04;
+const AUDIT_FILTER_PREPEND: u32 = 0x10;
+const AUDIT_NEVER: u32 = 0;
+const AUDIT_ALWAYS: u32 = 2;
+const AUDIT_BITMASK_SIZE: usize = 64;
+const AUDIT_MAX_FIELDS: usize = 64;
+const AUDIT_PID: u32 = 0;
+const AUDIT_UID: u32 = 1;
+const AUDIT_ARCH: u32 = 11;
+const AUDIT_NOT_EQUAL: u32 = 0x3000_0000;
+const AUDIT_EQUAL: u32 = 0x4000_0000;
+/// `struct audit_rule_data` 固定部分：flags/action/field_count、四个 64 项数组与 buflen。
+pub(super) const RULE_BYTES: usize = 12 + AUDIT_BITMASK_SIZE * 4 * 4 + 4;
+
+/// backlog 物理容量；也是 `backlog_limit` 可设置的上限。
+pub(super) const BACKLOG_CAPACITY: usize = 64;
+/// 全部字段取最大宽度时 record 为 273 字节：20 位秒、10 位 serial、20 位 syscall、带符号 19 位
+/// exit、四个 16 位十六进制参数与四个 10 位 id；向上取整留出对齐余量。
+pub(super) const RECORD_CAPACITY: usize = 280;
+
+/// @description NETLINK_AUDIT request 被拒绝的 Linux errno 分类。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AuditError {
+    Invalid,
+    Permission,
+    Access,
+    Exists,
+    NotFound,
+    NoSpace,
+}
+
+impl AuditError {
+    /// @description 返回 NLMSG_ERROR payload 中的负 Linux errno。
+    pub(super) const fn errno(self) -> i32 {
+        -match self {
+            Self::Permission => 1,
+            Self::NotFound => 2,
+            Self::Access => 13,
+            Self::Exists => 17,
+            Self::Invalid => 22,
+            Self::NoSpace => 28,
+        }
+    }
+}
+
+/// @description 一个已校验长度的 netlink message header。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct NetlinkHeader {
+    pub(super) length: u32,
+    pub(super) kind: u16,
+    pub(super) flags: u16,
+    pub(super) sequence: u32,
+    pub(super) port_id: u32,
+}
+
+impl NetlinkHeader {
+    /// @description 编码为 16-byte native-endian `struct nlmsghdr`。
+    pub(super) fn encode(self) -> [u8; NLMSG_HEADER_BYTES] {
+        let mut bytes = [0u8; NLMSG_HEADER_BYTES];
+        bytes[..4].copy_from_slice(&self.length.to_ne_bytes());
+        bytes[4..6].copy_from_slice(&self.kind.to_ne_bytes());
+        bytes[6..8].copy_from_slice(&self.flags.to_ne_bytes());
+        bytes[8..12].copy_from_slice(&self.sequence.to_ne_bytes());
+        bytes[12..].copy_from_slice(&self.port_id.to_ne_bytes());
+        bytes
+    }
+
+    /// @description kernel 发往 userspace 的 reply header，`nlmsg_pid` 固定为 kernel port 0。
+    pub(super) fn reply(kind: u16, flags: u16, sequence: u32, payload: usize) -> Self {
+        Self {
+            length: (NLMSG_HEADER_BYTES + payload) as u32,
+            kind,
+            flags,
+            sequence,
+            port_id: 0,
+        }
+    }
+
+    /// @description 判断 Linux `netlink_rcv_skb` 是否把该 message 交给 audit handler。
+    /// @return 缺少 NLM_F_REQUEST 或 control type 的 message 只按 NLM_F_ACK 回 ack。
+    pub(super) fn dispatches(self) -> bool {
+        self.flags & NLM_F_REQUEST != 0 && self.kind >= NLMSG_MIN_TYPE
+    }
+
+    /// @description 判断 request 是否显式要求成功 ack。
+    pub(super) fn wants_ack(self) -> bool {
+        self.flags & NLM_F_ACK != 0
+    }
+}
+
+/// @description 按 Linux `nlmsg_next` 语义遍历一个 datagram 中的 netlink message。
+/// @param datagram 一次 sendmsg 的完整 payload。
+/// @param offset 上一 message 之后 4-byte 对齐的位置。
+/// @return header、payload 与下一 offset；长度非法时停止遍历，与 Linux 丢弃剩余部分一致。
+pub(super) fn next_message(
+    datagram: &[u8],
+    offset: usize,
+) -> Option<(NetlinkHeader, &[u8], usize)> {
+    let rest = datagram.get(offset..)?;
+    if rest.len() < NLMSG_HEADER_BYTES {
+        return None;
+    }
+    let word = |at: usize| u32::from_ne_bytes(rest[at..at + 4].try_into().unwrap());
+    let half = |at: usize| u16::from_ne_bytes(rest[at..at + 2].try_into().unwrap());
+    let header = NetlinkHeader {
+        length: word(0),
+        kind: half(4),
+        flags: half(6),
+        sequence: word(8),
+        port_id: word(12),
+    };
+    let length = header.length as usize;
+    if length < NLMSG_HEADER_BYTES || length > rest.len() {
+        return None;
+    }
+    let next = offset + ((length + 3) & !3);
+    Some((header, &rest[NLMSG_HEADER_BYTES..length], next))
+}
+
+/// @description audit owner 对外可见的 `struct audit_status` 字段。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct AuditStatus {
+    pub(super) enabled: bool,
+    pub(super) daemon: u32,
+    pub(super) backlog_limit: u32,
+    pub(super) lost: u32,
+    pub(super) backlog: u32,
+}
+
+impl AuditStatus {
+    /// @description 编码为 Linux 44-byte `struct audit_status`；rate limit 与 wait time 未实现，恒为零。
+    pub(super) fn encode(self) -> [u8; STATUS_BYTES] {
+        let words = [
+            0,
+            u32::from(self.enabled),
+            AUDIT_FAIL_PRINTK,
+            self.daemon,
+            0,
+            self.backlog_limit,
+            self.lost,
+            self.backlog,
+            0,
+            0,
+            0,
+        ];
+        let mut bytes = [0u8; STATUS_BYTES];
+        for (chunk, word) in bytes.as_chunks_mut::<4>().0.iter_mut().zip(words) {
+            *chunk = word.to_ne_bytes();
+        }
+        bytes
+    }
+}
+
+/// @description 已校验的 AUDIT_SET 请求；字段为 None 表示 mask 未选择。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct StatusChange {
+    pub(super) enabled: Option<bool>,
+    pub(super) daemon: Option<u32>,
+    pub(super) backlog_limit: Option<u32>,
+}
+
+/// @description 解析 AUDIT_SET payload；短 payload 按 Linux 补零。
+/// @return 只接受 ENABLED(0/1)、PID 与 1..=64 的 BACKLOG_LIMIT；其余 mask 返回 Invalid。
+pub(super) fn parse_status_change(payload: &[u8]) -> Result<StatusChange, AuditError> {
+    let mut bytes = [0u8; STATUS_BYTES];
+    let length = payload.len().min(STATUS_BYTES);
+    bytes[..length].copy_from_slice(&payload[..length]);
+    let word =
+        |index: usize| u32::from_ne_bytes(bytes[index * 4..index * 4 + 4].try_into().unwrap());
+    let mask = word(0);
+    if mask & !(AUDIT_STATUS_ENABLED | AUDIT_STATUS_PID | AUDIT_STATUS_BACKLOG_LIMIT) != 0 {
+        return Err(AuditError::Invalid);
+    }
+    let enabled = match (mask & AUDIT_STATUS_ENABLED != 0, word(1)) {
+        (false, _) => None,
+        (true, value @ (0 | 1)) => Some(value == 1),
+        (true, _) => return Err(AuditError::Invalid),
+    };
+    let backlog_limit = match (mask & AUDIT_STATUS_BACKLOG_LIMIT != 0, word(5)) {
+        (false, _) => None,
+        (true, limit @ 1..=64) => Some(limit),
+        (true, _) => return Err(AuditError::Invalid),
+    };
+    Ok(StatusChange {
+        enabled,
+        daemon: (mask & AUDIT_STATUS_PID != 0).then(|| word(3)),
+        backlog_limit,
+    })
+}
+
+/// @description 一个 `AUDIT_FILTER_EXIT` rule；字段按原样保留，供 LIST/DEL 精确比较。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct AuditRule {
+    always: bool,
+    syscalls: [u32; AUDIT_BITMASK_SIZE],
+    field_count: usize,
+    fields: [u32; AUDIT_MAX_FIELDS],
+    values: [u32; AUDIT_MAX_FIELDS],
+    operators: [u32; AUDIT_MAX_FIELDS],
+}
+
+/// @description rule 匹配所需的 syscall exit 事实。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct RuleSubject {
+    pub(super) arch: u32,
+    pub(super) syscall: usize,
+    pub(super) pid: u32,
+    pub(super) uid: u32,
+}
+
+/// @description 解析 AUDIT_ADD_RULE/AUDIT_DEL_RULE 的 `struct audit_rule_data`。
+/// @return rule 与是否带 AUDIT_FILTER_PREPEND。
+/// @errors 非 exit filter、未知 action、字符串字段或 PID/UID/ARCH 以外的字段返回 Invalid。
+pub(super) fn parse_rule(payload: &[u8]) -> Result<(AuditRule, bool), AuditError> {
+    if payload.len() < RULE_BYTES {
+        return Err(AuditError::Invalid);
+    }
+    let word =
+        |index: usize| u32::from_ne_bytes(payload[index * 4..index * 4 + 4].try_into().unwrap());
+    let array = |start: usize| -> [u32; AUDIT_BITMASK_SIZE] {
+        core::array::from_fn(|index| word(start + index))
+    };
+    let flags = word(0);
+    if flags & !AUDIT_FILTER_PREPEND != AUDIT_FILTER_EXIT {
+        return Err(AuditError::Invalid);
+    }
+    let always = match word(1) {
+        AUDIT_ALWAYS => true,
+        AUDIT_NEVER => false,
+        _ => return Err(AuditError::Invalid),
+    };
+    let field_count = word(2) as usize;
+    if field_count > AUDIT_MAX_FIELDS || word(3 + AUDIT_BITMASK_SIZE * 4) != 0 {
+        return Err(AuditError::Invalid);
+    }
+    let mut rule = AuditRule {
+        always,
+        syscalls: array(3),
+        field_count,
+        fields: [0; AUDIT_MAX_FIELDS],
+        values: [0; AUDIT_MAX_FIELDS],
+        operators: [0; AUDIT_MAX_FIELDS],
+    };
+    let (fields, values, operators) = (
+        array(3 + AUDIT_BITMASK_SIZE),
+        array(3 + AUDIT_BITMASK_SIZE * 2),
+        array(3 + AUDIT_BITMASK_SIZE * 3),
+    );
+    for index in 0..field_count {
+        if !matches!(fields[index], AUDIT_PID | AUDIT_UID | AUDIT_ARCH)
+            || !matches!(operators[index], AUDIT_EQUAL | AUDIT_NOT_EQUAL)
+        {
+            return Err(AuditError::Invalid);
+        }
+        rule.fields[index] = fields[index];
+        rule.values[index] = values[index];
+        rule.operators[index] = operators[index];
+    }
+    Ok((rule, flags & AUDIT_FILTER_PREPEND != 0))
+}
+
+impl AuditRule {
+    /// @description 编码为 AUDIT_LIST_RULES 返回的 `struct audit_rule_data`，buflen 恒为零。
+    pub(super) fn encode(&self) -> [u8; RULE_BYTES] {
+        let mut bytes = [0u8; RULE_BYTES];
+        let mut put = |index: usize, value: u32| {
+            bytes[index * 4..index * 4 + 4].copy_from_slice(&value.to_ne_bytes());
+        };
+        put(0, AUDIT_FILTER_EXIT);
+        put(
+            1,
+            if self.always {
+                AUDIT_ALWAYS
+            } else {
+                AUDIT_NEVER
+            },
+        );
+        put(2, self.field_count as u32);
+        for index in 0..AUDIT_BITMASK_SIZE {
+            put(3 + index, self.syscalls[index]);
+            put(3 + AUDIT_BITMASK_SIZE + index, self.fields[index]);
+            put(3 + AUDIT_BITMASK_SIZE * 2 + index, self.values[index]);
+            put(3 + AUDIT_BITMASK_SIZE * 3 + index, self.operators[index]);
+        }
+        bytes
+    }
+
+    /// @description 按 Linux exit filter 语义判断 rule 是否命中：syscall bit 与全部字段同时成立。
+    /// @return 命中时返回该 rule 的 action（ALWAYS 为 true）。
+    pub(super) fn decide(&self, subject: RuleSubject) -> Option<bool> {
+        let word = self.syscalls.get(subject.syscall / 32)?;
+        if word & (1 << (subject.syscall % 32)) == 0 {
+            return None;
+        }
+        let matched = (0..self.field_count).all(|index| {
+            let actual = match self.fields[index] {
+                AUDIT_PID => subject.pid,
+                AUDIT_UID => subject.uid,
+                _ => subject.arch,
+            };
+            (actual == self.values[index]) == (self.operators[index] == AUDIT_EQUAL)
+        });
+        matched.then_some(self.always)
+    }
+}
+
+/// @description 一条已格式化的 AUDIT_SYSCALL 文本，不含 netlink header。
+#[derive(Clone, Copy)]
+pub(super) struct AuditRecord {
+    bytes: [u8; RECORD_CAPACITY],
+    length: u16,
+}
+
+/// @description 生成一条 AUDIT_SYSCALL record 所需的 syscall exit 事实。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct SyscallFacts {
+    pub(super) seconds: u64,
+    pub(super) milliseconds: u32,
+    pub(super) serial: u32,
+    pub(super) arch: u32,
+    pub(super) syscall: usize,
+    pub(super) result: isize,
+    pub(super) arguments: [usize; 4],
+    pub(super) pid: u32,
+    pub(super) uid: u32,
+    pub(super) euid: u32,
+    pub(super) gid: u32,
+}
+
+impl AuditRecord {
+    pub(super) const EMPTY: Self = Self {
+        bytes: [0; RECORD_CAPACITY],
+        length: 0,
+    };
+
+    /// @description 按 Linux `audit_log_exit` 的字段顺序格式化 record。
+    /// @return 固定容量覆盖每个字段的最大十进制/十六进制宽度，不截断。
+    pub(super) fn syscall(facts: SyscallFacts) -> Self {
+        let mut record = Self::EMPTY;
+        record.push(b"audit(");
+        record.push_decimal(facts.seconds);
+        record.push(b".");
+        record.push(&[
+            b'0' + (facts.milliseconds / 100 % 10) as u8,
+            b'0' + (facts.milliseconds / 10 % 10) as u8,
+            b'0' + (facts.milliseconds % 10) as u8,
+        ]);
+        record.push(b":");
+        record.push_decimal(u64::from(facts.serial));
+        record.push(b"): arch=");
+        record.push_hex(u64::from(facts.arch));
+        record.push(b" syscall=");
+        record.push_decimal(facts.syscall as u64);
+        // Linux 以 IS_ERR_VALUE 判定失败：只有 -4095..=-1 视为 errno。
+        let failed = (-4095..0).contains(&facts.result);
+        record.push(if failed {
+            b" success=no exit="
+        } else {
+            b" success=yes exit="
+        });
+        if facts.result < 0 {
+            record.push(b"-");
+        }
+        record.push_decimal(facts.result.unsigned_abs() as u64);
+        for (name, argument) in [b" a0=", b" a1=", b" a2=", b" a3="]
+            .into_iter()
+            .zip(facts.arguments)
+        {
+            record.push(name);
+            record.push_hex(argument as u64);
+        }
+        record.push(b" items=0 pid=");
+        record.push_decimal(u64::from(facts.pid));
+        record.push(b" uid=");
+        record.push_decimal(u64::from(facts.uid));
+        record.push(b" gid=");
+        record.push_decimal(u64::from(facts.gid));
+        record.push(b" euid=");
+        record.push_decimal(u64::from(facts.euid));
+        record
+    }
+
+    /// @description 返回 record 文本。
+    pub(super) fn text(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.length)]
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        let start = usize::from(self.length);
+        let end = start + bytes.len();
+        assert!(
+            end <= RECORD_CAPACITY,
+            "audit syscall record exceeds fixed capacity"
+        );
+        self.bytes[start..end].copy_from_slice(bytes);
+        self.length = end as u16;
+    }
+
+    fn push_decimal(&mut self, value: u64) {
+        self.push_radix(value, 10);
+    }
+
+    fn push_hex(&mut self, value: u64) {
+        self.push_radix(value, 16);
+    }
+
+    fn push_radix(&mut self, mut value: u64, radix: u64) {
+        let mut reversed = [0u8; 20];
+        let mut length = 0;
+        loop {
+            reversed[length] = b"0123456789abcdef"[(value % radix) as usize];
+            length += 1;
+            value /= radix;
+            if value == 0 {
+                break;
+            }
+        }
+        reversed[..length].reverse();
+        self.push(&reversed[..length]);
+    }
+}
@@ -17,6 +17,7 @@ impl Socket {
                 .address()
                 .map(|value| Some(SocketAddress::Packet(value))),
             SocketBackend::Kobject(socket) => Ok(Some(SocketAddress::Netlink(socket.address()))),
+            SocketBackend::Audit(socket) => Ok(Some(SocketAddress::Netlink(socket.address()))),
             SocketBackend::InterfaceControl => Ok(Some(SocketAddress::Inet(InetAddress {
                 address: Ipv4Addr::UNSPECIFIED,
                 port: 0,
@@ -32,6 +33,7 @@ impl Socket {
                 .map(|value| Some(SocketAddress::Inet(value))),
             SocketBackend::Packet(_)
             | SocketBackend::Kobject(_)
+            | SocketBackend::Audit(_)
             | SocketBackend::InterfaceControl => Err(SocketError::NotConnected),
         }
     }
@@ -42,6 +44,7 @@ impl Socket {
             SocketBackend::Inet(socket) => socket.poll_state(),
             SocketBackend::Packet(socket) => socket.poll_state(),
             SocketBackend::Kobject(socket) => socket.poll_state(),
+            SocketBackend::Audit(socket) => socket.poll_state(),
             SocketBackend::InterfaceControl => SocketPollState {
                 readable: false,
                 writable: true,
@@ -57,6 +60,7 @@ impl Socket {
             SocketBackend::Inet(socket) => socket.readiness_generation(),
             SocketBackend::Packet(socket) => socket.readiness_generation(),
             SocketBackend::Kobject(socket) => socket.readiness_generation(),
+            SocketBackend::Audit(socket) => socket.readiness_generation(),
             SocketBackend::InterfaceControl => 0,
         }
     }
@@ -76,6 +80,13 @@ impl Socket {
                 ],
                 None,
             ),
+            SocketBackend::Audit(socket) => (
+                [
+                    Some(SocketWaitSource::Notification(socket.wait_source())),
+                    None,
+                ],
+                None,
+            ),
             SocketBackend::InterfaceControl => ([None, None], None),
         }
     }
@@ -89,6 +100,7 @@ impl Socket {
             SocketBackend::Inet(socket) => socket.consume_wait_notifications(),
             SocketBackend::Packet(socket) => socket.consume_wait_notifications(),
             SocketBackend::Kobject(socket) => socket.consume_wait_notification(),
+            SocketBackend::Audit(socket) => socket.consume_wait_notification(),
             SocketBackend::InterfaceControl => {}
         }
     }
@@ -56,3 +56,14 @@ impl From<SocketError> for SocketSendError {
         }
     }
 }
+
+/// @description sendmsg caller 的 credential 快照；NETLINK_AUDIT 按 request 逐条鉴权。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SocketSender {
+    /// caller 的全局 thread-group id。
+    pub(crate) tgid: u32,
+    /// caller 是否持有 CAP_AUDIT_CONTROL（effective uid 0）。
+    pub(crate) privileged: bool,
+    /// Linux 只接受初始 PID namespace 内的 audit request。
+    pub(crate) initial_pid_namespace: bool,
+}
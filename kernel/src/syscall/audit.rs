@@ -0,0 +1,33 @@
+use crate::socket::{AuditSyscall, record_syscall_exit};
+use crate::task::current_task;
+
+use super::{INTERNAL_RESTART_SYS, INTERRUPTED_RESULT};
+
+/// @description 在 syscall exit 把调用事实交给 NETLINK_AUDIT owner 的 exit filter。
+///
+/// @param syscall_id raw Linux syscall number。
+/// @param args syscall 原始参数；record 只保留前四个。
+/// @param result dispatcher 结果；内部 restart 按用户态暂存值记为 `-EINTR`。
+/// @return 无返回值；rule 未命中或 backlog 溢出时不产生 record。
+pub(super) fn audit_syscall_exit(syscall_id: usize, args: &[usize; 6], result: isize) {
+    let Some(task) = current_task() else {
+        return;
+    };
+    let realtime = crate::timer::get_realtime_ns();
+    record_syscall_exit(&AuditSyscall {
+        arch: crate::system::audit_architecture(),
+        syscall: syscall_id,
+        result: if result == INTERNAL_RESTART_SYS {
+            INTERRUPTED_RESULT
+        } else {
+            result
+        },
+        arguments: [args[0], args[1], args[2], args[3]],
+        pid: task.tgid() as u32,
+        uid: task.credential_id(true, false),
+        euid: task.credential_id(true, true),
+        gid: task.credential_id(false, false),
+        seconds: realtime / 1_000_000_000,
+        milliseconds: (realtime / 1_000_000 % 1_000) as u32,
+    });
+}
@@ -84,6 +84,8 @@ pub(crate) const ENOTCONN: isize = 107;
 pub(crate) const EISCONN: isize = 106;
 /// real-UID SCM_RIGHTS inflight 已达到 RLIMIT_NOFILE resource bound。
 pub(crate) const ETOOMANYREFS: isize = 109;
+/// netlink receive queue 溢出，至少一条 reply 已被丢弃。
+pub(crate) const ENOBUFS: isize = 105;
 /// 已建立连接被 peer reset。
 pub(crate) const ECONNRESET: isize = 104;
 pub(crate) const ECONNREFUSED: isize = 111;
@@ -94,3 +96,9 @@ pub(crate) const ENOPROTOOPT: isize = 92;
 pub(crate) const EOVERFLOW: isize = 75;
 /// 等待在 deadline 前未完成。
 pub(crate) const ETIMEDOUT: isize = 110;
+/// 超过 per-UID key quota。
+pub(crate) const EDQUOT: isize = 122;
+/// key 不存在或 search 未命中。
+pub(crate) const ENOKEY: isize = 126;
+/// key 已被 revoke。
+pub(crate) const EKEYREVOKED: isize = 128;
@@ -18,11 +18,11 @@ pub(crate) use fcntl::sys_fcntl;
 pub(crate) use flock::sys_flock;
 pub(crate) use io::{
     sys_pread64, sys_preadv, sys_preadv2, sys_pwrite64, sys_pwritev, sys_pwritev2, sys_read,
-    sys_readv, sys_sendfile, sys_write, sys_writev,
+    sys_readv, sys_sendfile, sys_splice, sys_write, sys_writev,
 };
 pub(crate) use links::{sys_linkat, sys_symlinkat};
 pub(crate) use namespace::{sys_mkdirat, sys_mknodat, sys_renameat2, sys_unlinkat};
-pub(crate) use open::{sys_chdir, sys_fchdir, sys_openat};
+pub(crate) use open::{sys_chdir, sys_chroot, sys_fchdir, sys_openat};
 pub(in crate::syscall) use pathname::ferr as filesystem_error;
 use pathname::{base, ferr, path};
 pub(crate) use readlink::sys_readlinkat;
@@ -31,8 +31,8 @@ use crate::{
     fs::{
         CharacterDevice, DeviceKind, Dirent64Batch, InodeMetadata, InodeType,
         MAX_GETDENTS_BATCH_BYTES, O_ACCMODE, O_APPEND, O_CLOEXEC, O_NONBLOCK, O_RDONLY, O_WRONLY,
-        OpenFileDescription, OpenFileKind, RegularFile, RegularFileWrite, TerminalAccess,
-        TerminalRead, character_write_chunk, vfs,
+        OpenFileDescription, OpenFileKind, ReadaheadWindow, RegularFile, RegularFileWrite,
+        TerminalAccess, TerminalRead, character_write_chunk, vfs,
     },
     ipc::{PIPE_BUF, Pipe, PipeDirection, PipeRead, PipeWaitCondition, PipeWrite},
     syscall::errno,
@@ -339,7 +339,9 @@ pub(crate) fn sys_fstat(fd: usize, pointer: *mut u8) -> isize {
                 copy_stat(&task, pointer, None, 0o140777, socket.object_id())
             }
             OpenFileKind::Epoll(_) => copy_stat(&task, pointer, None, 0o100600, 0),
-            OpenFileKind::EventFd(_) => copy_stat(&task, pointer, None, 0o100600, 0),
+            OpenFileKind::EventFd(_) | OpenFileKind::PerfEvent(_) => {
+                copy_stat(&task, pointer, None, 0o100600, 0)
+            }
             OpenFileKind::Inode(_) => unreachable!("inode_ref lost inode OFD"),
         },
     }
@@ -528,10 +530,11 @@ pub(crate) fn sys_get_cwd(pointer: *mut u8, length: usize) -> isize {
     let Some(task) = current_task() else {
         return -errno::ESRCH;
     };
-    let mut bytes = match vfs().absolute_path(task.working_directory()) {
-        Ok(path) => path,
-        Err(error) => return ferr(error),
-    };
+    let mut bytes =
+        match vfs().absolute_path(task.working_directory(), task.root_directory().as_ref()) {
+            Ok(path) => path,
+            Err(error) => return ferr(error),
+        };
     if bytes.try_reserve(1).is_err() {
         return -errno::ENOMEM;
     }
@@ -1,8 +1,9 @@
 use super::*;
 use crate::{
     fs::{AdvisoryLockAttempt, AdvisoryLockError, RecordLockMode, RecordLockRange},
+    ipc::PipeResizeError,
     syscall::INTERNAL_RESTART_SYS,
-    task::{AdvisoryLockWaitError, wait_for_record_lock},
+    task::{AdvisoryLockWaitError, visible_pid, wait_for_record_lock},
 };
 
 const F_DUPFD: u32 = 0;
@@ -14,6 +15,10 @@ const F_GETLK: u32 = 5;
 const F_SETLK: u32 = 6;
 const F_SETLKW: u32 = 7;
 const F_DUPFD_CLOEXEC: u32 = 1030;
+const F_SETPIPE_SZ: u32 = 1031;
+const F_GETPIPE_SZ: u32 = 1032;
+/// Linux `/proc/sys/fs/pipe-max-size` 默认值；非特权 caller 不得把 pipe 扩大到超过该值。
+const PIPE_MAX_SIZE: usize = 1024 * 1024;
 const F_RDLCK: i16 = 0;
 const F_WRLCK: i16 = 1;
 const F_UNLCK: i16 = 2;
@@ -153,7 +158,7 @@ fn get_lock(task: &TaskControlBlock, ofd: &Arc<OpenFileDescription>, pointer: us
                 .range
                 .end
                 .map_or(0, |end| (end - conflict.range.start) as i64);
-            user.pid = match i32::try_from(conflict.owner) {
+            user.pid = match i32::try_from(visible_pid(task, conflict.owner)) {
                 Ok(pid) => pid,
                 Err(_) => return -errno::EOVERFLOW,
             };
@@ -210,7 +215,31 @@ fn set_lock(
     }
 }
 
-/// @description 实现 descriptor flags/status、dup 与 POSIX process-associated record locks。
+/// @description 按 Linux `round_pipe_size` 规整请求容量并替换 pipe ring。
+///
+/// @param pipe descriptor 引用的 pipe owner；read/write endpoint 共享同一容量。
+/// @param argument Linux 以 `unsigned int` 解释的请求 byte 数。
+/// @return 实际生效的 capacity。
+/// @error 超过 2 GiB 返回 `EINVAL`；非 root 扩大到超过 pipe-max-size 返回 `EPERM`；
+/// 已缓冲数据超过新容量返回 `EBUSY`；分配失败返回 `ENOMEM`。
+fn set_pipe_size(task: &TaskControlBlock, pipe: &Arc<Pipe>, argument: usize) -> isize {
+    let requested = argument as u32;
+    if requested > 1 << 31 {
+        return -errno::EINVAL;
+    }
+    let capacity = (requested as usize).max(PIPE_BUF).next_power_of_two();
+    if capacity > pipe.capacity() && capacity > PIPE_MAX_SIZE && task.credential_id(true, true) != 0
+    {
+        return -errno::EPERM;
+    }
+    match pipe.resize(capacity) {
+        Ok(()) => capacity as isize,
+        Err(PipeResizeError::Busy) => -errno::EBUSY,
+        Err(PipeResizeError::OutOfMemory) => -errno::ENOMEM,
+    }
+}
+
+/// @description 实现 descriptor flags/status、dup、pipe capacity 与 POSIX process-associated record locks。
 ///
 /// @param fd source descriptor。
 /// @param command Linux F_* command。
@@ -264,6 +293,20 @@ pub(crate) fn sys_fcntl(fd: usize, command: u32, argument: usize) -> isize {
                     .map_or_else(super::super::file_descriptor_error, |value| value as isize)
             }
         }
+        F_SETPIPE_SZ | F_GETPIPE_SZ => {
+            let Some(ofd) = task.fd_get(fd) else {
+                return -errno::EBADF;
+            };
+            let OpenFileKind::Pipe(endpoint) = &ofd.kind else {
+                return -errno::EBADF;
+            };
+            let pipe = endpoint.pipe();
+            if command == F_GETPIPE_SZ {
+                pipe.capacity() as isize
+            } else {
+                set_pipe_size(&task, &pipe, argument)
+            }
+        }
         _ => -errno::EINVAL,
     }
 }
@@ -11,12 +11,16 @@ pub(crate) use positioned::{
 mod sendfile;
 pub(crate) use sendfile::sys_sendfile;
 
+mod splice;
+pub(crate) use splice::sys_splice;
+
 mod regular;
 use regular::{
     PreparedRegularWriteStaging, read_vectors as read_regular_vectors,
     write_vectors as write_regular_vectors,
 };
 
+use crate::syscall::INTERNAL_RESTART_SYS;
 use crate::syscall::user_iovec::{
     ImportError, TotalLengthError, UserInputStaging, UserIoCursor, UserIoVec, checked_total_length,
     fallible_staging_capacity, import_iovecs as import_raw_iovecs, with_prepared_staging,
@@ -42,5 +46,18 @@ fn import_iovecs(
     Ok((vectors, total))
 }
 
+/// @description 把 task-layer pipe wait result 统一翻译为 syscall control flow。
+/// @param pipe anonymous pipe owner。
+/// @param condition blocking I/O 必须满足的精确 read/write 条件。
+/// @return ready 返回 Ok；signal interruption 按 Linux `-ERESTARTSYS` 交给 SA_RESTART 决定重启或 `EINTR`。
+fn block_on_pipe(pipe: &Arc<Pipe>, condition: PipeWaitCondition) -> Result<(), isize> {
+    match wait_for_pipe(pipe, condition) {
+        WaitResult::Woken => Ok(()),
+        WaitResult::Interrupted => Err(INTERNAL_RESTART_SYS),
+        WaitResult::TimedOut => panic!("pipe I/O wait cannot time out"),
+        WaitResult::OutOfMemory => Err(-errno::ENOMEM),
+    }
+}
+
 mod sequential;
 pub(crate) use sequential::{sys_read, sys_readv, sys_write, sys_writev};
@@ -18,7 +18,7 @@ fn positioned_read(fd: usize, vectors: &[UserIoVec], offset: i64) -> isize {
     if inode.inode_type() == InodeType::Directory {
         return -errno::EISDIR;
     }
-    if inode.inode_type() != InodeType::File {
+    if !matches!(inode.inode_type(), InodeType::File | InodeType::BlockDevice) {
         return -errno::ESPIPE;
     }
     if vectors.iter().all(|vector| vector.length == 0) {
@@ -29,7 +29,7 @@ fn positioned_read(fd: usize, vectors: &[UserIoVec], offset: i64) -> isize {
         Err(error) => return ferr(error),
     };
     let mut position = offset as u64;
-    let result = read_regular_vectors(&task, &file, &mut position, vectors);
+    let result = read_regular_vectors(&task, &file, &mut position, ofd.readahead.as_ref(), vectors);
     task.account_read_result(result);
     result
 }
@@ -82,7 +82,7 @@ fn positioned_write(
     if inode.inode_type() == InodeType::Directory {
         return -errno::EISDIR;
     }
-    if inode.inode_type() != InodeType::File {
+    if !matches!(inode.inode_type(), InodeType::File | InodeType::BlockDevice) {
         return -errno::ESPIPE;
     }
     if vectors.iter().all(|vector| vector.length == 0) {
@@ -1,15 +1,18 @@
 use super::*;
+use spin::Mutex;
 
 /// @description 将一个或多个 userspace vector 作为一次 contiguous regular-file read 执行。
 /// @param task userspace address owner。
 /// @param file 已解析 page-cache identity 的 regular file。
 /// @param position 本次操作唯一 byte offset；仅在成功 copyout 后推进。
+/// @param readahead 源 OFD 的顺序访问状态；block device OFD 没有 window。
 /// @param vectors 按序消费的 userspace buffers。
 /// @return 总读取字节数、EOF 零、首错负 errno 或已有进度后的 partial count。
 pub(super) fn read_vectors(
     task: &TaskControlBlock,
     file: &RegularFile,
     position: &mut u64,
+    readahead: Option<&Mutex<ReadaheadWindow>>,
     vectors: &[UserIoVec],
 ) -> isize {
     let mut total = 0usize;
@@ -21,7 +24,7 @@ pub(super) fn read_vectors(
         while done < vector.length {
             let count = chunk.len().min(vector.length - done);
             // 1. 每个 chunk 复用同一 file/position；vector 之间不重新解析 fd 或 cache identity。
-            let read = match file.read(*position, &mut chunk[..count]) {
+            let read = match file.read(*position, &mut chunk[..count], readahead) {
                 Ok(read) => read,
                 Err(error) => {
                     return if total == 0 {
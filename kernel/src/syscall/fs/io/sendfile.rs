@@ -1,6 +1,7 @@
+use super::splice::{SplicePosition, file_to_pipe};
 use super::*;
 
-const MAX_RW_COUNT: usize = 0x7fff_f000;
+pub(super) const MAX_RW_COUNT: usize = 0x7fff_f000;
 
 /// @description 将一次 regular-file 到 regular-file 的 kernel-owned copy 提交给 page cache。
 /// @param task 当前 caller，提供 RLIMIT_FSIZE 与 SIGXFSZ target。
@@ -48,7 +49,7 @@ fn copy_regular_file(
     let mut total = 0usize;
     while total < transferable {
         let requested = chunk.len().min(transferable - total);
-        let read = match input.read(*input_position, &mut chunk[..requested]) {
+        let read = match input.read(*input_position, &mut chunk[..requested], None) {
             Ok(read) => read,
             Err(error) => {
                 return if total == 0 {
@@ -116,7 +117,7 @@ fn copy_from_shared_offset(
     .expect("distinct OFDs must own distinct file positions")
 }
 
-/// @description 完成 descriptor 校验并执行 regular-file 到 regular-file copy。
+/// @description 完成 descriptor 校验并执行 regular-file 到 regular-file 或 pipe 的 copy。
 /// @param task 当前 caller 与 fd-table owner。
 /// @param output_fd 以 write access 打开的输出 descriptor。
 /// @param input_fd 以 read access 打开的输入 descriptor。
@@ -124,6 +125,7 @@ fn copy_from_shared_offset(
 /// @param count 最大传输长度；按 Linux MAX_RW_COUNT 截断。
 /// @return 已传输字节数、EOF 零、partial count 或负 errno。
 /// @error descriptor/access 错误返回 `EBADF`；当前 scope 外 backend 返回 `EINVAL/ESPIPE`。
+/// @error 输出为 pipe 时按 splice file→pipe 语义返回 `EAGAIN/EPIPE/EINTR`。
 /// @error 重叠同文件区间返回 `EINVAL`。
 /// @error 输出带 O_APPEND 返回 `EINVAL`；storage、内存与 RLIMIT 错误透传对应 errno。
 fn do_sendfile(
@@ -143,9 +145,6 @@ fn do_sendfile(
     {
         return -errno::EBADF;
     }
-    if *output_ofd.flags.lock() & O_APPEND != 0 {
-        return -errno::EINVAL;
-    }
     let OpenFileKind::Inode(input_opened) = &input_ofd.kind else {
         return if input_position.is_none() {
             -errno::EINVAL
@@ -153,23 +152,38 @@ fn do_sendfile(
             -errno::ESPIPE
         };
     };
-    let OpenFileKind::Inode(output_opened) = &output_ofd.kind else {
-        return -errno::EINVAL;
-    };
     let input_inode = input_opened.inode();
-    let output_inode = output_opened.inode();
-    if input_inode.inode_type() != InodeType::File || output_inode.inode_type() != InodeType::File {
+    if input_inode.inode_type() != InodeType::File {
         return -errno::EINVAL;
     }
     let input = match RegularFile::from_inode(input_inode) {
         Ok(file) => file,
         Err(error) => return ferr(error),
     };
+    let count = count.min(MAX_RW_COUNT);
+    if let OpenFileKind::Pipe(endpoint) = &output_ofd.kind {
+        // Linux 以输入 OFD 的 O_NONBLOCK 决定 pipe full 时是否等待。
+        let nonblocking = *input_ofd.flags.lock() & O_NONBLOCK != 0;
+        let position = match input_position {
+            Some(position) => SplicePosition::Explicit(position),
+            None => SplicePosition::Shared(&input_ofd),
+        };
+        return file_to_pipe(task, &input, position, endpoint, count, nonblocking);
+    }
+    if *output_ofd.flags.lock() & O_APPEND != 0 {
+        return -errno::EINVAL;
+    }
+    let OpenFileKind::Inode(output_opened) = &output_ofd.kind else {
+        return -errno::EINVAL;
+    };
+    let output_inode = output_opened.inode();
+    if output_inode.inode_type() != InodeType::File {
+        return -errno::EINVAL;
+    }
     let output = match RegularFile::from_inode(output_inode) {
         Ok(file) => file,
         Err(error) => return ferr(error),
     };
-    let count = count.min(MAX_RW_COUNT);
 
     let Some(input_position) = input_position else {
         return copy_from_shared_offset(task, &input_ofd, &output_ofd, &input, &output, count);
@@ -186,7 +200,7 @@ fn do_sendfile(
     })
 }
 
-/// @description 实现 Linux/riscv64 `sendfile` 的 regular-file 到 regular-file 数据路径。
+/// @description 实现 Linux/riscv64 `sendfile` 的 regular-file 到 regular-file 或 pipe 数据路径。
 /// @param output_fd 以 write access 打开的输出 descriptor。
 /// @param input_fd 以 read access 打开的输入 descriptor。
 /// @param offset 可空的 userspace signed 64-bit 输入 offset；非空时不修改输入 OFD offset。
@@ -205,8 +219,7 @@ pub(crate) fn sys_sendfile(
     };
     if offset == 0 {
         let result = do_sendfile(&task, output_fd, input_fd, None, count);
-        task.account_read_result(result);
-        task.account_write_result(result);
+        task.account_copy_result(result);
         return result;
     }
     let mut bytes = [0u8; core::mem::size_of::<i64>()];
@@ -225,8 +238,7 @@ pub(crate) fn sys_sendfile(
         }
         Err(_) => -errno::EINVAL,
     };
-    task.account_read_result(result);
-    task.account_write_result(result);
+    task.account_copy_result(result);
     if task
         .copy_to_user(offset, &signed_position.to_ne_bytes())
         .is_err()
@@ -5,19 +5,6 @@ use read::read_descriptor;
 mod write;
 use write::write_descriptor;
 
-/// @description 把 task-layer pipe wait result 统一翻译为 syscall control flow。
-/// @param pipe anonymous pipe owner。
-/// @param condition blocking I/O 必须满足的精确 read/write 条件。
-/// @return ready 返回 Ok；signal interruption 返回 `-EINTR`。
-fn block_on_pipe(pipe: &Arc<Pipe>, condition: PipeWaitCondition) -> Result<(), isize> {
-    match wait_for_pipe(pipe, condition) {
-        WaitResult::Woken => Ok(()),
-        WaitResult::Interrupted => Err(-errno::EINTR),
-        WaitResult::TimedOut => panic!("pipe I/O wait cannot time out"),
-        WaitResult::OutOfMemory => Err(-errno::ENOMEM),
-    }
-}
-
 /// @description 取得已证明可读且实现 read file operation 的 OFD。
 /// @param fd caller descriptor number。
 /// @return 当前 task 与共享 OFD；access/capability 检查先于任何 userspace iovec import。
@@ -48,7 +35,10 @@ fn writable_descriptor(
     if *ofd.flags.lock() & O_ACCMODE == O_RDONLY {
         return Err(-errno::EBADF);
     }
-    if matches!(&ofd.kind, OpenFileKind::Epoll(_)) {
+    if matches!(
+        &ofd.kind,
+        OpenFileKind::Epoll(_) | OpenFileKind::PerfEvent(_)
+    ) {
         return Err(-errno::EINVAL);
     }
     Ok((task, ofd))
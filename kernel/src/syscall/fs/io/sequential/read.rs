@@ -29,10 +29,12 @@ pub(super) fn read_descriptor(
             };
             // 单个 sequential read 唯一持有 OFD offset；缺失该 ownership 会让共享 OFD
             // 的并发 reader 在 chunks 之间穿插，使一次 operation 返回不连续的文件区间。
-            ofd.with_position(|offset| read_regular_vectors(task, &file, offset, vectors))
+            ofd.with_position(|offset| {
+                read_regular_vectors(task, &file, offset, ofd.readahead.as_ref(), vectors)
+            })
         }
         OpenFileKind::Pipe(endpoint) => {
-            if endpoint.direction() != PipeDirection::Read {
+            if !endpoint.allows(PipeDirection::Read) {
                 return -errno::EBADF;
             }
             let mut input = match ReceiveBuffer::try_new(total_length.min(64 * 1024)) {
@@ -80,7 +82,7 @@ pub(super) fn read_descriptor(
                     Err(crate::socket::SocketError::Again) => {
                         match crate::syscall::poll::wait_for_ofd(ofd, 1) {
                             WaitResult::Woken => {}
-                            WaitResult::Interrupted => return -errno::EINTR,
+                            WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                             WaitResult::TimedOut => unreachable!(),
                             WaitResult::OutOfMemory => return -errno::ENOMEM,
                         }
@@ -115,7 +117,7 @@ pub(super) fn read_descriptor(
                     crate::ipc::EventFdRead::Empty => {
                         match crate::syscall::poll::wait_for_ofd(ofd, 1) {
                             WaitResult::Woken => {}
-                            WaitResult::Interrupted => return -errno::EINTR,
+                            WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                             WaitResult::TimedOut => unreachable!(),
                             WaitResult::OutOfMemory => return -errno::ENOMEM,
                         }
@@ -128,6 +130,20 @@ pub(super) fn read_descriptor(
             }
             size as isize
         }
+        OpenFileKind::PerfEvent(event) => {
+            // Linux perf_read 对无 read_format 的计数器只 scatter 一个 u64；更小 buffer 为 ENOSPC。
+            if total_length < mem::size_of::<u64>() {
+                return -errno::ENOSPC;
+            }
+            let mut cursor = UserIoCursor::new(vectors);
+            if cursor
+                .copy_to_user(task, &event.read().to_ne_bytes())
+                .is_err()
+            {
+                return -errno::EFAULT;
+            }
+            mem::size_of::<u64>() as isize
+        }
         OpenFileKind::Epoll(_) => unreachable!("epoll read rejected before descriptor dispatch"),
         OpenFileKind::Character(device) => match device {
             CharacterDevice::Null => 0,
@@ -202,7 +218,7 @@ pub(super) fn read_descriptor(
                         }
                         match crate::syscall::poll::wait_for_ofd(ofd, 1) {
                             WaitResult::Woken => continue,
-                            WaitResult::Interrupted => return -errno::EINTR,
+                            WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                             WaitResult::TimedOut => unreachable!(),
                             WaitResult::OutOfMemory => return -errno::ENOMEM,
                         }
@@ -285,7 +301,7 @@ pub(super) fn read_descriptor(
                                 };
                                 match wait {
                                     WaitResult::Woken => {}
-                                    WaitResult::Interrupted => return -errno::EINTR,
+                                    WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                                     WaitResult::TimedOut => unreachable!(),
                                     WaitResult::OutOfMemory => return -errno::ENOMEM,
                                 }
@@ -334,7 +350,7 @@ pub(super) fn read_descriptor(
                         }
                         match crate::syscall::poll::wait_for_ofd(ofd, 1) {
                             WaitResult::Woken => continue,
-                            WaitResult::Interrupted => return -errno::EINTR,
+                            WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                             WaitResult::TimedOut => unreachable!(),
                             WaitResult::OutOfMemory => return -errno::ENOMEM,
                         }
@@ -461,7 +477,7 @@ pub(super) fn read_descriptor(
                             match wait {
                                 crate::task::WaitResult::Woken => continue,
                                 crate::task::WaitResult::Interrupted if read == 0 => {
-                                    return -errno::EINTR;
+                                    return INTERNAL_RESTART_SYS;
                                 }
                                 crate::task::WaitResult::Interrupted
                                 | crate::task::WaitResult::TimedOut => break,
@@ -40,7 +40,7 @@ pub(super) fn write_descriptor(
             })
         }
         OpenFileKind::Pipe(endpoint) => {
-            if endpoint.direction() != PipeDirection::Write {
+            if !endpoint.allows(PipeDirection::Write) {
                 return -errno::EBADF;
             }
             let mut cursor = UserIoCursor::new(vectors);
@@ -113,6 +113,7 @@ pub(super) fn write_descriptor(
             };
             let mut cursor = UserIoCursor::new(vectors);
             let mut written = 0usize;
+            let sender = crate::syscall::socket::socket_sender(task);
             while written < total_length {
                 // 2. stream 复用 bounded buffer，并在首次短写/阻塞后返回标准 partial count。
                 let requested = (total_length - written).min(input.capacity());
@@ -129,7 +130,7 @@ pub(super) fn write_descriptor(
                     }
                 }
                 loop {
-                    match socket.write(input.initialized()) {
+                    match socket.write(input.initialized(), sender) {
                         Ok(count) => {
                             written += count;
                             if count < requested {
@@ -149,7 +150,7 @@ pub(super) fn write_descriptor(
                         Err(crate::socket::SocketSendError::WouldBlock) => {
                             match crate::syscall::poll::wait_for_ofd(ofd, 4) {
                                 WaitResult::Woken => {}
-                                WaitResult::Interrupted => return -errno::EINTR,
+                                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                                 WaitResult::TimedOut => unreachable!(),
                                 WaitResult::OutOfMemory => return -errno::ENOMEM,
                             }
@@ -157,7 +158,7 @@ pub(super) fn write_descriptor(
                         Err(crate::socket::SocketSendError::PeerFull(blocker)) => {
                             match crate::syscall::poll::wait_for_socket_send(&blocker) {
                                 WaitResult::Woken => {}
-                                WaitResult::Interrupted => return -errno::EINTR,
+                                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                                 WaitResult::TimedOut => unreachable!(),
                                 WaitResult::OutOfMemory => return -errno::ENOMEM,
                             }
@@ -235,7 +236,7 @@ pub(super) fn write_descriptor(
                                 WaitResult::Woken => {}
                                 WaitResult::Interrupted => {
                                     return if written == 0 {
-                                        -errno::EINTR
+                                        INTERNAL_RESTART_SYS
                                     } else {
                                         written as isize
                                     };
@@ -255,7 +256,9 @@ pub(super) fn write_descriptor(
             }
             written as isize
         }
-        OpenFileKind::Epoll(_) => unreachable!("epoll write rejected before descriptor dispatch"),
+        OpenFileKind::Epoll(_) | OpenFileKind::PerfEvent(_) => {
+            unreachable!("epoll/perf write rejected before descriptor dispatch")
+        }
         OpenFileKind::Character(device) => {
             if let CharacterDevice::Terminal {
                 terminal,
@@ -313,7 +316,7 @@ pub(super) fn write_descriptor(
                                 WaitResult::Woken => {}
                                 WaitResult::Interrupted => {
                                     return if written == 0 {
-                                        -errno::EINTR
+                                        INTERNAL_RESTART_SYS
                                     } else {
                                         written as isize
                                     };
@@ -369,7 +372,7 @@ pub(super) fn write_descriptor(
                                     WaitResult::Woken => {}
                                     WaitResult::Interrupted => {
                                         return if written == 0 {
-                                            -errno::EINTR
+                                            INTERNAL_RESTART_SYS
                                         } else {
                                             written as isize
                                         };
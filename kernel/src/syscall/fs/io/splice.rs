@@ -0,0 +1,330 @@
+use super::sendfile::MAX_RW_COUNT;
+use super::*;
+use crate::ipc::{PipeEnd, ReceiveBuffer};
+
+const SPLICE_F_MOVE: u32 = 1;
+const SPLICE_F_NONBLOCK: u32 = 2;
+const SPLICE_F_MORE: u32 = 4;
+const SPLICE_F_GIFT: u32 = 8;
+const SIGPIPE: usize = 13;
+
+/// @description 一次 file position 临界区内的 pipe 传输结果。
+enum PipeTransfer {
+    /// 已传输字节数；零表示 file EOF 或 pipe EOF。
+    Bytes(usize),
+    /// 尚无进度且 pipe 需要等待该条件。
+    Wait(PipeWaitCondition),
+    Error(isize),
+}
+
+/// @description file side 的唯一 offset owner。
+pub(super) enum SplicePosition<'a> {
+    /// userspace 显式 offset；不修改 OFD position。
+    Explicit(&'a mut u64),
+    /// 共享 OFD position。
+    Shared(&'a OpenFileDescription),
+}
+
+/// @description 重复执行传输直至产生结果；pipe 等待发生在 spin position lock 之外。
+/// @param position file side offset owner。
+/// @param pipe 等待 readiness 的 pipe owner。
+/// @param nonblocking SPLICE_F_NONBLOCK 或 file side O_NONBLOCK。
+/// @param attempt 在 position 临界区内执行的一轮传输。
+/// @return 传输字节数或负 errno。
+fn transfer(
+    mut position: SplicePosition<'_>,
+    pipe: &Arc<Pipe>,
+    nonblocking: bool,
+    mut attempt: impl FnMut(&mut u64) -> PipeTransfer,
+) -> isize {
+    loop {
+        let result = match &mut position {
+            SplicePosition::Explicit(position) => attempt(position),
+            SplicePosition::Shared(ofd) => ofd.with_position(&mut attempt),
+        };
+        match result {
+            PipeTransfer::Bytes(count) => return count as isize,
+            PipeTransfer::Error(error) => return error,
+            PipeTransfer::Wait(_) if nonblocking => return -errno::EAGAIN,
+            PipeTransfer::Wait(condition) => {
+                if let Err(error) = block_on_pipe(pipe, condition) {
+                    return error;
+                }
+            }
+        }
+    }
+}
+
+/// @description 以 page-sized kernel chunk 把 regular file 数据写入 pipe。
+///
+/// 每个 chunk 先按 offset 读取，只按 pipe 实际接收的 bytes 推进 offset，因此 pipe 短写不会丢数据。
+/// @return 传输字节数、首轮 pipe full 的等待条件，或首错负 errno。
+/// @error 无 reader 时投递 SIGPIPE 并返回 `EPIPE`；storage 错误透传。
+fn fill_pipe(
+    task: &TaskControlBlock,
+    input: &RegularFile,
+    position: &mut u64,
+    endpoint: &PipeEnd,
+    count: usize,
+) -> PipeTransfer {
+    let mut chunk = [0u8; crate::memory::PAGE_SIZE];
+    let mut total = 0usize;
+    while total < count {
+        let requested = chunk.len().min(count - total);
+        let read = match input.read(*position, &mut chunk[..requested], None) {
+            Ok(read) => read,
+            Err(_) if total != 0 => break,
+            Err(error) => return PipeTransfer::Error(ferr(error)),
+        };
+        task.account_read_storage(read.storage_bytes);
+        if read.bytes == 0 {
+            break;
+        }
+        match endpoint.write_stream(&chunk[..read.bytes]) {
+            PipeWrite::Bytes(written) => {
+                *position = position
+                    .checked_add(written as u64)
+                    .expect("splice input position overflow");
+                total += written;
+                if written < read.bytes {
+                    break;
+                }
+            }
+            PipeWrite::Full if total == 0 => {
+                return PipeTransfer::Wait(PipeWaitCondition::Writable { minimum: 1 });
+            }
+            PipeWrite::Full => break,
+            PipeWrite::Broken if total == 0 => {
+                send_thread_signal(task.tgid(), task.tid(), SIGPIPE)
+                    .expect("current splice writer must exist");
+                return PipeTransfer::Error(-errno::EPIPE);
+            }
+            PipeWrite::Broken => break,
+        }
+    }
+    PipeTransfer::Bytes(total)
+}
+
+/// @description 以 page-sized kernel chunk 把 pipe 数据写入 regular file。
+///
+/// 每个 chunk 先 hold 在 pipe 头部再写入 page cache，只消费实际写出的 bytes；RLIMIT_FSIZE
+/// 在 peek 前截断，短写或 storage 错误留下的字节仍可被后续 read/splice 读到。
+/// @return 传输字节数、pipe 为空时的等待条件，或首错负 errno。
+/// @error RLIMIT_FSIZE 返回 `EFBIG` 并投递 SIGXFSZ；storage 错误透传。
+fn drain_pipe(
+    task: &TaskControlBlock,
+    endpoint: &PipeEnd,
+    output: &RegularFile,
+    position: &mut u64,
+    count: usize,
+) -> PipeTransfer {
+    let transferable = match bounded_regular_write(task, *position, count, 0) {
+        Ok(count) => count,
+        Err(error) => return PipeTransfer::Error(error),
+    };
+    let writer = match output.begin_write() {
+        Ok(writer) => writer,
+        Err(error) => return PipeTransfer::Error(ferr(error)),
+    };
+    let mut total = 0usize;
+    while total < transferable {
+        let mut storage = [0u8; crate::memory::PAGE_SIZE];
+        let mut chunk = ReceiveBuffer::from_slice(&mut storage);
+        let maximum = chunk.remaining().min(transferable - total);
+        match endpoint.peek_held(&mut chunk, maximum) {
+            PipeRead::Bytes(_) => {}
+            PipeRead::Eof => break,
+            PipeRead::Empty if total == 0 => {
+                return PipeTransfer::Wait(PipeWaitCondition::Readable);
+            }
+            PipeRead::Empty => break,
+        }
+        let result = writer.write(*position, chunk.initialized());
+        endpoint.commit_held(*result.as_ref().unwrap_or(&0));
+        let written = match result {
+            Ok(written) => written,
+            Err(_) if total != 0 => break,
+            Err(error) => return PipeTransfer::Error(ferr(error)),
+        };
+        task.account_write_storage(written);
+        *position = position
+            .checked_add(written as u64)
+            .expect("splice output position overflow");
+        total += written;
+        if written < chunk.len() {
+            break;
+        }
+    }
+    PipeTransfer::Bytes(total)
+}
+
+/// @description regular file 到 pipe write endpoint 的 kernel-owned copy；sendfile 与 splice 共用。
+/// @param task 当前 caller，提供 SIGPIPE target 与 I/O accounting。
+/// @param input 已解析的输入 page-cache facade。
+/// @param position 输入 offset owner。
+/// @param endpoint 输出 pipe write endpoint。
+/// @param count 最大传输长度。
+/// @param nonblocking pipe full 时返回 `EAGAIN` 而不等待。
+/// @return 已传输字节数、EOF 零或负 errno。
+pub(super) fn file_to_pipe(
+    task: &TaskControlBlock,
+    input: &RegularFile,
+    position: SplicePosition<'_>,
+    endpoint: &PipeEnd,
+    count: usize,
+    nonblocking: bool,
+) -> isize {
+    transfer(position, &endpoint.pipe(), nonblocking, |position| {
+        fill_pipe(task, input, position, endpoint, count)
+    })
+}
+
+/// @description 把 OFD 投影为 splice file side 的 regular-file page-cache facade。
+/// @return regular file facade。
+/// @error 非 regular inode backend 返回 `EINVAL`；page-cache 错误透传。
+fn regular_file(ofd: &OpenFileDescription) -> Result<RegularFile, isize> {
+    let OpenFileKind::Inode(opened) = &ofd.kind else {
+        return Err(-errno::EINVAL);
+    };
+    let inode = opened.inode();
+    if inode.inode_type() != InodeType::File {
+        return Err(-errno::EINVAL);
+    }
+    RegularFile::from_inode(inode).map_err(ferr)
+}
+
+/// @description 在显式 userspace offset 或 OFD position 上执行一次 splice，并按 Linux 回写 offset。
+/// @param offset 可空 userspace `loff_t *`。
+/// @return 传输结果；成功后 offset copyout 失败返回 `EFAULT`。
+fn with_file_position(
+    task: &TaskControlBlock,
+    ofd: &OpenFileDescription,
+    offset: usize,
+    operation: impl FnOnce(SplicePosition<'_>) -> isize,
+) -> isize {
+    if offset == 0 {
+        return operation(SplicePosition::Shared(ofd));
+    }
+    let mut bytes = [0u8; core::mem::size_of::<i64>()];
+    if task.copy_from_user(offset, &mut bytes).is_err() {
+        return -errno::EFAULT;
+    }
+    let Ok(mut position) = u64::try_from(i64::from_ne_bytes(bytes)) else {
+        return -errno::EINVAL;
+    };
+    let result = operation(SplicePosition::Explicit(&mut position));
+    if result < 0 {
+        return result;
+    }
+    let Ok(position) = i64::try_from(position) else {
+        return -errno::EOVERFLOW;
+    };
+    if task.copy_to_user(offset, &position.to_ne_bytes()).is_err() {
+        return -errno::EFAULT;
+    }
+    result
+}
+
+/// @description 完成 descriptor 校验并选择 file→pipe 或 pipe→file 数据路径。
+/// @return 已传输字节数、EOF 零或负 errno。
+/// @error 两侧都不是 pipe、两侧都是 pipe 或 file side 不是 regular file 返回 `EINVAL`。
+/// @error pipe side 带 offset 返回 `ESPIPE`；输出带 O_APPEND 返回 `EINVAL`。
+fn do_splice(
+    task: &TaskControlBlock,
+    input_fd: usize,
+    input_offset: usize,
+    output_fd: usize,
+    output_offset: usize,
+    count: usize,
+    flags: u32,
+) -> isize {
+    let Some((input_ofd, output_ofd)) =
+        task.with_file_descriptions(input_fd, output_fd, |input, output| (input, output))
+    else {
+        return -errno::EBADF;
+    };
+    if *input_ofd.flags.lock() & O_ACCMODE == O_WRONLY
+        || *output_ofd.flags.lock() & O_ACCMODE == O_RDONLY
+    {
+        return -errno::EBADF;
+    }
+    let count = count.min(MAX_RW_COUNT);
+    let nonblocking = flags & SPLICE_F_NONBLOCK != 0;
+    match (&input_ofd.kind, &output_ofd.kind) {
+        (OpenFileKind::Pipe(_), OpenFileKind::Pipe(_)) => -errno::EINVAL,
+        (OpenFileKind::Pipe(endpoint), _) => {
+            if input_offset != 0 {
+                return -errno::ESPIPE;
+            }
+            if *output_ofd.flags.lock() & O_APPEND != 0 {
+                return -errno::EINVAL;
+            }
+            let output = match regular_file(&output_ofd) {
+                Ok(file) => file,
+                Err(error) => return error,
+            };
+            if count == 0 {
+                return 0;
+            }
+            let nonblocking = nonblocking || *output_ofd.flags.lock() & O_NONBLOCK != 0;
+            with_file_position(task, &output_ofd, output_offset, |position| {
+                transfer(position, &endpoint.pipe(), nonblocking, |position| {
+                    drain_pipe(task, endpoint, &output, position, count)
+                })
+            })
+        }
+        (_, OpenFileKind::Pipe(endpoint)) => {
+            if output_offset != 0 {
+                return -errno::ESPIPE;
+            }
+            let input = match regular_file(&input_ofd) {
+                Ok(file) => file,
+                Err(error) => return error,
+            };
+            if count == 0 {
+                return 0;
+            }
+            let nonblocking = nonblocking || *input_ofd.flags.lock() & O_NONBLOCK != 0;
+            with_file_position(task, &input_ofd, input_offset, |position| {
+                file_to_pipe(task, &input, position, endpoint, count, nonblocking)
+            })
+        }
+        _ => -errno::EINVAL,
+    }
+}
+
+/// @description 实现 Linux `splice` 的 regular-file 与 pipe 之间的数据搬运，不经过 userspace buffer。
+/// @param input_fd 以 read access 打开的输入 descriptor。
+/// @param input_offset 输入为 regular file 时可空的 `loff_t *`；非空时不修改输入 OFD offset。
+/// @param output_fd 以 write access 打开的输出 descriptor。
+/// @param output_offset 输出为 regular file 时可空的 `loff_t *`。
+/// @param count 最大传输长度；按 Linux MAX_RW_COUNT 截断。
+/// @param flags `SPLICE_F_*`；MOVE/MORE/GIFT 只是 hint。
+/// @return 已传输字节数、EOF 零或负 errno。
+/// @error 未知 flag 返回 `EINVAL`；pipe 无数据/空间且非阻塞返回 `EAGAIN`；signal 按 SA_RESTART 重启或返回 `EINTR`。
+pub(crate) fn sys_splice(
+    input_fd: usize,
+    input_offset: usize,
+    output_fd: usize,
+    output_offset: usize,
+    count: usize,
+    flags: u32,
+) -> isize {
+    let Some(task) = current_task() else {
+        return -errno::ESRCH;
+    };
+    if flags & !(SPLICE_F_MOVE | SPLICE_F_NONBLOCK | SPLICE_F_MORE | SPLICE_F_GIFT) != 0 {
+        return -errno::EINVAL;
+    }
+    let result = do_splice(
+        &task,
+        input_fd,
+        input_offset,
+        output_fd,
+        output_offset,
+        count,
+        flags,
+    );
+    task.account_copy_result(result);
+    result
+}
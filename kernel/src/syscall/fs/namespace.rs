@@ -6,17 +6,20 @@ const AT_REMOVEDIR: usize = 0x200;
 const RENAME_NOREPLACE: u32 = 1;
 const S_IFMT: u32 = 0o170000;
 const S_IFREG: u32 = 0o100000;
+const S_IFIFO: u32 = 0o010000;
 
-/// @description 按 Linux mknodat ABI 创建普通文件 inode。
+/// @description 按 Linux mknodat ABI 创建普通文件或 FIFO inode。
 /// @param dirfd 相对 pathname 的目录 fd，或 AT_FDCWD。
 /// @param name NUL 结尾且非空的 pathname。
 /// @param mode inode type 与 permission/special bits；type 为零或 S_IFREG 时创建普通文件。
-/// @param device character/block device 的编码；普通文件不使用该参数。
+/// @param device character/block device 的编码；普通文件与 FIFO 不使用该参数。
 /// @return 成功返回零；不支持的 inode type、pathname、权限、空间或 I/O 错误返回负 errno。
 pub(crate) fn sys_mknodat(dirfd: isize, name: *const u8, mode: u32, _device: u64) -> isize {
-    if !matches!(mode & S_IFMT, 0 | S_IFREG) {
-        return -errno::EOPNOTSUPP;
-    }
+    let kind = match mode & S_IFMT {
+        0 | S_IFREG => InodeType::File,
+        S_IFIFO => InodeType::Fifo,
+        _ => return -errno::EOPNOTSUPP,
+    };
     let Some(task) = current_task() else {
         return -errno::ESRCH;
     };
@@ -32,7 +35,7 @@ pub(crate) fn sys_mknodat(dirfd: isize, name: *const u8, mode: u32, _device: u64
         .create_at(
             start,
             &path,
-            InodeType::File,
+            kind,
             task.creation_mode(mode),
             &task.access_identity(true),
         )
@@ -2,11 +2,15 @@ use alloc::sync::Arc;
 
 use crate::{
     fs::{
-        AccessIdentity, DeviceKind, InodeType, O_ACCMODE, O_CLOEXEC, O_RDONLY, O_WRONLY,
-        OpenFileDescription, OpenedFile, vfs,
+        AccessIdentity, DeviceKind, Inode, InodeType, O_ACCMODE, O_CLOEXEC, O_NONBLOCK, O_RDONLY,
+        O_RDWR, O_WRONLY, OpenFileDescription, OpenedFile, vfs,
+    },
+    ipc::{FifoOpenError, PipeDirection, PipeWaitCondition},
+    syscall::{INTERNAL_RESTART_SYS, errno},
+    task::{
+        TaskControlBlock, WaitResult, create_fifo_pipe, current_session, current_task,
+        wait_for_pipe,
     },
-    syscall::errno,
-    task::{TaskControlBlock, current_task, session_id},
 };
 
 use super::pathname::{base, ferr, path};
@@ -16,6 +20,23 @@ const O_EXCL: u32 = 0x80;
 const O_TRUNC: u32 = 0x200;
 const O_DIRECTORY: u32 = 0x10000;
 
+/// @description 校验 opened entry 是 caller 可 search 的目录，供 cwd 与 root 替换共用。
+/// @param opened pathname 或 fd 已解析出的 opened-entry identity。
+/// @param identity 本次 operation 唯一 effective-credentials snapshot。
+/// @return 目录可进入时成功。
+/// @error 非目录返回 `ENOTDIR`；metadata 或 search permission 失败返回对应 errno。
+fn require_searchable_directory(
+    opened: &Arc<OpenedFile>,
+    identity: &AccessIdentity,
+) -> Result<(), isize> {
+    let inode = opened.inode();
+    if inode.inode_type() != InodeType::Directory {
+        return Err(-errno::ENOTDIR);
+    }
+    let metadata = inode.metadata().map_err(ferr)?;
+    identity.require(metadata, 1).map_err(ferr)
+}
+
 /// @description 校验 directory/search permission 后原子替换 Process 唯一 cwd identity。
 /// @param task cwd owner。
 /// @param opened pathname 或 fd 已解析出的 opened-entry identity。
@@ -27,16 +48,8 @@ fn change_directory(
     opened: Arc<OpenedFile>,
     identity: &AccessIdentity,
 ) -> isize {
-    let inode = opened.inode();
-    if inode.inode_type() != InodeType::Directory {
-        return -errno::ENOTDIR;
-    }
-    let metadata = match inode.metadata() {
-        Ok(metadata) => metadata,
-        Err(error) => return ferr(error),
-    };
-    if let Err(error) = identity.require(metadata, 1) {
-        return ferr(error);
+    if let Err(error) = require_searchable_directory(&opened, identity) {
+        return error;
     }
     task.set_working_directory(opened);
     0
@@ -79,6 +92,78 @@ pub(crate) fn sys_fchdir(fd: usize) -> isize {
     change_directory(&task, opened, &identity)
 }
 
+/// @description 按 Linux `chroot` 把 pathname 目录设为 Process 的 lookup root；cwd 保持不变。
+///
+/// 与 Linux 一致，cwd 可留在新 root 之外；此时相对 lookup 仍从 cwd 出发，getcwd 返回 `ENOENT`。
+/// @param name NUL 结尾 raw pathname；绝对路径从当前 Process root 解析。
+/// @return 成功返回 0；失败返回负 errno 且 root 保持不变。
+/// @error lookup 失败返回对应 errno；非目录返回 `ENOTDIR`；search permission 失败返回 `EACCES`。
+/// @error 非 effective root 返回 `EPERM`；本 kernel 以 effective UID 0 代表 `CAP_SYS_CHROOT`。
+pub(crate) fn sys_chroot(name: *const u8) -> isize {
+    let Some(task) = current_task() else {
+        return -errno::ESRCH;
+    };
+    let path = match path(&task, name) {
+        Ok(path) => path,
+        Err(error) => return error,
+    };
+    let start = (path.first() != Some(&b'/')).then(|| task.working_directory());
+    let identity = task.access_identity(true);
+    let opened = match vfs().open_file_at(start, &path, &identity) {
+        Ok(opened) => opened,
+        Err(error) => return ferr(error),
+    };
+    if let Err(error) = require_searchable_directory(&opened, &identity) {
+        return error;
+    }
+    if identity.uid() != 0 {
+        return -errno::EPERM;
+    }
+    task.set_root_directory(opened);
+    0
+}
+
+/// @description 按 Linux `fifo_open` 打开 named pipe；阻塞 open 等待对端 endpoint 出现。
+/// @param inode 已通过 open permission 检查的 FIFO inode。
+/// @param flags OFD status flags。
+/// @return 共享该 FIFO Pipe 的 endpoint OFD。
+/// O_RDWR 与 Linux 一致同时登记 reader 与 writer，自身即对端故从不阻塞。
+/// @error 无 reader 的 O_NONBLOCK writer 返回 `ENXIO`。
+/// @error 等待对端时被 signal 中断返回 restart sentinel；分配失败返回 `ENOMEM`。
+fn open_fifo(inode: &Arc<dyn Inode>, flags: u32) -> Result<Arc<OpenFileDescription>, isize> {
+    let direction = match flags & O_ACCMODE {
+        O_RDONLY => Some(PipeDirection::Read),
+        O_WRONLY => Some(PipeDirection::Write),
+        O_RDWR => None,
+        _ => return Err(-errno::EINVAL),
+    };
+    let nonblocking = flags & O_NONBLOCK != 0;
+    let pipe = vfs().fifo_pipe(inode, create_fifo_pipe).map_err(ferr)?;
+    let open_error = |error| match error {
+        FifoOpenError::NoReader => -errno::ENXIO,
+        FifoOpenError::OutOfMemory => -errno::ENOMEM,
+    };
+    let Some(direction) = direction else {
+        let endpoint = pipe.open_fifo_duplex().map_err(open_error)?;
+        return OpenFileDescription::pipe(endpoint, flags).map_err(|()| -errno::ENOMEM);
+    };
+    let (endpoint, partner) = pipe
+        .open_fifo_end(direction, nonblocking && direction == PipeDirection::Write)
+        .map_err(open_error)?;
+    if let Some(opens) = partner
+        && !nonblocking
+    {
+        // endpoint 已计入 readers/writers；中断时随 Drop 撤销，与 Linux `err_rd/err_wr` 一致。
+        match wait_for_pipe(&pipe, PipeWaitCondition::Partner { direction, opens }) {
+            WaitResult::Woken => {}
+            WaitResult::Interrupted => return Err(INTERNAL_RESTART_SYS),
+            WaitResult::TimedOut => panic!("FIFO open wait cannot time out"),
+            WaitResult::OutOfMemory => return Err(-errno::ENOMEM),
+        }
+    }
+    OpenFileDescription::pipe(endpoint, flags).map_err(|()| -errno::ENOMEM)
+}
+
 /// @description 以 effective credentials 执行 open/create permission 并发布 OFD。
 pub(crate) fn sys_openat(fd: isize, name: *const u8, flags: u32, mode: u32) -> isize {
     let Some(task) = current_task() else {
@@ -132,18 +217,33 @@ pub(crate) fn sys_openat(fd: isize, name: *const u8, flags: u32, mode: u32) -> i
     if inode.inode_type() == InodeType::Directory && flags & O_ACCMODE != O_RDONLY {
         return -errno::EISDIR;
     }
+    let ofd_flags = flags & !(O_CREAT | O_EXCL | O_TRUNC | O_CLOEXEC);
     if !matches!(
         inode.inode_type(),
-        InodeType::File | InodeType::Directory | InodeType::CharacterDevice
-    ) || inode.inode_type() == InodeType::CharacterDevice && inode.device_kind().is_none()
+        InodeType::File
+            | InodeType::Directory
+            | InodeType::CharacterDevice
+            | InodeType::BlockDevice
+            | InodeType::Fifo
+    ) || matches!(
+        inode.inode_type(),
+        InodeType::CharacterDevice | InodeType::BlockDevice
+    ) && inode.device_kind().is_none()
     {
         return -errno::ENXIO;
     }
-    let ofd_flags = flags & !(O_CREAT | O_EXCL | O_TRUNC | O_CLOEXEC);
-    let ofd = if let Some(device) = inode.device_kind() {
+    let ofd = if inode.inode_type() == InodeType::Fifo {
+        match open_fifo(&inode, ofd_flags) {
+            Ok(ofd) => ofd,
+            Err(error) => return error,
+        }
+    } else if let Some(device) = inode
+        .device_kind()
+        .filter(|_| inode.inode_type() == InodeType::CharacterDevice)
+    {
         let terminal = task.terminal();
         if device == DeviceKind::Tty {
-            let Ok(session) = session_id(0) else {
+            let Ok(session) = current_session(&task) else {
                 return -errno::ENXIO;
             };
             if terminal.controlling_session() != Some(session) {
@@ -7,6 +7,7 @@ const FIONBIO: usize = 0x5421;
 
 use super::drm::drm_ioctl;
 use super::input::input_ioctl;
+use super::perf_event::perf_event_ioctl;
 use super::{
     errno,
     socket::socket_ioctl,
@@ -58,6 +59,7 @@ pub(crate) fn sys_ioctl(fd: usize, request: usize, argument: usize) -> isize {
             input_ioctl(&task, file, request, argument)
         }
         OpenFileKind::Socket(socket) => socket_ioctl(&task, socket, request, argument),
+        OpenFileKind::PerfEvent(event) => perf_event_ioctl(event.as_ref(), request, argument),
         _ => -errno::ENOTTY,
     }
 }
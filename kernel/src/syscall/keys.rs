@@ -0,0 +1,204 @@
+use alloc::vec::Vec;
+
+use crate::{
+    keys::{KeyError, SecretBytes, USER_PAYLOAD_MAX, keys},
+    memory::UserAccessError,
+    syscall::errno,
+    task::{TaskControlBlock, current_task},
+};
+
+const KEY_SPEC_USER_KEYRING: i32 = -4;
+const KEYCTL_GET_KEYRING_ID: usize = 0;
+const KEYCTL_REVOKE: usize = 3;
+const KEYCTL_UNLINK: usize = 9;
+const KEYCTL_READ: usize = 11;
+const KEYCTL_INVALIDATE: usize = 21;
+/// Linux `key_get_type_from_user` 的 type buffer，含 NUL。
+const KEY_TYPE_CAPACITY: usize = 32;
+/// Linux `strndup_user(_description, KEY_MAX_DESC_SIZE)`，含 NUL。
+const DESCRIPTION_CAPACITY: usize = 4096;
+/// Linux `add_key` 在 type preparse 前拒绝的 payload 长度。
+const ADD_KEY_PAYLOAD_MAX: usize = 1024 * 1024 - 1;
+
+fn key_error(error: KeyError) -> isize {
+    -match error {
+        KeyError::NotFound => errno::ENOKEY,
+        KeyError::Revoked => errno::EKEYREVOKED,
+        KeyError::Access => errno::EACCES,
+        KeyError::NotKeyring => errno::ENOTDIR,
+        KeyError::NotLinked => errno::ENOENT,
+        KeyError::Quota => errno::EDQUOT,
+        KeyError::OutOfMemory => errno::ENOMEM,
+    }
+}
+
+/// Linux key permission 以 fsuid 判定；本 kernel 的 fsuid 恒等于 effective UID。
+fn caller_uid(task: &TaskControlBlock) -> u32 {
+    task.credential_id(true, true)
+}
+
+fn copy_string(task: &TaskControlBlock, pointer: usize, capacity: usize) -> Result<Vec<u8>, isize> {
+    match task.copy_user_c_string(pointer, capacity) {
+        Ok(bytes) if bytes.is_empty() => Err(-errno::EINVAL),
+        Ok(bytes) => Ok(bytes),
+        Err(UserAccessError::Unterminated) => Err(-errno::EINVAL),
+        Err(UserAccessError::OutOfMemory) => Err(-errno::ENOMEM),
+        Err(UserAccessError::Fault | UserAccessError::Overflow) => Err(-errno::EFAULT),
+    }
+}
+
+/// @description 读取 key type 与 description；只开放 Linux `user` type。
+fn copy_user_key_name(
+    task: &TaskControlBlock,
+    key_type: usize,
+    description: usize,
+) -> Result<Vec<u8>, isize> {
+    if copy_string(task, key_type, KEY_TYPE_CAPACITY)? != b"user" {
+        return Err(-errno::ENODEV);
+    }
+    copy_string(task, description, DESCRIPTION_CAPACITY)
+}
+
+/// @description 把 keyring 参数解析为 store target；None 表示 caller 的 user keyring。
+fn keyring_target(keyring: i32) -> Result<Option<i32>, isize> {
+    match keyring {
+        KEY_SPEC_USER_KEYRING => Ok(None),
+        serial if serial > 0 => Ok(Some(serial)),
+        _ => Err(-errno::EINVAL),
+    }
+}
+
+/// @description 把 keyctl key id 解析为 serial；`KEY_SPEC_USER_KEYRING` 按需创建 user keyring。
+fn key_serial(uid: u32, id: usize) -> Result<i32, isize> {
+    match keyring_target(id as i32)? {
+        Some(serial) => Ok(serial),
+        None => keys().user_keyring(uid).map_err(key_error),
+    }
+}
+
+/// @description 实现 Linux `add_key` 的 `user` type，在目标 keyring 中创建或更新 key。
+/// @param key_type NUL 结尾的 type 名称。
+/// @param description NUL 结尾的非空 description。
+/// @param payload payload 用户地址；`length` 字节在锁外复制。
+/// @param keyring `KEY_SPEC_USER_KEYRING` 或 caller user keyring 的 serial。
+/// @return key serial。
+/// @errors 非 `user` type 返回 `ENODEV`；长度、description 或其他 `KEY_SPEC_*` 非法返回 `EINVAL`；
+/// quota 超限返回 `EDQUOT`；其余 keyring 解析错误按 keyctl errno 投影。
+pub(crate) fn sys_add_key(
+    key_type: usize,
+    description: usize,
+    payload: usize,
+    length: usize,
+    keyring: i32,
+) -> isize {
+    let task = current_task().expect("add_key requires current task");
+    if length > ADD_KEY_PAYLOAD_MAX {
+        return -errno::EINVAL;
+    }
+    let description = match copy_user_key_name(&task, key_type, description) {
+        Ok(description) => description,
+        Err(error) => return error,
+    };
+    let target = match keyring_target(keyring) {
+        Ok(target) => target,
+        Err(error) => return error,
+    };
+    // Linux `user_preparse` 拒绝空 payload 与超过 32767 字节的 payload。
+    if length == 0 || length > USER_PAYLOAD_MAX {
+        return -errno::EINVAL;
+    }
+    let mut secret = match SecretBytes::try_new(length) {
+        Ok(secret) => secret,
+        Err(error) => return key_error(error),
+    };
+    if task.copy_from_user(payload, secret.as_mut_slice()).is_err() {
+        return -errno::EFAULT;
+    }
+    keys()
+        .add(caller_uid(&task), target, description, secret)
+        .map_or_else(key_error, |serial| serial as isize)
+}
+
+/// @description 实现 Linux `request_key` 对 caller user keyring 的同步查找。
+///
+/// kernel 不执行 `/sbin/request-key` upcall；`callout_info` 不改变结果，未命中总返回 `ENOKEY`。
+/// @param destination 0 或 `KEY_SPEC_USER_KEYRING`；命中的 key 已链接在该 keyring 中。
+/// @return 命中的 key serial。
+/// @errors 未命中返回 `ENOKEY`；已 revoke 返回 `EKEYREVOKED`；其他 destination 返回 `EINVAL`。
+pub(crate) fn sys_request_key(key_type: usize, description: usize, destination: i32) -> isize {
+    let task = current_task().expect("request_key requires current task");
+    let description = match copy_user_key_name(&task, key_type, description) {
+        Ok(description) => description,
+        Err(error) => return error,
+    };
+    if destination != 0 && destination != KEY_SPEC_USER_KEYRING {
+        return -errno::EINVAL;
+    }
+    keys()
+        .search(caller_uid(&task), &description)
+        .map_or_else(key_error, |serial| serial as isize)
+}
+
+/// @description 实现 Linux `keyctl` 的 GET_KEYRING_ID、REVOKE、UNLINK、READ 与 INVALIDATE。
+///
+/// 一次性读取 secret 由 `KEYCTL_READ` 后接 `KEYCTL_INVALIDATE` 完成，不另设私有 flag。
+/// @param operation keyctl command。
+/// @param arguments command 的 `arg2..arg4`。
+/// @return GET_KEYRING_ID 返回 serial，READ 返回完整 payload 长度，其余返回零。
+/// @errors 未开放 command 返回 `EOPNOTSUPP`；READ copyout 失败返回 `EFAULT`；key 错误按 keyctl errno 投影。
+pub(crate) fn sys_keyctl(operation: usize, arguments: [usize; 3]) -> isize {
+    let task = current_task().expect("keyctl requires current task");
+    let uid = caller_uid(&task);
+    let serial = match operation {
+        KEYCTL_GET_KEYRING_ID | KEYCTL_REVOKE | KEYCTL_UNLINK | KEYCTL_READ | KEYCTL_INVALIDATE => {
+            match key_serial(uid, arguments[0]) {
+                Ok(serial) => serial,
+                Err(error) => return error,
+            }
+        }
+        _ => return -errno::EOPNOTSUPP,
+    };
+    let result = match operation {
+        KEYCTL_GET_KEYRING_ID => keys().read(uid, serial, &mut []).map(|_| serial as isize),
+        KEYCTL_REVOKE => keys().revoke(uid, serial).map(|()| 0),
+        KEYCTL_INVALIDATE => keys().invalidate(uid, serial).map(|()| 0),
+        KEYCTL_UNLINK => match keyring_target(arguments[1] as i32) {
+            Ok(keyring) => keys().unlink(uid, serial, keyring).map(|()| 0),
+            Err(error) => return error,
+        },
+        KEYCTL_READ => return read_key(&task, uid, serial, arguments[1], arguments[2]),
+        _ => unreachable!("keyctl operation was filtered above"),
+    };
+    result.unwrap_or_else(key_error)
+}
+
+/// @description 先查询长度，再在锁外把有界 staging 前缀复制给用户。
+fn read_key(
+    task: &TaskControlBlock,
+    uid: u32,
+    serial: i32,
+    buffer: usize,
+    capacity: usize,
+) -> isize {
+    let length = match keys().read(uid, serial, &mut []) {
+        Ok(length) => length,
+        Err(error) => return key_error(error),
+    };
+    if buffer == 0 || capacity == 0 {
+        return length as isize;
+    }
+    let mut staging = match SecretBytes::try_new(length.min(capacity)) {
+        Ok(staging) => staging,
+        Err(error) => return key_error(error),
+    };
+    // 两次 read 之间 payload 可被同 UID 更新；返回第二次的完整长度，只复制 staging 能容纳的前缀。
+    let length = match keys().read(uid, serial, staging.as_mut_slice()) {
+        Ok(length) => length,
+        Err(error) => return key_error(error),
+    };
+    let copied = length.min(staging.as_slice().len());
+    match task.copy_to_user(buffer, &staging.as_slice()[..copied]) {
+        Ok(()) => length as isize,
+        Err(_) => -errno::EFAULT,
+    }
+}
@@ -64,7 +64,7 @@ pub(crate) fn sys_brk(new_brk: usize) -> isize {
 ///
 /// @param address 零或地址 hint；`MAP_FIXED_NOREPLACE` 时必须页对齐且非零。
 /// @param length 非零映射长度。
-/// @param prot `PROT_NONE/READ/WRITE/EXEC` 的任意合法组合。
+/// @param prot `PROT_NONE/READ/WRITE/EXEC` 的任意合法组合；MDWE 下 WRITE|EXEC 返回 `EACCES`。
 /// @param flags 必须选择一个 `MAP_PRIVATE/MAP_SHARED`，可附加已声明的 semantic/advisory variants。
 /// @param fd anonymous mapping 必须传 `-1`；file mapping 为 readable regular-file fd。
 /// @param offset anonymous mapping 必须传零。
@@ -95,6 +95,14 @@ pub(crate) fn sys_mmap(
         return -errno::EINVAL;
     }
     let task = current_task().expect("mmap requires a current task");
+    // Linux MDWE 在任何 backing acquisition 与 MAP_FIXED replacement 前拒绝可写可执行 mapping。
+    if permission.contains(MapPermission::W | MapPermission::X) {
+        match task.deny_write_exec(false) {
+            Ok(true) => return -errno::EACCES,
+            Ok(false) => {}
+            Err(error) => return -memory_errno(error),
+        }
+    }
 
     enum PreparedMapping {
         Anonymous,
@@ -244,11 +252,11 @@ pub(crate) fn sys_munmap(address: usize, length: usize) -> isize {
         .map_or_else(|error| -memory_errno(error), |()| 0)
 }
 
-/// @description 修改完整用户 VMA 区间的页权限，保留 Linux 对合法 `PROT_*` 组合的语义。
+/// @description 修改完整用户 VMA 区间的页权限，保留 Linux 对合法 `PROT_*` 组合与 MDWE 的语义。
 ///
 /// @param address page-aligned 起始地址。
 /// @param length 非零长度，向上取整到整页。
-/// @param prot `PROT_NONE/READ/WRITE/EXEC` 子集。
+/// @param prot `PROT_NONE/READ/WRITE/EXEC` 子集；MDWE 下 WRITE|EXEC 或新增 EXEC 返回 `EACCES`。
 /// @return 成功返回零；缺页、越界或权限策略失败返回负 errno。
 pub(crate) fn sys_mprotect(address: usize, length: usize, prot: usize) -> isize {
     let permission = match permission_from_prot(prot) {
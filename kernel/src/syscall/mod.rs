@@ -1,3 +1,4 @@
+mod audit;
 mod clone_errno;
 mod credentials;
 mod drm;
@@ -9,9 +10,12 @@ mod futex;
 mod getrandom_flags;
 mod input;
 mod ioctl;
+mod keys;
 mod membarrier;
 mod memory;
 mod mmap_flags;
+mod perf_event;
+mod perf_event_attr;
 mod poll;
 mod process;
 mod process_control;
@@ -33,7 +37,9 @@ use crate::syscall::{
     reboot::*, scheduler::*, signal::*, socket::*, system_identity::*, system_info::*, timer::*,
 };
 use eventfd::sys_eventfd2;
+use keys::{sys_add_key, sys_keyctl, sys_request_key};
 use membarrier::sys_membarrier;
+use perf_event::sys_perf_event_open;
 use process_control::sys_prctl;
 use resource_limit::sys_prlimit64;
 use riscv_hwprobe::sys_riscv_hwprobe;
@@ -123,6 +129,7 @@ pub(crate) fn syscall(syscall_id: usize, args: [usize; 6]) -> SyscallOutcome {
             SYSCALL_FALLOCATE => sys_fallocate(args[0], args[1], args[2] as i64, args[3] as i64),
             SYSCALL_CHDIR => sys_chdir(args[0] as *const u8),
             SYSCALL_FCHDIR => sys_fchdir(args[0]),
+            SYSCALL_CHROOT => sys_chroot(args[0] as *const u8),
             SYSCALL_OPENAT => sys_openat(
                 args[0] as isize,
                 args[1] as *const u8,
@@ -144,6 +151,9 @@ pub(crate) fn syscall(syscall_id: usize, args: [usize; 6]) -> SyscallOutcome {
             SYSCALL_SENDFILE => sys_sendfile(args[0], args[1], args[2], args[3]),
             SYSCALL_PPOLL => sys_ppoll(args[0], args[1], args[2], args[3], args[4]),
             SYSCALL_PSELECT6 => sys_pselect6(args[0], args[1], args[2], args[3], args[4], args[5]),
+            SYSCALL_SPLICE => {
+                sys_splice(args[0], args[1], args[2], args[3], args[4], args[5] as u32)
+            }
             SYSCALL_READLINKAT => sys_readlinkat(
                 args[0] as isize,
                 args[1] as *const u8,
@@ -188,6 +198,13 @@ pub(crate) fn syscall(syscall_id: usize, args: [usize; 6]) -> SyscallOutcome {
             ),
             SYSCALL_EXIT => sys_exit(args[0] as i32),
             SYSCALL_EXIT_GROUP => sys_exit_group(args[0] as i32),
+            SYSCALL_WAITID => sys_waitid(
+                args[0],
+                args[1],
+                args[2] as *mut u8,
+                args[3],
+                args[4] as *mut u8,
+            ),
             SYSCALL_SET_TID_ADDRESS => sys_set_tid_address(args[0]),
             SYSCALL_FUTEX => sys_futex(
                 args[0],
@@ -315,7 +332,17 @@ pub(crate) fn syscall(syscall_id: usize, args: [usize; 6]) -> SyscallOutcome {
             SYSCALL_MSYNC => sys_msync(args[0], args[1], args[2]),
             SYSCALL_MADVISE => sys_madvise(args[0], args[1], args[2]),
             SYSCALL_GETRANDOM => sys_getrandom(args[0], args[1], args[2]),
+            SYSCALL_ADD_KEY => sys_add_key(args[0], args[1], args[2], args[3], args[4] as i32),
+            SYSCALL_REQUEST_KEY => sys_request_key(args[0], args[1], args[3] as i32),
+            SYSCALL_KEYCTL => sys_keyctl(args[0], [args[1], args[2], args[3]]),
             SYSCALL_MEMBARRIER => sys_membarrier(args[0], args[1], args[2]),
+            SYSCALL_PERF_EVENT_OPEN => sys_perf_event_open(
+                args[0],
+                args[1] as i32,
+                args[2] as i32,
+                args[3] as i32,
+                args[4],
+            ),
             SYSCALL_WAIT4 => sys_wait4(
                 args[0] as isize,
                 args[1] as *mut i32,
@@ -331,6 +358,9 @@ pub(crate) fn syscall(syscall_id: usize, args: [usize; 6]) -> SyscallOutcome {
             _ => unreachable!("architecture decoder returned an unowned syscall"),
         },
     };
+    if crate::socket::audit_active() {
+        audit::audit_syscall_exit(syscall_id, &args, result);
+    }
     if result == INTERNAL_RESTART_SYS {
         SyscallOutcome::Restart
     } else {
@@ -0,0 +1,130 @@
+use crate::{
+    fs::{OpenFileDescription, PerfEvent, PerfEventControl},
+    task::{HardwareCounter, HardwareCounterError, current_task, open_thread_counter, resolve_pid},
+};
+
+use super::{
+    errno,
+    perf_event_attr::{
+        PERF_ATTR_SIZE_VER0, PerfAttrError, PerfHardwareEvent, attr_copy_size, parse_counter_attr,
+    },
+};
+
+const PERF_FLAG_FD_NO_GROUP: usize = 1 << 0;
+const PERF_FLAG_FD_CLOEXEC: usize = 1 << 3;
+
+const PERF_EVENT_IOC_ENABLE: usize = 0x2400;
+const PERF_EVENT_IOC_DISABLE: usize = 0x2401;
+const PERF_EVENT_IOC_RESET: usize = 0x2403;
+const PERF_IOC_FLAG_GROUP: usize = 1;
+
+/// @description 为调用 Thread 打开 counting-only 硬件 cycle/instruction 计数器。
+///
+/// @param attr userspace `struct perf_event_attr`。
+/// @param pid 只接受 0 或调用 Thread 自身 TID。
+/// @param cpu 只接受 -1，即跟随 Thread 在任意 CPU 上计数。
+/// @param group_fd 只接受 -1；event group 未实现。
+/// @param flags 只接受 `PERF_FLAG_FD_NO_GROUP`/`PERF_FLAG_FD_CLOEXEC`。
+/// @return 新 perf fd；失败返回负 errno。
+/// @errors 未知 flags/保留 attr bit 为 EINVAL；attr 尺寸为 E2BIG 并回写 VER0 尺寸；
+/// 无对应 PMU 事件或 counter 不可用为 ENOENT；sampling、其他 Thread、per-CPU 与 group 为 EOPNOTSUPP。
+pub(super) fn sys_perf_event_open(
+    attr: usize,
+    pid: i32,
+    cpu: i32,
+    group_fd: i32,
+    flags: usize,
+) -> isize {
+    if flags & !(PERF_FLAG_FD_NO_GROUP | PERF_FLAG_FD_CLOEXEC) != 0 {
+        return -errno::EINVAL;
+    }
+    let task = current_task().expect("perf_event_open requires current task");
+    // 1. 先复制 VER0 头部；size 字段决定是否还需检查 userspace 扩展尾部。
+    let mut head = [0u8; PERF_ATTR_SIZE_VER0];
+    if task.copy_from_user(attr, &mut head).is_err() {
+        return -errno::EFAULT;
+    }
+    let declared = u32::from_ne_bytes(head[4..8].try_into().expect("attr size"));
+    let size = match attr_copy_size(declared) {
+        Ok(size) => size,
+        Err(error) => return attr_error(&task, attr, error),
+    };
+    // 2. 新版本字段全部为零才与 VER0 语义等价，与 Linux copy_struct_from_user 一致。
+    let mut tail = [0u8; PERF_ATTR_SIZE_VER0];
+    let mut offset = PERF_ATTR_SIZE_VER0;
+    while offset < size {
+        let chunk = &mut tail[..(size - offset).min(PERF_ATTR_SIZE_VER0)];
+        if task.copy_from_user(attr + offset, chunk).is_err() {
+            return -errno::EFAULT;
+        }
+        if chunk.iter().any(|byte| *byte != 0) {
+            return attr_error(&task, attr, PerfAttrError::TooBig);
+        }
+        offset += chunk.len();
+    }
+    let parsed = match parse_counter_attr(&head) {
+        Ok(parsed) => parsed,
+        Err(error) => return attr_error(&task, attr, error),
+    };
+    // 3. 计数只由被测 Thread 自己的 scheduler slice 提交；跨 Thread/CPU/group 没有 owner。
+    if pid == -1 && cpu == -1 {
+        return -errno::EINVAL;
+    }
+    if (pid != 0 && resolve_pid(&task, pid as usize) != Some(task.tid()))
+        || cpu != -1
+        || group_fd != -1
+    {
+        return -errno::EOPNOTSUPP;
+    }
+    let counter = match parsed.event {
+        PerfHardwareEvent::Cycles => HardwareCounter::Cycles,
+        PerfHardwareEvent::Instructions => HardwareCounter::Instructions,
+    };
+    let event = match open_thread_counter(counter, !parsed.disabled) {
+        Ok(event) => event,
+        Err(HardwareCounterError::Unavailable) => return -errno::ENOENT,
+        Err(HardwareCounterError::OutOfMemory) => return -errno::ENOMEM,
+    };
+    let ofd = match OpenFileDescription::perf_event(event, 0) {
+        Ok(ofd) => ofd,
+        Err(()) => return -errno::ENOMEM,
+    };
+    task.fd_allocate(ofd, flags & PERF_FLAG_FD_CLOEXEC != 0)
+        .map_or_else(super::file_descriptor_error, |fd| fd as isize)
+}
+
+/// @description 执行 `PERF_EVENT_IOC_ENABLE/DISABLE/RESET`。
+///
+/// @param event perf OFD 后端。
+/// @param request 已归一化为 32-bit 的 ioctl request。
+/// @param argument 只接受 0 或 `PERF_IOC_FLAG_GROUP`；单事件 group 即事件自身。
+/// @return 成功为 0；其他 perf ioctl 为 ENOTTY，未知 argument 为 EINVAL。
+pub(super) fn perf_event_ioctl(event: &dyn PerfEvent, request: usize, argument: usize) -> isize {
+    let control = match request {
+        PERF_EVENT_IOC_ENABLE => PerfEventControl::Enable,
+        PERF_EVENT_IOC_DISABLE => PerfEventControl::Disable,
+        PERF_EVENT_IOC_RESET => PerfEventControl::Reset,
+        _ => return -errno::ENOTTY,
+    };
+    if argument & !PERF_IOC_FLAG_GROUP != 0 {
+        return -errno::EINVAL;
+    }
+    event.control(control);
+    0
+}
+
+fn attr_error(task: &crate::task::TaskControlBlock, attr: usize, error: PerfAttrError) -> isize {
+    match error {
+        PerfAttrError::TooBig => {
+            // Linux 把内核接受的 attr 尺寸写回 `attr.size`，供 userspace 协商版本。
+            let size = (PERF_ATTR_SIZE_VER0 as u32).to_ne_bytes();
+            if task.copy_to_user(attr + 4, &size).is_err() {
+                return -errno::EFAULT;
+            }
+            -errno::E2BIG
+        }
+        PerfAttrError::Invalid => -errno::EINVAL,
+        PerfAttrError::NoEvent => -errno::ENOENT,
+        PerfAttrError::Unsupported => -errno::EOPNOTSUPP,
+    }
+}
@@ -0,0 +1,104 @@
+/// `PERF_ATTR_SIZE_VER0`；LiteOS 只解释该版本覆盖的字段。
+pub(super) const PERF_ATTR_SIZE_VER0: usize = 64;
+/// Linux `perf_copy_attr` 接受的最大 userspace attr 尺寸。
+pub(super) const PERF_ATTR_SIZE_MAX: usize = 4096;
+
+const PERF_TYPE_HARDWARE: u32 = 0;
+const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+
+const DISABLED: u64 = 1 << 0;
+const PINNED: u64 = 1 << 2;
+const EXCLUSIVE: u64 = 1 << 3;
+const EXCLUDE_KERNEL: u64 = 1 << 5;
+const EXCLUDE_HV: u64 = 1 << 6;
+const EXCLUDE_IDLE: u64 = 1 << 7;
+const EXCLUDE_HOST: u64 = 1 << 19;
+const EXCLUDE_GUEST: u64 = 1 << 20;
+// 只有一个 Thread-private counter，pinned/exclusive 总能满足。exclude_kernel 等没有
+// privilege-mode filter 可执行，计数包含该 Thread 的内核路径；拒绝它们会让非特权
+// perf 工具的默认请求无法计量。exclude_user 无法满足，落入 `Unsupported`。
+const ACCEPTED_FLAGS: u64 = DISABLED
+    | PINNED
+    | EXCLUSIVE
+    | EXCLUDE_KERNEL
+    | EXCLUDE_HV
+    | EXCLUDE_IDLE
+    | EXCLUDE_HOST
+    | EXCLUDE_GUEST;
+/// `__reserved_1` 之前的全部已定义 bitfield（disabled..sigtrap）。
+const DEFINED_FLAGS: u64 = (1 << 38) - 1;
+/// `PERF_FORMAT_*` 已定义 bits（TOTAL_TIME_ENABLED..LOST）。
+const DEFINED_READ_FORMAT: u64 = (1 << 5) - 1;
+
+/// @description 已验证 attr 选择的 Thread 硬件事件。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PerfHardwareEvent {
+    Cycles,
+    Instructions,
+}
+
+/// @description 可由 LiteOS counting-only perf 实现执行的 attr。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PerfCounterAttr {
+    pub(super) event: PerfHardwareEvent,
+    pub(super) disabled: bool,
+}
+
+/// @description attr 被拒绝的 Linux errno 类别。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PerfAttrError {
+    /// 尺寸不可接受或超出 VER0 的尾部非零：`E2BIG`。
+    TooBig,
+    /// 保留 bit 或未知 read_format：`EINVAL`。
+    Invalid,
+    /// 没有该 type/config 的 PMU 事件：`ENOENT`。
+    NoEvent,
+    /// 合法但未实现的 sampling、exclude_user 或 read_format：`EOPNOTSUPP`。
+    Unsupported,
+}
+
+/// @description 把 userspace 声明的 `attr.size` 规范化为需要复制的字节数。
+///
+/// @param declared `attr.size`；零按 Linux 约定表示 VER0。
+/// @return 需要从 userspace 读取的完整尺寸。
+/// @errors 小于 VER0 或大于一页时返回 `TooBig`。
+pub(super) fn attr_copy_size(declared: u32) -> Result<usize, PerfAttrError> {
+    match declared as usize {
+        0 => Ok(PERF_ATTR_SIZE_VER0),
+        size if (PERF_ATTR_SIZE_VER0..=PERF_ATTR_SIZE_MAX).contains(&size) => Ok(size),
+        _ => Err(PerfAttrError::TooBig),
+    }
+}
+
+/// @description 解析 VER0 `perf_event_attr` 并收窄到 counting-only cycle/instruction 事件。
+///
+/// @param attr 前 64 字节；更长尺寸的尾部零检查由 caller 完成。
+/// @return 可执行的 counter 请求。
+/// @errors 按 Linux errno 类别返回 `PerfAttrError`。
+pub(super) fn parse_counter_attr(
+    attr: &[u8; PERF_ATTR_SIZE_VER0],
+) -> Result<PerfCounterAttr, PerfAttrError> {
+    let word =
+        |offset: usize| u64::from_ne_bytes(attr[offset..offset + 8].try_into().expect("attr word"));
+    let kind = u32::from_ne_bytes(attr[0..4].try_into().expect("attr type"));
+    let config = word(8);
+    let sample_period = word(16);
+    let read_format = word(32);
+    let flags = word(40);
+    if flags & !DEFINED_FLAGS != 0 || read_format & !DEFINED_READ_FORMAT != 0 {
+        return Err(PerfAttrError::Invalid);
+    }
+    let event = match (kind, config) {
+        (PERF_TYPE_HARDWARE, PERF_COUNT_HW_CPU_CYCLES) => PerfHardwareEvent::Cycles,
+        (PERF_TYPE_HARDWARE, PERF_COUNT_HW_INSTRUCTIONS) => PerfHardwareEvent::Instructions,
+        _ => return Err(PerfAttrError::NoEvent),
+    };
+    if sample_period != 0 || read_format != 0 || flags & !ACCEPTED_FLAGS != 0 {
+        return Err(PerfAttrError::Unsupported);
+    }
+    Ok(PerfCounterAttr {
+        event,
+        disabled: flags & DISABLED != 0,
+    })
+}
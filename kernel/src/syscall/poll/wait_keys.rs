@@ -127,13 +127,15 @@ impl PollWaitKeys {
                 ))?;
             }
             OpenFileKind::Pipe(endpoint) => {
-                self.push(PollWaitKey::pipe(
-                    &endpoint.pipe(),
-                    endpoint.direction(),
-                    events,
-                    exclusive,
-                    wake_group,
-                ))?;
+                for &direction in endpoint.directions() {
+                    self.push(PollWaitKey::pipe(
+                        &endpoint.pipe(),
+                        direction,
+                        events,
+                        exclusive,
+                        wake_group,
+                    ))?;
+                }
             }
             OpenFileKind::Socket(socket) => {
                 let (sources, guard) = socket.wait_sources(events);
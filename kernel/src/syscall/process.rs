@@ -5,12 +5,12 @@ use crate::{
     memory::{ElfLoadError, UserAccessError},
     syscall::errno,
     task::{
-        EXEC_ARGUMENT_BYTES_LIMIT, ProcessCloneError, ProcessGroupError, ProgramLoadError,
-        SetProcessGroupError, TaskControlBlock, ThreadCloneError, WaitChildError,
-        clone_current_thread, consume_child_status, create_session, current_task,
+        EXEC_ARGUMENT_BYTES_LIMIT, PendingSignal, ProcessCloneError, ProcessGroupError,
+        ProgramLoadError, SetProcessGroupError, TaskControlBlock, ThreadCloneError, WaitChildError,
+        WaitEvents, clone_current_thread, consume_child_status, create_session, current_task,
         exit_current_group, exit_current_thread, fork_current_process, load_executable, parent_pid,
         process_group, release_child_status, session_id, set_process_group, thread_count,
-        vfork_current_process, wait_child,
+        vfork_current_process, visible_pid, wait_child,
     },
 };
 
@@ -40,28 +40,26 @@ pub(crate) fn sys_exit_group(exit_code: i32) -> ! {
 
 /// @description 返回当前进程标识。
 ///
-/// @return 当前任务的 PID。
+/// @return 当前任务在自身 PID namespace 中的 PID。
 pub(crate) fn sys_get_pid() -> isize {
-    current_task()
-        .expect("getpid requires a current task")
-        .tgid() as isize
+    let task = current_task().expect("getpid requires a current task");
+    task.tgid_seen_by(&task) as isize
 }
 
 /// @description 返回当前进程的父进程标识。
 ///
-/// @return process graph 中的 parent TGID；init 返回零。
+/// @return process graph 中的 parent TGID；init 与 PID namespace 外的 parent 返回零。
 pub(crate) fn sys_get_ppid() -> isize {
     let task = current_task().expect("getppid requires a current task");
-    parent_pid(task.tgid()) as isize
+    visible_pid(&task, parent_pid(task.tgid())) as isize
 }
 
 /// @description 返回当前线程标识；单线程模型中与 PID 相同。
 ///
-/// @return 当前任务的 TID。
+/// @return 当前任务在自身 PID namespace 中的 TID。
 pub(crate) fn sys_get_tid() -> isize {
-    current_task()
-        .expect("gettid requires a current task")
-        .tid() as isize
+    let task = current_task().expect("gettid requires a current task");
+    task.tid_seen_by(&task) as isize
 }
 
 fn process_group_error(error: ProcessGroupError) -> isize {
@@ -114,7 +112,7 @@ pub(crate) fn sys_setsid() -> isize {
 
 /// @description 实现 fork、vfork 与 pthread-shaped Linux/riscv64 clone。
 ///
-/// @param flags 当前必须精确为 `SIGCHLD`。
+/// @param flags fork 为 `SIGCHLD`，可附加 `CLONE_NEWPID`；vfork/pthread 为固定形状。
 /// @param stack fork 必须为零；vfork 可提供 aligned child SP；pthread clone 必须非零。
 /// @param parent_tid fork flags 未启用对应语义，按 Linux 规则忽略。
 /// @param tls fork flags 未启用对应语义，按 Linux 规则忽略。
@@ -130,15 +128,26 @@ pub(crate) fn sys_clone(
     const SIGCHLD: usize = 17;
     const CLONE_VM: usize = 0x100;
     const CLONE_VFORK: usize = 0x4000;
-    if flags == SIGCHLD {
+    const CLONE_NEWPID: usize = 0x2000_0000;
+    if flags & !CLONE_NEWPID == SIGCHLD {
         if stack != 0 {
             return -errno::EINVAL;
         }
         let current = current_task().expect("clone requires current task");
+        let new_pid_namespace = flags & CLONE_NEWPID != 0;
+        if new_pid_namespace {
+            if current.credential_id(true, true) != 0 {
+                return -errno::EPERM;
+            }
+            // 只支持全局 namespace 之下一层；嵌套创建按 Linux 超出层级上限返回 ENOSPC。
+            if current.pid_namespace().is_some() {
+                return -errno::ENOSPC;
+            }
+        }
         if thread_count(current.tgid()) != 1 {
             return -errno::EAGAIN;
         }
-        return match fork_current_process() {
+        return match fork_current_process(new_pid_namespace) {
             Ok(pid) => pid as isize,
             Err(ProcessCloneError::Memory(error)) => {
                 process_clone_memory_errno(error.is_out_of_memory())
@@ -217,45 +226,143 @@ pub(crate) fn sys_set_robust_list(head: usize, length: usize) -> isize {
         .map_or(-errno::EINVAL, |()| 0)
 }
 
-/// @description 等待并消费直接 child 的最小 exit record。
+/// @description 编码 64-bit Linux `struct rusage`；runtime 无 user/system 拆分，全部计入 `ru_utime`。
+///
+/// @param runtime_us child 及其已回收 descendant 的累计 CPU runtime。
+/// @return 其余计数字段为零的 144-byte ABI 字节。
+fn encode_child_rusage(runtime_us: u64) -> [u8; 144] {
+    let mut bytes = [0u8; 144];
+    bytes[..8].copy_from_slice(&(runtime_us / 1_000_000).to_ne_bytes());
+    bytes[8..16].copy_from_slice(&(runtime_us % 1_000_000).to_ne_bytes());
+    bytes
+}
+
+fn wait_child_errno(error: WaitChildError) -> isize {
+    match error {
+        WaitChildError::NoChild => -errno::ECHILD,
+        WaitChildError::InvalidSelector => -errno::EINVAL,
+        WaitChildError::Interrupted => INTERNAL_RESTART_SYS,
+        WaitChildError::OutOfMemory => -errno::ENOMEM,
+    }
+}
+
+/// @description 将 child wait status word 转换为 `CLD_*` SIGCHLD siginfo 来源。
+fn child_status_siginfo(pid: usize, status: i32) -> PendingSignal {
+    match status {
+        0xffff => PendingSignal::child_continued(pid),
+        status if status & 0xff == 0x7f => {
+            PendingSignal::child_stopped(pid, ((status >> 8) & 0xff) as usize)
+        }
+        status if status & 0x7f == 0 => PendingSignal::child_exited(pid, (status >> 8) & 0xff),
+        status => PendingSignal::child_killed(pid, (status & 0x7f) as usize),
+    }
+}
+
+/// @description 等待并消费直接 child 的 exit、stopped 或 continued record。
 ///
-/// @param pid `-1` 表示任一 child，正数表示指定 child。
+/// @param pid `-1` 表示任一 child，正数表示指定 child，`0`/`<-1` 表示 process group。
 /// @param status 可为空；非空时写入 Linux wait status word。
 /// @param options 当前接受 `WNOHANG/WUNTRACED/WCONTINUED` 的任意组合。
-/// @param rusage 当前必须为空，避免返回未实现的资源统计。
+/// @param rusage 可为空；非空时写入 child 的 `RUSAGE_BOTH` CPU runtime。
 /// @return child PID、WNOHANG 的零，或负 Linux errno。
+/// @errors 任一 copyout 失败时 record 保留给后续 wait，并返回 `EFAULT`。
 pub(crate) fn sys_wait4(pid: isize, status: *mut i32, options: usize, rusage: *mut u8) -> isize {
     const WNOHANG: usize = 1;
     const WUNTRACED: usize = 2;
     const WCONTINUED: usize = 8;
-    if options & !(WNOHANG | WUNTRACED | WCONTINUED) != 0 || !rusage.is_null() {
+    if options & !(WNOHANG | WUNTRACED | WCONTINUED) != 0 {
         return -errno::EINVAL;
     }
-    let record = match wait_child(
-        pid,
-        options & WNOHANG != 0,
-        options & WUNTRACED != 0,
-        options & WCONTINUED != 0,
-    ) {
+    let events = WaitEvents {
+        exited: true,
+        stopped: options & WUNTRACED != 0,
+        continued: options & WCONTINUED != 0,
+    };
+    let record = match wait_child(pid, options & WNOHANG != 0, events) {
         Ok(Some(record)) => record,
         Ok(None) => return 0,
-        Err(WaitChildError::NoChild) => return -errno::ECHILD,
-        Err(WaitChildError::InvalidSelector) => return -errno::EINVAL,
-        Err(WaitChildError::Interrupted) => return INTERNAL_RESTART_SYS,
-        Err(WaitChildError::OutOfMemory) => return -errno::ENOMEM,
+        Err(error) => return wait_child_errno(error),
     };
-    if !status.is_null() {
-        let task = current_task().expect("wait4 copyout requires current task");
-        if task
+    let task = current_task().expect("wait4 copyout requires current task");
+    let copied = (status.is_null()
+        || task
             .copy_to_user(status as usize, &record.status.to_ne_bytes())
-            .is_err()
-        {
-            release_child_status(record);
-            return -errno::EFAULT;
+            .is_ok())
+        && (rusage.is_null()
+            || task
+                .copy_to_user(rusage as usize, &encode_child_rusage(record.runtime_us))
+                .is_ok());
+    if !copied {
+        release_child_status(record);
+        return -errno::EFAULT;
+    }
+    consume_child_status(record);
+    record.visible_pid as isize
+}
+
+/// @description 按 `idtype/id` 等待直接 child 的 exit、stopped 或 continued record。
+///
+/// @param idtype `P_ALL=0`、`P_PID=1` 或 `P_PGID=2`；`P_PIDFD` 尚无 pidfd owner。
+/// @param id `P_PID` 的 PID，或 `P_PGID` 的 process group（零为 caller 所在 group）。
+/// @param infop 可为空；非空时写入 SIGCHLD 形状的 128-byte `siginfo_t`，WNOHANG 无 record 时清零。
+/// @param options 必须含 `WEXITED/WSTOPPED/WCONTINUED` 之一，可附加 `WNOHANG/WNOWAIT`。
+/// @param rusage 可为空；非空时写入 child 的 `RUSAGE_BOTH` CPU runtime。
+/// @return 成功或 WNOHANG 无 record 时返回零，失败返回负 Linux errno。
+/// @errors `WNOWAIT` 或任一 copyout 失败时 record 保留给后续 wait。
+pub(crate) fn sys_waitid(
+    idtype: usize,
+    id: usize,
+    infop: *mut u8,
+    options: usize,
+    rusage: *mut u8,
+) -> isize {
+    const WNOHANG: usize = 1;
+    const WSTOPPED: usize = 2;
+    const WEXITED: usize = 4;
+    const WCONTINUED: usize = 8;
+    const WNOWAIT: usize = 0x0100_0000;
+    const SIGCHLD: usize = 17;
+    let events = WaitEvents {
+        exited: options & WEXITED != 0,
+        stopped: options & WSTOPPED != 0,
+        continued: options & WCONTINUED != 0,
+    };
+    if options & !(WNOHANG | WSTOPPED | WEXITED | WCONTINUED | WNOWAIT) != 0
+        || !(events.exited || events.stopped || events.continued)
+    {
+        return -errno::EINVAL;
+    }
+    let id = id as i32 as isize;
+    let selector = match idtype {
+        0 => -1,
+        1 if id > 0 => id,
+        2 if id >= 0 => -id,
+        _ => return -errno::EINVAL,
+    };
+    let task = current_task().expect("waitid copyout requires current task");
+    let record = match wait_child(selector, options & WNOHANG != 0, events) {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            return if infop.is_null() || task.copy_to_user(infop as usize, &[0; 128]).is_ok() {
+                0
+            } else {
+                -errno::EFAULT
+            };
         }
+        Err(error) => return wait_child_errno(error),
+    };
+    let siginfo = child_status_siginfo(record.visible_pid, record.status).encode(SIGCHLD);
+    let copied = (infop.is_null() || task.copy_to_user(infop as usize, &siginfo).is_ok())
+        && (rusage.is_null()
+            || task
+                .copy_to_user(rusage as usize, &encode_child_rusage(record.runtime_us))
+                .is_ok());
+    if !copied || options & WNOWAIT != 0 {
+        release_child_status(record);
+        return if copied { 0 } else { -errno::EFAULT };
     }
     consume_child_status(record);
-    record.pid as isize
+    0
 }
 
 /// @description 用新的 RV64 ET_EXEC 或动态 PIE 映像、参数和环境替换当前进程。
@@ -5,12 +5,17 @@ use crate::{
 
 const PR_SET_PDEATHSIG: usize = 1;
 const PR_GET_PDEATHSIG: usize = 2;
+const PR_SET_MDWE: usize = 65;
+const PR_GET_MDWE: usize = 66;
+const PR_MDWE_REFUSE_EXEC_GAIN: usize = 1;
 
-/// @description 实现 Linux `prctl` 当前开放的 parent-death signal operations。
-/// @param option 标准 `PR_SET_PDEATHSIG` 或 `PR_GET_PDEATHSIG` selector。
-/// @param argument SET 的 signal value，或 GET 的 `int *` userspace pointer。
-/// @return 成功返回零。
-/// @errors selector/signal 非法返回 `EINVAL`；GET copyout 失败返回 `EFAULT`。
+/// @description 实现 Linux `prctl` 当前开放的 parent-death signal 与 MDWE operations。
+/// @param option 标准 `PR_SET/GET_PDEATHSIG` 或 `PR_SET/GET_MDWE` selector。
+/// @param argument PDEATHSIG SET 的 signal value、GET 的 `int *` userspace pointer，
+///                 MDWE SET 的 flag bits，或 MDWE GET 的零。
+/// @return 成功返回零；`PR_GET_MDWE` 返回当前 flag bits。
+/// @errors selector/signal/flag 非法返回 `EINVAL`；撤销已开启的 MDWE 返回 `EPERM`；
+///         GET copyout 失败返回 `EFAULT`。
 pub(crate) fn sys_prctl(option: usize, argument: usize) -> isize {
     match option {
         PR_SET_PDEATHSIG => parent_death_signal(Some(argument)).map_or(-errno::EINVAL, |_| 0),
@@ -24,6 +29,28 @@ pub(crate) fn sys_prctl(option: usize, argument: usize) -> isize {
                 .copy_to_user(argument, &signal.to_ne_bytes())
                 .map_or(-errno::EFAULT, |()| 0)
         }
+        PR_SET_MDWE => set_memory_deny_write_exec(argument),
+        PR_GET_MDWE if argument == 0 => match current_task()
+            .expect("prctl requires current task")
+            .deny_write_exec(false)
+        {
+            Ok(enabled) => (usize::from(enabled) * PR_MDWE_REFUSE_EXEC_GAIN) as isize,
+            Err(_) => -errno::ENOMEM,
+        },
         _ => -errno::EINVAL,
     }
 }
+
+/// @description Linux `prctl_set_mdwe`：只开放 `PR_MDWE_REFUSE_EXEC_GAIN`，policy 不可撤销。
+/// `PR_MDWE_NO_INHERIT` 未实现，以 `EINVAL` 拒绝而不是静默忽略 exec 边界。
+fn set_memory_deny_write_exec(bits: usize) -> isize {
+    if bits & !PR_MDWE_REFUSE_EXEC_GAIN != 0 {
+        return -errno::EINVAL;
+    }
+    let task = current_task().expect("prctl requires current task");
+    match task.deny_write_exec(bits != 0) {
+        Ok(true) if bits == 0 => -errno::EPERM,
+        Ok(_) => 0,
+        Err(_) => -errno::ENOMEM,
+    }
+}
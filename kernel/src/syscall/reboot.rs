@@ -1,4 +1,5 @@
 use crate::{
+    fs::vfs,
     syscall::errno,
     system::{self, ResetKind},
 };
@@ -9,7 +10,7 @@ use crate::{
 /// @param magic2 接受 Linux 当前及历史兼容 magic2。
 /// @param command CAD toggle、halt/poweroff 或 restart command。
 /// @param argument `RESTART2` 的用户字符串；当前 platform 不支持 restart reason。
-/// @return CAD toggle 返回零；reset 成功不返回；非法参数或 SBI 错误返回负 errno。
+/// @return CAD toggle 返回零；reset 先同步 root filesystem 后不返回，firmware 失败 fail-stop；非法参数返回负 errno。
 pub(crate) fn sys_reboot(magic: usize, magic2: usize, command: usize, argument: usize) -> isize {
     const MAGIC1: usize = 0xfee1_dead;
     const MAGIC2: [usize; 4] = [0x2812_1969, 0x0512_1996, 0x1604_1998, 0x2011_2000];
@@ -38,8 +39,12 @@ pub(crate) fn sys_reboot(magic: usize, magic2: usize, command: usize, argument:
     }
 }
 
-fn reset(kind: ResetKind) -> isize {
-    match system::reset(kind) {
-        Ok(()) | Err(_) => -errno::EIO,
+fn reset(kind: ResetKind) -> ! {
+    // Linux 只在 reset 前 shutdown device；此处额外提交 page cache 与 block write cache，
+    // 未先 sync(2) 的 caller 也不会在 firmware 断电时丢失已返回的写入。writeback 错误不阻止
+    // reset，但必须留下 kmsg/console 证据，否则数据丢失在重启后无从诊断。
+    if let Err(error) = vfs().sync() {
+        error!("filesystem writeback before reset failed: {:?}", error);
     }
+    system::reset(kind)
 }
@@ -55,7 +55,7 @@ fn nice_selector(which: i32, who: u32) -> Result<SchedulerNiceSelector, isize> {
 /// @description 设置一个 live Thread 的 Linux I/O priority。
 ///
 /// @param which 当前实现支持 `IOPRIO_WHO_PROCESS`。
-/// @param who 零选择 caller，正数选择全局 TID。
+/// @param who 零选择 caller，正数选择 caller PID namespace 中的 TID。
 /// @param priority encoded class/data；支持 NONE/RT/BE/IDLE 与 data 0..7。
 /// @return 成功返回 0。
 /// @errors selector/encoding 非法返回 EINVAL；目标不存在或权限不足返回 ESRCH/EPERM。
@@ -77,7 +77,7 @@ pub(crate) fn sys_ioprio_set(which: i32, who: i32, priority: i32) -> isize {
 /// @description 查询一个 live Thread 的 Linux I/O priority。
 ///
 /// @param which 当前实现支持 `IOPRIO_WHO_PROCESS`。
-/// @param who 零选择 caller，正数选择全局 TID。
+/// @param who 零选择 caller，正数选择 caller PID namespace 中的 TID。
 /// @return encoded priority；未显式设置时为 class NONE 的零。
 /// @errors selector 非法或目标不存在返回 EINVAL/ESRCH。
 pub(crate) fn sys_ioprio_get(which: i32, who: i32) -> isize {
@@ -135,7 +135,7 @@ fn read_sched_priority(tid: i32, address: usize) -> Result<i32, isize> {
 
 /// @description 保留目标 policy，只替换 legacy `sched_priority`。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID，负数非法。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID，负数非法。
 /// @param parameter 用户态 4-byte `struct sched_param` 地址。
 /// @return 成功返回 0。
 /// @errors 参数非法返回 `-EINVAL`；copyin 失败返回 `-EFAULT`；目标/权限错误返回 `-ESRCH/-EPERM`。
@@ -154,7 +154,7 @@ pub(crate) fn sys_sched_setparam(tid: i32, parameter: usize) -> isize {
 
 /// @description 替换目标 Thread 的 legacy scheduler policy 与 priority。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID，负数非法。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID，负数非法。
 /// @param policy Linux scheduler policy 与可选 `SCHED_RESET_ON_FORK` bit。
 /// @param parameter 用户态 4-byte `struct sched_param` 地址。
 /// @return 成功返回 0。
@@ -177,7 +177,7 @@ pub(crate) fn sys_sched_setscheduler(tid: i32, policy: i32, parameter: usize) ->
 
 /// @description 返回目标 Thread 的 legacy scheduler policy。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID，负数非法。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID，负数非法。
 /// @return `SCHED_OTHER`，并在设置时包含 `SCHED_RESET_ON_FORK`。
 /// @errors selector 非法返回 `-EINVAL`；目标不存在返回 `-ESRCH`。
 pub(crate) fn sys_sched_getscheduler(tid: i32) -> isize {
@@ -191,7 +191,7 @@ pub(crate) fn sys_sched_getscheduler(tid: i32) -> isize {
 
 /// @description 返回目标 Thread 的 legacy real-time priority。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID，负数非法。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID，负数非法。
 /// @param parameter 用户态 4-byte `struct sched_param` 输出地址。
 /// @return 成功返回 0；当前 `SCHED_OTHER` priority 固定为 0。
 /// @errors 参数非法返回 `-EINVAL`；目标不存在返回 `-ESRCH`；copyout 失败返回 `-EFAULT`。
@@ -235,7 +235,7 @@ pub(crate) fn sys_sched_get_priority_min(policy: i32) -> isize {
 
 /// @description 返回目标 Thread 的固定 `SCHED_OTHER` 基础时间片。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID，负数非法。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID，负数非法。
 /// @param interval 用户态 16-byte `__kernel_timespec` 输出地址。
 /// @return 成功返回 0。
 /// @errors selector 非法返回 `-EINVAL`；目标不存在返回 `-ESRCH`；copyout 失败返回 `-EFAULT`。
@@ -257,7 +257,7 @@ pub(crate) fn sys_sched_rr_get_interval(tid: i32, interval: usize) -> isize {
 
 /// @description 按 Linux 变长 cpumask ABI 替换目标 Thread affinity。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID。
 /// @param length userspace mask 字节数；短 mask 高位补零，长 mask 截断到 kernel 宽度。
 /// @param input 用户态 CPU mask 地址。
 /// @return 成功返回 0。
@@ -279,7 +279,7 @@ pub(crate) fn sys_sched_setaffinity(tid: i32, length: u32, input: usize) -> isiz
 
 /// @description 返回目标 Thread 当前可运行的 active logical CPU mask。
 ///
-/// @param tid 零选择 calling Thread；正数选择全局 TID。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID。
 /// @param length 用户缓冲区字节数，必须足够且按 RV64 `unsigned long` 对齐。
 /// @param output 用户态 CPU mask 输出地址。
 /// @return 成功返回实际复制的 8 bytes。
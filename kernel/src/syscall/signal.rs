@@ -2,8 +2,8 @@ use crate::{
     syscall::errno,
     task::{
         SignalAction, SignalSendError, SignalStack, SignalStackError, SignalWaitError, WaitResult,
-        current_task, send_process_signal, send_thread_signal, send_tid_signal, wait_for_signal,
-        wait_for_signal_delivery,
+        current_task, resolve_pid, send_process_signal, send_thread_signal, send_tid_signal,
+        wait_for_signal, wait_for_signal_delivery,
     },
 };
 
@@ -139,14 +139,18 @@ pub(crate) fn sys_rt_sigprocmask(
 
 /// @description 实现 Linux thread-group-aware signal 投递与 signal-zero probe。
 ///
-/// @param tgid 目标 Process ID。
-/// @param tid 目标 Thread ID。
+/// @param tgid caller PID namespace 中的目标 Process ID。
+/// @param tid caller PID namespace 中的目标 Thread ID。
 /// @param signal Linux signal number。
 /// @return 成功返回零，失败返回负 errno。
 pub(crate) fn sys_tgkill(tgid: usize, tid: usize, signal: usize) -> isize {
     if signal > 64 {
         return -errno::EINVAL;
     }
+    let task = current_task().expect("tgkill requires current task");
+    let (Some(tgid), Some(tid)) = (resolve_pid(&task, tgid), resolve_pid(&task, tid)) else {
+        return -errno::ESRCH;
+    };
     if signal == 0 {
         return send_thread_signal(tgid, tid, 0).map_or_else(signal_send_errno, |()| 0);
     }
@@ -155,13 +159,17 @@ pub(crate) fn sys_tgkill(tgid: usize, tid: usize, signal: usize) -> isize {
 
 /// @description 实现 Linux `tkill` 的全局 TID selector，并复用 thread-signal routing。
 ///
-/// @param tid 目标 Thread ID。
+/// @param tid caller PID namespace 中的目标 Thread ID。
 /// @param signal Linux signal number；零只做 existence probe。
 /// @return 成功返回零；signal 非法返回 `EINVAL`，TID 不存在返回 `ESRCH`。
 pub(crate) fn sys_tkill(tid: usize, signal: usize) -> isize {
     if signal > 64 {
         return -errno::EINVAL;
     }
+    let task = current_task().expect("tkill requires current task");
+    let Some(tid) = resolve_pid(&task, tid) else {
+        return -errno::ESRCH;
+    };
     send_tid_signal(tid, signal).map_or_else(signal_send_errno, |()| 0)
 }
 
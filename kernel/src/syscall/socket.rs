@@ -4,13 +4,13 @@ use crate::{
     fs::{O_CLOEXEC, O_NONBLOCK, O_RDWR, OpenFileDescription, OpenFileKind},
     socket::{
         InetAddress, NetlinkAddress, PacketAddress, Socket, SocketAddress, SocketDomain,
-        SocketError, SocketType, UnixAddress, UnixConnectResources, UnixCredentials,
+        SocketError, SocketSender, SocketType, UnixAddress, UnixConnectResources, UnixCredentials,
         configure_address, configure_gateway, configure_netmask, configure_up, interface_snapshot,
     },
     task::{self, TaskControlBlock, WaitResult, current_task},
 };
 
-use super::{errno, poll::wait_for_ofd};
+use super::{INTERNAL_RESTART_SYS, errno, poll::wait_for_ofd};
 
 mod control;
 mod interface;
@@ -86,6 +86,7 @@ pub(super) fn socket_error(error: SocketError) -> isize {
         SocketError::PermissionDenied => errno::EACCES,
         SocketError::NoDevice => errno::ENODEV,
         SocketError::TooManyReferences => errno::ETOOMANYREFS,
+        SocketError::NoBufferSpace => errno::ENOBUFS,
     }
 }
 
@@ -129,6 +130,15 @@ fn current_unix_credentials() -> UnixCredentials {
     }
 }
 
+/// @description 为 send path 捕获 caller credential；CAP_AUDIT_CONTROL 同样以 effective UID 0 等价。
+pub(super) fn socket_sender(task: &TaskControlBlock) -> SocketSender {
+    SocketSender {
+        tgid: task.tgid() as u32,
+        privileged: task.credential_id(true, true) == 0,
+        initial_pid_namespace: task.pid_namespace().is_none(),
+    }
+}
+
 fn socket_ofd(fd: usize) -> Result<(Arc<OpenFileDescription>, Arc<Socket>), isize> {
     let task = current_task().expect("socket syscall requires current task");
     let ofd = task.fd_get(fd).ok_or(-errno::EBADF)?;
@@ -255,7 +265,9 @@ pub(crate) fn sys_socket(domain: usize, kind: usize, protocol: usize) -> isize {
     };
     // 当前没有 capability bitmap；effective UID 0 是 CAP_NET_RAW 的唯一标准等价策略。
     // 缺失该检查会允许普通用户创建 raw control/packet fd，绕过 L3 policy。
-    if (domain == SocketDomain::Packet || kind == SocketType::Raw)
+    // netlink raw socket 不携带 L3 数据，Linux 不要求 CAP_NET_RAW。
+    if (domain == SocketDomain::Packet
+        || (kind == SocketType::Raw && domain != SocketDomain::Netlink))
         && current_task().unwrap().credential_id(true, true) != 0
     {
         return -errno::EPERM;
@@ -399,15 +411,21 @@ pub(crate) fn sys_connect(fd: usize, address: usize, length: usize) -> isize {
     };
     match client.connect(address, credentials, unix_identity, resources) {
         Ok(()) => 0,
-        Err(SocketError::InProgress) if *ofd.flags.lock() & O_NONBLOCK != 0 => -errno::EINPROGRESS,
-        Err(SocketError::InProgress) => loop {
+        Err(error @ (SocketError::InProgress | SocketError::AlreadyInProgress))
+            if *ofd.flags.lock() & O_NONBLOCK != 0 =>
+        {
+            socket_error(error)
+        }
+        // Linux `__inet_stream_connect` 让 blocking connect 在 SYN_SENT socket 上继续等待同一连接；
+        // signal 打断后的 restart 因此观察到 Connecting 时恢复等待，而不是返回 EALREADY。
+        Err(SocketError::InProgress | SocketError::AlreadyInProgress) => loop {
             match wait_for_ofd(&ofd, 4 | 8) {
                 WaitResult::Woken => match client.connection_result() {
                     Ok(()) => return 0,
                     Err(SocketError::InProgress) => {}
                     Err(error) => return socket_error(error),
                 },
-                WaitResult::Interrupted => return -errno::EINTR,
+                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                 WaitResult::TimedOut => unreachable!(),
                 WaitResult::OutOfMemory => return -errno::ENOMEM,
             }
@@ -464,7 +482,7 @@ pub(crate) fn sys_accept4(fd: usize, address: usize, length: usize, flags: usize
             }
             Err(SocketError::Again) => match wait_for_ofd(&ofd, 1) {
                 WaitResult::Woken => {}
-                WaitResult::Interrupted => return -errno::EINTR,
+                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                 WaitResult::TimedOut => unreachable!(),
                 WaitResult::OutOfMemory => return -errno::ENOMEM,
             },
@@ -10,6 +10,7 @@ use crate::{
     ipc::ReceiveBuffer,
     socket::{Socket, SocketSendError},
     syscall::{
+        INTERNAL_RESTART_SYS,
         poll::wait_for_socket_send,
         user_iovec::{
             BufferError, ImportError, UserInputStaging, UserIoCursor, UserIoVec,
@@ -105,6 +106,10 @@ impl SendContext<'_> {
     fn nonblocking(&self) -> bool {
         self.flags & MSG_DONTWAIT != 0 || *self.ofd.flags.lock() & O_NONBLOCK != 0
     }
+
+    fn sender(&self) -> crate::socket::SocketSender {
+        super::socket_sender(self.task)
+    }
 }
 
 fn send_one_message(
@@ -113,10 +118,12 @@ fn send_one_message(
     rights: &mut Option<crate::socket::UnixRights>,
 ) -> isize {
     loop {
-        match context
-            .socket
-            .send_to_with_rights(bytes, context.target.clone(), rights)
-        {
+        match context.socket.send_to_with_rights(
+            bytes,
+            context.target.clone(),
+            rights,
+            context.sender(),
+        ) {
             Ok(count) => return count as isize,
             Err(SocketSendError::WouldBlock | SocketSendError::PeerFull(_))
                 if context.nonblocking() =>
@@ -125,13 +132,13 @@ fn send_one_message(
             }
             Err(SocketSendError::WouldBlock) => match wait_for_ofd(context.ofd, 4) {
                 WaitResult::Woken => {}
-                WaitResult::Interrupted => return -errno::EINTR,
+                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                 WaitResult::TimedOut => unreachable!(),
                 WaitResult::OutOfMemory => return -errno::ENOMEM,
             },
             Err(SocketSendError::PeerFull(blocker)) => match wait_for_socket_send(&blocker) {
                 WaitResult::Woken => {}
-                WaitResult::Interrupted => return -errno::EINTR,
+                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                 WaitResult::TimedOut => unreachable!(),
                 WaitResult::OutOfMemory => return -errno::ENOMEM,
             },
@@ -187,6 +194,7 @@ fn send_stream_message(
                 staging.initialized(),
                 context.target.clone(),
                 &mut rights,
+                context.sender(),
             ) {
                 Ok(sent) => {
                     assert!(sent <= staged.count, "socket consumed beyond staged prefix");
@@ -208,13 +216,13 @@ fn send_stream_message(
                 }
                 Err(SocketSendError::WouldBlock) => match wait_for_ofd(context.ofd, 4) {
                     WaitResult::Woken => {}
-                    WaitResult::Interrupted => return -errno::EINTR,
+                    WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                     WaitResult::TimedOut => unreachable!(),
                     WaitResult::OutOfMemory => return -errno::ENOMEM,
                 },
                 Err(SocketSendError::PeerFull(blocker)) => match wait_for_socket_send(&blocker) {
                     WaitResult::Woken => {}
-                    WaitResult::Interrupted => return -errno::EINTR,
+                    WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                     WaitResult::TimedOut => unreachable!(),
                     WaitResult::OutOfMemory => return -errno::ENOMEM,
                 },
@@ -402,7 +410,7 @@ pub(crate) fn sys_recvfrom(
             }
             Err(SocketError::Again) => match wait_for_ofd(&ofd, 1) {
                 WaitResult::Woken => {}
-                WaitResult::Interrupted => return -errno::EINTR,
+                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                 WaitResult::TimedOut => unreachable!(),
                 WaitResult::OutOfMemory => return -errno::ENOMEM,
             },
@@ -470,7 +478,7 @@ pub(crate) fn sys_recvmsg(fd: usize, message: usize, flags: usize) -> isize {
             Err(SocketError::Again) if nonblocking => return -errno::EAGAIN,
             Err(SocketError::Again) => match wait_for_ofd(&ofd, 1) {
                 WaitResult::Woken => {}
-                WaitResult::Interrupted => return -errno::EINTR,
+                WaitResult::Interrupted => return INTERNAL_RESTART_SYS,
                 WaitResult::TimedOut => unreachable!(),
                 WaitResult::OutOfMemory => return -errno::ENOMEM,
             },
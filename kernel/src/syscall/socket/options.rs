@@ -1,4 +1,5 @@
 use super::{SocketType, current_task, errno, socket_error, socket_ofd};
+use crate::task::visible_pid;
 
 const IPPROTO_IP: usize = 0;
 const IP_PKTINFO: usize = 8;
@@ -146,7 +147,10 @@ pub(crate) fn sys_getsockopt(
                 Ok(credentials) => credentials,
                 Err(error) => return socket_error(error),
             };
-            result[..4].copy_from_slice(&credentials.pid.to_ne_bytes());
+            // ucred 保存创建/连接者的全局 TGID；读取时按 observer 的 PID namespace 翻译。
+            let observer = current_task().unwrap();
+            let pid = visible_pid(&observer, credentials.pid as usize) as i32;
+            result[..4].copy_from_slice(&pid.to_ne_bytes());
             result[4..8].copy_from_slice(&credentials.uid.to_ne_bytes());
             result[8..12].copy_from_slice(&credentials.gid.to_ne_bytes());
             12
@@ -18,6 +18,9 @@ const CLOCK_REALTIME: i32 = 0;
 const CLOCK_MONOTONIC: i32 = 1;
 const CLOCK_PROCESS_CPUTIME_ID: i32 = 2;
 const CLOCK_THREAD_CPUTIME_ID: i32 = 3;
+// LiteOS 没有 suspend phase，BOOTTIME 与 MONOTONIC 共享同一 timebase；
+// 单独维护 offset 只会复制一个永远为零的 owner。
+const CLOCK_BOOTTIME: i32 = 7;
 const TIMER_ABSTIME: i32 = 1;
 const ITIMER_REAL: usize = 0;
 
@@ -210,7 +213,7 @@ pub(crate) fn sys_nanosleep(req: *const TimeSpec, rem: *mut TimeSpec) -> isize {
 
 /// @description 按 Linux clock selector 执行 relative 或 absolute interruptible sleep。
 ///
-/// @param clock_id 当前支持 `CLOCK_REALTIME` 与 `CLOCK_MONOTONIC`。
+/// @param clock_id 当前支持 `CLOCK_REALTIME`、`CLOCK_MONOTONIC` 与 `CLOCK_BOOTTIME`。
 /// @param flags `TIMER_ABSTIME` 选择 absolute deadline；Linux 对其他位不赋予语义。
 /// @param req 用户态 64-bit timespec 请求地址。
 /// @param rem relative sleep 被 signal 中断时的可选剩余时间输出；absolute 模式不修改。
@@ -222,7 +225,7 @@ pub(crate) fn sys_clock_nanosleep(
     rem: *mut TimeSpec,
 ) -> isize {
     // 1. selector capability 在 user-copy 前确定；否则坏指针会掩盖 invalid/unsupported clock。
-    if !matches!(clock_id, CLOCK_REALTIME | CLOCK_MONOTONIC) {
+    if !matches!(clock_id, CLOCK_REALTIME | CLOCK_MONOTONIC | CLOCK_BOOTTIME) {
         return if matches!(clock_id, CLOCK_PROCESS_CPUTIME_ID | CLOCK_THREAD_CPUTIME_ID) {
             -EOPNOTSUPP
         } else {
@@ -245,10 +248,10 @@ pub(crate) fn sys_clock_nanosleep(
     // 2. absolute 值是所选 clock 的 timestamp；若当作 duration，realtime 会多睡一个 epoch。
     let absolute = flags & TIMER_ABSTIME != 0;
     let deadline = if absolute {
-        if clock_id == CLOCK_MONOTONIC {
-            requested_ns
-        } else {
+        if clock_id == CLOCK_REALTIME {
             crate::timer::realtime_deadline_to_monotonic_ns(requested_ns)
+        } else {
+            requested_ns
         }
     } else {
         match crate::timer::get_time_ns().checked_add(requested_ns) {
@@ -265,14 +268,14 @@ pub(crate) fn sys_clock_nanosleep(
     )
 }
 
-/// @description 查询 Linux/riscv64 realtime、monotonic 或 calling task CPU clock。
+/// @description 查询 Linux/riscv64 realtime、monotonic/boottime 或 calling task CPU clock。
 ///
-/// @param clock_id Linux `CLOCK_REALTIME/MONOTONIC/PROCESS_CPUTIME_ID/THREAD_CPUTIME_ID`。
+/// @param clock_id Linux `CLOCK_REALTIME/MONOTONIC/PROCESS_CPUTIME_ID/THREAD_CPUTIME_ID/BOOTTIME`。
 /// @param result 用户态 timespec 输出地址。
 /// @return 成功返回 0，非法 clock ID 返回 -EINVAL，copyout fault 返回 -EFAULT。
 pub(crate) fn sys_clock_gettime(clock_id: i32, result: *mut TimeSpec) -> isize {
     let value = match clock_id {
-        CLOCK_REALTIME | CLOCK_MONOTONIC => {
+        CLOCK_REALTIME | CLOCK_MONOTONIC | CLOCK_BOOTTIME => {
             let nanoseconds = if clock_id == CLOCK_REALTIME {
                 crate::timer::get_realtime_ns()
             } else {
@@ -319,12 +322,14 @@ pub(crate) fn sys_clock_gettime(clock_id: i32, result: *mut TimeSpec) -> isize {
 
 /// @description 查询 LiteOS 已实现 Linux clocks 的实际可观察分辨率。
 ///
-/// @param clock_id Linux `CLOCK_REALTIME/MONOTONIC/PROCESS_CPUTIME_ID/THREAD_CPUTIME_ID`。
+/// @param clock_id Linux `CLOCK_REALTIME/MONOTONIC/PROCESS_CPUTIME_ID/THREAD_CPUTIME_ID/BOOTTIME`。
 /// @param result 可为空的用户态 timespec 输出地址；为空时只校验 clock ID。
 /// @return 成功返回 0，非法 clock ID 返回 -EINVAL，copyout fault 返回 -EFAULT。
 pub(crate) fn sys_clock_getres(clock_id: i32, result: *mut TimeSpec) -> isize {
     let nanoseconds = match clock_id {
-        CLOCK_REALTIME | CLOCK_MONOTONIC => crate::timer::monotonic_resolution_ns(),
+        CLOCK_REALTIME | CLOCK_MONOTONIC | CLOCK_BOOTTIME => {
+            crate::timer::monotonic_resolution_ns()
+        }
         CLOCK_PROCESS_CPUTIME_ID | CLOCK_THREAD_CPUTIME_ID => 1_000,
         _ => return -EINVAL,
     };
@@ -1,10 +1,13 @@
 use super::{
-    CLOCK_MONOTONIC, CLOCK_PROCESS_CPUTIME_ID, CLOCK_REALTIME, CLOCK_THREAD_CPUTIME_ID,
-    TIMER_ABSTIME, TimeSpec, decode_timespec, encode_timespec,
+    CLOCK_BOOTTIME, CLOCK_MONOTONIC, CLOCK_PROCESS_CPUTIME_ID, CLOCK_REALTIME,
+    CLOCK_THREAD_CPUTIME_ID, TIMER_ABSTIME, TimeSpec, decode_timespec, encode_timespec,
 };
 use crate::{
     syscall::errno::{EAGAIN, EFAULT, EINVAL, ENOMEM, EOPNOTSUPP},
-    task::{PosixTimerClock, PosixTimerNotification, TimerError, TimerSetting, current_task},
+    task::{
+        PosixTimerClock, PosixTimerNotification, TimerError, TimerSetting, current_task,
+        resolve_pid,
+    },
 };
 
 const SIGEV_SIGNAL: i32 = 0;
@@ -50,7 +53,7 @@ fn timer_error(error: TimerError, create: bool) -> isize {
 
 fn validate_clock(clock_id: i32) -> Result<(), isize> {
     match clock_id {
-        CLOCK_REALTIME | CLOCK_MONOTONIC => Ok(()),
+        CLOCK_REALTIME | CLOCK_MONOTONIC | CLOCK_BOOTTIME => Ok(()),
         CLOCK_PROCESS_CPUTIME_ID | CLOCK_THREAD_CPUTIME_ID => Err(-EOPNOTSUPP),
         _ => Err(-EINVAL),
     }
@@ -87,11 +90,14 @@ pub(crate) fn sys_timer_create(clock_id: i32, event: usize, output: usize) -> is
             },
             SIGEV_THREAD_ID => {
                 let tid = i32::from_ne_bytes(bytes[16..20].try_into().unwrap());
-                if tid <= 0 {
+                let Some(tid) = (tid > 0)
+                    .then(|| resolve_pid(&task, tid as usize))
+                    .flatten()
+                else {
                     return -EINVAL;
-                }
+                };
                 PosixTimerNotification::Thread {
-                    tid: tid as usize,
+                    tid,
                     signal: signal as usize,
                     value,
                 }
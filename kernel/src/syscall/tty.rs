@@ -4,7 +4,7 @@ use crate::{
     task::{
         ProcessGroupError, TaskControlBlock, TerminalAccessError, check_terminal_access,
         claim_controlling_terminal, resize_terminal, set_terminal_foreground_group,
-        terminal_foreground_group,
+        terminal_foreground_group, visible_pid,
     },
 };
 
@@ -140,7 +140,7 @@ pub(super) fn tty_ioctl(
         }
         TIOCGSID => match terminal.controlling_session() {
             Some(session) => task
-                .copy_to_user(argument, &(session as i32).to_ne_bytes())
+                .copy_to_user(argument, &(visible_pid(task, session) as i32).to_ne_bytes())
                 .map_or(-errno::EFAULT, |()| 0),
             None => -errno::ENOTTY,
         },
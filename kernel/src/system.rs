@@ -31,6 +31,12 @@ pub(crate) fn decode_architecture_syscall(syscall_id: usize) -> Option<usize> {
     crate::arch::user::decode_private_syscall(syscall_id)
 }
 
+/// @description 返回当前 user ABI 的 Linux `AUDIT_ARCH_*` 值。
+/// @return `EM_*` machine 加 64-bit 与 little-endian flag。
+pub(crate) fn audit_architecture() -> u32 {
+    0xc000_0000 | crate::arch::user::ELF_MACHINE as u32
+}
+
 /// @description 返回 platform monotonic counter 的固定频率。
 /// @return DTB/architecture platform owner 已验证的 Hz 值。
 pub(crate) fn time_counter_frequency() -> u64 {
@@ -52,16 +58,20 @@ pub(crate) fn online_cpu_mask() -> usize {
     crate::cpu::online().native_word()
 }
 
-/// @description 通过唯一 platform reset seam 关闭或冷重启整个 SMP system。
+/// @description 静默全部 driver 后，通过唯一 platform reset seam 关闭或冷重启整个 SMP system。
 ///
 /// @param kind 已由 syscall UAPI 层验证的 reset 类型。
-/// @return firmware 异常返回时传播 typed platform error；成功通常不返回。
-pub(crate) fn reset(kind: ResetKind) -> Result<(), crate::platform::ResetError> {
-    let reset_type = match kind {
-        ResetKind::Shutdown => 0,
-        ResetKind::ColdReboot => 1,
+/// @return 永不返回；driver 已静默，firmware 返回（成功值或错误）时无法恢复 block/console I/O，
+/// 因此 fail-stop 而不是把 errno 交还给失去设备的 userspace。
+/// @panics firmware reset 调用返回时 panic，并记录 reset 类型与 platform error。
+pub(crate) fn reset(kind: ResetKind) -> ! {
+    let (reset_type, name) = match kind {
+        ResetKind::Shutdown => (0, "shutdown"),
+        ResetKind::ColdReboot => (1, "cold reboot"),
     };
-    crate::platform::reset_system(reset_type, 0)
+    crate::drivers::shutdown_devices();
+    let result = crate::platform::reset_system(reset_type, 0);
+    panic!("platform {name} returned after device quiesce: {result:?}")
 }
 
 /// @description 更新 Linux Ctrl-Alt-Delete 的 whole-system reset policy。
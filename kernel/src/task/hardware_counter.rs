@@ -0,0 +1,211 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU8, AtomicU64, Ordering};
+
+use spin::Mutex;
+
+use crate::{
+    arch::performance::{self, CounterSample},
+    fs::{PerfEvent, PerfEventControl},
+    platform,
+};
+
+use super::current_task;
+
+const UNPROBED: u8 = 0;
+const AVAILABLE: u8 = 1;
+const UNAVAILABLE: u8 = 2;
+
+// OWNER: task hardware-counter module 唯一发布全部 online CPU 一致的 counter 可用性；
+// 缺失该 owner 时 Thread 可能迁移到未启动 counter 的 CPU，slice 差值会读取未定义 CSR 值。
+static AVAILABILITY: AtomicU8 = AtomicU8::new(UNPROBED);
+
+/// @description perf 可计量的 Thread 硬件事件；数组下标即 `ThreadCounters` 槽位。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HardwareCounter {
+    Cycles = 0,
+    Instructions = 1,
+}
+
+/// @description 打开 Thread counter 失败的原因。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HardwareCounterError {
+    /// firmware/CPU 未启动该事件的 counter。
+    Unavailable,
+    OutOfMemory,
+}
+
+/// @description 单个 Thread 自绑定以来在全部 CPU 上累计的硬件事件数。
+#[derive(Debug)]
+pub(in crate::task) struct ThreadCounters {
+    totals: [AtomicU64; 2],
+}
+
+/// @description Thread scheduling policy 持有的 counter 绑定与当前 active slice 起点。
+#[derive(Debug)]
+pub(in crate::task) struct CounterBinding {
+    counters: Arc<ThreadCounters>,
+    // OWNER: policy lock 独占 active slice 的硬件快照；缺失时 CPU 迁移后的 finish 会用
+    // 另一 CPU 的 counter 做差，产生任意大的事件数。
+    slice_start: Option<CounterSample>,
+}
+
+impl CounterBinding {
+    /// @description 为正在运行的 Thread 建立绑定，并从当前 CPU 快照开始计量。
+    pub(in crate::task) fn running(counters: Arc<ThreadCounters>) -> Self {
+        Self {
+            counters,
+            slice_start: Some(performance::sample()),
+        }
+    }
+
+    pub(in crate::task) fn counters(&self) -> Arc<ThreadCounters> {
+        self.counters.clone()
+    }
+
+    /// @description 在 dispatch CPU 上开始新的计量 slice。
+    /// @panics 上一个 slice 尚未提交时 panic。
+    pub(in crate::task) fn begin(&mut self) {
+        assert!(
+            self.slice_start.replace(performance::sample()).is_none(),
+            "counter slice began twice"
+        );
+    }
+
+    /// @description 在 slice 所在 CPU 上提交增量。
+    /// @param restart checkpoint 为 true，保持 slice 并以当前快照为新起点；deschedule 为 false。
+    /// @panics 没有 active slice 时 panic。
+    pub(in crate::task) fn commit(&mut self, restart: bool) {
+        let start = self
+            .slice_start
+            .take()
+            .expect("counter slice committed twice");
+        let now = performance::sample();
+        for (total, delta) in self.counters.totals.iter().zip(now.since(start)) {
+            total.fetch_add(delta, Ordering::Relaxed);
+        }
+        if restart {
+            self.slice_start = Some(now);
+        }
+    }
+}
+
+impl ThreadCounters {
+    fn total(&self, counter: HardwareCounter) -> u64 {
+        self.totals[counter as usize].load(Ordering::Relaxed)
+    }
+}
+
+struct EventState {
+    enabled: bool,
+    /// enabled 时对应 Thread total 的起点。
+    base: u64,
+    /// 此前各 enabled 区间的和。
+    accumulated: u64,
+}
+
+/// @description 一个 `perf_event_open` fd 的 enable 区间视图；共享 Thread total 而不拥有计量。
+struct CounterEvent {
+    counters: Arc<ThreadCounters>,
+    counter: HardwareCounter,
+    state: Mutex<EventState>,
+}
+
+impl CounterEvent {
+    fn current_total(&self) -> u64 {
+        flush_current();
+        self.counters.total(self.counter)
+    }
+}
+
+impl PerfEvent for CounterEvent {
+    fn read(&self) -> u64 {
+        // state lock 先于 total 快照，并发 control 无法在两者之间发布更新的 base。
+        let state = self.state.lock();
+        let total = self.current_total();
+        if state.enabled {
+            state.accumulated + (total - state.base)
+        } else {
+            state.accumulated
+        }
+    }
+
+    fn control(&self, control: PerfEventControl) {
+        let mut state = self.state.lock();
+        let total = self.current_total();
+        match control {
+            PerfEventControl::Enable if !state.enabled => {
+                state.enabled = true;
+                state.base = total;
+            }
+            PerfEventControl::Disable if state.enabled => {
+                state.enabled = false;
+                state.accumulated += total - state.base;
+            }
+            PerfEventControl::Reset => {
+                state.accumulated = 0;
+                state.base = total;
+            }
+            PerfEventControl::Enable | PerfEventControl::Disable => {}
+        }
+    }
+}
+
+/// @description 在当前 CPU 启动 cycle/instruction counter，并与先前 CPU 的结果核对。
+///
+/// 每个 CPU 必须在 `cpu::mark_online` 前调用，使任何可运行 Thread 的 CPU 都已启动 counter。
+/// @panics CPU 之间 counter 可用性不一致时 fail-stop。
+pub(crate) fn start_local() {
+    let state = if platform::start_performance_counters() {
+        AVAILABLE
+    } else {
+        UNAVAILABLE
+    };
+    if let Err(published) =
+        AVAILABILITY.compare_exchange(UNPROBED, state, Ordering::AcqRel, Ordering::Acquire)
+    {
+        assert_eq!(published, state, "performance counters differ between CPUs");
+    }
+}
+
+/// @description 为调用 Thread 打开一个按 enable 区间累计的硬件事件视图。
+///
+/// @param counter 要读取的 Thread 硬件事件。
+/// @param enabled false 对应 `attr.disabled`，计数从首次 enable 开始。
+/// @return fs OFD 可持有的事件后端。
+/// @errors counter 不可用返回 `Unavailable`；绑定或事件分配失败返回 `OutOfMemory`。
+pub(crate) fn open_thread_counter(
+    counter: HardwareCounter,
+    enabled: bool,
+) -> Result<Arc<dyn PerfEvent>, HardwareCounterError> {
+    if AVAILABILITY.load(Ordering::Acquire) != AVAILABLE
+        || (counter == HardwareCounter::Instructions && !performance::COUNTS_INSTRUCTIONS)
+    {
+        return Err(HardwareCounterError::Unavailable);
+    }
+    let task = current_task().expect("perf counter open requires current task");
+    let fresh = Arc::try_new(ThreadCounters {
+        totals: [AtomicU64::new(0), AtomicU64::new(0)],
+    })
+    .map_err(|_| HardwareCounterError::OutOfMemory)?;
+    // 首个事件才安装绑定，未使用 perf 的 Thread 不在 context switch 中读取 counter。
+    let counters = task.scheduling.policy.lock().bind_counters(fresh);
+    let base = counters.total(counter);
+    let event = Arc::try_new(CounterEvent {
+        counters,
+        counter,
+        state: Mutex::new(EventState {
+            enabled,
+            base,
+            accumulated: 0,
+        }),
+    })
+    .map_err(|_| HardwareCounterError::OutOfMemory)?;
+    Ok(event)
+}
+
+/// 把调用 Thread 尚未提交的 slice 计入 total，使同一 Thread 的 read/ioctl 观察到实时值。
+fn flush_current() {
+    if let Some(task) = current_task() {
+        task.scheduling.policy.lock().checkpoint_counters();
+    }
+}
@@ -26,6 +26,7 @@ impl LoadedExecutable {
     /// @description 从最终 ELF plan 与 rewritten argv transactionally 构造新地址空间。
     ///
     /// @param environments 已从 userspace 完整复制且不含 NUL 的 envp strings。
+    /// @param deny_write_exec 旧地址空间的 Linux MDWE policy；新地址空间继承。
     /// @return 新 MemorySet、initial sp 与 entry point。
     /// @errors ELF mapping、initial stack、source I/O 或资源失败。
     pub(super) fn build_address_space(
@@ -34,8 +35,9 @@ impl LoadedExecutable {
         stack_limit: u64,
         address_space_limit: u64,
         data_limit: u64,
+        deny_write_exec: bool,
     ) -> Result<(MemorySet, usize, usize), ElfLoadError> {
-        MemorySet::from_elf(
+        let (mut memory_set, user_sp, entry_point) = MemorySet::from_elf(
             &self.image,
             &self.arguments,
             environments,
@@ -43,7 +45,9 @@ impl LoadedExecutable {
             stack_limit,
             address_space_limit,
             data_limit,
-        )
+        )?;
+        memory_set.inherit_deny_write_exec(deny_write_exec)?;
+        Ok((memory_set, user_sp, entry_point))
     }
 
     /// @description 返回用户传给 execve 的原始 pathname，用于 AT_EXECFN 与 process comm。
@@ -89,7 +93,7 @@ impl ExecutableSource for InodeExecutableSource {
 
     fn read_exact_at(&self, offset: usize, buffer: &mut [u8]) -> Result<(), ()> {
         self.file
-            .read(offset as u64, buffer)
+            .read(offset as u64, buffer, None)
             .ok()
             .filter(|read| read.bytes == buffer.len())
             .map(|_| ())
@@ -3,6 +3,7 @@ use alloc::{sync::Arc, vec::Vec};
 use crate::fs::{AccessIdentity, Console, vfs};
 use crate::task::pid::ProcessId;
 
+mod hardware_counter;
 mod loader;
 mod memory_barrier;
 mod model;
@@ -11,6 +12,10 @@ mod processor;
 mod scheduler;
 mod task_manager;
 
+pub(crate) use hardware_counter::{
+    HardwareCounter, HardwareCounterError, open_thread_counter,
+    start_local as start_hardware_counters,
+};
 pub(crate) use loader::{EXEC_ARGUMENT_BYTES_LIMIT, ProgramLoadError, load_executable};
 pub(crate) use memory_barrier::{
     complete_pending as complete_pending_memory_barrier, register_private_memory_barrier,
@@ -35,8 +40,6 @@ pub(crate) use task_manager::timer_queue::{
 };
 pub(crate) use task_manager::*;
 
-const INIT_PROC_NAME: &[u8] = b"/bin/init";
-
 /// @description 在任何启动期 external/software trap 前构造 membarrier per-CPU state。
 ///
 /// @return 无返回值。
@@ -59,6 +62,7 @@ pub(crate) fn init(
     kernel_trap_handler: crate::arch::trap::UserTrapEntry,
     kernel_trap_return: crate::arch::context::KernelResume,
     console: Arc<dyn Console>,
+    init_path: &[u8],
 ) {
     // Bootstrap executable loading can issue block I/O before a current task exists. Build the
     // processor topology first so the installed wait-target factory can safely observe `None`;
@@ -68,14 +72,14 @@ pub(crate) fn init(
     task_manager::task_mutex_wait::initialize();
     install_advisory_lock_notifier();
     let mut path = Vec::new();
-    path.try_reserve_exact(INIT_PROC_NAME.len())
+    path.try_reserve_exact(init_path.len())
         .expect("failed to allocate init pathname");
-    path.extend_from_slice(INIT_PROC_NAME);
+    path.extend_from_slice(init_path);
     let mut argv0 = Vec::new();
     argv0
-        .try_reserve_exact(INIT_PROC_NAME.len())
+        .try_reserve_exact(init_path.len())
         .expect("failed to allocate init argv[0]");
-    argv0.extend_from_slice(INIT_PROC_NAME);
+    argv0.extend_from_slice(init_path);
     let argument_bytes = 3 * core::mem::size_of::<usize>() + argv0.len() + 1;
     let mut arguments = Vec::new();
     arguments
@@ -90,7 +94,13 @@ pub(crate) fn init(
         argument_bytes,
         &AccessIdentity::root(),
     )
-    .expect("failed to load /bin/init");
+    .unwrap_or_else(|error| {
+        panic!(
+            "requested init {:?} failed: {:?}",
+            core::str::from_utf8(init_path).unwrap_or("<non-UTF-8>"),
+            error
+        )
+    });
     let init_proc = TaskControlBlock::new_with_pid(
         &loaded,
         ProcessId::init(),
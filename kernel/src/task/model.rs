@@ -23,7 +23,7 @@ use spin::Mutex;
 
 use crate::{
     arch::context::{KernelContext, UserContext},
-    fs::{Console, FileDescriptorTable, OpenedFile, Terminal, vfs},
+    fs::{Console, FileDescriptorTable, Terminal},
     memory::{
         DeviceMappingSource, ElfLoadError, FileMappingSource, FutexKey, KERNEL_SPACE, KernelStack,
         MapPermission, MappingResourceLimits, MemoryError, MemoryMappingOwner, MemoryReclaimer,
@@ -44,6 +44,7 @@ pub(crate) use file_descriptions::ReceivedFdTransaction;
 use io_accounting::IoAccounting;
 pub(crate) use io_accounting::IoStatistics;
 use process_exec::{process_name, try_elf_arc};
+use process_resources::ProcessFs;
 pub(in crate::task) use resource_limits::RLIMIT_NICE;
 use resource_limits::ResourceLimits;
 pub(crate) use resource_limits::{
@@ -102,7 +103,7 @@ pub(crate) enum StopResume {
 }
 #[derive(Debug)]
 struct ThreadContext {
-    tid: usize,
+    tid: ProcessId,
     // OWNER: ThreadContext 独占线程创建时刻；若复用 Process 创建时刻，后建 pthread 的
     // `/proc/<tgid>/task/<tid>/stat` starttime 会错误回退到主线程启动时间。
     start_time_us: u64,
@@ -160,8 +161,9 @@ struct Process {
     // 初始共享 parent Arc，exec 只替换 child Process 的 handle。若直接缓存第二份 mm pointer，
     // exec detach 会让 syscall、trap 与 futex 在不同地址空间继续运行。
     address_space: Mutex<Arc<AddressSpace>>,
-    // OWNER: Process 独占 VFS opened cwd identity；只保存 inode 会使 rename 后的 getcwd 与相对 lookup 分裂。
-    cwd: Mutex<Arc<OpenedFile>>,
+    // OWNER: Process 独占 VFS opened cwd 与 chroot root identity；只保存 inode 会使 rename 后的 getcwd
+    // 与相对 lookup 分裂，缺失 root 时绝对路径与 `..` 可逃出 chroot sandbox。
+    fs: Mutex<ProcessFs>,
     files: Mutex<FileDescriptorTable>,
     // OWNER: Process 的单锁凭据集供 thread 共享；拆分字段会让 setres* 暴露中间身份。
     credentials: Mutex<Credentials>,
@@ -205,12 +207,12 @@ impl TaskControlBlock {
         let address_space_limit = resource_limits.get(RLIMIT_AS).unwrap().soft;
         let data_limit = resource_limits.get(RLIMIT_DATA).unwrap().soft;
         let (memory_set, user_sp, entry_point) =
-            loaded.build_address_space(&[], stack_limit, address_space_limit, data_limit)?;
+            loaded.build_address_space(&[], stack_limit, address_space_limit, data_limit, false)?;
         let kernel_stack = KernelStack::try_new()?;
         let kernel_stack_top = kernel_stack.get_top();
         let context_binding =
             ContextBinding::for_placement(kernel_stack.user_context_address(), TRAP_CONTEXT);
-        let tid = pid.0;
+        let tid = pid;
         let terminal = Terminal::new(console, crate::fs::DeviceKind::Console)
             .map_err(|()| ElfLoadError::OutOfMemory)?;
         let address_space = AddressSpace::new(memory_set)?;
@@ -228,7 +230,7 @@ impl TaskControlBlock {
             comm: Mutex::new(process_name(loaded.execfn())?),
             start_time_us,
             address_space: Mutex::new(address_space),
-            cwd: Mutex::new(vfs().open_file(b"/").expect("mounted root must resolve")),
+            fs: Mutex::new(ProcessFs::namespace_root()),
             files: Mutex::new(
                 FileDescriptorTable::with_terminal(terminal.clone())
                     .map_err(|()| ElfLoadError::OutOfMemory)?,
@@ -285,14 +287,14 @@ impl TaskControlBlock {
 
     /// @description 在当前 Process 内创建共享资源的独立 Thread 执行实体。
     ///
-    /// @param tid TaskManager 分配的全局唯一 TID。
+    /// @param thread_id TaskManager 分配的全局唯一 TID 与可选 PID namespace 编号。
     /// @param user_stack child 首次返回用户态使用的栈顶。
     /// @param tls 写入 child `tp(x4)` 的 TLS pointer。
     /// @param clear_child_tid thread exit 时清零并 futex-wake 的用户地址。
     /// @return 成功返回 New Thread；任何映射失败都不发布 scheduler membership。
     pub(super) fn clone_thread(
         &self,
-        tid: usize,
+        thread_id: ProcessId,
         user_stack: usize,
         tls: usize,
         clear_child_tid: Option<usize>,
@@ -300,6 +302,7 @@ impl TaskControlBlock {
         if user_stack == 0 || user_stack & 0xf != 0 {
             return Err(MemoryError::InvalidRange);
         }
+        let tid = thread_id.global;
         let kernel_stack = KernelStack::try_new()?;
         let kernel_stack_top = kernel_stack.get_top();
         let address_space = self.process.address_space();
@@ -326,7 +329,7 @@ impl TaskControlBlock {
         let child = Self {
             process: self.process.clone(),
             thread: ThreadContext {
-                tid,
+                tid: thread_id,
                 start_time_us: get_time_us(),
                 kernel_stack,
                 user_context,
@@ -364,7 +367,7 @@ impl TaskControlBlock {
 
     pub(crate) fn set_clear_child_tid(&self, address: usize) -> usize {
         *self.thread.clear_child_tid.lock() = (address != 0).then_some(address);
-        self.tid()
+        self.thread.tid.own_view()
     }
 
     /// @description 查询或替换 calling Thread 的 Linux parent-death signal。
@@ -499,20 +502,6 @@ impl TaskControlBlock {
         *mask & (1u64 << (signal - 1)) == 0 && !signal_is_ignored(signal, state.actions[signal])
     }
 
-    /// @description 判断 global init 是否应在 generation 阶段丢弃默认 disposition signal。
-    ///
-    /// @param signal 已校验的 Linux signal number。
-    /// @return PID 1 对不可捕获 signal，或对当前未屏蔽的默认 action 返回 true。
-    pub(super) fn ignores_generated_signal_as_init(&self, signal: usize) -> bool {
-        if self.tgid() != crate::task::pid::INIT_PID {
-            return false;
-        }
-        let mask = self.thread.signal_mask.lock();
-        let state = self.process.signal_state.lock();
-        state.actions[signal].handler == 0
-            && (matches!(signal, 9 | 19) || *mask & (1u64 << (signal - 1)) == 0)
-    }
-
     /// @description 原子检查给定 signal set 是否含 pending signal，并在成立时执行短操作。
     ///
     /// @param mask `rt_sigtimedwait` 正在等待的 signal set。
@@ -288,6 +288,14 @@ impl AddressSpace {
             .protect_user_mapping(address, length, permission)
     }
 
+    pub(super) fn deny_write_exec(&self, enable: bool) -> Result<bool, MemoryError> {
+        Ok(self
+            .memory_set
+            .lock()
+            .map_err(|_| MemoryError::OutOfMemory)?
+            .deny_write_exec(enable))
+    }
+
     pub(super) fn advise_user_mapping(
         &self,
         address: usize,
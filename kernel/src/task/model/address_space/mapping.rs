@@ -141,6 +141,14 @@ impl TaskControlBlock {
             .protect_user_mapping(address, length, permission)
     }
 
+    /// @description 查询或开启 calling Process 地址空间的 Linux `PR_SET_MDWE` policy。
+    ///
+    /// @param enable 为 true 时开启；policy 经 fork 与 exec 继承，开启后不可撤销。
+    /// @return 调用后 policy 是否生效。
+    pub(crate) fn deny_write_exec(&self, enable: bool) -> Result<bool, MemoryError> {
+        self.process.address_space().deny_write_exec(enable)
+    }
+
     pub(crate) fn advise_user_mapping(
         &self,
         address: usize,
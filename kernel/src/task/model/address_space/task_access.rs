@@ -176,6 +176,16 @@ impl TaskControlBlock {
         self.process.address_space().process_arguments()
     }
 
+    /// @description 读取 OOM victim selection 使用的 Process resident page 数，不分配 comm snapshot。
+    /// @errors task-mutex waiter storage OOM 时返回错误。
+    pub(in crate::task) fn resident_pages(&self) -> Result<usize, ()> {
+        self.process
+            .address_space()
+            .page_statistics()
+            .map(|(_, resident_pages, ..)| resident_pages)
+            .map_err(|_| ())
+    }
+
     /// @description 从 Process 与 AddressSpace owner 取得一次 procfs 统计快照。
     /// @errors comm 或 task-mutex waiter storage OOM 时返回错误。
     pub(in crate::task) fn process_statistics(&self) -> Result<ProcessStatistics, ()> {
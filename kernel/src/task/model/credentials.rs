@@ -23,11 +23,12 @@ pub(super) struct Credentials {
 }
 
 impl TaskControlBlock {
-    /// @description 取得一次权限判断身份快照。
+    /// @description 取得一次权限判断与 pathname lookup 身份快照。
     /// @param effective true 选择 effective ID，false 选择 real ID。
-    /// @return 包含 supplementary groups 的独立快照。
+    /// @return 包含 supplementary groups 与 Process chroot 目录的独立快照。
     pub(crate) fn access_identity(&self, effective: bool) -> AccessIdentity {
-        self.process.credentials.lock().access_identity(effective)
+        let identity = self.process.credentials.lock().access_identity(effective);
+        identity.with_root(self.root_directory())
     }
 
     /// @description 读取 real/effective UID 或 GID。
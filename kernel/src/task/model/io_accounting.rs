@@ -83,6 +83,16 @@ impl TaskControlBlock {
         self.process.io_accounting.account_write_result(result);
     }
 
+    /// @description 记录一次 kernel 内 copy syscall（`sendfile`、`splice`）的 logical byte 与 syscall 计数。
+    ///
+    /// @param result Linux byte result 或 operation errno。
+    /// @return 无返回值；与 Linux `do_sendfile` 相同，一次传输同时推进一次 syscr/syscw，
+    /// 并把同一 byte 数各计入一次 rchar 与 wchar；storage 字节只由实际 fill/提交路径累计。
+    pub(crate) fn account_copy_result(&self, result: isize) {
+        self.account_read_result(result);
+        self.account_write_result(result);
+    }
+
     /// @description 记录本次 regular read 实际触发 cache-miss storage fill 的字节数。
     ///
     /// @param bytes filesystem storage owner 成功读取的字节数。
@@ -29,7 +29,7 @@ impl TaskControlBlock {
     ) -> Result<Self, MemoryError> {
         // share_user_memory 只区分 fork COW 与 vfork CLONE_VM contract；缺失该选择会让
         // posix_spawn child 的 stack/errno-pipe 操作脱离 parent mm，破坏标准 handoff。
-        let tid = pid.0;
+        let tid = pid.global;
         // 1. 先构造地址空间和所有可能失败的 process-owned 资源，发布前不修改 process graph。
         let parent_address_space = self.process.address_space();
         let address_space = if share_user_memory {
@@ -42,7 +42,7 @@ impl TaskControlBlock {
                 .try_clone_for_fork()?;
             AddressSpace::new(memory_set)?
         };
-        let cwd = self.process.cwd.lock().clone();
+        let fs = self.process.fs.lock().clone();
         let files = self
             .process
             .files
@@ -78,7 +78,7 @@ impl TaskControlBlock {
             comm: Mutex::new(comm),
             start_time_us,
             address_space: Mutex::new(address_space.clone()),
-            cwd: Mutex::new(cwd),
+            fs: Mutex::new(fs),
             files: Mutex::new(files),
             credentials: Mutex::new(credentials),
             resource_limits: Mutex::new(resource_limits),
@@ -119,7 +119,7 @@ impl TaskControlBlock {
         let child = Self {
             process,
             thread: ThreadContext {
-                tid,
+                tid: pid,
                 start_time_us,
                 kernel_stack,
                 user_context,
@@ -35,8 +35,14 @@ impl TaskControlBlock {
         let stack_limit = self.resource_limit(RLIMIT_STACK).unwrap().soft;
         let address_space_limit = self.resource_limit(RLIMIT_AS).unwrap().soft;
         let data_limit = self.resource_limit(RLIMIT_DATA).unwrap().soft;
-        let (new_memory_set, user_sp, entry_point) =
-            loaded.build_address_space(envs, stack_limit, address_space_limit, data_limit)?;
+        let deny_write_exec = self.process.address_space().deny_write_exec(false)?;
+        let (new_memory_set, user_sp, entry_point) = loaded.build_address_space(
+            envs,
+            stack_limit,
+            address_space_limit,
+            data_limit,
+            deny_write_exec,
+        )?;
         let new_address_space = AddressSpace::new(new_memory_set)?;
         let new_comm = process_name(loaded.execfn())?;
         let credential_metadata = loaded.credential_metadata();
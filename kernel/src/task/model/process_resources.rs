@@ -1,20 +1,51 @@
 use alloc::sync::Arc;
 
 use super::TaskControlBlock;
-use crate::fs::{OpenedFile, Terminal};
+use crate::fs::{OpenedFile, Terminal, vfs};
+use crate::task::pid::ProcessId;
+
+/// @description Linux `fs_struct` 投影：Process 的 cwd 与可选 chroot root；fork 复制、exec 保留。
+#[derive(Clone)]
+pub(super) struct ProcessFs {
+    cwd: Arc<OpenedFile>,
+    root: Option<Arc<OpenedFile>>,
+}
+
+impl ProcessFs {
+    /// @description 初始 Process 的 cwd 与 root 都是 VFS namespace root。
+    pub(super) fn namespace_root() -> Self {
+        Self {
+            cwd: vfs().open_file(b"/").expect("mounted root must resolve"),
+            root: None,
+        }
+    }
+}
 
 impl TaskControlBlock {
     /// @description 复制当前 Process 工作目录的唯一 inode identity。
     /// @return 当前目录的共享 inode。
     pub(crate) fn working_directory(&self) -> Arc<OpenedFile> {
-        self.process.cwd.lock().clone()
+        self.process.fs.lock().cwd.clone()
     }
 
     /// @description 原子替换当前 Process 的工作目录 identity。
     /// @param opened 已由 VFS 证明为目录的 opened entry。
     /// @return 无返回值。
     pub(crate) fn set_working_directory(&self, opened: Arc<OpenedFile>) {
-        *self.process.cwd.lock() = opened;
+        self.process.fs.lock().cwd = opened;
+    }
+
+    /// @description 复制当前 Process 的 chroot 目录。
+    /// @return None 表示 Process 使用 VFS namespace root。
+    pub(crate) fn root_directory(&self) -> Option<Arc<OpenedFile>> {
+        self.process.fs.lock().root.clone()
+    }
+
+    /// @description 原子替换当前 Process 的 chroot 目录；cwd 保持不变。
+    /// @param opened 已由 VFS 证明为目录的 opened entry。
+    /// @return 无返回值。
+    pub(crate) fn set_root_directory(&self, opened: Arc<OpenedFile>) {
+        self.process.fs.lock().root = Some(opened);
     }
 
     /// @description 返回当前 Process 可继承的 controlling Terminal identity。
@@ -40,12 +71,46 @@ impl TaskControlBlock {
     /// @description 返回当前 Process/thread group ID。
     /// @return TGID；Linux getpid 与 process-directed lookup 使用该值。
     pub(crate) fn tgid(&self) -> usize {
-        self.process.tgid.0
+        self.process.tgid.global
     }
 
     /// @description 返回当前 Thread ID。
-    /// @return 与 TGID 数值独立、由 ThreadContext 唯一拥有的 TID。
+    /// @return 与 TGID 数值独立、由 ThreadContext 唯一拥有的全局 TID。
     pub(crate) fn tid(&self) -> usize {
-        self.thread.tid
+        self.thread.tid.global
+    }
+
+    /// @description 返回 Process 所属子 PID namespace。
+    /// @return namespace init 的全局 TGID；全局 namespace 成员返回 None。
+    pub(crate) fn pid_namespace(&self) -> Option<usize> {
+        self.process.tgid.namespace.map(|pid| pid.namespace)
+    }
+
+    /// @description 判断 Process 是否为 global init 或子 PID namespace init。
+    /// @return child reaper 返回 true；其默认 disposition signal 受 Linux SIGNAL_UNKILLABLE 保护。
+    pub(crate) fn is_child_reaper(&self) -> bool {
+        self.process.tgid.own_view() == crate::task::pid::INIT_PID
+    }
+
+    /// @description 返回 observer 所在 PID namespace 看到的本 Process TGID。
+    /// @param observer 执行 syscall 的 Task。
+    /// @return 全局 observer 看到全局 TGID，同 namespace observer 看到本地编号，否则为 0。
+    pub(crate) fn tgid_seen_by(&self, observer: &TaskControlBlock) -> usize {
+        pid_seen_by(self.process.tgid, observer.pid_namespace())
+    }
+
+    /// @description 返回 observer 所在 PID namespace 看到的本 Thread TID。
+    /// @param observer 执行 syscall 的 Task。
+    /// @return 全局 observer 看到全局 TID，同 namespace observer 看到本地编号，否则为 0。
+    pub(crate) fn tid_seen_by(&self, observer: &TaskControlBlock) -> usize {
+        pid_seen_by(self.thread.tid, observer.pid_namespace())
+    }
+}
+
+fn pid_seen_by(pid: ProcessId, view: Option<usize>) -> usize {
+    match (view, pid.namespace) {
+        (None, _) => pid.global,
+        (Some(view), Some(namespace)) if namespace.namespace == view => namespace.local,
+        (Some(_), _) => 0,
     }
 }
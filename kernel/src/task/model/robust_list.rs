@@ -74,7 +74,7 @@ impl TaskControlBlock {
                 address_space.wake_robust_waiter(address, fault_limits);
                 return Ok(());
             }
-            if owner != self.tid() as u32 {
+            if owner != self.thread.tid.own_view() as u32 {
                 return Ok(());
             }
             let replacement = observed & FUTEX_WAITERS | FUTEX_OWNER_DIED;
@@ -1,7 +1,10 @@
 use super::*;
 use crate::{
     cpu::{self, CpuId, CpuSet},
-    task::processor::account_current_cpu_runtime,
+    task::{
+        hardware_counter::{CounterBinding, ThreadCounters},
+        processor::account_current_cpu_runtime,
+    },
 };
 use core::{num::NonZeroU64, sync::atomic::Ordering};
 
@@ -120,6 +123,8 @@ pub(crate) struct Sched {
     total_runtime_us: u64,
     /// 所属 Process 的唯一 CPU runtime counter。
     process_runtime_us: Arc<AtomicU64>,
+    /// 首个 perf counter 打开后才存在；每个 runtime slice 同步提交硬件事件增量。
+    counter_binding: Option<CounterBinding>,
 }
 
 /// @description 调度器唯一拥有和解释的 Thread 运行状态。
@@ -339,6 +344,7 @@ impl Sched {
             io_priority: 0,
             total_runtime_us: 0,
             process_runtime_us,
+            counter_binding: None,
         }
     }
 
@@ -358,6 +364,7 @@ impl Sched {
             io_priority: self.io_priority,
             total_runtime_us: 0,
             process_runtime_us,
+            counter_binding: None,
         }
     }
 
@@ -424,6 +431,9 @@ impl Sched {
             NICE_TO_WEIGHT_RECIPROCAL.len() as u8,
             "task dispatched with an active priority snapshot"
         );
+        if let Some(binding) = &mut self.counter_binding {
+            binding.begin();
+        }
     }
 
     /// @description 恰好一次结束 active CPU slice，并累计 Thread、Process、CPU 与 vruntime。
@@ -441,6 +451,9 @@ impl Sched {
         let priority = self.checked_active_priority();
         self.active_priority = NICE_TO_WEIGHT_RECIPROCAL.len() as u8;
         self.commit_runtime(end_time_us.saturating_sub(start_time_us), priority);
+        if let Some(binding) = &mut self.counter_binding {
+            binding.commit(false);
+        }
     }
 
     /// @description 在不结束 active slice 的前提下提交 timer tick 前已消耗的 CPU runtime。
@@ -464,6 +477,32 @@ impl Sched {
         let priority = self.checked_active_priority();
         // 3. 所有累计 owner 一次推进，避免恢复 tick context switch 才能刷新 /proc/stat。
         self.commit_runtime(checkpoint_us.saturating_sub(start_time_us), priority);
+        self.checkpoint_counters();
+    }
+
+    /// @description 为 running Thread 安装或复用唯一硬件 counter 绑定。
+    /// @param fresh 尚无绑定时安装的 total owner；已有绑定时丢弃。
+    /// @return 已提交 active slice 的 Thread total owner。
+    /// @panics Thread 未处于 active slice 时 panic。
+    pub(in crate::task) fn bind_counters(
+        &mut self,
+        fresh: Arc<ThreadCounters>,
+    ) -> Arc<ThreadCounters> {
+        assert!(
+            self.active_runtime_start.is_some(),
+            "perf counter bound outside a running slice"
+        );
+        self.checkpoint_counters();
+        self.counter_binding
+            .get_or_insert_with(|| CounterBinding::running(fresh))
+            .counters()
+    }
+
+    /// @description 提交已绑定 running Thread 的硬件事件增量，slice 继续计量。
+    pub(in crate::task) fn checkpoint_counters(&mut self) {
+        if let Some(binding) = &mut self.counter_binding {
+            binding.commit(true);
+        }
     }
 
     fn checked_active_priority(&self) -> usize {
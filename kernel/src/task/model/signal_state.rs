@@ -195,6 +195,30 @@ impl PendingSignal {
         bytes
     }
 
+    /// @description 把 kill/tkill/SIGCHLD 来源 PID 改写为接收方 PID namespace 视角。
+    ///
+    /// @param translate 全局 TGID 到接收方可见编号的映射；不可见时返回 0。
+    /// @return 改写后的来源；kernel、timer 与 fault 来源不携带 PID，原样返回。
+    pub(crate) fn in_pid_view(self, translate: impl FnOnce(usize) -> usize) -> Self {
+        if self.fault_layout || !matches!(self.code, -6 | 0..=6) {
+            return self;
+        }
+        Self {
+            pid: translate(self.pid as usize) as i32,
+            ..self
+        }
+    }
+
+    /// @description 标记来自祖先 PID namespace、必须穿透 namespace init 默认豁免的信号。
+    ///
+    /// @return 携带 forced 标记的同一来源。
+    pub(crate) fn forced_from_ancestor(self) -> Self {
+        Self {
+            forced: true,
+            ..self
+        }
+    }
+
     fn is_forced_fault(self) -> bool {
         self.fault_layout && self.forced
     }
@@ -359,6 +383,25 @@ impl TaskControlBlock {
         state.pending.queue(signal, info);
         Ok(true)
     }
+
+    /// @description 判断 child reaper 是否应在 generation 阶段丢弃默认 disposition signal。
+    ///
+    /// @param signal 已校验的 Linux signal number。
+    /// @param from_ancestor sender 位于目标 PID namespace 之外；仅 SIGKILL/SIGSTOP 因此穿透。
+    /// @return global/namespace init 对不可捕获 signal，或对当前未屏蔽的默认 action 返回 true。
+    pub(in crate::task) fn ignores_generated_signal_as_init(
+        &self,
+        signal: usize,
+        from_ancestor: bool,
+    ) -> bool {
+        if !self.is_child_reaper() || from_ancestor && matches!(signal, 9 | 19) {
+            return false;
+        }
+        let mask = self.thread.signal_mask.lock();
+        let state = self.process.signal_state.lock();
+        state.actions[signal].handler == 0
+            && (matches!(signal, 9 | 19) || *mask & (1u64 << (signal - 1)) == 0)
+    }
 }
 
 fn signal_conflicting_mask(signal: usize) -> u64 {
@@ -406,18 +449,19 @@ impl TaskControlBlock {
                     .map(|(signal, info)| (signal, info, state.actions[signal]))
             };
             let Some((signal, signal_info, action)) = selected else {
-                self.thread.syscall_restart.lock().take();
+                // 没有 handler 运行时 Linux 总是重放 ERESTARTSYS：ignored、PID 1 默认 disposition
+                // 或被同组 thread 先取走的 signal 都不能让 syscall 以 EINTR 泄漏到用户态。
+                self.thread
+                    .user_context
+                    .with(|context| self.apply_syscall_restart(context));
                 return Ok(SignalDelivery::None);
             };
             if signal_is_ignored(signal, action) {
                 continue;
             }
-            // Linux 的 SIGNAL_UNKILLABLE 语义只压制 PID 1 的异步默认 disposition；显式
-            // handler 仍需执行，force_sig_info 发布的同步 fault 必须绕过该豁免。
-            if self.tgid() == crate::task::pid::INIT_PID
-                && action.handler == 0
-                && !signal_info.forced
-            {
+            // Linux 的 SIGNAL_UNKILLABLE 语义只压制 child reaper 的异步默认 disposition；显式
+            // handler 仍需执行，同步 fault 与祖先 namespace 的 SIGKILL/SIGSTOP 必须绕过该豁免。
+            if self.is_child_reaper() && action.handler == 0 && !signal_info.forced {
                 continue;
             }
             if signal_is_default_stop(signal, action) {
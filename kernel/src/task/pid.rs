@@ -2,22 +2,49 @@ pub(crate) const INIT_PID: usize = 1;
 /// Linux futex owner word reserves the top two bits for WAITERS/OWNER_DIED.
 pub(super) const PID_MAX: usize = 0x3fff_ffff;
 
-#[derive(Debug)]
-pub(super) struct ProcessId(pub(super) usize);
+/// @description 子 PID namespace 成员在该 namespace 内的编号。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct NamespacePid {
+    /// namespace identity，即其 init Process 的全局 TGID；全局 PID 永不复用，因此 identity 唯一。
+    pub(super) namespace: usize,
+    /// namespace 内从 1 开始单调分配的编号。
+    pub(super) local: usize,
+}
+
+/// @description Task 的 TGID/TID 编号：全局值与可选的子 PID namespace 本地值。
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ProcessId {
+    pub(super) global: usize,
+    pub(super) namespace: Option<NamespacePid>,
+}
 
 impl ProcessId {
     /// @description 构造当前唯一 init process 的 TGID owner。
     ///
-    /// @return 数值为 1 的 ProcessId；新增创建 ABI 前不得增加任意整数构造入口。
+    /// @return 数值为 1 的全局 ProcessId；新增创建 ABI 前不得增加任意整数构造入口。
     pub(crate) const fn init() -> Self {
-        Self(INIT_PID)
+        Self {
+            global: INIT_PID,
+            namespace: None,
+        }
     }
 
-    /// @description 由 TaskManager 的单一 PID allocator 构造动态 TGID。
+    /// @description 由 TaskManager 的单一 PID allocator 构造动态 TGID/TID。
     ///
-    /// @param value 大于 INIT_PID 且从未分配过的数值。
+    /// @param global 大于 INIT_PID 且从未分配过的数值。
+    /// @param namespace 子 PID namespace 成员由同一次 allocator 事务分配的本地编号。
     /// @return 新 ProcessId；allocator 违反单调唯一性时由调用方 fail-stop。
-    pub(super) const fn allocated(value: usize) -> Self {
-        Self(value)
+    pub(super) const fn allocated(global: usize, namespace: Option<NamespacePid>) -> Self {
+        Self { global, namespace }
+    }
+
+    /// @description 返回 Task 在自身所属 PID namespace 中可见的编号。
+    ///
+    /// @return 子 namespace 成员返回本地编号，全局 namespace 成员返回全局编号。
+    pub(super) const fn own_view(self) -> usize {
+        match self.namespace {
+            Some(namespace) => namespace.local,
+            None => self.global,
+        }
     }
 }
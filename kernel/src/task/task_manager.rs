@@ -8,7 +8,7 @@ use crate::{
     task::{
         PendingSignal, Processor, RunState, StopResume, TaskControlBlock, WaitMembership,
         WaitResult,
-        pid::{INIT_PID, PID_MAX, ProcessId},
+        pid::{INIT_PID, PID_MAX},
         processor::{begin_preempt_running_task, enqueue_new_task},
         with_current_processor,
     },
@@ -24,7 +24,10 @@ mod deferred;
 mod futex;
 mod io_wait;
 mod load_average;
+mod out_of_memory;
 mod parent_death;
+mod pid_namespace;
+mod pid_view;
 mod pipe_wait;
 mod policy;
 mod process_exit;
@@ -53,9 +56,14 @@ pub(crate) use deferred::dispatch_pending_deferred_work;
 pub(in crate::task) use futex::futex_wake_with_key;
 pub(crate) use futex::{FutexWaitError, futex_requeue, futex_wait, futex_wake};
 pub(super) use io_wait::initialize_driver_io_wait;
+pub(crate) use out_of_memory::{
+    OutOfMemoryAction, resolve_fault_out_of_memory, wait_for_out_of_memory_victim,
+};
 pub(crate) use parent_death::parent_death_signal;
+pub(crate) use pid_view::{resolve_pid, visible_pid};
 pub(crate) use pipe_wait::{
-    create_notification_endpoints, create_pipe_endpoints, wait_for_pipe, wait_for_pipe_until,
+    create_fifo_pipe, create_notification_endpoints, create_pipe_endpoints, wait_for_pipe,
+    wait_for_pipe_until,
 };
 pub(crate) use policy::{SchedulerNiceSelector, scheduler_nice, scheduler_rr_interval};
 pub(crate) use policy::{
@@ -68,7 +76,7 @@ pub(crate) use process_exit::{
 };
 pub(crate) use process_group::{
     ProcessGroupError, SetProcessGroupError, claim_controlling_terminal, create_session,
-    process_group, session_id, set_process_group, set_terminal_foreground_group,
+    current_session, process_group, session_id, set_process_group, set_terminal_foreground_group,
     terminal_foreground_group,
 };
 pub(in crate::task) use process_group::{current_process_group_is_orphaned, mark_process_exec};
@@ -91,7 +99,7 @@ use vfork::complete_vfork;
 pub(crate) use vfork::{ProcessCloneError, fork_current_process, vfork_current_process};
 use wait_child::take_child_waiters;
 pub(crate) use wait_child::{
-    WaitChildError, consume_child_status, release_child_status, wait_child,
+    WaitChildError, WaitEvents, consume_child_status, release_child_status, wait_child,
 };
 use wait_key::IndexedWaitKind;
 pub(crate) use wait_key::PollWaitKey;
@@ -136,7 +144,12 @@ struct ProcessNode {
     child_events: ChildEvents,
     child_waiters: FallibleMap<usize, Arc<TaskControlBlock>>,
     child_wait_claim: Option<wait_child::ChildWaitClaim>,
+    // Live 时累计已回收 descendant 的 CPU runtime；最后 Thread 退出时并入自身 runtime，
+    // 此后即 wait rusage 与 parent 累计使用的 RUSAGE_BOTH 值。
+    rusage_runtime_us: u64,
     vfork_parent: Option<Arc<TaskControlBlock>>,
+    // 所属子 PID namespace；Exited 后仍保留，wait 回收时据此释放 namespace 计数。
+    pid_namespace: Option<usize>,
 }
 
 struct ProcessGraph {
@@ -148,6 +161,10 @@ struct ProcessGraph {
     exit_group_head: Option<(usize, usize)>,
     exit_effect_heads: [Option<usize>; 2],
     pdeath_head: Option<usize>,
+    // OWNER: graph 独占 fault-time OOM victim 与其退出时限；时限内并发 OOM fault 只等待不再选择。
+    oom_victim: Option<out_of_memory::PublishedVictim>,
+    // OWNER: graph 独占子 PID namespace 编号表；与 nodes 分离会让 kill/wait 翻译与 reap 竞态。
+    pid_namespaces: FallibleMap<usize, pid_namespace::PidNamespace>,
 }
 
 /// @description parent relation、live task 或最小 exit record 的唯一 process graph owner。
@@ -188,6 +205,8 @@ impl TaskManager {
                 exit_group_head: None,
                 exit_effect_heads: [None; 2],
                 pdeath_head: None,
+                oom_victim: None,
+                pid_namespaces: FallibleMap::new(),
             }),
             timers: IrqMutex::new(timer_queue::TimerQueue::new()),
             load_average: load_average::LoadAverage::new(),
@@ -246,7 +265,9 @@ impl TaskManager {
                 child_events: ChildEvents::default(),
                 child_waiters: FallibleMap::new(),
                 child_wait_claim: None,
+                rusage_runtime_us: 0,
                 vfork_parent: None,
+                pid_namespace: None,
             },
         )
         .expect("init process node allocation failed");
@@ -258,24 +279,17 @@ impl TaskManager {
         enqueue_new_task(task);
     }
 
-    fn allocate_pid(&self) -> Option<ProcessId> {
-        let mut graph = self.graph.lock();
-        let pid = graph.next_pid;
-        if pid > PID_MAX {
-            return None;
-        }
-        graph.next_pid = pid + 1;
-        Some(ProcessId::allocated(pid))
-    }
-
     fn publish_thread(
         &self,
         tgid: usize,
         thread: Arc<TaskControlBlock>,
         prepared: VacantEntry<usize, Arc<TaskControlBlock>>,
         thread_index: VacantEntry<usize, ThreadIndex>,
+        pid_namespace: pid_view::NamespacePublication,
     ) {
         let mut graph = self.graph.lock();
+        // 同组 Thread 共享 process-directed pending；namespace 成员 SIGKILL 无需逐 Thread 继承。
+        pid_namespace.publish(&mut graph, thread.tid(), false);
         let node = graph
             .nodes
             .get_mut(&tgid)
@@ -15,7 +15,7 @@ pub(crate) enum SchedulerAffinityError {
 
 /// @description 查询或替换 live Thread affinity，并同步完成已禁止 CPU 的执行 ownership migration。
 ///
-/// @param tid 零选择 calling Thread；正数使用 Linux 全局 TID selector。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID。
 /// @param replacement `None` 只查询；`Some` 提交 userspace logical CPU bitmap。
 /// @return 当前 active topology 上实际生效的 logical CPU bitmap。
 /// @errors 目标不存在返回 `NotFound`；set 权限不足返回 `Permission`；mask 无 active CPU 返回 `Empty`。
@@ -119,6 +119,21 @@ fn wake_expired_tasks(now_ns: u64) {
     }
 }
 
+/// @description 到期 event deadline 被认领后，从两个 deadline owner 重新发布最早项。
+///
+/// @param now_ns 本批次 expiry 之后的 absolute monotonic 纳秒时刻。
+/// @return 无返回值；认领先于扫描，扫描后新发布的 deadline 由各自 publisher 直接合并。
+fn republish_event_deadline(now_ns: u64) {
+    if !crate::timer::take_due_event(now_ns) {
+        return;
+    }
+    let wait = WAIT_REGISTRY.earliest_deadline();
+    let timer = TASK_MANAGER.timers.lock().earliest();
+    if let Some(deadline) = wait.into_iter().chain(timer).min() {
+        crate::timer::request_event(deadline);
+    }
+}
+
 /// @description 仅在 user-return 或 local-IRQ-closed scheduler idle safe point 消费 deferred work。
 ///
 /// kernel software-interrupt handler 不得调用本函数：它可重入持有普通 VirtIO queue、
@@ -136,10 +151,12 @@ pub(crate) fn dispatch_pending_deferred_work() {
         wake_expired_tasks(get_time_ns());
         load_average::update(now_us);
         expire_timers(get_time_ns());
+        republish_event_deadline(get_time_ns());
         request_tick_reschedule();
     } else if work.contains(DeferredWork::TimerBacklog) {
         wake_expired_tasks(get_time_ns());
         expire_timers(get_time_ns());
+        republish_event_deadline(get_time_ns());
     }
     if work.contains(DeferredWork::Console) {
         let input_backlog = process_terminal_input();
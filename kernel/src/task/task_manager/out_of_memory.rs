@@ -0,0 +1,125 @@
+use super::*;
+
+mod victim_policy;
+pub(super) use victim_policy::PublishedVictim;
+use victim_policy::{LargestResident, PendingVictim, pending_victim, recheck_deadline};
+
+/// @description 用户 page fault 物理页耗尽后的唯一处理结论。
+pub(crate) enum OutOfMemoryAction {
+    /// 其他 victim 已收到或正在完成 SIGKILL；caller 经 `wait_for_out_of_memory_victim` 后重试同一 fault。
+    Retry,
+    /// caller 自身是 resident 最大者、不存在可牺牲的其他 Process，或已发布 victim 超出退出时限。
+    KillCurrent,
+}
+
+/// @description 为无 swap 系统的 fault-time OOM 选择 resident 最大的非 init Process 并投递 SIGKILL。
+///
+/// 1. graph 已发布的 victim 仍为 Live 时不重新选择；缺少该 owner 会让同一次内存压力下
+///    并发 fault 的每个 Thread 各杀死一个 Process。victim 超出退出时限后 caller 终止自身。
+/// 2. 按 TGID cursor 每次只 clone 一个代表 Thread，在 graph lock 外读取 resident page 数；
+///    物理页耗尽时 heap 同样可能失败，因此不构造候选 snapshot。统计失败的 Process 被跳过。
+/// 3. 重新持 graph lock 复查 victim 仍 Live 且没有并发发布的 victim，再记录并投递 kernel SIGKILL。
+///
+/// @param current 发生 fault 的 Process TGID。
+/// @return victim 是其他 Process 或已有 victim 正在时限内退出时返回 `Retry`；否则返回 `KillCurrent`。
+pub(crate) fn resolve_fault_out_of_memory(current: usize) -> OutOfMemoryAction {
+    match published_victim(current) {
+        PendingVictim::Reselect => {}
+        PendingVictim::Wait => return OutOfMemoryAction::Retry,
+        PendingVictim::KillCurrent => {
+            warn!(
+                "[OOM] killing faulting process {} (victim is the caller or missed its exit deadline)",
+                current
+            );
+            return OutOfMemoryAction::KillCurrent;
+        }
+    }
+
+    let mut largest = LargestResident::new();
+    let mut cursor = INIT_PID;
+    loop {
+        let next = {
+            let graph = TASK_MANAGER.graph.lock();
+            graph
+                .nodes
+                .iter_after(&cursor)
+                .find_map(|(&tgid, node)| match &node.state {
+                    ProcessState::Live(threads) if node.group_exit.is_none() => {
+                        threads.values().next().map(|thread| (tgid, thread.clone()))
+                    }
+                    _ => None,
+                })
+        };
+        let Some((tgid, representative)) = next else {
+            break;
+        };
+        cursor = tgid;
+        let Ok(resident) = representative.resident_pages() else {
+            continue;
+        };
+        largest.offer(tgid, resident);
+    }
+
+    let Some((victim, resident)) = largest.victim(current) else {
+        warn!(
+            "[OOM] killing faulting process {} (largest resident or sole candidate)",
+            current
+        );
+        return OutOfMemoryAction::KillCurrent;
+    };
+    {
+        let mut graph = TASK_MANAGER.graph.lock();
+        if graph.oom_victim.is_some() || !process_is_live(&graph, victim) {
+            return OutOfMemoryAction::Retry;
+        }
+        graph.oom_victim = Some(PublishedVictim::new(victim, get_time_ns()));
+    }
+    warn!(
+        "[OOM] killing process {} ({} resident pages) for fault in process {}",
+        victim, resident, current
+    );
+    send_kernel_process_signal(victim, 9, PendingSignal::kernel());
+    OutOfMemoryAction::Retry
+}
+
+/// @description 在已发布 victim 退出、退出时限到达或 signal 打断前睡眠。
+///
+/// 返回后 caller 重新执行 faulting 指令：victim 已退出时 fault 通常成功或重新选择，
+/// 时限已到时 `resolve_fault_out_of_memory` 返回 `KillCurrent`，因此等待总长有界。
+pub(crate) fn wait_for_out_of_memory_victim() {
+    loop {
+        let victim = {
+            let graph = TASK_MANAGER.graph.lock();
+            match graph.oom_victim {
+                Some(victim) if process_is_live(&graph, victim.tgid) => victim,
+                _ => return,
+            }
+        };
+        let Some(deadline) = recheck_deadline(victim, get_time_ns()) else {
+            return;
+        };
+        if sleep_until(deadline) == WaitResult::Interrupted {
+            return;
+        }
+    }
+}
+
+/// @description 按已发布 victim 决定本次 fault；victim 已退出时清除记录。
+fn published_victim(current: usize) -> PendingVictim {
+    let now = get_time_ns();
+    let mut graph = TASK_MANAGER.graph.lock();
+    let victim = graph.oom_victim;
+    let live = victim.is_some_and(|victim| process_is_live(&graph, victim.tgid));
+    let decision = pending_victim(current, victim, live, now);
+    if decision == PendingVictim::Reselect {
+        graph.oom_victim = None;
+    }
+    decision
+}
+
+fn process_is_live(graph: &ProcessGraph, tgid: usize) -> bool {
+    graph
+        .nodes
+        .get(&tgid)
+        .is_some_and(|node| matches!(node.state, ProcessState::Live(_)))
+}
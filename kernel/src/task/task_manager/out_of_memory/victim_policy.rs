@@ -0,0 +1,95 @@
+/// victim 收到 SIGKILL 后的退出时限，对应 Linux `OOM_REAPER_DELAY`；超时说明 victim 卡在
+/// 无法释放 resident page 的路径上，继续等待只会让 faulting Thread 无界睡眠。
+pub(super) const VICTIM_EXIT_TIMEOUT_NS: u64 = 2_000_000_000;
+/// faulting Thread 复查 victim 是否退出的间隔；非 child Process 的退出没有可订阅的 wait source。
+pub(super) const VICTIM_RECHECK_NS: u64 = 10_000_000;
+
+/// process graph 发布的唯一 OOM victim 及其退出时限。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PublishedVictim {
+    pub(crate) tgid: usize,
+    pub(crate) deadline_ns: u64,
+}
+
+impl PublishedVictim {
+    /// @description 以 SIGKILL 投递时刻开始计算退出时限。
+    /// @param tgid victim Process TGID。
+    /// @param now_ns 当前 monotonic 纳秒。
+    pub(crate) const fn new(tgid: usize, now_ns: u64) -> Self {
+        Self {
+            tgid,
+            deadline_ns: now_ns.saturating_add(VICTIM_EXIT_TIMEOUT_NS),
+        }
+    }
+}
+
+/// 已发布 victim 对一次 OOM fault 的结论。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PendingVictim {
+    /// 没有仍 Live 的 victim；caller 清除旧记录并重新选择。
+    Reselect,
+    /// 其他 victim 仍在退出时限内；caller 等待它退出后重试 fault。
+    Wait,
+    /// caller 自身就是 victim，或 victim 已超出退出时限。
+    KillCurrent,
+}
+
+/// @description 决定已发布 victim 存在时本次 OOM fault 的动作。
+/// @param current 发生 fault 的 Process TGID。
+/// @param victim graph 记录的 victim。
+/// @param live victim 是否仍为 Live。
+/// @param now_ns 当前 monotonic 纳秒。
+/// @return 超时 victim 保持发布，期间的 OOM fault 终止 caller 自身而不连续杀死其他 Process。
+pub(super) const fn pending_victim(
+    current: usize,
+    victim: Option<PublishedVictim>,
+    live: bool,
+    now_ns: u64,
+) -> PendingVictim {
+    match victim {
+        Some(victim) if live => {
+            if victim.tgid == current || now_ns >= victim.deadline_ns {
+                PendingVictim::KillCurrent
+            } else {
+                PendingVictim::Wait
+            }
+        }
+        _ => PendingVictim::Reselect,
+    }
+}
+
+/// @description 计算 faulting Thread 下一次复查 victim 的 deadline。
+/// @param victim 仍为 Live 的已发布 victim。
+/// @param now_ns 当前 monotonic 纳秒。
+/// @return 退出时限已到时返回 `None`，caller 重新执行 fault 以得到 `KillCurrent`。
+pub(super) fn recheck_deadline(victim: PublishedVictim, now_ns: u64) -> Option<u64> {
+    (now_ns < victim.deadline_ns).then(|| {
+        now_ns
+            .saturating_add(VICTIM_RECHECK_NS)
+            .min(victim.deadline_ns)
+    })
+}
+
+/// resident page 最多的 candidate；相等时保留先扫描到的较小 TGID。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct LargestResident(Option<(usize, usize)>);
+
+impl LargestResident {
+    pub(super) const fn new() -> Self {
+        Self(None)
+    }
+
+    /// @description 记录一个 Process 的 resident page 数。
+    pub(super) fn offer(&mut self, tgid: usize, resident: usize) {
+        if self.0.is_none_or(|(_, largest)| resident > largest) {
+            self.0 = Some((tgid, resident));
+        }
+    }
+
+    /// @description 返回应被 SIGKILL 的其他 Process。
+    /// @param current 发生 fault 的 Process TGID。
+    /// @return caller 自身最大或没有候选时返回 `None`，caller 以 SIGKILL 终止。
+    pub(super) fn victim(self, current: usize) -> Option<(usize, usize)> {
+        self.0.filter(|&(victim, _)| victim != current)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::fallible_tree::{FallibleMap, NodeSlot, OutOfMemory};
+
+/// 单层子 PID namespace 的编号 owner。
+///
+/// local 编号与全局 TGID/TID 一样单调且不复用；与 Linux 释放 `upid` 一致，编号在对应 Task 被回收
+/// 且不再作为 PGID/SID 使用后移出双向表，使僵尸 PID 与 exited leader 的 PGID/SID 仍可被观察。
+#[derive(Debug)]
+pub(super) struct PidNamespace {
+    next_local: usize,
+    // OWNER: namespace 独占 local -> global；缺项会让 kill/wait 的 namespace PID 命中错误 Task。
+    globals: FallibleMap<usize, usize>,
+    // OWNER: namespace 独占 global -> local；缺项会让 getppid/siginfo 把可见成员报告为 0。
+    locals: FallibleMap<usize, usize>,
+    processes: usize,
+    init_exited: bool,
+}
+
+/// 在 graph lock 外为一次 namespace 编号发布预留的两个 map node。
+pub(super) struct PidNamespaceSlots {
+    global: NodeSlot<usize, usize>,
+    local: NodeSlot<usize, usize>,
+}
+
+impl PidNamespaceSlots {
+    /// @description 预留 local/global 双向表各一个 node。
+    /// @return 可在 graph lock 内无失败提交的 slot。
+    /// @errors 任一 node 分配失败时返回 `OutOfMemory`。
+    pub(super) fn try_new() -> Result<Self, OutOfMemory> {
+        Ok(Self {
+            global: FallibleMap::try_reserve_node()?,
+            local: FallibleMap::try_reserve_node()?,
+        })
+    }
+}
+
+impl PidNamespace {
+    /// @description 构造只含 init 编号 1 的 namespace。
+    /// @param init init Process 的全局 TGID，同时作为 namespace identity。
+    /// @param slots init 编号映射的预留 node。
+    /// @return 已计入 init Process 的 namespace。
+    pub(super) fn new(init: usize, slots: PidNamespaceSlots) -> Self {
+        let mut namespace = Self {
+            next_local: 2,
+            globals: FallibleMap::new(),
+            locals: FallibleMap::new(),
+            processes: 1,
+            init_exited: false,
+        };
+        namespace.publish(1, init, slots);
+        namespace
+    }
+
+    /// @description 为新 Process/Thread 分配下一个 namespace 内编号。
+    /// @return 单调递增的 local 编号；每个 local 编号都消耗一个全局 PID，因此受 PID_MAX 间接约束。
+    pub(super) fn allocate(&mut self) -> usize {
+        let local = self.next_local;
+        self.next_local += 1;
+        local
+    }
+
+    /// @description 发布一个已分配编号的双向映射。
+    /// @param local `allocate` 返回的编号。
+    /// @param global 同一 Task 的全局 TGID/TID。
+    /// @param slots graph lock 外预留的 node。
+    pub(super) fn publish(&mut self, local: usize, global: usize, slots: PidNamespaceSlots) {
+        self.globals.commit_vacant(slots.global.fill(local, global));
+        self.locals.commit_vacant(slots.local.fill(global, local));
+    }
+
+    /// @description 撤销一个不再被 Task、process group 或 session 引用的编号映射。
+    /// @param global 已发布编号的全局 TGID/TID。
+    /// @return 映射存在并被移除时返回 true。
+    pub(super) fn release(&mut self, global: usize) -> bool {
+        let Some(local) = self.locals.remove(&global) else {
+            return false;
+        };
+        self.globals
+            .remove(&local)
+            .expect("PID namespace maps diverged");
+        true
+    }
+
+    /// @description 返回当前仍发布的编号映射数。
+    #[cfg(test)]
+    pub(super) fn published(&self) -> usize {
+        self.locals.len()
+    }
+
+    /// @description 把全局编号翻译为 namespace 内编号。
+    /// @return 非成员编号返回 `None`。
+    pub(super) fn local(&self, global: usize) -> Option<usize> {
+        self.locals.get(&global).copied()
+    }
+
+    /// @description 把 namespace 内编号翻译为全局编号。
+    /// @return 未发布编号返回 `None`。
+    pub(super) fn global(&self, local: usize) -> Option<usize> {
+        self.globals.get(&local).copied()
+    }
+
+    /// @description 返回 namespace 内最近一次分配的编号，供 `/proc/loadavg` last_pid 使用。
+    pub(super) fn last_allocated(&self) -> usize {
+        self.next_local - 1
+    }
+
+    /// @description 按全局编号顺序返回 cursor 之后的下一个成员编号。
+    /// @param after 上一次返回的全局编号；从头开始时为 0。
+    pub(super) fn member_after(&self, after: usize) -> Option<usize> {
+        self.locals.successor(&after).map(|(&global, _)| global)
+    }
+
+    /// @description 记录一个新 Process 加入 namespace。
+    pub(super) fn add_process(&mut self) {
+        self.processes += 1;
+    }
+
+    /// @description 记录一个 namespace Process 被 wait 回收。
+    /// @return 最后一个 Process 被回收、namespace 可以销毁时返回 true。
+    pub(super) fn remove_process(&mut self) -> bool {
+        self.processes -= 1;
+        self.processes == 0
+    }
+
+    /// @description 标记 namespace init 已退出；此后发布的成员在 activation 前即收到 SIGKILL。
+    pub(super) fn mark_init_exited(&mut self) {
+        self.init_exited = true;
+    }
+
+    /// @description 判断 namespace init 是否已退出。
+    pub(super) fn init_exited(&self) -> bool {
+        self.init_exited
+    }
+}
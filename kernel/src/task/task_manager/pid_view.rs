@@ -0,0 +1,312 @@
+use super::*;
+use crate::{
+    fallible_tree::OutOfMemory,
+    task::pid::{NamespacePid, ProcessId},
+};
+use pid_namespace::{PidNamespace, PidNamespaceSlots};
+
+/// fork/clone 在 graph lock 外为新 Task 的 PID namespace 编号预留的 node。
+pub(super) enum NamespacePublication {
+    Global,
+    Member(PidNamespaceSlots, NamespacePid),
+    New(
+        PidNamespaceSlots,
+        crate::fallible_tree::NodeSlot<usize, PidNamespace>,
+    ),
+}
+
+impl NamespacePublication {
+    /// @description 按 allocator 返回的编号预留 namespace 发布所需 node。
+    /// @param pid `TaskManager::allocate_pid` 返回的编号。
+    /// @return 无 namespace 时不分配；新 namespace 额外预留 namespace node。
+    /// @errors 任一 node 分配失败时返回 `OutOfMemory`。
+    pub(super) fn try_new(pid: &ProcessId) -> Result<Self, OutOfMemory> {
+        Ok(match pid.namespace {
+            None => Self::Global,
+            Some(namespace) if namespace.namespace == pid.global => Self::New(
+                PidNamespaceSlots::try_new()?,
+                FallibleMap::try_reserve_node()?,
+            ),
+            Some(namespace) => Self::Member(PidNamespaceSlots::try_new()?, namespace),
+        })
+    }
+
+    /// @description 在 graph owner 内发布编号映射，并在新 Process 时计入 namespace。
+    /// @param graph 已持有的唯一 process graph。
+    /// @param global 新 Task 的全局 TGID/TID。
+    /// @param process 发布的是新 Process 而非同组 Thread。
+    /// @return 所属 namespace init 已退出时返回 true，caller 必须在 activation 前投递 SIGKILL。
+    pub(super) fn publish(self, graph: &mut ProcessGraph, global: usize, process: bool) -> bool {
+        match self {
+            Self::Global => false,
+            Self::New(slots, node) => {
+                let namespace = PidNamespace::new(global, slots);
+                graph
+                    .pid_namespaces
+                    .commit_vacant(node.fill(global, namespace));
+                false
+            }
+            Self::Member(slots, pid) => {
+                let owner = graph
+                    .pid_namespaces
+                    .get_mut(&pid.namespace)
+                    .expect("live member references missing PID namespace");
+                owner.publish(pid.local, global, slots);
+                if process {
+                    owner.add_process();
+                }
+                owner.init_exited()
+            }
+        }
+    }
+}
+
+impl TaskManager {
+    /// @description 在唯一 graph owner 内分配全局 PID 与可选 namespace 编号。
+    /// @param creator 发起 fork/clone 的 Task；新 Task 默认加入其 PID namespace。
+    /// @param new_namespace `CLONE_NEWPID`：新 Process 成为新 namespace 的编号 1。
+    /// @return 新 ProcessId；全局编号超过 PID_MAX 时返回 None。
+    pub(super) fn allocate_pid(
+        &self,
+        creator: &TaskControlBlock,
+        new_namespace: bool,
+    ) -> Option<ProcessId> {
+        let mut graph = self.graph.lock();
+        let pid = graph.next_pid;
+        if pid > PID_MAX {
+            return None;
+        }
+        graph.next_pid = pid + 1;
+        let namespace = if new_namespace {
+            Some(NamespacePid {
+                namespace: pid,
+                local: INIT_PID,
+            })
+        } else {
+            creator.pid_namespace().map(|namespace| NamespacePid {
+                namespace,
+                local: graph
+                    .pid_namespaces
+                    .get_mut(&namespace)
+                    .expect("live creator references missing PID namespace")
+                    .allocate(),
+            })
+        };
+        Some(ProcessId::allocated(pid, namespace))
+    }
+}
+
+/// @description 把全局编号翻译为 PID namespace 视角。
+/// @param view observer 所属 namespace；None 为全局 namespace。
+/// @return 可见编号；namespace 外 Process 返回 0，与 Linux `pid_vnr` 一致。
+pub(super) fn pid_in_view(graph: &ProcessGraph, view: Option<usize>, global: usize) -> usize {
+    let Some(view) = view else {
+        return global;
+    };
+    graph
+        .pid_namespaces
+        .get(&view)
+        .and_then(|namespace| namespace.local(global))
+        .unwrap_or(0)
+}
+
+/// @description 把 PID namespace 视角的编号翻译为全局编号。
+/// @param view observer 所属 namespace；None 为全局 namespace。
+/// @return 全局编号；namespace 中未发布的编号返回 None。
+pub(super) fn pid_from_view(
+    graph: &ProcessGraph,
+    view: Option<usize>,
+    pid: usize,
+) -> Option<usize> {
+    let Some(view) = view else {
+        return Some(pid);
+    };
+    graph
+        .pid_namespaces
+        .get(&view)
+        .and_then(|namespace| namespace.global(pid))
+}
+
+/// @description 判断 sender 是否位于目标 Process 的祖先 PID namespace。
+/// @param sender 用户态 sender；kernel generation 为 None。
+/// @param target 目标 Process 的任一 Thread。
+/// @return 全局 sender 命中子 namespace 成员时返回 true。
+pub(super) fn sender_is_ancestor(
+    sender: Option<&Arc<TaskControlBlock>>,
+    target: &TaskControlBlock,
+) -> bool {
+    sender.is_some_and(|sender| sender.pid_namespace() != target.pid_namespace())
+}
+
+/// @description 为 sender 的 siginfo 选择目标 Process 视角，并标记穿透 namespace init 的信号。
+/// @param graph 已持有的唯一 process graph。
+/// @param info 以全局 TGID 记录来源的 siginfo。
+/// @param target 接收 signal 的 Thread。
+/// @param signal Linux signal number。
+/// @param from_ancestor `sender_is_ancestor` 的结果。
+/// @return 可直接排队的 siginfo。
+pub(super) fn signal_info_for(
+    graph: &ProcessGraph,
+    info: PendingSignal,
+    target: &TaskControlBlock,
+    signal: usize,
+    from_ancestor: bool,
+) -> PendingSignal {
+    let info = info.in_pid_view(|pid| pid_in_view(graph, target.pid_namespace(), pid));
+    if from_ancestor && matches!(signal, 9 | 19) {
+        info.forced_from_ancestor()
+    } else {
+        info
+    }
+}
+
+/// @description 把用户提供的 PID 翻译为全局编号。
+/// @param observer 执行 syscall 的 Task。
+/// @param pid observer namespace 中的正 PID/TID/PGID/SID。
+/// @return 全局编号；namespace 中不存在的编号返回 None。
+pub(crate) fn resolve_pid(observer: &TaskControlBlock, pid: usize) -> Option<usize> {
+    match observer.pid_namespace() {
+        None => Some(pid),
+        view => pid_from_view(&TASK_MANAGER.graph.lock(), view, pid),
+    }
+}
+
+/// @description 把全局 PID 翻译为 observer 可见编号。
+/// @param observer 执行 syscall 的 Task。
+/// @param pid 全局 TGID/TID/PGID/SID。
+/// @return 可见编号；observer namespace 外的编号返回 0。
+pub(crate) fn visible_pid(observer: &TaskControlBlock, pid: usize) -> usize {
+    match observer.pid_namespace() {
+        None => pid,
+        view => pid_in_view(&TASK_MANAGER.graph.lock(), view, pid),
+    }
+}
+
+/// @description 选择退出 Process 的 orphan 收养者。
+/// @param graph 已持有的唯一 process graph。
+/// @param exiting 正在退出的 Process TGID。
+/// @return namespace init 仍存活时返回其 TGID；namespace init 自身或全局成员返回 INIT_PID。
+pub(super) fn child_reaper(graph: &ProcessGraph, exiting: usize) -> usize {
+    graph
+        .nodes
+        .get(&exiting)
+        .and_then(|node| node.pid_namespace)
+        .filter(|&namespace| {
+            namespace != exiting
+                && graph
+                    .pid_namespaces
+                    .get(&namespace)
+                    .is_some_and(|namespace| !namespace.init_exited())
+        })
+        .unwrap_or(INIT_PID)
+}
+
+/// @description namespace init 退出时冻结 namespace，使之后发布的成员在 activation 前被杀死。
+/// @param graph 已持有的唯一 process graph。
+/// @param exiting 已转为 Exited 的 Process TGID。
+/// @return exiting 是 namespace init 时返回 namespace identity，caller 在锁外执行成员 SIGKILL。
+pub(super) fn mark_namespace_init_exit(graph: &mut ProcessGraph, exiting: usize) -> Option<usize> {
+    let namespace = graph.pid_namespaces.get_mut(&exiting)?;
+    namespace.mark_init_exited();
+    Some(exiting)
+}
+
+/// @description 按全局编号 cursor 向 namespace 内每个 live Process 发布 SIGKILL。
+/// @param namespace `mark_namespace_init_exit` 返回的 namespace identity。
+/// @return 无返回值；cursor 之后发布的成员由 publication 路径自行收到 SIGKILL。
+pub(super) fn kill_namespace_members(namespace: usize) {
+    let mut cursor = 0;
+    loop {
+        let member = {
+            let graph = TASK_MANAGER.graph.lock();
+            let Some(owner) = graph.pid_namespaces.get(&namespace) else {
+                return;
+            };
+            let mut next = owner.member_after(cursor);
+            while let Some(global) = next {
+                if graph
+                    .nodes
+                    .get(&global)
+                    .is_some_and(|node| matches!(node.state, ProcessState::Live(_)))
+                {
+                    break;
+                }
+                next = owner.member_after(global);
+            }
+            next
+        };
+        let Some(member) = member else {
+            return;
+        };
+        cursor = member;
+        send_kernel_process_signal(member, 9, PendingSignal::kernel());
+    }
+}
+
+/// @description wait 回收 Process 后释放其 namespace 计数，最后一个成员回收时销毁编号表。
+/// @param graph 已持有的唯一 process graph。
+/// @param namespace 被回收 Process 所属 namespace。
+pub(super) fn release_namespace_process(graph: &mut ProcessGraph, namespace: Option<usize>) {
+    let Some(namespace) = namespace else {
+        return;
+    };
+    let empty = graph
+        .pid_namespaces
+        .get_mut(&namespace)
+        .expect("reaped member references missing PID namespace")
+        .remove_process();
+    if empty {
+        graph.pid_namespaces.remove(&namespace);
+    }
+}
+
+/// @description 判断全局编号是否仍被 live/zombie Process、live Thread、process group 或 session 引用。
+/// @param session 编号作为 PGID 时所属的 session。
+fn pid_in_use(graph: &ProcessGraph, global: usize, session: usize) -> bool {
+    let group_in_use = |key: &(usize, usize)| {
+        graph
+            .groups
+            .get(key)
+            .is_some_and(|group| !group.members.is_empty())
+    };
+    if graph.nodes.contains_key(&global)
+        || graph.threads.contains_key(&global)
+        || group_in_use(&(session, global))
+    {
+        return true;
+    }
+    let mut cursor = (global, 0);
+    while let Some((&key, _)) = graph.groups.successor(&cursor) {
+        if key.0 != global {
+            break;
+        }
+        if group_in_use(&key) {
+            return true;
+        }
+        cursor = key;
+    }
+    false
+}
+
+/// @description Task 回收或离开 process group/session 后释放不再使用的 namespace 编号映射。
+/// @param graph 已持有的唯一 process graph。
+/// @param candidates 可能失去最后引用的全局编号，与其作为 PGID 时所属 session 成对给出。
+pub(super) fn release_unused_pids(graph: &mut ProcessGraph, candidates: &[(usize, usize)]) {
+    for &(global, session) in candidates {
+        if graph.pid_namespaces.is_empty() || pid_in_use(graph, global, session) {
+            continue;
+        }
+        let owner = graph
+            .pid_namespaces
+            .iter()
+            .find(|(_, namespace)| namespace.local(global).is_some())
+            .map(|(&owner, _)| owner);
+        if let Some(owner) = owner {
+            graph
+                .pid_namespaces
+                .get_mut(&owner)
+                .expect("PID namespace disappeared under graph lock")
+                .release(global);
+        }
+    }
+}
@@ -23,6 +23,13 @@ pub(crate) fn create_pipe_endpoints() -> Result<(Arc<PipeEnd>, Arc<PipeEnd>), ()
     create_endpoints(Pipe::pair)
 }
 
+/// @description 创建绑定统一 task wait registry、尚无 endpoint 的 FIFO Pipe。
+/// @return named pipe inode 共享的 Pipe owner。
+pub(crate) fn create_fifo_pipe() -> Result<Arc<Pipe>, ()> {
+    let notifier = Arc::try_new(TaskPipeNotifier).map_err(|_| ())?;
+    Pipe::fifo(notifier)
+}
+
 /// @description 创建绑定同一 task wait registry 的一字节 notification Pipe endpoints。
 /// @return DRM/input/PTY/epoll/eventfd/socket readiness 使用的 read/write token endpoints。
 pub(crate) fn create_notification_endpoints() -> Result<(Arc<PipeEnd>, Arc<PipeEnd>), ()> {
@@ -49,9 +56,8 @@ fn wake_pipe_waiters(pipe: &Arc<Pipe>) -> usize {
                 (if state.writable { OUTPUT } else { 0 }) | if state.error { ERROR } else { 0 }
             }
         };
-        if ready == 0 {
-            continue;
-        }
+        // FIFO partner arrival 不产生 poll bit；ready 为零时仍复查 non-exclusive open waiter，
+        // 否则 reader 在空 FIFO 上等待 writer 时永远不会被唤醒。
         while let Some(wake) =
             WAIT_REGISTRY.wake_pipe_one(identity, direction, false, ready, state, &wake_groups)
         {
@@ -64,6 +70,9 @@ fn wake_pipe_waiters(pipe: &Arc<Pipe>) -> usize {
                 break 'sources;
             }
         }
+        if ready == 0 {
+            continue;
+        }
         if let Some(wake) =
             WAIT_REGISTRY.wake_pipe_one(identity, direction, true, ready, state, &wake_groups)
         {
@@ -1,5 +1,5 @@
 use super::thread_selector::scheduler_thread;
-use super::{ProcessState, TASK_MANAGER};
+use super::{ProcessNode, ProcessState, TASK_MANAGER};
 use crate::task::{
     TaskControlBlock, current_task, model::RLIMIT_NICE, processor::request_task_reschedule,
 };
@@ -40,7 +40,7 @@ pub(crate) enum SchedulerPolicyError {
 /// @description Linux get/setpriority 的 task collection selector。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum SchedulerNiceSelector {
-    /// 零选择 caller，非零选择全局 TID。
+    /// 零选择 caller，非零选择 caller PID namespace 中的 TID。
     Process(u32),
     /// 零选择 caller process group，非零选择 PGID。
     Group(u32),
@@ -64,6 +64,7 @@ fn nice_targets(
         return Ok(targets);
     }
     let mut candidates = Vec::new();
+    let view = caller.pid_namespace();
     loop {
         let graph = TASK_MANAGER.graph.lock();
         let selected_group = match selector {
@@ -73,13 +74,24 @@ fn nice_targets(
                 };
                 Some(node.process_group)
             }
-            SchedulerNiceSelector::Group(group) => Some(group as usize),
+            SchedulerNiceSelector::Group(group) => {
+                let Some(group) = super::pid_view::pid_from_view(&graph, view, group as usize)
+                else {
+                    return Ok(Vec::new());
+                };
+                Some(group)
+            }
             SchedulerNiceSelector::Process(_) | SchedulerNiceSelector::User(_) => None,
         };
+        // 子 PID namespace 内的 caller 只能选中同 namespace 成员。
+        let selected = |node: &&ProcessNode| {
+            selected_group.is_none_or(|group| node.process_group == group)
+                && view.is_none_or(|view| node.pid_namespace == Some(view))
+        };
         let required = graph
             .nodes
             .values()
-            .filter(|node| selected_group.is_none_or(|group| node.process_group == group))
+            .filter(selected)
             .try_fold(0usize, |count, node| match &node.state {
                 ProcessState::Live(threads) => count.checked_add(threads.len()).ok_or(()),
                 ProcessState::Exited(_) => Ok(count),
@@ -95,11 +107,7 @@ fn nice_targets(
             }
             super::snapshot_staging::SnapshotCapacity::Capture => {
                 candidates.clear();
-                for node in graph
-                    .nodes
-                    .values()
-                    .filter(|node| selected_group.is_none_or(|group| node.process_group == group))
-                {
+                for node in graph.nodes.values().filter(selected) {
                     let ProcessState::Live(threads) = &node.state else {
                         continue;
                     };
@@ -177,7 +185,7 @@ pub(crate) fn scheduler_nice(
 
 /// @description 查询或替换一个 live Thread 的 Linux I/O priority policy。
 ///
-/// @param tid 零选择 calling Thread；正数使用全局 TID selector。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID。
 /// @param replacement None 查询，Some 替换已验证的 encoded priority。
 /// @return 当前或新 I/O priority。
 /// @errors caller/目标不存在返回 NotFound；设置其他身份目标且无 root 权限返回 Permission。
@@ -197,7 +205,7 @@ pub(crate) fn scheduler_io_priority(
 
 /// @description 查询或替换 live Thread 的 legacy Linux scheduler policy。
 ///
-/// @param tid 零选择 calling Thread；正数使用 Linux 全局 TID selector。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID。
 /// @param request 查询、保留 policy 设置参数，或替换完整 legacy policy。
 /// @return 当前 `SCHED_OTHER`，并在 owner 设置时包含 `SCHED_RESET_ON_FORK`。
 /// @errors 目标不存在返回 `NotFound`；policy/priority 不可表达返回 `Invalid`；权限不足返回 `Permission`。
@@ -256,7 +264,7 @@ pub(crate) fn scheduler_policy(
 
 /// @description 查询 live Thread 可观察的 scheduler 基础时间片。
 ///
-/// @param tid 零选择 calling Thread；正数使用 Linux 全局 TID selector。
+/// @param tid 零选择 calling Thread；正数选择 caller PID namespace 中的 TID。
 /// @return timer owner 校准后的固定 `SCHED_OTHER` preemption quantum，单位纳秒。
 /// @errors calling Thread 或目标不存在时返回 `NotFound`。
 pub(crate) fn scheduler_rr_interval(tid: usize) -> Result<u64, SchedulerPolicyError> {
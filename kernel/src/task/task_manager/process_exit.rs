@@ -299,7 +299,9 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
     // wait target and turns ordinary same-mm contention into a kernel panic.
     let task = current_task().expect("No current task to exit");
     task.cleanup_robust_list();
-    let (removed, process_status, parent_waiters, init_waiters, parent_signal_pid) = {
+    // scheduling policy lock 不能嵌套在 graph lock 内；最后 Thread 的 runtime 在进入 graph 前快照。
+    let (process_runtime_us, _) = task.cpu_runtime_snapshot(get_time_us());
+    let (removed, process_status, parent_waiters, init_waiters, parent_signal_pid, namespace_exit) = {
         let mut graph = TASK_MANAGER.graph.lock();
         let exiting_pid = task.tgid();
         let process_will_exit = graph.nodes.get(&exiting_pid).is_some_and(
@@ -334,6 +336,7 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
             if let Some(status) = process_status {
                 assert!(node.child_waiters.is_empty());
                 node.state = ProcessState::Exited(status);
+                node.rusage_runtime_us = node.rusage_runtime_us.saturating_add(process_runtime_us);
             }
             (
                 removed,
@@ -350,17 +353,31 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
             task.tid(),
             replacement_parent_tid,
         );
+        if task.tid() != exiting_pid {
+            // 非 leader TID 不会成为 PGID/SID，session 参数无意义。
+            super::pid_view::release_unused_pids(&mut graph, &[(task.tid(), 0)]);
+        }
         if process_status.is_some() {
             // graph → timer 与 set/get 共用唯一锁序；持 graph 期间删除使 exit 后不存在 stale timer。
             TASK_MANAGER.timers.lock().remove_process(exiting_pid);
         }
 
         match process_status {
-            None => (removed, None, FallibleMap::new(), FallibleMap::new(), None),
+            None => (
+                removed,
+                None,
+                FallibleMap::new(),
+                FallibleMap::new(),
+                None,
+                None,
+            ),
             Some(status) => {
-                // orphan membership nodes move to init in the same owner transaction. No
-                // allocation can fail after the first edge has moved.
+                // orphan membership nodes move to the child reaper in the same owner transaction.
+                // No allocation can fail after the first edge has moved.
                 let mut adopted_exited = false;
+                let reaper = super::pid_view::child_reaper(&graph, exiting_pid);
+                let namespace_exit =
+                    super::pid_view::mark_namespace_init_exit(&mut graph, exiting_pid);
                 if exiting_pid != INIT_PID {
                     loop {
                         let membership = {
@@ -382,12 +399,12 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
                             .get_mut(&child)
                             .expect("child index references missing process");
                         debug_assert_eq!(node.parent, Some(exiting_pid));
-                        node.parent = Some(INIT_PID);
+                        node.parent = Some(reaper);
                         adopted_exited |= matches!(node.state, ProcessState::Exited(_));
                         graph
                             .nodes
-                            .get_mut(&INIT_PID)
-                            .expect("init missing during orphan reparent")
+                            .get_mut(&reaper)
+                            .expect("child reaper missing during orphan reparent")
                             .children
                             .commit_vacant(membership);
                     }
@@ -406,7 +423,7 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
                 let init_waiters = if adopted_exited {
                     graph
                         .nodes
-                        .get_mut(&INIT_PID)
+                        .get_mut(&reaper)
                         .map(take_child_waiters)
                         .unwrap_or_default()
                 } else {
@@ -442,6 +459,7 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
                     parent_waiters,
                     init_waiters,
                     parent_signal_pid,
+                    namespace_exit,
                 )
             }
         }
@@ -454,6 +472,10 @@ fn prepare_current_exit(requested: ProcessExitStatus) -> (*mut KernelContext, *m
     // 退出导致的 terminal/orphan signal 必须先于 parent wake/SIGCHLD；否则 parent 可先
     // reap 并推进 shell 状态，使 POSIX exit consequences 的观察顺序依赖调度竞态。
     drain_exit_effects();
+    // namespace init 退出后按 Linux zap_pid_ns_processes 杀死剩余成员；其 orphan 已由 global init 收养。
+    if let Some(namespace) = namespace_exit {
+        super::pid_view::kill_namespace_members(namespace);
+    }
 
     // 1. process graph 先注销 Thread owner，再发布 clear-child-tid completion。
     // 2. 若顺序相反，pthread_join 可在 graph 仍计数已退出 sibling 时返回，使紧随的
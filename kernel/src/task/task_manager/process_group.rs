@@ -16,10 +16,11 @@ pub(crate) enum SetProcessGroupError {
 
 /// @description 将当前 Process 建为新 session 与 process-group leader。
 ///
-/// @return 成功返回新 SID（等于 TGID）。
+/// @return 成功返回 caller PID namespace 视角的新 SID（等于 TGID）。
 /// @errors 当前 PID 已是任一 process group ID 时返回 Permission。
 pub(crate) fn create_session() -> Result<usize, ProcessGroupError> {
-    let pid = current_task().expect("setsid requires current task").tgid();
+    let task = current_task().expect("setsid requires current task");
+    let pid = task.tgid();
     let mut graph = TASK_MANAGER.graph.lock();
     if graph
         .groups
@@ -72,56 +73,75 @@ pub(crate) fn create_session() -> Result<usize, ProcessGroupError> {
         .expect("setsid process disappeared under graph lock");
     node.session = pid;
     node.process_group = pid;
-    Ok(pid)
+    super::pid_view::release_unused_pids(
+        &mut graph,
+        &[(old_group.1, old_group.0), (old_group.0, old_group.0)],
+    );
+    Ok(task.tgid_seen_by(&task))
 }
 
 /// @description 查询指定 Process 的 process group ID。
 ///
-/// @param pid 零表示当前 TGID，否则为目标 TGID。
-/// @return live/zombie process 的 PGID。
+/// @param pid 零表示当前 TGID，否则为 caller PID namespace 中的目标 TGID。
+/// @return live/zombie process 的 PGID；namespace 外的 group 报告为零。
 /// @errors 目标不存在时返回 NotFound。
 pub(crate) fn process_group(pid: usize) -> Result<usize, ProcessGroupError> {
-    let current = current_task()
-        .expect("getpgid requires current task")
-        .tgid();
-    TASK_MANAGER
-        .graph
-        .lock()
-        .nodes
-        .get(&if pid == 0 { current } else { pid })
-        .map(|node| node.process_group)
-        .ok_or(ProcessGroupError::NotFound)
+    let task = current_task().expect("getpgid requires current task");
+    process_identity(&task, pid, |node| node.process_group)
 }
 
 /// @description 查询指定 Process 的 session ID。
 ///
-/// @param pid 零表示当前 TGID，否则为目标 TGID。
-/// @return live/zombie process 的 SID。
+/// @param pid 零表示当前 TGID，否则为 caller PID namespace 中的目标 TGID。
+/// @return live/zombie process 的 SID；namespace 外的 session 报告为零。
 /// @errors 目标不存在时返回 NotFound。
 pub(crate) fn session_id(pid: usize) -> Result<usize, ProcessGroupError> {
-    let current = current_task().expect("getsid requires current task").tgid();
-    TASK_MANAGER
-        .graph
-        .lock()
+    let task = current_task().expect("getsid requires current task");
+    process_identity(&task, pid, |node| node.session)
+}
+
+fn process_identity(
+    task: &TaskControlBlock,
+    pid: usize,
+    field: impl FnOnce(&ProcessNode) -> usize,
+) -> Result<usize, ProcessGroupError> {
+    let view = task.pid_namespace();
+    let graph = TASK_MANAGER.graph.lock();
+    let target = match pid {
+        0 => task.tgid(),
+        pid => {
+            super::pid_view::pid_from_view(&graph, view, pid).ok_or(ProcessGroupError::NotFound)?
+        }
+    };
+    let value = graph
         .nodes
-        .get(&if pid == 0 { current } else { pid })
-        .map(|node| node.session)
-        .ok_or(ProcessGroupError::NotFound)
+        .get(&target)
+        .map(field)
+        .ok_or(ProcessGroupError::NotFound)?;
+    Ok(super::pid_view::pid_in_view(&graph, view, value))
 }
 
 /// @description 按 Linux parent/child/session/exec 约束修改 process group membership。
 ///
-/// @param pid 零表示 caller；非零只允许 caller 的直接 child。
+/// @param pid 零表示 caller；非零只允许 caller 的直接 child（caller PID namespace 视角）。
 /// @param pgid 零表示目标 TGID；非零必须是同 session 已存在 group 或目标自身。
 /// @return 成功返回 `Ok(())`。
 /// @errors 目标不存在返回 NotFound；child 已 exec 返回 Executed；其余约束失败返回 Permission。
 pub(crate) fn set_process_group(pid: usize, pgid: usize) -> Result<(), SetProcessGroupError> {
-    let caller = current_task()
-        .expect("setpgid requires current task")
-        .tgid();
-    let target = if pid == 0 { caller } else { pid };
-    let desired = if pgid == 0 { target } else { pgid };
+    let task = current_task().expect("setpgid requires current task");
+    let caller = task.tgid();
+    let view = task.pid_namespace();
     let mut graph = TASK_MANAGER.graph.lock();
+    let target = match pid {
+        0 => caller,
+        pid => super::pid_view::pid_from_view(&graph, view, pid)
+            .ok_or(SetProcessGroupError::NotFound)?,
+    };
+    let desired = match pgid {
+        0 => target,
+        pgid => super::pid_view::pid_from_view(&graph, view, pgid)
+            .ok_or(SetProcessGroupError::Permission)?,
+    };
     let caller_session = graph
         .nodes
         .get(&caller)
@@ -193,6 +213,7 @@ pub(crate) fn set_process_group(pid: usize, pgid: usize) -> Result<(), SetProces
         .get_mut(&target)
         .expect("validated process disappeared under graph lock")
         .process_group = desired;
+    super::pid_view::release_unused_pids(&mut graph, &[(old_group.1, old_group.0)]);
     Ok(())
 }
 
@@ -243,29 +264,49 @@ pub(crate) fn claim_controlling_terminal(
 /// @description 查询当前 session controlling TTY 的 foreground process group。
 ///
 /// @param terminal ioctl fd 指向的 TTY owner。
-/// @return foreground PGID。
+/// @return caller PID namespace 视角的 foreground PGID。
 /// @errors fd 的 TTY 不属于 caller session 时返回 NotTerminal。
 pub(crate) fn terminal_foreground_group(
     terminal: &crate::fs::Terminal,
 ) -> Result<usize, ProcessGroupError> {
-    let session = session_id(0)?;
-    terminal
+    let task = current_task().expect("TIOCGPGRP requires current task");
+    let session = current_session(&task)?;
+    let pgid = terminal
         .foreground_pgid(session)
-        .map_err(|()| ProcessGroupError::NotTerminal)
+        .map_err(|()| ProcessGroupError::NotTerminal)?;
+    Ok(super::visible_pid(&task, pgid))
+}
+
+/// @description 查询 Task 所属 Process 的全局 SID，供 TTY owner 比较 session identity。
+///
+/// @param task calling Task。
+/// @return 全局 SID；不经 PID namespace 翻译。
+/// @errors Process 已不在 graph 中时返回 NotFound。
+pub(crate) fn current_session(task: &TaskControlBlock) -> Result<usize, ProcessGroupError> {
+    TASK_MANAGER
+        .graph
+        .lock()
+        .nodes
+        .get(&task.tgid())
+        .map(|node| node.session)
+        .ok_or(ProcessGroupError::NotFound)
 }
 
 /// @description 将 caller session 内已存在的 process group 设为 TTY foreground owner。
 ///
 /// @param terminal ioctl fd 指向的 TTY owner。
-/// @param pgid 同 session 的已存在 process group ID。
+/// @param pgid caller PID namespace 中同 session 的已存在 process group ID。
 /// @return 成功返回 `Ok(())`。
 /// @errors group 不存在/跨 session 返回 Permission；TTY 不属于 caller session 返回 NotTerminal。
 pub(crate) fn set_terminal_foreground_group(
     terminal: &crate::fs::Terminal,
     pgid: usize,
 ) -> Result<(), ProcessGroupError> {
-    let session = session_id(0)?;
+    let task = current_task().expect("TIOCSPGRP requires current task");
+    let session = current_session(&task)?;
     let graph = TASK_MANAGER.graph.lock();
+    let pgid = super::pid_view::pid_from_view(&graph, task.pid_namespace(), pgid)
+        .ok_or(ProcessGroupError::Permission)?;
     if !graph
         .groups
         .get(&(session, pgid))
@@ -11,13 +11,14 @@ use crate::{
     timer::{boot_epoch_seconds, get_time_us},
 };
 
-use super::{ProcessState, TASK_MANAGER};
+use super::{ProcessState, TASK_MANAGER, pid_view::pid_in_view};
 
 struct ProcessSnapshotRow {
     pid: usize,
     ppid: usize,
     process_group: usize,
     session: usize,
+    global_session: usize,
     representative: alloc::sync::Arc<crate::task::TaskControlBlock>,
     threads: core::ops::Range<usize>,
 }
@@ -29,7 +30,9 @@ type GraphSnapshotRows = (
     u64,
 );
 
-fn graph_snapshot_rows() -> Result<GraphSnapshotRows, crate::fs::FileSystemError> {
+fn graph_snapshot_rows(
+    view: Option<usize>,
+) -> Result<GraphSnapshotRows, crate::fs::FileSystemError> {
     let (mut row_capacity, mut thread_capacity) = {
         let graph = TASK_MANAGER.graph.lock();
         graph.nodes.values().fold((0usize, 0usize), |counts, node| {
@@ -71,20 +74,27 @@ fn graph_snapshot_rows() -> Result<GraphSnapshotRows, crate::fs::FileSystemError
         }
 
         // 2. 容量验证与 Arc/关系快照处于同一次 graph linearization；push/extend 已证明不会增长。
+        // 3. 子 PID namespace observer 只看到本 namespace 成员，且全部关系编号按其视角翻译。
         for (&pid, node) in &graph.nodes {
             let ProcessState::Live(threads) = &node.state else {
                 continue;
             };
+            if view.is_some_and(|view| node.pid_namespace != Some(view)) {
+                continue;
+            }
             let Some(representative) = threads.values().next() else {
                 continue;
             };
             let start = tasks.len();
             tasks.extend(threads.values().cloned());
             rows.push(ProcessSnapshotRow {
-                pid,
-                ppid: node.parent.unwrap_or(0),
-                process_group: node.process_group,
-                session: node.session,
+                pid: pid_in_view(&graph, view, pid),
+                ppid: node
+                    .parent
+                    .map_or(0, |parent| pid_in_view(&graph, view, parent)),
+                process_group: pid_in_view(&graph, view, node.process_group),
+                session: pid_in_view(&graph, view, node.session),
+                global_session: node.session,
                 representative: representative.clone(),
                 threads: start..tasks.len(),
             });
@@ -92,7 +102,10 @@ fn graph_snapshot_rows() -> Result<GraphSnapshotRows, crate::fs::FileSystemError
         return Ok((
             rows,
             tasks,
-            graph.next_pid.saturating_sub(1),
+            view.and_then(|view| graph.pid_namespaces.get(&view))
+                .map_or(graph.next_pid.saturating_sub(1), |namespace| {
+                    namespace.last_allocated()
+                }),
             graph.processes_created,
         ));
     }
@@ -121,13 +134,16 @@ impl ProcSource for KernelProcSource {
     }
 
     fn current_pid(&self) -> Option<usize> {
-        crate::task::current_task().map(|task| task.tgid())
+        crate::task::current_task().map(|task| task.tgid_seen_by(&task))
     }
 
     fn process_arguments(
         &self,
         pid: usize,
     ) -> Result<Option<alloc::vec::Vec<u8>>, crate::fs::FileSystemError> {
+        let Some(pid) = observer_pid(pid) else {
+            return Ok(None);
+        };
         let representative = {
             let graph = TASK_MANAGER.graph.lock();
             let Some(node) = graph.nodes.get(&pid) else {
@@ -155,6 +171,9 @@ impl ProcSource for KernelProcSource {
         pid: usize,
     ) -> Result<Option<alloc::vec::Vec<ProcFileDescriptorSnapshot>>, crate::fs::FileSystemError>
     {
+        let Some(pid) = observer_pid(pid) else {
+            return Ok(None);
+        };
         let representative = {
             let graph = TASK_MANAGER.graph.lock();
             let Some(node) = graph.nodes.get(&pid) else {
@@ -183,11 +202,21 @@ impl ProcSource for KernelProcSource {
     }
 }
 
+/// @description 把 `/proc/<pid>` 路径中的编号按 caller 的 PID namespace 翻译为全局 TGID。
+/// @return caller namespace 中不存在该编号时返回 None。
+fn observer_pid(pid: usize) -> Option<usize> {
+    match current_task() {
+        Some(task) => super::resolve_pid(&task, pid),
+        None => Some(pid),
+    }
+}
+
 fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
     let uptime_us = get_time_us();
     let current = current_task();
+    let view = current.as_ref().and_then(|task| task.pid_namespace());
     // graph lock 内只做已预留 Vec 的关系/Arc 快照；后续不得带 graph lock 获取 task 内部锁。
-    let (rows, tasks, last_pid, processes_created) = graph_snapshot_rows()?;
+    let (rows, tasks, last_pid, processes_created) = graph_snapshot_rows(view)?;
 
     // 2. 聚合每个 live thread 的 scheduler 状态；Process 级内存只从 representative 读取一次。
     let mut runnable_tasks = 0;
@@ -202,6 +231,7 @@ fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
             ppid,
             process_group,
             session,
+            global_session,
             representative,
             threads,
         } = row;
@@ -232,7 +262,9 @@ fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
             let (start_time_us, nice, priority, runtime_us) =
                 thread.thread_statistics(active_now_us);
             thread_snapshots.push(ProcThreadSnapshot {
-                tid: thread.tid(),
+                tid: current
+                    .as_ref()
+                    .map_or(thread.tid(), |observer| thread.tid_seen_by(observer)),
                 state: if runnable {
                     b'R'
                 } else if matches!(run_state, RunState::Stopped { .. }) {
@@ -263,7 +295,9 @@ fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
         // 1. Linux 只刷新 same-thread-group 的 current task；其他 running sibling 由下一 tick 提交。
         // 2. Process counter 保留 exited Thread；改回累加 live Thread 会让 /proc runtime 倒退。
         let runtime_us = match current.as_ref() {
-            Some(task) if task.tgid() == pid => task.cpu_runtime_snapshot(uptime_us).0,
+            Some(task) if task.tgid() == representative.tgid() => {
+                task.cpu_runtime_snapshot(uptime_us).0
+            }
             _ => representative.process_cpu_runtime_us(),
         };
         let statistics = representative
@@ -274,7 +308,14 @@ fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
         let groups = representative
             .supplementary_groups()
             .map_err(|()| crate::fs::FileSystemError::OutOfMemory)?;
-        let (tty_number, terminal_process_group) = representative.terminal_proc_identity(session);
+        let (tty_number, terminal_process_group) =
+            representative.terminal_proc_identity(global_session);
+        let terminal_process_group = match current.as_ref() {
+            Some(observer) if view.is_some() && terminal_process_group > 0 => {
+                super::visible_pid(observer, terminal_process_group as usize) as isize
+            }
+            _ => terminal_process_group,
+        };
         processes.push(ProcProcessSnapshot {
             pid,
             ppid,
@@ -307,6 +348,7 @@ fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
     let frame = frame_statistics();
     let heap = crate::memory::heap_statistics();
     let reclaim = reclaim_statistics();
+    let balloon = crate::drivers::balloon_statistics();
     let cache = page_cache_statistics();
     let load_milli = TASK_MANAGER.load_average.values();
     let cpu_runtime =
@@ -338,10 +380,13 @@ fn process_snapshot() -> Result<ProcSnapshot, crate::fs::FileSystemError> {
         direct_reclaim_attempts: reclaim.attempts,
         direct_reclaim_scanned_pages: reclaim.scanned_pages,
         direct_reclaim_reclaimed_pages: reclaim.reclaimed_pages,
+        balloon_inflated_pages: balloon.inflated_pages,
+        balloon_deflated_pages: balloon.deflated_pages,
         cached_pages: cache.resident_pages,
         dirty_pages: cache.dirty_pages,
         reclaimable_cached_pages: cache.reclaimable_pages,
         heap_pages: heap.resident_pages,
+        kernel_stack_pages: crate::memory::kernel_stack_pages(),
         runnable_tasks,
         total_tasks,
         processes_created,
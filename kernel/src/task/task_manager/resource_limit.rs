@@ -104,14 +104,17 @@ pub(super) fn enforce_cpu_limit(task: &Arc<TaskControlBlock>) {
     super::send_kernel_process_signal(task.tgid(), signal, PendingSignal::kernel());
 }
 
-/// @description 按 Linux prlimit64 permission 读取并可选替换一个 live Process 的 limit。
+/// @description 按 Linux prlimit64 permission 读取并可选替换 caller PID namespace 中一个 live Process 的 limit。
 pub(crate) fn process_resource_limit(
     pid: usize,
     resource: usize,
     replacement: Option<ResourceLimit>,
 ) -> Result<ResourceLimit, ResourceLimitError> {
     let caller = current_task().ok_or(ResourceLimitError::NotFound)?;
-    let target_pid = if pid == 0 { caller.tgid() } else { pid };
+    let target_pid = match pid {
+        0 => caller.tgid(),
+        pid => super::resolve_pid(&caller, pid).ok_or(ResourceLimitError::NotFound)?,
+    };
     let target = representative(target_pid).ok_or(ResourceLimitError::NotFound)?;
     let caller_uid = caller.credential_res_ids(true);
     let caller_gid = caller.credential_res_ids(false);
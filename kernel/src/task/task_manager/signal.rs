@@ -121,9 +121,13 @@ fn send_selected_thread_signal(
         if signal == 0 {
             return Ok(());
         }
-        let sender = current_task().map_or(0, |task| task.tgid());
-        let info = kernel_info.unwrap_or_else(|| PendingSignal::thread_directed(sender));
-        let queued = if target.ignores_generated_signal_as_init(signal) {
+        let sender = kernel_info.is_none().then(current_task).flatten();
+        let from_ancestor = super::pid_view::sender_is_ancestor(sender.as_ref(), &target);
+        let info = kernel_info.unwrap_or_else(|| {
+            PendingSignal::thread_directed(sender.as_ref().map_or(0, |task| task.tgid()))
+        });
+        let info = super::pid_view::signal_info_for(&graph, info, &target, signal, from_ancestor);
+        let queued = if target.ignores_generated_signal_as_init(signal, from_ancestor) {
             false
         } else {
             target
@@ -152,14 +156,16 @@ fn send_selected_thread_signal(
 
 /// @description 按 Linux kill pid selector 向每个匹配 Process 发布一次 SI_USER signal。
 ///
-/// @param pid `>0` 为 TGID，`0` 为 caller PGID，`-1` 为除 init/caller 外全部，`<-1` 为 PGID。
+/// @param pid caller PID namespace 视角：`>0` 为 TGID，`0` 为 caller PGID，`-1` 为除 init/caller 外全部，`<-1` 为 PGID。
 /// @param signal Linux signal number；零仅执行 existence/selection probe。
 /// @return 至少一个 live Process 匹配时成功。
 /// @errors signal 非法或没有匹配 live Process。
 pub(crate) fn send_process_signal(pid: i32, signal: usize) -> Result<(), SignalSendError> {
-    let caller = current_task().ok_or(SignalSendError::NotFound)?.tgid();
+    let task = current_task().ok_or(SignalSendError::NotFound)?;
+    let caller = task.tgid();
+    let resolve = |pid: usize| super::resolve_pid(&task, pid).ok_or(SignalSendError::NotFound);
     let selector = match pid {
-        value if value > 0 => ProcessSelector::Process(value as usize),
+        value if value > 0 => ProcessSelector::Process(resolve(value as usize)?),
         0 => {
             let group = TASK_MANAGER
                 .graph
@@ -171,12 +177,12 @@ pub(crate) fn send_process_signal(pid: i32, signal: usize) -> Result<(), SignalS
             ProcessSelector::Group(group)
         }
         -1 => ProcessSelector::AllExcept { caller },
-        value => {
-            ProcessSelector::Group(value.checked_neg().ok_or(SignalSendError::NotFound)? as usize)
-        }
+        value => ProcessSelector::Group(resolve(
+            value.checked_neg().ok_or(SignalSendError::NotFound)? as usize,
+        )?),
     };
     let info = PendingSignal::process_directed(caller);
-    send_selected_processes(selector, signal, info, current_task()).map(|_| ())
+    send_selected_processes(selector, signal, info, Some(task)).map(|_| ())
 }
 
 /// @description 向一个 process group 的每个 live Process 投递一次 kernel-generated signal。
@@ -247,14 +253,20 @@ fn select_and_generate_process_signal(
     sender: Option<&Arc<TaskControlBlock>>,
 ) -> Option<SelectedProcess> {
     let mut graph = TASK_MANAGER.graph.lock();
+    // 子 PID namespace 内的 sender 只能选中同 namespace 成员；kill(-1) 同样排除 namespace init。
+    let view = sender.and_then(|sender| sender.pid_namespace());
     let tgid = graph.nodes.iter_after(&after).find_map(|(&tgid, node)| {
         let selected = match selector {
             ProcessSelector::Process(pid) => tgid == pid,
             ProcessSelector::Group(pgid) => node.process_group == pgid,
-            ProcessSelector::AllExcept { caller } => tgid > INIT_PID && tgid != caller,
+            ProcessSelector::AllExcept { caller } => {
+                tgid != caller && super::pid_view::pid_in_view(&graph, view, tgid) > INIT_PID
+            }
         };
-        (selected && matches!(&node.state, ProcessState::Live(threads) if !threads.is_empty()))
-            .then_some(tgid)
+        (selected
+            && view.is_none_or(|view| node.pid_namespace == Some(view))
+            && matches!(&node.state, ProcessState::Live(threads) if !threads.is_empty()))
+        .then_some(tgid)
     })?;
     let (eligible, queued) = {
         let node = graph
@@ -293,7 +305,10 @@ fn select_and_generate_process_signal(
             .values()
             .find(|thread| thread.accepts_process_signal(signal))
             .cloned();
-        let queued = if representative.ignores_generated_signal_as_init(signal) {
+        let from_ancestor = super::pid_view::sender_is_ancestor(sender, &representative);
+        let info =
+            super::pid_view::signal_info_for(&graph, info, &representative, signal, from_ancestor);
+        let queued = if representative.ignores_generated_signal_as_init(signal, from_ancestor) {
             false
         } else {
             representative
@@ -13,7 +13,7 @@ pub(crate) enum ThreadCloneError {
 /// @param parent_tid 可选 parent TID copyout。
 /// @param child_set_tid 可选 child TID copyout。
 /// @param clear_child_tid 可选 thread exit 清零地址。
-/// @return 成功返回 child TID；任何验证/分配失败都不发布 graph/runqueue membership。
+/// @return 成功返回 caller PID namespace 视角的 child TID；任何验证/分配失败都不发布 graph/runqueue membership。
 pub(crate) fn clone_current_thread(
     stack: usize,
     tls: usize,
@@ -23,9 +23,10 @@ pub(crate) fn clone_current_thread(
 ) -> Result<usize, ThreadCloneError> {
     let parent = current_task().expect("thread clone requires current task");
     let tid = TASK_MANAGER
-        .allocate_pid()
-        .ok_or(ThreadCloneError::ResourceLimit)?
-        .0;
+        .allocate_pid(&parent, false)
+        .ok_or(ThreadCloneError::ResourceLimit)?;
+    let pid_namespace = super::pid_view::NamespacePublication::try_new(&tid)
+        .map_err(|_| ThreadCloneError::Memory(crate::memory::MemoryError::OutOfMemory))?;
     let graph_slot = FallibleMap::<usize, Arc<TaskControlBlock>>::try_reserve_node()
         .map_err(|_| ThreadCloneError::Memory(crate::memory::MemoryError::OutOfMemory))?;
     let thread_index_slot = FallibleMap::<usize, ThreadIndex>::try_reserve_node()
@@ -65,19 +66,26 @@ pub(crate) fn clone_current_thread(
             child.remove_thread_trap_context();
             return Err(ThreadCloneError::ResourceLimit);
         }
-        let membership = graph_slot.fill(tid, child.clone());
+        let membership = graph_slot.fill(child.tid(), child.clone());
         let thread_index = thread_index_slot.fill(
-            tid,
+            child.tid(),
             ThreadIndex {
                 tgid: parent.tgid(),
                 created_children: FallibleMap::new(),
             },
         );
-        TASK_MANAGER.publish_thread(parent.tgid(), child.clone(), membership, thread_index);
+        TASK_MANAGER.publish_thread(
+            parent.tgid(),
+            child.clone(),
+            membership,
+            thread_index,
+            pid_namespace,
+        );
         drop(creation);
         break;
     }
     drop(snapshot);
+    let tid = child.tid_seen_by(&parent);
     parent.write_clone_tid_values([parent_tid, child_set_tid], tid as i32);
     TASK_MANAGER.activate_thread(parent.tgid(), child);
     Ok(tid)
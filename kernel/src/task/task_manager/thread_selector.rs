@@ -18,7 +18,7 @@ pub(super) fn thread_by_tid(
 
 /// @description 解析 Linux scheduler 的零/current 或正数/global TID selector。
 ///
-/// @param tid 零选择 caller；正数选择全局 live Thread。
+/// @param tid 零选择 caller；正数选择 caller PID namespace 中的 live Thread。
 /// @param caller calling Thread 的保活 owner。
 /// @return 命中时返回保活的目标 Thread；Exited Process 不参与选择。
 pub(super) fn scheduler_thread(
@@ -29,6 +29,7 @@ pub(super) fn scheduler_thread(
         return Some(caller.clone());
     }
     let graph = TASK_MANAGER.graph.lock();
+    let tid = super::pid_view::pid_from_view(&graph, caller.pid_namespace(), tid)?;
     thread_by_tid(&graph, tid).map(|(_, thread)| thread)
 }
 
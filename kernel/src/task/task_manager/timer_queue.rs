@@ -234,6 +234,7 @@ impl TimerQueue {
                 entry
             };
             self.deadline_index.commit_vacant(entry);
+            crate::timer::request_event(expiration);
         }
         Some(previous)
     }
@@ -318,6 +319,7 @@ impl TimerQueue {
                 entry
             };
             self.deadline_index.commit_vacant(entry);
+            crate::timer::request_event(expiration);
         }
         Ok(Some(previous))
     }
@@ -382,6 +384,7 @@ impl TimerQueue {
                 if let Some(next) = timer.next_expiration_ns {
                     deadline.set_key((next, identity));
                     self.deadline_index.commit_vacant(deadline);
+                    crate::timer::request_event(next);
                 }
                 Some(ExpiredTimer::Real(tgid))
             }
@@ -397,6 +400,7 @@ impl TimerQueue {
                 if let Some(next) = next {
                     deadline.set_key((next, identity));
                     self.deadline_index.commit_vacant(deadline);
+                    crate::timer::request_event(next);
                 }
                 let notification = match timer.notification {
                     PosixTimerNotification::Default => PosixTimerNotification::Process {
@@ -419,6 +423,13 @@ impl TimerQueue {
         }
     }
 
+    /// @description 返回最早的 ITIMER_REAL 或 POSIX timer expiration。
+    pub(super) fn earliest(&self) -> Option<u64> {
+        self.deadline_index
+            .first_key_value()
+            .map(|(&(expiration, _), _)| expiration)
+    }
+
     pub(super) fn has_expired(&self, now_ns: u64) -> bool {
         self.deadline_index
             .first_key_value()
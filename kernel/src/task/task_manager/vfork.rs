@@ -14,10 +14,11 @@ struct ChildGraphSlots {
     creator_child: crate::fallible_tree::NodeSlot<usize, ()>,
     group_member: crate::fallible_tree::NodeSlot<usize, ()>,
     future_group: crate::fallible_tree::NodeSlot<(usize, usize), ProcessGroupIndex>,
+    pid_namespace: super::pid_view::NamespacePublication,
 }
 
 impl ChildGraphSlots {
-    fn try_new() -> Result<Self, ProcessCloneError> {
+    fn try_new(pid: &crate::task::pid::ProcessId) -> Result<Self, ProcessCloneError> {
         let oom = |_| ProcessCloneError::Memory(crate::memory::MemoryError::OutOfMemory);
         Ok(Self {
             thread: FallibleMap::try_reserve_node().map_err(oom)?,
@@ -27,6 +28,7 @@ impl ChildGraphSlots {
             creator_child: FallibleMap::try_reserve_node().map_err(oom)?,
             group_member: FallibleMap::try_reserve_node().map_err(oom)?,
             future_group: FallibleMap::try_reserve_node().map_err(oom)?,
+            pid_namespace: super::pid_view::NamespacePublication::try_new(pid).map_err(oom)?,
         })
     }
 }
@@ -54,6 +56,13 @@ fn publish_child(
     let session = parent_node.session;
     let process_group = parent_node.process_group;
     let child_tid = child.tid();
+    let pid_namespace = child.pid_namespace();
+    // namespace init 退出后的成员 SIGKILL cursor 可能已越过该 PID；发布与继承同处 graph owner。
+    if slots.pid_namespace.publish(&mut graph, pid, true) {
+        child
+            .queue_signal(core::iter::empty(), 9, PendingSignal::kernel())
+            .expect("kernel SIGKILL must be valid");
+    }
     let mut threads = FallibleMap::new();
     threads.commit_vacant(slots.thread.fill(child_tid, child));
     graph.nodes.commit_vacant(slots.process.fill(
@@ -78,7 +87,9 @@ fn publish_child(
             child_events: ChildEvents::default(),
             child_waiters: FallibleMap::new(),
             child_wait_claim: None,
+            rusage_runtime_us: 0,
             vfork_parent,
+            pid_namespace,
         },
     ));
     graph.threads.commit_vacant(slots.thread_index.fill(
@@ -110,19 +121,20 @@ fn publish_child(
 }
 
 /// @description COW fork 当前单线程 process 并发布 child 到唯一 graph/runqueue。
-/// @return parent 成功获得 child PID；COW/page-table 事务 OOM 时 graph 不发布 child。
+/// @param new_pid_namespace `CLONE_NEWPID`：child 成为新 PID namespace 的 init。
+/// @return parent 成功获得其 namespace 视角的 child PID；COW/page-table 事务 OOM 时 graph 不发布 child。
 /// @errors 地址空间/Process 分配失败返回 Memory，RLIMIT_NPROC/PID namespace 耗尽返回 ResourceLimit。
-pub(crate) fn fork_current_process() -> Result<usize, ProcessCloneError> {
+pub(crate) fn fork_current_process(new_pid_namespace: bool) -> Result<usize, ProcessCloneError> {
     let parent = current_task().expect("fork requires current task");
     let pid = TASK_MANAGER
-        .allocate_pid()
+        .allocate_pid(&parent, new_pid_namespace)
         .ok_or(ProcessCloneError::ResourceLimit)?;
-    let graph_slots = ChildGraphSlots::try_new()?;
+    let graph_slots = ChildGraphSlots::try_new(&pid)?;
     let child = try_allocate_task(
         ProcessCloneError::Memory(crate::memory::MemoryError::OutOfMemory),
         || parent.fork_process(pid).map_err(ProcessCloneError::Memory),
     )?;
-    let child_pid = child.tgid();
+    let child_pid = child.tgid_seen_by(&parent);
     let mut minimum_snapshot_capacity = 0;
     let mut snapshot = match ProcessSlotSnapshot::prepare(minimum_snapshot_capacity) {
         Ok(snapshot) => snapshot,
@@ -167,14 +179,14 @@ pub(crate) fn fork_current_process() -> Result<usize, ProcessCloneError> {
 
 /// @description 发布共享 AddressSpace 的 vfork child，并只阻塞 calling Thread 到 child exec/exit。
 /// @param child_stack musl clone wrapper 提供的 16-byte aligned child SP；零值继承。
-/// @return parent 恢复后获得 child PID；准备失败时不发布 child 或 wait membership。
+/// @return parent 恢复后获得其 namespace 视角的 child PID；准备失败时不发布 child 或 wait membership。
 /// @errors 地址空间/Process 分配失败返回 Memory，RLIMIT_NPROC/PID namespace 耗尽返回 ResourceLimit。
 pub(crate) fn vfork_current_process(child_stack: usize) -> Result<usize, ProcessCloneError> {
     let parent = current_task().expect("vfork requires current task");
     let pid = TASK_MANAGER
-        .allocate_pid()
+        .allocate_pid(&parent, false)
         .ok_or(ProcessCloneError::ResourceLimit)?;
-    let graph_slots = ChildGraphSlots::try_new()?;
+    let graph_slots = ChildGraphSlots::try_new(&pid)?;
     let child = try_allocate_task(
         ProcessCloneError::Memory(crate::memory::MemoryError::OutOfMemory),
         || {
@@ -184,6 +196,7 @@ pub(crate) fn vfork_current_process(child_stack: usize) -> Result<usize, Process
         },
     )?;
     let child_pid = child.tgid();
+    let visible_child_pid = child.tgid_seen_by(&parent);
     let mut minimum_snapshot_capacity = 0;
     let mut snapshot = match ProcessSlotSnapshot::prepare(minimum_snapshot_capacity) {
         Ok(snapshot) => snapshot,
@@ -232,7 +245,7 @@ pub(crate) fn vfork_current_process(child_stack: usize) -> Result<usize, Process
         WaitResult::Woken,
         "vfork parent resumed without child exec/exit completion"
     );
-    Ok(child_pid)
+    Ok(visible_child_pid)
 }
 
 pub(super) fn complete_vfork(child_pid: usize) {
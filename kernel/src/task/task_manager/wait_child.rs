@@ -25,8 +25,12 @@ pub(super) fn take_child_waiters(
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ChildExit {
-    pub(crate) pid: usize,
+    pid: usize,
+    /// waiter 所在 PID namespace 看到的 child TGID。
+    pub(crate) visible_pid: usize,
     pub(crate) status: i32,
+    /// child 自身与其已回收 descendant 的 CPU runtime（Linux `RUSAGE_BOTH`）。
+    pub(crate) runtime_us: u64,
     kind: ChildStatusKind,
     claimant: usize,
 }
@@ -58,13 +62,35 @@ fn matching_child(
     }
 }
 
+/// @description 把 waiter namespace 视角的 wait selector 翻译为全局 PID/PGID。
+/// @return `-1`/`0` 原样返回；namespace 中不存在的 PID/PGID 没有可等待 child。
+fn global_selector(
+    graph: &ProcessGraph,
+    view: Option<usize>,
+    selector: isize,
+) -> Result<isize, WaitChildError> {
+    let (pid, negate) = match selector {
+        -1 | 0 => return Ok(selector),
+        value if value > 0 => (value as usize, false),
+        value => (
+            value.checked_neg().ok_or(WaitChildError::InvalidSelector)? as usize,
+            true,
+        ),
+    };
+    let global = super::pid_view::pid_from_view(graph, view, pid).ok_or(WaitChildError::NoChild)?;
+    Ok(if negate {
+        -(global as isize)
+    } else {
+        global as isize
+    })
+}
+
 fn find_waitable_child(
     graph: &mut ProcessGraph,
     parent: usize,
     claimant: usize,
     selector: isize,
-    include_stopped: bool,
-    include_continued: bool,
+    events: WaitEvents,
 ) -> Result<Option<ChildExit>, WaitChildError> {
     let mut has_child = false;
     let mut selected = None;
@@ -102,35 +128,54 @@ fn find_waitable_child(
         if node.child_wait_claim.is_some() {
             continue;
         }
-        if let ProcessState::Exited(status) = node.state {
-            selected = Some(ChildExit {
-                pid,
-                status: status.wait_status(),
-                kind: ChildStatusKind::Exited,
-                claimant,
-            });
-            break;
-        }
-        if include_stopped && let Some(signal) = node.child_events.stopped {
+        let live_runtime_us = match &node.state {
+            ProcessState::Exited(status) => {
+                if events.exited {
+                    selected = Some(ChildExit {
+                        pid,
+                        visible_pid: pid,
+                        status: status.wait_status(),
+                        runtime_us: node.rusage_runtime_us,
+                        kind: ChildStatusKind::Exited,
+                        claimant,
+                    });
+                    break;
+                }
+                continue;
+            }
+            // Process runtime 是 lock-free 聚合 owner；graph lock 内不得进入 scheduling policy lock。
+            ProcessState::Live(threads) => threads.values().next().map_or(0, |thread| {
+                thread
+                    .process_cpu_runtime_us()
+                    .saturating_add(node.rusage_runtime_us)
+            }),
+        };
+        if events.stopped
+            && let Some(signal) = node.child_events.stopped
+        {
             selected = Some(ChildExit {
                 pid,
+                visible_pid: pid,
                 status: ((signal as i32) << 8) | 0x7f,
+                runtime_us: live_runtime_us,
                 kind: ChildStatusKind::Stopped,
                 claimant,
             });
             break;
         }
-        if include_continued && node.child_events.continued {
+        if events.continued && node.child_events.continued {
             selected = Some(ChildExit {
                 pid,
+                visible_pid: pid,
                 status: 0xffff,
+                runtime_us: live_runtime_us,
                 kind: ChildStatusKind::Continued,
                 claimant,
             });
             break;
         }
     }
-    let Some(record) = selected else {
+    let Some(mut record) = selected else {
         return if has_child {
             Ok(None)
         } else {
@@ -150,21 +195,32 @@ fn find_waitable_child(
             .is_none(),
         "child event claimed twice"
     );
+    let view = graph.nodes.get(&parent).and_then(|node| node.pid_namespace);
+    record.visible_pid = super::pid_view::pid_in_view(graph, view, record.pid);
     Ok(Some(record))
 }
 
+/// @description wait4/waitid 选择的 child event 类别。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct WaitEvents {
+    /// 是否消费 exit record；wait4 恒为真，waitid 由 `WEXITED` 决定。
+    pub(crate) exited: bool,
+    /// 是否消费尚未报告的 job-control stop。
+    pub(crate) stopped: bool,
+    /// 是否消费尚未报告的 continue。
+    pub(crate) continued: bool,
+}
+
 /// @description 等待直接 child 的 exit、stopped 或 continued 状态。
 ///
 /// @param selector `>0` 为 PID，`-1` 为任一 child，`0`/`<-1` 为 process group。
 /// @param nohang 无可消费 record 时是否立即返回。
-/// @param include_stopped 是否消费尚未报告的 job-control stop。
-/// @param include_continued 是否消费尚未报告的 continue。
+/// @param events 允许消费的 child event 类别。
 /// @return child record、WNOHANG 的 None，或 selector/child/interruption 错误。
 pub(crate) fn wait_child(
     selector: isize,
     nohang: bool,
-    include_stopped: bool,
-    include_continued: bool,
+    events: WaitEvents,
 ) -> Result<Option<ChildExit>, WaitChildError> {
     let task = current_task().expect("wait4 requires current task");
     let parent = task.tgid();
@@ -173,14 +229,8 @@ pub(crate) fn wait_child(
         // successful on OOM; only publishing a blocking waiter requires this allocation.
         let waiter = FallibleMap::<usize, Arc<TaskControlBlock>>::try_reserve_node();
         let mut graph = TASK_MANAGER.graph.lock();
-        let record = find_waitable_child(
-            &mut graph,
-            parent,
-            task.tid(),
-            selector,
-            include_stopped,
-            include_continued,
-        )?;
+        let selector = global_selector(&graph, task.pid_namespace(), selector)?;
+        let record = find_waitable_child(&mut graph, parent, task.tid(), selector, events)?;
         match super::wait_publication::child_wait_publication(
             record.is_some(),
             nohang,
@@ -297,15 +347,22 @@ pub(crate) fn consume_child_status(record: ChildExit) {
             )
         };
         if record.kind == ChildStatusKind::Exited {
-            graph.nodes.remove(&record.pid);
+            let reaped = graph
+                .nodes
+                .remove(&record.pid)
+                .expect("reaped child missing from process graph");
+            super::pid_view::release_namespace_process(&mut graph, reaped.pid_namespace);
             if let Some(parent) = parent {
-                graph
+                let parent = graph
                     .nodes
                     .get_mut(&parent)
-                    .expect("reaped child parent missing from graph")
+                    .expect("reaped child parent missing from graph");
+                parent
                     .children
                     .remove(&record.pid)
                     .expect("reaped child missing from parent index");
+                parent.rusage_runtime_us =
+                    parent.rusage_runtime_us.saturating_add(record.runtime_us);
             }
             if let Some(parent_thread) = parent_thread
                 && let Some(index) = graph.threads.get_mut(&parent_thread)
@@ -319,6 +376,14 @@ pub(crate) fn consume_child_status(record: ChildExit) {
                 .members
                 .remove(&record.pid)
                 .expect("reaped child missing from group index");
+            super::pid_view::release_unused_pids(
+                &mut graph,
+                &[
+                    (record.pid, session),
+                    (process_group, session),
+                    (session, session),
+                ],
+            );
         }
         parent
             .and_then(|pid| graph.nodes.get_mut(&pid))
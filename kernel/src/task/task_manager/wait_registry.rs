@@ -136,6 +136,12 @@ impl WaitRegistry {
             "wait registration published twice"
         );
         drop(shards);
+        // index 先于 event 发布；并发 expiry 的重扫描因此要么看到该 deadline，要么被本次发布合并。
+        for key in keys.iter() {
+            if let WaitIndexKey::Deadline { deadline, .. } = key {
+                crate::timer::request_event(*deadline);
+            }
+        }
         drop(keys);
         PublishedWait {
             registration: Some(registration),
@@ -486,6 +492,21 @@ impl WaitRegistry {
         }
     }
 
+    /// @description 返回全部 shard 中最早的 wait deadline。
+    pub(super) fn earliest_deadline(&self) -> Option<u64> {
+        self.shards
+            .iter()
+            .filter_map(|shard| {
+                let entries = shard.lock();
+                let lower = WaitIndexKey::Deadline { deadline: 0, id: 0 };
+                match entries.index.ceiling(&lower) {
+                    Some((WaitIndexKey::Deadline { deadline, .. }, _)) => Some(*deadline),
+                    _ => None,
+                }
+            })
+            .min()
+    }
+
     pub(super) fn has_expired_deadline(&self, now: u64) -> bool {
         self.shards.iter().any(|shard| {
             let entries = shard.lock();
@@ -135,7 +135,8 @@ impl WaitTicket {
         keys.push(WaitIndexKey::Pipe {
             identity,
             direction: pipe_direction(condition.direction()),
-            exclusive: true,
+            // 一个 FIFO peer 的到达必须唤醒全部 blocking opener，与 Linux `wake_up_partner` 一致。
+            exclusive: !matches!(condition, PipeWaitCondition::Partner { .. }),
             id: self.id,
         });
         self.prepare(
@@ -3,7 +3,7 @@ use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use spin::Once;
 
-use crate::{arch, config, cpu, platform};
+use crate::{arch, config, cpu, cpu::CpuSet, platform, sync::LocalIrqGuard};
 
 mod deadline;
 
@@ -14,6 +14,14 @@ static TICK_INTERVAL_VALUE: AtomicU64 = AtomicU64::new(0);
 // 提供 interior mutability。若从 handler 完成时刻重算，延迟会累积并使 scheduler tick 漂移。
 static CPU_DEADLINES: Once<Box<[AtomicU64]>> = Once::new();
 
+// OWNER: timer module 发布全部 wait/timer deadline 中最早的 monotonic ns，`u64::MAX` 表示无。
+// 只有 boot CPU 的 always-armed source 据此提前 arm；缺失时 sleep 被量化到下一 tick。
+// 值允许早于现存最早 deadline（cancel 不回收），代价只是一次提前中断后的重扫描。
+static EVENT_DEADLINE_NS: AtomicU64 = AtomicU64::new(u64::MAX);
+// OWNER: boot CPU 独占写入当前 armed counter，零表示尚未 arm；远端 publisher 只读它决定是否 IPI。
+// 与 EVENT_DEADLINE_NS 成对使用 SeqCst，保证 publisher 与 boot re-arm 至少一方观察到对方。
+static BOOT_ARMED_COUNTER: AtomicU64 = AtomicU64::new(0);
+
 const USEC_PER_SEC: u64 = 1_000_000;
 const NSEC_PER_SEC: u64 = 1_000_000_000;
 
@@ -99,8 +107,12 @@ pub(crate) fn scheduler_quantum_ns() -> u64 {
     (interval as u128 * NSEC_PER_SEC as u128).div_ceil(frequency as u128) as u64
 }
 
+/// @description 沿固定相位推进本地 tick，并 arm 本 CPU 下一次 timer interrupt。
+///
+/// boot CPU arm tick 与最早 event deadline 中较早者；其他 CPU 只 arm tick。
+/// @return 周期 tick 已到期时为 true；false 表示仅 event deadline 到期，不做 tick accounting。
 #[inline(always)]
-pub(crate) fn set_next_timer_interrupt() {
+pub(crate) fn set_next_timer_interrupt() -> bool {
     let current_mtime = arch::time::counter();
     let interval = TICK_INTERVAL_VALUE.load(Ordering::Acquire);
     assert!(
@@ -113,7 +125,76 @@ pub(crate) fn set_next_timer_interrupt() {
         .expect("timer deadline exhausted the time counter");
     state.store(next_mtime, Ordering::Relaxed);
 
-    platform::arm_timer(next_mtime).expect("platform timer programming failed");
+    let armed = if cpu::current_id() == cpu::boot_id() {
+        publish_boot_deadline(next_mtime)
+    } else {
+        next_mtime
+    };
+    platform::arm_timer(armed).expect("platform timer programming failed");
+    next_mtime != previous
+}
+
+/// 发布 boot CPU 的 armed counter；SeqCst 复查使并发 publisher 要么看到新值后 IPI，要么被本次 arm 覆盖。
+fn publish_boot_deadline(next_tick: u64) -> u64 {
+    let frequency = platform::timebase_frequency();
+    loop {
+        let event = deadline::counter_at(EVENT_DEADLINE_NS.load(Ordering::SeqCst), frequency);
+        let armed = next_tick.min(event);
+        BOOT_ARMED_COUNTER.store(armed, Ordering::SeqCst);
+        if deadline::counter_at(EVENT_DEADLINE_NS.load(Ordering::SeqCst), frequency) >= armed {
+            return armed;
+        }
+    }
+}
+
+/// @description 发布一个新的 absolute monotonic deadline，使 boot CPU 不晚于它触发 timer interrupt。
+///
+/// @param deadline_ns wait 或 Process timer 的 absolute monotonic 纳秒 deadline。
+/// @return 无返回值；比 boot armed counter 更早时，boot CPU 本地重 arm，其他 CPU 向 boot CPU 发 IPI。
+/// @errors platform IPI 失败时 fail-stop，不退回 tick 量化。
+pub(crate) fn request_event(deadline_ns: u64) {
+    if EVENT_DEADLINE_NS.fetch_min(deadline_ns, Ordering::SeqCst) <= deadline_ns {
+        return;
+    }
+    let armed = BOOT_ARMED_COUNTER.load(Ordering::SeqCst);
+    // 零表示 boot source 尚未 arm；首次 arm 会读取已发布的 event deadline。
+    if armed == 0 || deadline::counter_at(deadline_ns, platform::timebase_frequency()) >= armed {
+        return;
+    }
+    if cpu::current_id() == cpu::boot_id() {
+        let _irq = LocalIrqGuard::disable();
+        rearm_boot_event();
+    } else {
+        platform::send_ipi(CpuSet::singleton(cpu::boot_id()))
+            .expect("platform IPI failed for timer event");
+    }
+}
+
+/// @description boot CPU 在 software IPI 中按最新 event deadline 提前 arm；其他 CPU 无操作。
+///
+/// @return 无返回值；caller 必须处于 local IRQ 关闭的 trap 或 guard 上下文。
+pub(crate) fn rearm_boot_event() {
+    let previous = BOOT_ARMED_COUNTER.load(Ordering::SeqCst);
+    if previous == 0 || cpu::current_id() != cpu::boot_id() {
+        return;
+    }
+    let next_tick = CPU_DEADLINES.wait()[cpu::current_id().index()].load(Ordering::Relaxed);
+    let armed = publish_boot_deadline(next_tick);
+    if armed < previous {
+        platform::arm_timer(armed).expect("platform timer programming failed");
+    }
+}
+
+/// @description 认领已到期的 event deadline，使 caller 重扫描 owner 并重新发布下一个 deadline。
+///
+/// @param now_ns 本批次固定的 absolute monotonic 纳秒时刻。
+/// @return event 已到期并被重置为空时为 true；caller 必须在此之后扫描全部 deadline owner。
+pub(crate) fn take_due_event(now_ns: u64) -> bool {
+    EVENT_DEADLINE_NS
+        .try_update(Ordering::SeqCst, Ordering::SeqCst, |deadline| {
+            (deadline <= now_ns).then_some(u64::MAX)
+        })
+        .is_ok()
 }
 
 pub(crate) fn enable_timer_interrupt() {
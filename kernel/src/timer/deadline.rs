@@ -19,3 +19,13 @@ pub(crate) fn next(previous: u64, now: u64, interval: u64) -> Option<u64> {
             .and_then(|advance| deadline.checked_add(advance)),
     }
 }
+
+/// @description 把 absolute monotonic 纳秒 deadline 换算为首个不早于它的 counter 值。
+///
+/// @param deadline_ns absolute monotonic 纳秒；`u64::MAX` 表示没有 deadline。
+/// @param frequency 非零 timebase 频率。
+/// @return 向上取整的 counter；超出 counter 可表达范围时饱和为 `u64::MAX`。
+pub(crate) fn counter_at(deadline_ns: u64, frequency: u64) -> u64 {
+    let counter = (deadline_ns as u128 * frequency as u128).div_ceil(1_000_000_000);
+    u64::try_from(counter).unwrap_or(u64::MAX)
+}
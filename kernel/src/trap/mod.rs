@@ -16,6 +16,18 @@ fn handle_supervisor_soft_interrupt() {
     // RISC-V SSIP 必须先 clear 再完成同步 barrier；两步是唯一 trap-owned ack seam。
     arch::interrupt::clear_software();
     crate::task::complete_pending_memory_barrier();
+    timer::rearm_boot_event();
+}
+
+/// 仅 event deadline 到期时只续批 expiry，不计 tick；否则 hrtimer 会缩短 scheduler slice。
+#[inline(always)]
+fn handle_timer_interrupt() {
+    let work = if timer::set_next_timer_interrupt() {
+        DeferredWork::Timer
+    } else {
+        DeferredWork::TimerBacklog
+    };
+    cpu::raise_deferred(work);
 }
 
 #[inline(always)]
@@ -25,8 +37,7 @@ fn handle_claimed_interrupt() {
     match &claimed {
         crate::platform::ClaimedInterrupt::Timer(_) => {
             // 先重置 level timer source，再 EOI；反序会让 GIC 立即重投同一 PPI。
-            timer::set_next_timer_interrupt();
-            cpu::raise_deferred(DeferredWork::Timer);
+            handle_timer_interrupt();
         }
         crate::platform::ClaimedInterrupt::Device(_) => {}
         crate::platform::ClaimedInterrupt::Software(_) => {}
@@ -39,6 +50,7 @@ fn handle_claimed_interrupt() {
         // 必须先 EOI/清除 local pending edge，再读取 barrier request；若反序，远端在
         // completion 与 EOI 之间发布的新 request 可能合并到旧 edge 并永久等待。
         crate::task::complete_pending_memory_barrier();
+        timer::rearm_boot_event();
     }
 }
 
@@ -52,8 +64,7 @@ pub(crate) fn handle_user_trap() -> ! {
     match arch::trap::event() {
         TrapEvent::TimerInterrupt => {
             // 仅重置下一次中断并发布 per-CPU deferred work，不在 hardirq 调度。
-            timer::set_next_timer_interrupt();
-            cpu::raise_deferred(DeferredWork::Timer);
+            handle_timer_interrupt();
         }
         TrapEvent::ExternalInterrupt => {
             handle_claimed_interrupt();
@@ -172,9 +183,16 @@ fn handle_user_page_fault(address: usize, access: crate::memory::PageFaultAccess
         }
         // 物理页耗尽不是 address violation；缺少该分支会把真实 OOM 静默伪装为 SIGSEGV，
         // 让 userspace 无法区分坏指针与无 swap 系统的 memory-pressure termination。
+        // victim 是其他 Process 时在其退出时限内睡眠等待，返回用户态后重新执行 faulting 指令。
         Some(Err(error)) if error.is_out_of_memory() => {
             debug!("user page fault out of memory, VA:{address:#x}");
-            exit_current_group_by_signal(9);
+            let current = task::current_task()
+                .expect("page fault lost current task")
+                .tgid();
+            match task::resolve_fault_out_of_memory(current) {
+                task::OutOfMemoryAction::Retry => task::wait_for_out_of_memory_victim(),
+                task::OutOfMemoryAction::KillCurrent => exit_current_group_by_signal(9),
+            }
         }
         Some(Ok(crate::memory::PageFaultOutcome::SegmentationFault)) | Some(Err(_)) | None => {
             debug!("user page fault, VA:{address:#x}");
@@ -221,9 +239,8 @@ pub(crate) fn trap_return() -> ! {
 pub(crate) fn handle_kernel_trap() {
     match arch::trap::event() {
         TrapEvent::TimerInterrupt => {
-            timer::set_next_timer_interrupt();
             // kernel/user timer 使用同一 per-CPU softirq；hardirq 不扫描任务表或分配。
-            cpu::raise_deferred(DeferredWork::Timer);
+            handle_timer_interrupt();
         }
         TrapEvent::ExternalInterrupt => {
             // 内核态同步 I/O 可以被 external IRQ 打断；此处只确认 platform
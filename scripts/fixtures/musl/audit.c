@@ -0,0 +1,140 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <linux/audit.h>
+#include <linux/netlink.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/socket.h>
+#include <sys/syscall.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+#if defined(__riscv)
+#define LOCAL_AUDIT_ARCH AUDIT_ARCH_RISCV64
+#else
+#define LOCAL_AUDIT_ARCH AUDIT_ARCH_AARCH64
+#endif
+
+struct audit_message {
+	struct nlmsghdr header;
+	union {
+		struct audit_status status;
+		struct audit_rule_data rule;
+		struct nlmsgerr error;
+		char text[1024];
+	};
+};
+
+static unsigned int sequence;
+
+static int audit_socket(void)
+{
+	return socket(AF_NETLINK, SOCK_RAW | SOCK_CLOEXEC, NETLINK_AUDIT);
+}
+
+static int audit_send(int fd, int type, int flags, const void *payload, size_t length)
+{
+	struct audit_message message = { 0 };
+	struct sockaddr_nl kernel = { .nl_family = AF_NETLINK };
+	message.header.nlmsg_len = NLMSG_LENGTH(length);
+	message.header.nlmsg_type = type;
+	message.header.nlmsg_flags = NLM_F_REQUEST | flags;
+	message.header.nlmsg_seq = ++sequence;
+	memcpy(NLMSG_DATA(&message.header), payload, length);
+	return sendto(fd, &message, message.header.nlmsg_len, 0, (struct sockaddr *)&kernel,
+	              sizeof kernel) == (ssize_t)message.header.nlmsg_len ? 0 : -1;
+}
+
+static int audit_receive(int fd, struct audit_message *message)
+{
+	ssize_t count = recv(fd, message, sizeof *message, 0);
+	if (count < (ssize_t)NLMSG_HDRLEN || message->header.nlmsg_len != (size_t)count) return -1;
+	return message->header.nlmsg_type;
+}
+
+/* Sends one request with NLM_F_ACK and returns the errno carried by NLMSG_ERROR. */
+static int audit_request(int fd, int type, const void *payload, size_t length)
+{
+	struct audit_message reply;
+	if (audit_send(fd, type, NLM_F_ACK, payload, length) != 0) return 1000;
+	if (audit_receive(fd, &reply) != NLMSG_ERROR) return 1001;
+	if (reply.error.msg.nlmsg_type != type || reply.error.msg.nlmsg_seq != sequence) return 1002;
+	return -reply.error.error;
+}
+
+static int unprivileged_request(void)
+{
+	struct audit_message reply;
+	if (setuid(65534) != 0) return 1;
+	int fd = audit_socket();
+	if (fd < 0 || audit_send(fd, AUDIT_GET, 0, 0, 0) != 0) return 2;
+	if (audit_receive(fd, &reply) != NLMSG_ERROR || reply.error.error != -EPERM) return 3;
+	return 0;
+}
+
+int verify_audit(void)
+{
+	struct audit_message reply;
+	struct audit_status status = { 0 };
+	struct audit_rule_data rule = { 0 };
+	char expected[64];
+	int code;
+
+	/* 1. AUDIT_GET reports the status snapshot as a plain reply. */
+	int fd = audit_socket();
+	if (fd < 0) return 1;
+	if (audit_send(fd, AUDIT_GET, 0, 0, 0) != 0 || audit_receive(fd, &reply) != AUDIT_GET)
+		return 2;
+	if (reply.header.nlmsg_len != NLMSG_LENGTH(sizeof reply.status)) return 3;
+
+	/* 2. The caller enables auditing and registers itself as the daemon. */
+	status.mask = AUDIT_STATUS_ENABLED | AUDIT_STATUS_PID;
+	status.enabled = 1;
+	status.pid = getpid();
+	if (audit_request(fd, AUDIT_SET, &status, sizeof status) != 0) return 4;
+
+	/* 3. An exit rule selects getppid for this process; duplicates are rejected. */
+	rule.flags = AUDIT_FILTER_EXIT;
+	rule.action = AUDIT_ALWAYS;
+	rule.mask[SYS_getppid / 32] = 1u << (SYS_getppid % 32);
+	rule.field_count = 2;
+	rule.fields[0] = AUDIT_ARCH;
+	rule.values[0] = LOCAL_AUDIT_ARCH;
+	rule.fieldflags[0] = AUDIT_EQUAL;
+	rule.fields[1] = AUDIT_PID;
+	rule.values[1] = getpid();
+	rule.fieldflags[1] = AUDIT_EQUAL;
+	if (audit_request(fd, AUDIT_ADD_RULE, &rule, sizeof rule) != 0) return 5;
+	if (audit_request(fd, AUDIT_ADD_RULE, &rule, sizeof rule) != EEXIST) return 6;
+
+	/* 4. AUDIT_LIST_RULES returns the rule as a multipart dump. */
+	if (audit_send(fd, AUDIT_LIST_RULES, 0, 0, 0) != 0) return 7;
+	if (audit_receive(fd, &reply) != AUDIT_LIST_RULES || !(reply.header.nlmsg_flags & NLM_F_MULTI))
+		return 8;
+	if (memcmp(&reply.rule, &rule, sizeof rule) != 0) return 9;
+	if (audit_receive(fd, &reply) != NLMSG_DONE) return 10;
+
+	/* 5. The audited syscall queues one AUDIT_SYSCALL record for the daemon. */
+	syscall(SYS_getppid);
+	if (audit_receive(fd, &reply) != AUDIT_SYSCALL) return 11;
+	reply.text[reply.header.nlmsg_len - NLMSG_HDRLEN] = '\0';
+	snprintf(expected, sizeof expected, " syscall=%d success=yes", SYS_getppid);
+	if (!strstr(reply.text, expected)) return 12;
+	snprintf(expected, sizeof expected, " pid=%d ", getpid());
+	if (!strstr(reply.text, expected)) return 13;
+
+	/* 6. Audit control requires CAP_AUDIT_CONTROL. */
+	pid_t child = fork();
+	if (child == 0) _exit(unprivileged_request());
+	if (waitpid(child, &code, 0) != child || !WIFEXITED(code)) return 14;
+	if (WEXITSTATUS(code)) return 20 + WEXITSTATUS(code);
+
+	/* 7. Removing the rule twice reports ENOENT; then auditing is switched off. */
+	if (audit_request(fd, AUDIT_DEL_RULE, &rule, sizeof rule) != 0) return 15;
+	if (audit_request(fd, AUDIT_DEL_RULE, &rule, sizeof rule) != ENOENT) return 16;
+	status.enabled = 0;
+	status.pid = 0;
+	if (audit_request(fd, AUDIT_SET, &status, sizeof status) != 0) return 17;
+	close(fd);
+	return 0;
+}
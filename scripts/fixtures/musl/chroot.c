@@ -0,0 +1,96 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <fcntl.h>
+#include <limits.h>
+#include <string.h>
+#include <sys/stat.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+static const char jail_path[] = "/chroot-jail";
+
+static int wait_child_ok(pid_t child)
+{
+	int status;
+	return child > 0 && waitpid(child, &status, 0) == child
+		&& WIFEXITED(status) && WEXITSTATUS(status) == 0;
+}
+
+static int read_marker(const char *path)
+{
+	char byte;
+	int fd = open(path, O_RDONLY);
+	int ok = fd >= 0 && read(fd, &byte, 1) == 1 && byte == 'J';
+	if (fd >= 0 && close(fd) != 0) ok = 0;
+	return ok;
+}
+
+static int jailed_view(void)
+{
+	char cwd[PATH_MAX];
+
+	/* 1. chroot keeps cwd; a cwd left outside the new root has no reachable getcwd path. */
+	if (chdir("/") != 0 || chroot(jail_path) != 0) return 1;
+	errno = 0;
+	if (getcwd(cwd, sizeof cwd) || errno != ENOENT) return 2;
+
+	/* 2. Absolute paths, absolute symlink targets and ".." all stop at the new root. */
+	if (chdir("/") != 0 || !getcwd(cwd, sizeof cwd) || strcmp(cwd, "/") != 0) return 3;
+	if (!read_marker("/../../jail-only") || !read_marker("/link")) return 4;
+	errno = 0;
+	if (access(jail_path, F_OK) == 0 || errno != ENOENT) return 5;
+	if (chdir("inner/../..") != 0 || !getcwd(cwd, sizeof cwd) || strcmp(cwd, "/") != 0) return 6;
+	if (chdir("/inner") != 0 || !getcwd(cwd, sizeof cwd) || strcmp(cwd, "/inner") != 0) return 7;
+
+	/* 3. fork inherits the root together with the cwd. */
+	pid_t child = fork();
+	if (child == 0) _exit(read_marker("../jail-only") && access(jail_path, F_OK) != 0 ? 0 : 1);
+	return wait_child_ok(child) ? 0 : 8;
+}
+
+static int unprivileged_chroot(void)
+{
+	/* 1. Lookup and type errors precede the privilege check, as in Linux. */
+	errno = 0;
+	if (chroot("/chroot-jail/jail-only") == 0 || errno != ENOTDIR) return 1;
+	if (setuid(65534) != 0) return 2;
+	errno = 0;
+	if (chroot(jail_path) == 0 || errno != EPERM) return 3;
+	return 0;
+}
+
+static int run_child(int (*check)(void))
+{
+	pid_t child = fork();
+	if (child == 0) _exit(check());
+	int status;
+	if (child <= 0 || waitpid(child, &status, 0) != child || !WIFEXITED(status)) return 9;
+	return WEXITSTATUS(status);
+}
+
+int verify_chroot(void)
+{
+	int fd;
+	int result;
+
+	/* 1. Build a jail whose files do not exist in the namespace root. */
+	unlink("/chroot-jail/link");
+	unlink("/chroot-jail/jail-only");
+	rmdir("/chroot-jail/inner");
+	rmdir(jail_path);
+	if (mkdir(jail_path, 0755) != 0 || mkdir("/chroot-jail/inner", 0755) != 0) return 1;
+	fd = open("/chroot-jail/jail-only", O_WRONLY | O_CREAT | O_EXCL, 0644);
+	if (fd < 0 || write(fd, "J", 1) != 1 || close(fd) != 0) return 2;
+	if (symlink("/jail-only", "/chroot-jail/link") != 0 || access("/jail-only", F_OK) == 0) return 3;
+
+	/* 2. Jailed and unprivileged checks run in children so this process keeps its root. */
+	result = run_child(jailed_view);
+	if (result) result += 10;
+	if (!result) {
+		result = run_child(unprivileged_chroot);
+		if (result) result += 20;
+	}
+	if ((unlink("/chroot-jail/link") != 0 || unlink("/chroot-jail/jail-only") != 0
+	     || rmdir("/chroot-jail/inner") != 0 || rmdir(jail_path) != 0) && !result) result = 98;
+	return result;
+}
@@ -0,0 +1,140 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <fcntl.h>
+#include <sys/stat.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+enum { FIFO_READERS = 4 };
+
+static const char fifo_path[] = "/fifo-open";
+
+static int wait_child_ok(pid_t child)
+{
+	int status;
+	return child > 0 && waitpid(child, &status, 0) == child
+		&& WIFEXITED(status) && WEXITSTATUS(status) == 0;
+}
+
+static int verify_nonblocking_open(void)
+{
+	struct stat metadata;
+	char byte;
+	int reader;
+	int writer;
+
+	/* 1. mkfifo publishes a FIFO inode; a writer without readers must not block or succeed. */
+	unlink(fifo_path);
+	if (mkfifo(fifo_path, 0600) != 0 || stat(fifo_path, &metadata) != 0
+	    || !S_ISFIFO(metadata.st_mode)) return 1;
+	errno = 0;
+	if (open(fifo_path, O_WRONLY | O_NONBLOCK) != -1 || errno != ENXIO) return 2;
+
+	/* 2. A nonblocking reader opens alone and reads EOF until a writer appears. */
+	reader = open(fifo_path, O_RDONLY | O_NONBLOCK);
+	if (reader < 0 || read(reader, &byte, 1) != 0) return 3;
+	writer = open(fifo_path, O_WRONLY | O_NONBLOCK);
+	if (writer < 0) return 4;
+	errno = 0;
+	if (read(reader, &byte, 1) != -1 || errno != EAGAIN) return 5;
+	if (write(writer, "N", 1) != 1 || close(writer) != 0
+	    || read(reader, &byte, 1) != 1 || byte != 'N'
+	    || read(reader, &byte, 1) != 0 || close(reader) != 0) return 6;
+	return 0;
+}
+
+static int verify_blocking_rendezvous(void)
+{
+	char byte;
+	int reader;
+	pid_t child;
+
+	/* 1. The blocking writer and reader wait for each other in either arrival order. */
+	child = fork();
+	if (child == 0) {
+		int writer = open(fifo_path, O_WRONLY);
+		if (writer < 0) _exit(10);
+		if (write(writer, "F", 1) != 1) _exit(11);
+		_exit(close(writer) == 0 ? 0 : 12);
+	}
+	if (child <= 0) return 1;
+	reader = open(fifo_path, O_RDONLY);
+	if (reader < 0) return 2;
+
+	/* 2. Data written before the last writer closes stays readable, then EOF follows. */
+	if (read(reader, &byte, 1) != 1 || byte != 'F'
+	    || read(reader, &byte, 1) != 0 || !wait_child_ok(child)
+	    || close(reader) != 0) return 3;
+	return 0;
+}
+
+static int verify_reader_race(void)
+{
+	pid_t children[FIFO_READERS];
+	int writer;
+	int index;
+	int result = 0;
+
+	/* 1. Several readers race the writer's blocking open; any reader arrival completes it. */
+	for (index = 0; index < FIFO_READERS; index++) {
+		children[index] = fork();
+		if (children[index] == 0) {
+			char byte;
+			int reader = open(fifo_path, O_RDONLY);
+			if (reader < 0) _exit(20);
+			if (read(reader, &byte, 1) != 1 || byte != 'R') _exit(21);
+			_exit(close(reader) == 0 ? 0 : 22);
+		}
+		if (children[index] <= 0) return 1;
+	}
+	writer = open(fifo_path, O_WRONLY);
+	if (writer < 0) return 2;
+
+	/* 2. The writer stays open until every reader consumed its byte, so late readers never see EOF. */
+	for (index = 0; index < FIFO_READERS; index++) {
+		if (write(writer, "R", 1) != 1) result = 3;
+	}
+	for (index = 0; index < FIFO_READERS; index++) {
+		if (!wait_child_ok(children[index]) && !result) result = 4;
+	}
+	if (close(writer) != 0 && !result) result = 5;
+	return result;
+}
+
+static int verify_read_write_open(void)
+{
+	char byte;
+	int duplex;
+	int reader;
+
+	/* 1. A blocking O_RDWR open counts as both ends, so it never waits for a partner. */
+	duplex = open(fifo_path, O_RDWR);
+	if (duplex < 0) return 1;
+	if (write(duplex, "D", 1) != 1 || read(duplex, &byte, 1) != 1 || byte != 'D') return 2;
+
+	/* 2. Its writer registration lets a blocking reader open at once; the reader sees no EOF. */
+	reader = open(fifo_path, O_RDONLY);
+	if (reader < 0) return 3;
+	if (write(duplex, "W", 1) != 1 || read(reader, &byte, 1) != 1 || byte != 'W') return 4;
+	if (close(duplex) != 0 || read(reader, &byte, 1) != 0 || close(reader) != 0) return 5;
+	return 0;
+}
+
+int verify_fifo_open(void)
+{
+	int result = verify_nonblocking_open();
+	if (!result) {
+		result = verify_blocking_rendezvous();
+		if (result) result += 10;
+	}
+	if (!result) {
+		result = verify_reader_race();
+		if (result) result += 20;
+	}
+	if (!result) {
+		result = verify_read_write_open();
+		if (result) result += 30;
+	}
+	if (unlink(fifo_path) != 0 && !result) result = 99;
+	return result;
+}
@@ -0,0 +1,76 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <string.h>
+#include <sys/syscall.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+/* musl has no keyutils wrappers; these values come from Linux <linux/keyctl.h>. */
+enum {
+	KEY_SPEC_USER_KEYRING = -4,
+	KEYCTL_READ = 11,
+	KEYCTL_INVALIDATE = 21,
+};
+
+static const char description[] = "liteos-smoke-session";
+static const char token[] = "session-token-42";
+
+static long add_user_key(const void *payload, size_t length)
+{
+	return syscall(SYS_add_key, "user", description, payload, length, KEY_SPEC_USER_KEYRING);
+}
+
+static long request_user_key(void)
+{
+	return syscall(SYS_request_key, "user", description, NULL, 0);
+}
+
+static long read_key(long key, char *buffer, size_t capacity)
+{
+	return syscall(SYS_keyctl, KEYCTL_READ, key, buffer, capacity);
+}
+
+static int other_uid_read(long key)
+{
+	char buffer[sizeof token];
+
+	/* Keys live in the owner's user keyring; another UID can neither find nor read them. */
+	if (setuid(65534) != 0) return 1;
+	errno = 0;
+	if (read_key(key, buffer, sizeof buffer) != -1 || errno != EACCES) return 2;
+	errno = 0;
+	if (request_user_key() != -1 || errno != ENOKEY) return 3;
+	return 0;
+}
+
+int verify_keyring(void)
+{
+	char buffer[sizeof token];
+	int status;
+
+	/* 1. add_key creates a user key that request_key finds in the caller's user keyring. */
+	long key = add_user_key(token, sizeof token - 1);
+	if (key <= 0) return 1;
+	if (request_user_key() != key) return 2;
+
+	/* 2. A short buffer receives only a prefix while READ still reports the full length. */
+	memset(buffer, 0x5a, sizeof buffer);
+	if (read_key(key, buffer, 4) != (long)(sizeof token - 1)) return 3;
+	if (memcmp(buffer, token, 4) != 0 || buffer[4] != 0x5a) return 4;
+	if (read_key(key, buffer, sizeof buffer) != (long)(sizeof token - 1)) return 5;
+	if (memcmp(buffer, token, sizeof token - 1) != 0) return 6;
+
+	/* 3. After setuid the same serial is refused with EACCES. */
+	pid_t child = fork();
+	if (child == 0) _exit(other_uid_read(key));
+	if (child < 0 || waitpid(child, &status, 0) != child || !WIFEXITED(status)) return 7;
+	if (WEXITSTATUS(status) != 0) return 20 + WEXITSTATUS(status);
+
+	/* 4. INVALIDATE removes the key at once; read and search then report ENOKEY. */
+	if (syscall(SYS_keyctl, KEYCTL_INVALIDATE, key) != 0) return 8;
+	errno = 0;
+	if (read_key(key, buffer, sizeof buffer) != -1 || errno != ENOKEY) return 9;
+	errno = 0;
+	if (request_user_key() != -1 || errno != ENOKEY) return 10;
+	return 0;
+}
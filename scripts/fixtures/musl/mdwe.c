@@ -0,0 +1,79 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <stddef.h>
+#include <sys/mman.h>
+#include <sys/prctl.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+/* musl may predate Linux 6.3; these values come from Linux <linux/prctl.h>. */
+#ifndef PR_SET_MDWE
+#define PR_SET_MDWE 65
+#define PR_GET_MDWE 66
+#endif
+#ifndef PR_MDWE_REFUSE_EXEC_GAIN
+#define PR_MDWE_REFUSE_EXEC_GAIN 1
+#endif
+
+enum { PAGE_BYTES = 4096 };
+
+static void *map_page(int protection)
+{
+	return mmap(NULL, PAGE_BYTES, protection, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+}
+
+static int refused(int result)
+{
+	return result == -1 && errno == EACCES;
+}
+
+static int report_inherited(void)
+{
+	pid_t child = fork();
+	int status = 0;
+
+	if (child == 0) _exit(prctl(PR_GET_MDWE, 0, 0, 0, 0) == PR_MDWE_REFUSE_EXEC_GAIN ? 0 : 1);
+	return child > 0 && waitpid(child, &status, 0) == child && WIFEXITED(status)
+		&& WEXITSTATUS(status) == 0;
+}
+
+/* PR_SET_MDWE cannot be undone, so the opt-in runs in a child of the smoke process. */
+static int deny_write_exec(void)
+{
+	void *jit = map_page(PROT_READ | PROT_WRITE);
+	void *text = map_page(PROT_READ | PROT_EXEC);
+
+	if (jit == MAP_FAILED || text == MAP_FAILED) return 1;
+	if (prctl(PR_SET_MDWE, PR_MDWE_REFUSE_EXEC_GAIN, 0, 0, 0) != 0) return 2;
+	if (prctl(PR_GET_MDWE, 0, 0, 0, 0) != PR_MDWE_REFUSE_EXEC_GAIN) return 3;
+	errno = 0;
+	if (prctl(PR_SET_MDWE, 0, 0, 0, 0) != -1 || errno != EPERM) return 4;
+	errno = 0;
+	if (map_page(PROT_READ | PROT_WRITE | PROT_EXEC) != MAP_FAILED || errno != EACCES) return 5;
+	/* Exec gain: the write-then-R|X JIT sequence is refused as well. */
+	errno = 0;
+	if (!refused(mprotect(jit, PAGE_BYTES, PROT_READ | PROT_EXEC))) return 6;
+	errno = 0;
+	if (!refused(mprotect(text, PAGE_BYTES, PROT_READ | PROT_WRITE | PROT_EXEC))) return 7;
+	if (mprotect(text, PAGE_BYTES, PROT_READ) != 0) return 8;
+	errno = 0;
+	if (!refused(mprotect(text, PAGE_BYTES, PROT_READ | PROT_EXEC))) return 9;
+	if (!report_inherited()) return 10;
+	return 0;
+}
+
+int verify_mdwe(void)
+{
+	/* Like Linux, a process that has not opted in may create W+X mappings. */
+	void *mapping = map_page(PROT_READ | PROT_WRITE | PROT_EXEC);
+	pid_t child;
+	int status = 0;
+
+	if (prctl(PR_GET_MDWE, 0, 0, 0, 0) != 0) return 20;
+	if (mapping == MAP_FAILED) return 21;
+	if (munmap(mapping, PAGE_BYTES) != 0) return 22;
+	child = fork();
+	if (child == 0) _exit(deny_write_exec());
+	if (child < 0 || waitpid(child, &status, 0) != child || !WIFEXITED(status)) return 23;
+	return WEXITSTATUS(status);
+}
@@ -20,6 +20,7 @@ static pthread_mutex_t mutex = PTHREAD_MUTEX_INITIALIZER;
 static pthread_cond_t condition = PTHREAD_COND_INITIALIZER;
 static int interrupt_futex;
 static _Atomic int restart_futex;
+static int restart_pipe[2];
 static int state;
 static pid_t main_tid;
 static volatile sig_atomic_t signal_count;
@@ -27,6 +28,14 @@ static volatile sig_atomic_t signal_count;
 enum { FUTEX_WAIT_PRIVATE = 128, FUTEX_WAKE_PRIVATE = 129 };
 
 int verify_shared_sync(void);
+int verify_splice(void);
+int verify_fifo_open(void);
+int verify_chroot(void);
+int verify_pid_namespace(void);
+int verify_audit(void);
+int verify_waitid(void);
+int verify_keyring(void);
+int verify_mdwe(void);
 
 static void signal_handler(int signal)
 {
@@ -65,12 +74,95 @@ static void *restart_futex_main(void *argument)
 	return argument;
 }
 
+static void *restart_pipe_main(void *argument)
+{
+	const struct timespec delay = { .tv_sec = 0, .tv_nsec = 20 * 1000 * 1000 };
+	if (nanosleep(&delay, 0) != 0) return 0;
+	if (syscall(SYS_tgkill, getpid(), main_tid, SIGUSR1) != 0) return 0;
+	if (nanosleep(&delay, 0) != 0) return 0;
+	if (write(restart_pipe[1], "R", 1) != 1) return 0;
+	return argument;
+}
+
 static void *group_exit_worker(void *argument)
 {
 	(void)argument;
 	for (;;) sched_yield();
 }
 
+static int timespec_before(struct timespec left, struct timespec right)
+{
+	return left.tv_sec < right.tv_sec || (left.tv_sec == right.tv_sec && left.tv_nsec < right.tv_nsec);
+}
+
+/* Runs in a single-threaded child so the blocked SIGALRM cannot reach another thread. */
+static int verify_boottime(void)
+{
+	struct timespec resolution, monotonic, boottime, later;
+	struct sigevent event = { .sigev_notify = SIGEV_SIGNAL, .sigev_signo = SIGALRM };
+	const struct itimerspec expiry = { .it_value = { .tv_nsec = 10 * 1000 * 1000 } };
+	const struct timespec timeout = { .tv_sec = 1 };
+	sigset_t alarm;
+	timer_t timer;
+
+	/* 1. CLOCK_BOOTTIME reports a sub-second resolution. */
+	if (clock_getres(CLOCK_BOOTTIME, &resolution) != 0 || resolution.tv_sec != 0
+	    || resolution.tv_nsec <= 0) return 1;
+	/* 2. Boot time covers all monotonic time plus any suspend and never runs backwards. */
+	if (clock_gettime(CLOCK_MONOTONIC, &monotonic) != 0
+	    || clock_gettime(CLOCK_BOOTTIME, &boottime) != 0
+	    || clock_gettime(CLOCK_BOOTTIME, &later) != 0) return 2;
+	if (timespec_before(boottime, monotonic) || timespec_before(later, boottime)) return 3;
+	/* 3. A CLOCK_BOOTTIME timer delivers its expiry signal. */
+	sigemptyset(&alarm);
+	sigaddset(&alarm, SIGALRM);
+	if (sigprocmask(SIG_BLOCK, &alarm, NULL) != 0) return 4;
+	if (timer_create(CLOCK_BOOTTIME, &event, &timer) != 0) return 5;
+	if (timer_settime(timer, 0, &expiry, NULL) != 0) return 6;
+	if (sigtimedwait(&alarm, NULL, &timeout) != SIGALRM) return 7;
+	return timer_delete(timer) == 0 ? 0 : 8;
+}
+
+static int verify_boottime_in_child(void)
+{
+	int child_status;
+	pid_t child = fork();
+
+	if (child == 0) _exit(verify_boottime());
+	if (child > 0 && waitpid(child, &child_status, 0) == child && WIFEXITED(child_status))
+		return WEXITSTATUS(child_status);
+	return 9;
+}
+
+/* Each check returns zero or its first failing step; the smoke exits with the check's own code. */
+static const struct smoke_check {
+	const char *failure;
+	int (*verify)(void);
+	int exit_code;
+} smoke_checks[] = {
+	{ "LiteOS musl shared sync failed\n", verify_shared_sync, 13 },
+	{ "LiteOS musl splice failed\n", verify_splice, 15 },
+	{ "LiteOS musl fifo open failed\n", verify_fifo_open, 16 },
+	{ "LiteOS musl chroot failed\n", verify_chroot, 17 },
+	{ "LiteOS musl pid namespace failed\n", verify_pid_namespace, 18 },
+	{ "LiteOS musl audit failed\n", verify_audit, 19 },
+	{ "LiteOS musl waitid failed\n", verify_waitid, 20 },
+	{ "LiteOS musl keyring failed\n", verify_keyring, 21 },
+	{ "LiteOS musl boottime clock failed\n", verify_boottime_in_child, 22 },
+	{ "LiteOS musl mdwe failed\n", verify_mdwe, 23 },
+};
+
+static void report_result(const char *failure, int code)
+{
+	char result_code[3] = {
+		(char)('0' + code / 10 % 10),
+		(char)('0' + code % 10),
+		'\n',
+	};
+	write(STDOUT_FILENO, failure, strlen(failure));
+	write(STDOUT_FILENO, result_code, sizeof result_code);
+}
+
 int main(int argc, char **argv, char **envp)
 {
 	if (argc == 2 && argv && argv[1] && strcmp(argv[1], "setpgid-child") == 0) {
@@ -89,9 +181,9 @@ int main(int argc, char **argv, char **envp)
 	static const char restart_futex_failed[] = "LiteOS musl restart futex failed\n";
 	static const char restart_wait_failed[] = "LiteOS musl restart wait failed\n";
 	static const char restart_sleep_failed[] = "LiteOS musl restart sleep failed\n";
+	static const char restart_pipe_failed[] = "LiteOS musl restart pipe read failed\n";
 	static const char sigwait_failed[] = "LiteOS musl sigwait failed\n";
 	static const char group_exit_failed[] = "LiteOS musl group exit failed\n";
-	static const char shared_sync_failed[] = "LiteOS musl shared sync failed\n";
 	static const char tty_failed[] = "LiteOS musl tty session failed\n";
 	static const char pipe_failed[] = "LiteOS musl pipe readv failed\n";
 	static const char cwd_failed[] = "LiteOS musl cwd failed\n";
@@ -381,6 +473,20 @@ int main(int argc, char **argv, char **envp)
 		write(STDOUT_FILENO, restart_sleep_failed, sizeof restart_sleep_failed - 1);
 		return 10;
 	}
+	char restart_byte = 0;
+	if (pipe(restart_pipe) != 0
+	    || pthread_create(&thread, 0, restart_pipe_main, (void *)(uintptr_t)0x534b) != 0) {
+		write(STDOUT_FILENO, restart_setup_failed, sizeof restart_setup_failed - 1);
+		return 10;
+	}
+	/* SA_RESTART: the blocked pipe read runs the handler, then resumes instead of failing with EINTR. */
+	if (read(restart_pipe[0], &restart_byte, 1) != 1 || restart_byte != 'R' || signal_count != 7
+	    || pthread_join(thread, &thread_result) != 0
+	    || thread_result != (void *)(uintptr_t)0x534b
+	    || close(restart_pipe[0]) != 0 || close(restart_pipe[1]) != 0) {
+		write(STDOUT_FILENO, restart_pipe_failed, sizeof restart_pipe_failed - 1);
+		return 10;
+	}
 	sigemptyset(&wait_set);
 	sigaddset(&wait_set, SIGUSR2);
 	sigaddset(&wait_set, SIGCHLD);
@@ -430,16 +536,12 @@ int main(int argc, char **argv, char **envp)
 		write(STDOUT_FILENO, group_exit_failed, sizeof group_exit_failed - 1);
 		return 12;
 	}
-	int shared_sync_result = verify_shared_sync();
-	if (shared_sync_result != 0) {
-		char result_code[3] = {
-			(char)('0' + shared_sync_result / 10),
-			(char)('0' + shared_sync_result % 10),
-			'\n',
-		};
-		write(STDOUT_FILENO, shared_sync_failed, sizeof shared_sync_failed - 1);
-		write(STDOUT_FILENO, result_code, sizeof result_code);
-		return 13;
+	for (size_t index = 0; index < sizeof smoke_checks / sizeof smoke_checks[0]; index++) {
+		int result = smoke_checks[index].verify();
+		if (result != 0) {
+			report_result(smoke_checks[index].failure, result);
+			return smoke_checks[index].exit_code;
+		}
 	}
 	if (write(STDOUT_FILENO, message, sizeof message - 1) != sizeof message - 1) return 14;
 	return 0;
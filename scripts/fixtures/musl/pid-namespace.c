@@ -0,0 +1,94 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <sched.h>
+#include <signal.h>
+#include <sys/syscall.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+static pid_t outer_pid;
+static int zap_pipe[2];
+
+static pid_t clone_new_pid(void)
+{
+	return (pid_t)syscall(SYS_clone, CLONE_NEWPID | SIGCHLD, 0, 0, 0, 0);
+}
+
+static int wait_status(pid_t child, int *code)
+{
+	int status;
+	if (child <= 0 || waitpid(child, &status, 0) != child || !WIFEXITED(status)) return 0;
+	*code = WEXITSTATUS(status);
+	return 1;
+}
+
+static int namespace_init(void)
+{
+	int code;
+
+	/* 1. The first process is pid 1 and its parent lies outside the namespace. */
+	if (getpid() != 1 || getppid() != 0) return 1;
+
+	/* 2. Members are numbered from 2 and see the namespace init as their parent. */
+	pid_t member = fork();
+	if (member == 0) {
+		if (getpid() != 2 || getppid() != 1) _exit(1);
+		/* The namespace init ignores default-action signals sent from inside. */
+		if (kill(1, SIGTERM) != 0) _exit(2);
+		errno = 0;
+		if (outer_pid > 2 && (kill(outer_pid, 0) == 0 || errno != ESRCH)) _exit(3);
+		_exit(0);
+	}
+	if (member != 2 || !wait_status(member, &code)) return 2;
+	if (code) return 2 + code;
+	return 0;
+}
+
+static int zapped_init(void)
+{
+	/* A member left running when the namespace init exits must receive SIGKILL. */
+	pid_t member = fork();
+	if (member == 0) {
+		close(zap_pipe[0]);
+		for (;;) pause();
+	}
+	close(zap_pipe[1]);
+	return member == 2 ? 0 : 1;
+}
+
+static int unprivileged_clone(void)
+{
+	if (setuid(65534) != 0) return 1;
+	errno = 0;
+	if (clone_new_pid() != -1 || errno != EPERM) return 2;
+	return 0;
+}
+
+int verify_pid_namespace(void)
+{
+	int code;
+	char byte;
+	pid_t child;
+
+	/* 1. The parent waits on the global pid returned by clone. */
+	outer_pid = getpid();
+	child = clone_new_pid();
+	if (child == 0) _exit(namespace_init());
+	if (child <= 2 || !wait_status(child, &code)) return 1;
+	if (code) return 10 + code;
+
+	/* 2. Exiting the namespace init kills every remaining member. */
+	if (pipe(zap_pipe) != 0) return 2;
+	child = clone_new_pid();
+	if (child == 0) _exit(zapped_init());
+	close(zap_pipe[1]);
+	if (!wait_status(child, &code) || code) return 3;
+	if (read(zap_pipe[0], &byte, 1) != 0) return 4;
+	close(zap_pipe[0]);
+
+	/* 3. Creating a namespace requires CAP_SYS_ADMIN. */
+	child = fork();
+	if (child == 0) _exit(unprivileged_clone());
+	if (!wait_status(child, &code)) return 5;
+	return code ? 20 + code : 0;
+}
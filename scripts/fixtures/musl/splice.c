@@ -0,0 +1,184 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <fcntl.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/resource.h>
+#include <sys/sendfile.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+static const char source_path[] = "/splice-source";
+static const char copy_path[] = "/splice-copy";
+static const char payload[] = "splice-round-trip";
+
+enum { PAYLOAD_LENGTH = sizeof payload - 1 };
+
+static int file_matches(int fd, off_t offset, const char *expected, size_t length)
+{
+	char buffer[64];
+	return length <= sizeof buffer && pread(fd, buffer, length, offset) == (ssize_t)length
+		&& memcmp(buffer, expected, length) == 0;
+}
+
+static int verify_round_trip(int pipe_fds[2])
+{
+	loff_t input_offset = 6;
+	loff_t output_offset = 0;
+	int source;
+	int copy;
+
+	/* 1. Explicit input offsets copy out a suffix without moving the input OFD offset. */
+	source = open(source_path, O_RDWR | O_CREAT | O_TRUNC, 0600);
+	copy = open(copy_path, O_RDWR | O_CREAT | O_TRUNC, 0600);
+	if (source < 0 || copy < 0) return 1;
+	if (write(source, payload, PAYLOAD_LENGTH) != PAYLOAD_LENGTH || lseek(source, 0, SEEK_SET) != 0)
+		return 2;
+	if (splice(source, &input_offset, pipe_fds[1], 0, 64, 0) != PAYLOAD_LENGTH - 6) return 3;
+	if (input_offset != PAYLOAD_LENGTH || lseek(source, 0, SEEK_CUR) != 0) return 4;
+
+	/* 2. Draining the pipe into an explicit output offset reproduces the bytes exactly. */
+	if (splice(pipe_fds[0], 0, copy, &output_offset, 64, 0) != PAYLOAD_LENGTH - 6) return 5;
+	if (output_offset != PAYLOAD_LENGTH - 6 || !file_matches(copy, 0, payload + 6, PAYLOAD_LENGTH - 6))
+		return 6;
+
+	/* 3. Shared offsets advance on both files when no offset pointer is supplied. */
+	if (splice(source, 0, pipe_fds[1], 0, 6, 0) != 6 || lseek(source, 0, SEEK_CUR) != 6) return 7;
+	if (lseek(copy, PAYLOAD_LENGTH - 6, SEEK_SET) < 0
+	    || splice(pipe_fds[0], 0, copy, 0, 64, 0) != 6
+	    || !file_matches(copy, PAYLOAD_LENGTH - 6, payload, 6)) return 8;
+
+	/* 4. sendfile into a pipe honours its offset argument and leaves the input OFD alone. */
+	input_offset = 0;
+	if (sendfile(pipe_fds[1], source, &input_offset, 6) != 6 || input_offset != 6
+	    || lseek(source, 0, SEEK_CUR) != 6) return 9;
+	output_offset = 0;
+	if (splice(pipe_fds[0], 0, copy, &output_offset, 64, 0) != 6 || !file_matches(copy, 0, payload, 6))
+		return 10;
+	return close(source) == 0 && close(copy) == 0 ? 0 : 11;
+}
+
+static int verify_partial_drain(int pipe_fds[2])
+{
+	struct rlimit limit = { .rlim_cur = 4, .rlim_max = RLIM_INFINITY };
+	char buffer[PAYLOAD_LENGTH];
+	loff_t output_offset = 0;
+	int copy;
+
+	/* 1. A file-size limit truncates the drain; the unwritten tail stays queued in the pipe. */
+	copy = open(copy_path, O_RDWR | O_TRUNC);
+	if (copy < 0 || write(pipe_fds[1], payload, PAYLOAD_LENGTH) != PAYLOAD_LENGTH) return 1;
+	if (setrlimit(RLIMIT_FSIZE, &limit) != 0) return 2;
+	ssize_t drained = splice(pipe_fds[0], 0, copy, &output_offset, 64, 0);
+	limit.rlim_cur = RLIM_INFINITY;
+	if (setrlimit(RLIMIT_FSIZE, &limit) != 0 || drained != 4) return 3;
+	if (read(pipe_fds[0], buffer, sizeof buffer) != PAYLOAD_LENGTH - 4
+	    || memcmp(buffer, payload + 4, PAYLOAD_LENGTH - 4) != 0) return 4;
+
+	/* 2. SPLICE_F_NONBLOCK reports EAGAIN on an empty pipe instead of sleeping. */
+	errno = 0;
+	if (splice(pipe_fds[0], 0, copy, &output_offset, 64, SPLICE_F_NONBLOCK) != -1 || errno != EAGAIN)
+		return 5;
+	return close(copy) == 0 ? 0 : 6;
+}
+
+struct io_counters {
+	unsigned long long rchar;
+	unsigned long long wchar;
+	unsigned long long syscr;
+	unsigned long long syscw;
+};
+
+static int read_io_counters(struct io_counters *counters)
+{
+	char buffer[256];
+	int fd = open("/proc/self/io", O_RDONLY | O_CLOEXEC);
+	ssize_t length = fd < 0 ? -1 : read(fd, buffer, sizeof buffer - 1);
+
+	if (fd >= 0) close(fd);
+	if (length <= 0) return 0;
+	buffer[length] = 0;
+	return sscanf(buffer, "rchar: %llu wchar: %llu syscr: %llu syscw: %llu",
+		      &counters->rchar, &counters->wchar, &counters->syscr, &counters->syscw) == 4;
+}
+
+static int verify_copy_accounting(int pipe_fds[2])
+{
+	struct io_counters before;
+	struct io_counters after;
+	char buffer[6];
+	loff_t input_offset = 0;
+	ssize_t counter_read;
+	int source;
+
+	/*
+	 * 1. One splice counts its bytes once as rchar and once as wchar, with one syscr and one
+	 *    syscw, like sendfile. The later counter read also accounts the first one, so only the
+	 *    write side is compared exactly and the read side is bounded from below.
+	 */
+	source = open(source_path, O_RDONLY);
+	if (source < 0 || !read_io_counters(&before)) return 1;
+	if (splice(source, &input_offset, pipe_fds[1], 0, sizeof buffer, 0) != sizeof buffer) return 2;
+	if (!read_io_counters(&after)) return 3;
+	if (after.wchar - before.wchar != sizeof buffer || after.syscw - before.syscw != 1) return 4;
+	counter_read = (ssize_t)(after.rchar - before.rchar) - (ssize_t)sizeof buffer;
+	if (counter_read <= 0 || after.syscr - before.syscr != 2) return 5;
+	if (read(pipe_fds[0], buffer, sizeof buffer) != sizeof buffer || memcmp(buffer, payload, sizeof buffer) != 0)
+		return 6;
+	return close(source) == 0 ? 0 : 7;
+}
+
+static int unprivileged_resize(int fd)
+{
+	if (setuid(65534) != 0) return 1;
+	errno = 0;
+	return fcntl(fd, F_SETPIPE_SZ, 2 << 20) == -1 && errno == EPERM ? 0 : 2;
+}
+
+static int verify_pipe_size(int pipe_fds[2])
+{
+	char buffer[8192];
+	int status;
+
+	/* 1. Shrinking below the buffered byte count is EBUSY and keeps the old capacity. */
+	memset(buffer, 'S', sizeof buffer);
+	if (fcntl(pipe_fds[1], F_SETPIPE_SZ, 16384) != 16384) return 1;
+	if (write(pipe_fds[1], buffer, sizeof buffer) != sizeof buffer) return 2;
+	errno = 0;
+	if (fcntl(pipe_fds[0], F_SETPIPE_SZ, 4096) != -1 || errno != EBUSY) return 3;
+	if (fcntl(pipe_fds[0], F_GETPIPE_SZ) != 16384) return 4;
+	if (read(pipe_fds[0], buffer, sizeof buffer) != sizeof buffer) return 5;
+
+	/* 2. Growing past pipe-max-size requires privilege. */
+	pid_t child = fork();
+	if (child == 0) _exit(unprivileged_resize(pipe_fds[1]));
+	if (child < 0 || waitpid(child, &status, 0) != child || !WIFEXITED(status)) return 6;
+	if (WEXITSTATUS(status)) return 6 + WEXITSTATUS(status);
+	return 0;
+}
+
+int verify_splice(void)
+{
+	int pipe_fds[2];
+	int result;
+
+	if (pipe2(pipe_fds, O_CLOEXEC) != 0) return 1;
+	result = verify_round_trip(pipe_fds);
+	if (result) result += 10;
+	if (!result) {
+		result = verify_partial_drain(pipe_fds);
+		if (result) result += 30;
+	}
+	if (!result) {
+		result = verify_copy_accounting(pipe_fds);
+		if (result) result += 50;
+	}
+	if (!result) {
+		result = verify_pipe_size(pipe_fds);
+		if (result) result += 40;
+	}
+	close(pipe_fds[0]);
+	close(pipe_fds[1]);
+	if ((unlink(source_path) != 0 || unlink(copy_path) != 0) && !result) result = 99;
+	return result;
+}
@@ -0,0 +1,120 @@
+#define _GNU_SOURCE
+#include <errno.h>
+#include <signal.h>
+#include <string.h>
+#include <sys/resource.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+static int child_record(const siginfo_t *info, pid_t child, int code, int status)
+{
+	return info->si_signo == SIGCHLD && info->si_pid == child
+		&& info->si_code == code && info->si_status == status;
+}
+
+static void burn_cpu_and_exit(void)
+{
+	struct timespec used;
+
+	/* Spin until the scheduler has charged at least 20 ms of CPU to this process. */
+	do {
+		if (clock_gettime(CLOCK_PROCESS_CPUTIME_ID, &used) != 0) _exit(1);
+	} while (used.tv_sec == 0 && used.tv_nsec < 20000000);
+	_exit(7);
+}
+
+static int kill_and_reap(pid_t child, int result)
+{
+	kill(child, SIGKILL);
+	waitpid(child, NULL, 0);
+	return result;
+}
+
+static int verify_stop_continue(void)
+{
+	siginfo_t info;
+	int status;
+
+	pid_t child = fork();
+	if (child == 0) {
+		for (;;) pause();
+	}
+	if (child < 0) return 4;
+
+	/* 1. WNOHANG without a ready record succeeds and clears si_pid. */
+	memset(&info, 0xff, sizeof info);
+	if (waitid(P_PID, child, &info, WEXITED | WSTOPPED | WNOHANG) != 0 || info.si_pid != 0)
+		return kill_and_reap(child, 5);
+
+	/* 2. A stopped record is visible to WNOWAIT and then still consumed by WUNTRACED. */
+	if (kill(child, SIGSTOP) != 0) return kill_and_reap(child, 6);
+	memset(&info, 0, sizeof info);
+	if (waitid(P_PID, child, &info, WSTOPPED | WNOWAIT) != 0
+		|| !child_record(&info, child, CLD_STOPPED, SIGSTOP))
+		return kill_and_reap(child, 7);
+	if (waitpid(child, &status, WUNTRACED) != child || !WIFSTOPPED(status)
+		|| WSTOPSIG(status) != SIGSTOP)
+		return kill_and_reap(child, 8);
+	memset(&info, 0xff, sizeof info);
+	if (waitid(P_PID, child, &info, WSTOPPED | WNOHANG) != 0 || info.si_pid != 0)
+		return kill_and_reap(child, 9);
+
+	/* 3. SIGCONT produces one CLD_CONTINUED record for WCONTINUED. */
+	if (kill(child, SIGCONT) != 0) return kill_and_reap(child, 10);
+	memset(&info, 0, sizeof info);
+	if (waitid(P_PID, child, &info, WCONTINUED) != 0
+		|| !child_record(&info, child, CLD_CONTINUED, SIGCONT))
+		return kill_and_reap(child, 11);
+
+	/* 4. A signal death reports CLD_KILLED with the signal number. */
+	if (kill(child, SIGKILL) != 0) return 12;
+	memset(&info, 0, sizeof info);
+	if (waitid(P_PID, child, &info, WEXITED) != 0 || !child_record(&info, child, CLD_KILLED, SIGKILL))
+		return 13;
+	return 0;
+}
+
+static int verify_exit_rusage(void)
+{
+	siginfo_t info;
+	struct rusage usage;
+	int status;
+
+	pid_t child = fork();
+	if (child == 0) burn_cpu_and_exit();
+	if (child < 0) return 14;
+
+	/* 1. WNOWAIT leaves the exit record for the following wait4. */
+	memset(&info, 0, sizeof info);
+	if (waitid(P_PID, child, &info, WEXITED | WNOWAIT) != 0
+		|| !child_record(&info, child, CLD_EXITED, 7))
+		return kill_and_reap(child, 15);
+
+	/* 2. wait4 consumes the record and reports the child's CPU time. */
+	memset(&usage, 0, sizeof usage);
+	if (wait4(child, &status, 0, &usage) != child || !WIFEXITED(status) || WEXITSTATUS(status) != 7)
+		return 16;
+	if (usage.ru_utime.tv_sec == 0 && usage.ru_utime.tv_usec == 0) return 17;
+	errno = 0;
+	if (waitid(P_PID, child, &info, WEXITED | WNOHANG) == 0 || errno != ECHILD) return 18;
+	return 0;
+}
+
+int verify_waitid(void)
+{
+	siginfo_t info;
+	int result;
+
+	/* 1. id is a 32-bit pid_t: P_PID needs a positive pid and P_PGID a non-negative group. */
+	errno = 0;
+	if (waitid(P_PID, 0, &info, WEXITED | WNOHANG) == 0 || errno != EINVAL) return 1;
+	errno = 0;
+	if (waitid(P_PID, (id_t)-1, &info, WEXITED | WNOHANG) == 0 || errno != EINVAL) return 2;
+	errno = 0;
+	if (waitid(P_PGID, (id_t)-5, &info, WEXITED | WNOHANG) == 0 || errno != EINVAL) return 3;
+
+	result = verify_stop_continue();
+	if (result != 0) return result;
+	return verify_exit_rusage();
+}
@@ -3,17 +3,19 @@
 
 from __future__ import annotations
 
+import json
 import os
 import re
 import select
 import shutil
 import signal
+import socket
 import subprocess
 import tempfile
 import time
 from dataclasses import dataclass
 from pathlib import Path
-from typing import BinaryIO, Mapping
+from typing import BinaryIO, Callable, Mapping
 
 from build_target import (
     Acceleration,
@@ -73,7 +75,12 @@ def qemu_runtime(
 
 
 def _qemu_command(
-    image: Path, smp: int, interactive_devices: bool = False
+    image: Path,
+    smp: int,
+    interactive_devices: bool = False,
+    balloon_qmp: Path | None = None,
+    append: str | None = None,
+    extra_drives: tuple[Path, ...] = (),
 ) -> list[str]:
     runtime = qemu_runtime()
     qemu = shutil.which(runtime.binary)
@@ -99,10 +106,11 @@ def _qemu_command(
     )
     if runtime.bootloader is not None:
         command.extend(["-bios", runtime.bootloader])
+    command.extend(["-kernel", runtime.kernel_boot_artifact])
+    if append is not None:
+        command.extend(["-append", append])
     command.extend(
         [
-            "-kernel",
-            runtime.kernel_boot_artifact,
             "-drive",
             f"file={image},if=none,format=raw,id=x0",
             "-device",
@@ -113,6 +121,15 @@ def _qemu_command(
             "virtio-rng-device,rng=rng0",
         ]
     )
+    for index, drive in enumerate(extra_drives, start=1):
+        command.extend(
+            [
+                "-drive",
+                f"file={drive},if=none,format=raw,id=x{index}",
+                "-device",
+                f"virtio-blk-device,drive=x{index}",
+            ]
+        )
     if interactive_devices:
         command.extend(
             [
@@ -126,6 +143,15 @@ def _qemu_command(
                 "virtio-tablet-device",
             ]
         )
+    if balloon_qmp is not None:
+        command.extend(
+            [
+                "-device",
+                "virtio-balloon-device",
+                "-qmp",
+                f"unix:{balloon_qmp},server=on,wait=off",
+            ]
+        )
     command.extend(
         [
             "-netdev",
@@ -137,6 +163,46 @@ def _qemu_command(
     return command
 
 
+class QmpClient:
+    """QEMU Machine Protocol 的最小同步客户端；只发送命令并等待对应 return。"""
+
+    def __init__(self, path: Path, timeout_seconds: float = 10) -> None:
+        self._socket = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+        self._socket.settimeout(timeout_seconds)
+        self._socket.connect(str(path))
+        self._stream = self._socket.makefile("rwb")
+        self._read()
+        self.execute("qmp_capabilities")
+
+    def _read(self) -> dict:
+        line = self._stream.readline()
+        if not line:
+            raise RuntimeError("QMP connection closed")
+        return json.loads(line)
+
+    def execute(self, command: str, arguments: dict | None = None) -> dict:
+        """执行一条 QMP 命令并返回其 `return` 对象；异步 event 被跳过。
+
+        Raises:
+            RuntimeError: QEMU 返回 error 或连接关闭。
+        """
+        request: dict = {"execute": command}
+        if arguments is not None:
+            request["arguments"] = arguments
+        self._stream.write(json.dumps(request).encode() + b"\n")
+        self._stream.flush()
+        while True:
+            reply = self._read()
+            if "error" in reply:
+                raise RuntimeError(f"QMP {command} failed: {reply['error']}")
+            if "return" in reply:
+                return reply["return"]
+
+    def close(self) -> None:
+        self._stream.close()
+        self._socket.close()
+
+
 def cpu_topology_markers(cpu_count: int) -> tuple[str, str]:
     """构造 architecture-neutral CPU topology 启动契约。
 
@@ -245,22 +311,28 @@ def boot(
     smp: int,
     markers: tuple[str, ...],
     timeout_seconds: int = 30,
-    interactions: tuple[tuple[str, bytes], ...] = (),
+    interactions: tuple[tuple[str, bytes | Callable[[], bytes]], ...] = (),
     forbidden_markers: tuple[str, ...] = (),
     persistent_writes: bool = False,
     interactive_devices: bool = False,
+    balloon_qmp: Path | None = None,
+    append: str | None = None,
+    extra_drives: tuple[Path, ...] = (),
 ) -> None:
     """冷启动指定镜像，按 marker 注入输入，直到全部结果出现或 fail-stop。
 
     Args:
-        image: 作为唯一 root block device 的 ext2 镜像。
+        image: 作为首个 virtio-blk 设备的 ext2 镜像。
         smp: QEMU 向 DTB 暴露的 hart 数。
         markers: 成功前必须全部出现的输出标记。
         timeout_seconds: 单次冷启动的 monotonic deadline 秒数。
-        interactions: 按输出 marker 排序触发的终端输入。
+        interactions: 按输出 marker 排序触发的终端输入；callable 在 marker 出现时执行 host 侧动作并返回输入。
         forbidden_markers: 任一出现即立即失败的输出标记。
         persistent_writes: 是否直接使用传入的一次性镜像；默认创建私有副本隔离 guest 写入。
         interactive_devices: 是否加入 run-gui 的 GPU、keyboard 与 tablet 设备拓扑。
+        balloon_qmp: 非空时加入 virtio-balloon 并在该 Unix socket 上监听 QMP。
+        append: 非空时经 `-append` 写入 DTB `/chosen/bootargs` 的 kernel command line。
+        extra_drives: 依次追加的 virtio-blk raw 镜像；调用方负责隔离，gate 不复制它们。
 
     Returns:
         None；全部 marker 出现时返回。
@@ -276,7 +348,9 @@ def boot(
         private_image = Path(private_directory.name) / image.name
         shutil.copyfile(image, private_image)
         image = private_image
-    command = _qemu_command(image, smp, interactive_devices)
+    command = _qemu_command(
+        image, smp, interactive_devices, balloon_qmp, append, extra_drives
+    )
     process = subprocess.Popen(
         command,
         cwd=ROOT,
@@ -318,6 +392,8 @@ def boot(
                     # Stopped 文本会立即触发未来输入，使 gate 绕过 guest 的真实状态转换。
                     interaction_cursor = marker_offset + len(marker)
                     assert process.stdin is not None
+                    if callable(data):
+                        data = data()
                     if data:
                         # marker 通常先于 ash 的下一条 prompt；立即注入会让 prompt 切断命令前缀。
                         # 空 data 只推进单调 cursor，是无需触碰 UART 的 ordering barrier。
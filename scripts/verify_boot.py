@@ -4,18 +4,31 @@
 from __future__ import annotations
 
 import argparse
+import shutil
 import sys
+import tempfile
+import time
 from pathlib import Path
 
 from build_cache import publish_runtime_gate, runtime_gate_hit, runtime_gate_payload
 from build_target import BuildTarget, target_from_environment
-from qemu_gate import boot as boot_image, cpu_topology_markers
+from ext2_image import run_debugfs
+from qemu_gate import QmpClient, boot as boot_image, cpu_topology_markers
 from verify_busybox import cached_busybox_binary
 from verify_musl import cached_musl_paths, find_compiler
 
 ROOT = Path(__file__).resolve().parent.parent
 # musl 和 BusyBox runtime gates 已分别覆盖 1/8 hart；这里只保留独有的非幂次 3-hart DTB。
 SMP_CONFIGURATIONS = (3,)
+SECTOR_SIZE = 512
+# 1 MiB 起点同时满足 parted 默认对齐与 kernel 4 KiB logical block 对齐。
+PARTITION_ALIGNMENT_SECTORS = 2048
+SECOND_PARTITION_MARKER = b"LITEOS_PARTITION_TWO"
+# ext2 superblock 自 1024 起，`s_volume_name[16]` 位于其内偏移 120。
+EXT2_VOLUME_NAME_OFFSET = 1024 + 120
+EXT2_VOLUME_NAME_BYTES = 16
+MIB = 1024 * 1024
+BALLOON_STEP_BYTES = 16 * MIB
 
 
 def default_image(target: BuildTarget) -> Path:
@@ -74,6 +87,266 @@ def boot(image: Path, smp: int) -> None:
     boot_image(image, smp, markers)
 
 
+def boot_command_line(image: Path) -> None:
+    """以 `-append "nosmp loglevel=5 init=/bin/sh"` 在 2-CPU DTB 上启动，核对三个参数均生效。
+
+    1. `nosmp`：`/proc/stat` 只有 cpu0，且 `/proc/bootlog` 仍记录 smp stage；
+    2. `init=`：PID 1 的 argv[0] 为 `/bin/sh`，BusyBox init 从未启动；
+    3. `loglevel=5`：只保留 priority 小于 5 的 Warn/Error，Info 既不上 console 也不进 kmsg ring。
+    """
+    boot_image(
+        image,
+        2,
+        ("LITEOS_COMMAND_LINE_42",),
+        interactions=(
+            (
+                "Enter 'help' for a list of built-in commands.",
+                b"/bin/timeout 1 /bin/cat /dev/kmsg >/kmsg.out;"
+                b" [ \"$(/bin/grep -c '^cpu[0-9]' /proc/stat)\" = 1 ]"
+                b" && /bin/grep -q '^smp ' /proc/bootlog"
+                b" && [ \"$(/bin/tr '\\0' '\\n' </proc/1/cmdline | /bin/head -n1)\" = /bin/sh ]"
+                b" && ! /bin/grep -q 'mounted at /' /kmsg.out"
+                b" && echo LITEOS_COMMAND_LINE_$((6*7))\n",
+            ),
+        ),
+        forbidden_markers=(
+            "init started: BusyBox",
+            "ext2 root filesystem mounted at /",
+            "all platform CPUs online",
+        ),
+        append="nosmp loglevel=5 init=/bin/sh",
+    )
+
+
+def write_partitioned_image(rootfs: Path, output: Path) -> None:
+    """生成 MBR 磁盘：分区 1 为 rootfs 副本，分区 2 首部写入独立 marker。
+
+    Args:
+        rootfs: 只读 ext2 rootfs baseline。
+        output: 新建的私有磁盘镜像路径。
+    """
+    alignment = PARTITION_ALIGNMENT_SECTORS
+    root_sectors = -(-rootfs.stat().st_size // SECTOR_SIZE)
+    root_sectors = -(-root_sectors // alignment) * alignment
+    partitions = ((alignment, root_sectors), (alignment + root_sectors, alignment))
+    mbr = bytearray(SECTOR_SIZE)
+    for index, (first, count) in enumerate(partitions):
+        entry = 446 + index * 16
+        mbr[entry + 4] = 0x83
+        mbr[entry + 8 : entry + 12] = first.to_bytes(4, "little")
+        mbr[entry + 12 : entry + 16] = count.to_bytes(4, "little")
+    mbr[510:512] = b"\x55\xaa"
+    with output.open("wb") as stream, rootfs.open("rb") as source:
+        stream.write(mbr)
+        stream.seek(partitions[0][0] * SECTOR_SIZE)
+        shutil.copyfileobj(source, stream)
+        stream.seek(partitions[1][0] * SECTOR_SIZE)
+        stream.write(SECOND_PARTITION_MARKER)
+        stream.truncate(sum(partitions[1]) * SECTOR_SIZE)
+
+
+def boot_partitioned(image: Path) -> None:
+    """从 MBR 分区 1 启动 rootfs，并经 `/dev/vda2` block node 读回分区 2 的 marker。
+
+    缺失 per-partition registry 时 `/dev/vda2` 不存在，shell 不会打印 marker。
+    """
+    with tempfile.TemporaryDirectory(prefix="liteos-partitioned-") as directory:
+        disk = Path(directory) / "partitioned.img"
+        write_partitioned_image(image, disk)
+        boot_image(
+            disk,
+            1,
+            (
+                "root filesystem on vda1",
+                "init started: BusyBox v1.37.0",
+                "LITEOS_SECOND_PARTITION_42",
+            ),
+            interactions=(
+                (
+                    "Enter 'help' for a list of built-in commands.",
+                    b"[ -b /dev/vda2 ] && /bin/head -c 20 /dev/vda2 | /bin/grep -q "
+                    + SECOND_PARTITION_MARKER
+                    + b" && echo LITEOS_SECOND_PARTITION_$((6*7))\n",
+                ),
+            ),
+            persistent_writes=True,
+        )
+
+
+def ext2_volume_name(device: str) -> str:
+    """返回从 raw block node 读取 ext2 volume name 的 guest shell pipeline。"""
+    end = EXT2_VOLUME_NAME_OFFSET + EXT2_VOLUME_NAME_BYTES
+    return (
+        f"/bin/head -c {end} {device} | /bin/tail -c {EXT2_VOLUME_NAME_BYTES}"
+        " | /bin/tr -d '\\0'"
+    )
+
+
+def write_identified_rootfs(rootfs: Path, output: Path, identity: str) -> None:
+    """复制 rootfs，并把同一 identity 写入 ext2 volume name 与 `/disk-identity`。
+
+    volume name 位于 superblock 固定偏移，guest 可直接从 raw block node 读取；文件内容只能经
+    mounted `/` 读取。两者相等即证明 `/` 来自该 block device。
+    """
+    shutil.copyfile(rootfs, output)
+    payload = output.with_suffix(".identity")
+    payload.write_text(identity)
+    run_debugfs(output, f"ssv volume_name {identity}", writable=True)
+    run_debugfs(output, f"write {payload} disk-identity", writable=True)
+
+
+def boot_second_disk_root(image: Path) -> None:
+    """挂接两块 virtio-blk rootfs 副本，以 `root=/dev/vdb` 启动并核对 `/` 的来源磁盘。
+
+    QEMU virt 把 `-device` 放入 MMIO transport 的顺序随 machine 实现而异，gate 因此不假设哪份
+    副本成为 vdb，而是在 guest 内比较 `/disk-identity` 与两个 raw node 的 ext2 volume name。
+    缺失 `root=` 选择时 `/` 来自 vda，identity 只与 vda 的 volume name 相等。
+    """
+    root_volume = ext2_volume_name("/dev/vdb")
+    other_volume = ext2_volume_name("/dev/vda")
+    with tempfile.TemporaryDirectory(prefix="liteos-two-disk-") as directory:
+        disks = tuple(Path(directory) / f"disk-{name}.img" for name in ("a", "b"))
+        for disk in disks:
+            write_identified_rootfs(image, disk, f"liteos-{disk.stem}")
+        boot_image(
+            disks[0],
+            1,
+            (
+                "root filesystem on vdb",
+                "init started: BusyBox v1.37.0",
+                "LITEOS_SECOND_DISK_ROOT_42",
+            ),
+            interactions=(
+                (
+                    "Enter 'help' for a list of built-in commands.",
+                    (
+                        "[ -b /dev/vda ] && [ -b /dev/vdb ]"
+                        " && identity=$(/bin/cat /disk-identity)"
+                        f' && [ "$({root_volume})" = "$identity" ]'
+                        f' && [ "$({other_volume})" != "$identity" ]'
+                        " && echo LITEOS_SECOND_DISK_ROOT_$((6*7))\n"
+                    ).encode(),
+                ),
+            ),
+            forbidden_markers=("root filesystem on vda",),
+            persistent_writes=True,
+            append="root=/dev/vdb",
+            extra_drives=disks[1:],
+        )
+
+
+def boot_reboot_persistence(image: Path) -> None:
+    """写入文件后不经 userspace sync 直接 `reboot -f -n`，在同一 QEMU 的下一次 boot 读回。
+
+    `-f` 跳过 init 的 shutdown 序列，`-n` 跳过 BusyBox 的 sync(2)；文件只可能由 reboot(2)
+    自身的 writeback 与 device quiesce 落盘。缺失该路径时重启后的 shell 读不到 payload。
+    """
+    boot_image(
+        image,
+        1,
+        ("root filesystem on vda", "LITEOS_REBOOT_PERSIST_42"),
+        interactions=(
+            (
+                "Enter 'help' for a list of built-in commands.",
+                b"echo LITEOS_REBOOT_PAYLOAD >/reboot-persist && /bin/reboot -f -n\n",
+            ),
+            (
+                "Enter 'help' for a list of built-in commands.",
+                b"/bin/grep -q '^LITEOS_REBOOT_PAYLOAD$' /reboot-persist"
+                b" && echo LITEOS_REBOOT_PERSIST_$((6*7))\n",
+            ),
+        ),
+    )
+
+
+def set_balloon(qmp: QmpClient, target: int, timeout_seconds: float = 20) -> int:
+    """经 QMP `balloon` 设置 guest 可见内存，并等待 `query-balloon` 报告 guest 已收敛。
+
+    Returns:
+        guest 回写 `actual` 后 QEMU 报告的字节数。
+
+    Raises:
+        RuntimeError: 超时仍未收敛，或 QMP 命令失败。
+    """
+    qmp.execute("balloon", {"value": target})
+    deadline = time.monotonic() + timeout_seconds
+    while True:
+        actual = qmp.execute("query-balloon")["actual"]
+        if actual == target:
+            return actual
+        if time.monotonic() >= deadline:
+            raise RuntimeError(
+                f"balloon stuck at {actual} bytes; target {target} bytes"
+            )
+        time.sleep(0.1)
+
+
+def boot_balloon(image: Path) -> None:
+    """经 QMP inflate/deflate virtio-balloon，核对 MemTotal 与 vmstat 计数随之变化。
+
+    inflate 后 MemTotal 至少下降一个 step，deflate 后回到基线；两次的 balloon 大小以
+    `query-balloon` 的 guest `actual` 为准并打印。
+    """
+    with tempfile.TemporaryDirectory(prefix="liteos-balloon-") as directory:
+        socket_path = Path(directory) / "qmp.sock"
+        clients: list[QmpClient] = []
+
+        def client() -> QmpClient:
+            if not clients:
+                clients.append(QmpClient(socket_path))
+            return clients[0]
+
+        def inflate() -> bytes:
+            qmp = client()
+            full = qmp.execute("query-balloon")["actual"]
+            actual = set_balloon(qmp, full - BALLOON_STEP_BYTES)
+            print(
+                f"QEMU balloon inflated: guest actual {actual // MIB} MiB,"
+                f" balloon {(full - actual) // MIB} MiB"
+            )
+            return (
+                b"read -r _ low _ < /proc/meminfo;"
+                b" [ $((base - low)) -ge %d ]" % (BALLOON_STEP_BYTES // 1024)
+                + b" && /bin/grep -q '^balloon_inflate [1-9]' /proc/vmstat"
+                b" && echo LITEOS_BALLOON_INFLATED_$((6*7))\n"
+            )
+
+        def deflate() -> bytes:
+            qmp = client()
+            actual = set_balloon(
+                qmp, qmp.execute("query-balloon")["actual"] + BALLOON_STEP_BYTES
+            )
+            print(f"QEMU balloon deflated: guest actual {actual // MIB} MiB, balloon 0 MiB")
+            return (
+                b"read -r _ high _ < /proc/meminfo; [ \"$high\" -eq \"$base\" ]"
+                b" && /bin/grep -q '^balloon_deflate [1-9]' /proc/vmstat"
+                b" && echo LITEOS_BALLOON_DEFLATED_$((6*7))\n"
+            )
+
+        try:
+            boot_image(
+                image,
+                1,
+                (
+                    "init started: BusyBox v1.37.0",
+                    "LITEOS_BALLOON_INFLATED_42",
+                    "LITEOS_BALLOON_DEFLATED_42",
+                ),
+                interactions=(
+                    (
+                        "Enter 'help' for a list of built-in commands.",
+                        b"read -r _ base _ < /proc/meminfo; echo LITEOS_BALLOON_BASE_$((6*7))\n",
+                    ),
+                    ("LITEOS_BALLOON_BASE_42", inflate),
+                    ("LITEOS_BALLOON_INFLATED_42", deflate),
+                ),
+                balloon_qmp=socket_path,
+            )
+        finally:
+            for qmp in clients:
+                qmp.close()
+
+
 def boot_interactive_devices(image: Path) -> None:
     """在无 host 窗口下验证 run-gui 的 GPU、输入设备拓扑与桌面全链路。
 
@@ -122,7 +395,7 @@ def main() -> int:
         stamp = ROOT / "target" / "verify-gates" / f"boot-{target.arch}.json"
         payload = runtime_gate_payload(
             "boot-topology",
-            4,
+            7,
             gate_inputs(target, image, busybox, musl.install),
         )
         if runtime_gate_hit(stamp, payload, (image,)):
@@ -131,6 +404,16 @@ def main() -> int:
         for smp in SMP_CONFIGURATIONS:
             boot(image, smp)
             print(f"QEMU -smp {smp} boot verification passed")
+        boot_command_line(image)
+        print("QEMU kernel command line verification passed")
+        boot_partitioned(image)
+        print("QEMU partitioned-disk boot verification passed")
+        boot_second_disk_root(image)
+        print("QEMU second-disk root boot verification passed")
+        boot_balloon(image)
+        print("QEMU balloon inflate/deflate verification passed")
+        boot_reboot_persistence(image)
+        print("QEMU reboot writeback verification passed")
         if target.arch == "aarch64":
             boot_interactive_devices(image)
             print("QEMU AArch64 interactive-device boot verification passed")
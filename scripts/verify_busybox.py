@@ -61,7 +61,7 @@ BINARY_RECIPE_VERSION = 6
 # 完整 fingerprint directory 原子发布为不可变 generation。
 # FAILURE: 缺少该 cache 会让 ext2 创建时间在每次 build 改写 fs.img，即使执行输入未变也会
 # 使全部下游 APK install/runtime gate 失效。
-ROOTFS_RECIPE_VERSION = 10
+ROOTFS_RECIPE_VERSION = 11
 if TARGET.arch == "aarch64":
     BUSYBOX_ARCH = "arm64"
     BUSYBOX_TARGET_CFLAGS = "-march=armv8-a"
@@ -103,6 +103,8 @@ BUSYBOX_LINKS = (
     "clear",
     "cmp",
     "cp",
+    "cpio",
+    "cryptpw",
     "cut",
     "date",
     "dd",
@@ -132,6 +134,7 @@ BUSYBOX_LINKS = (
     "ln",
     "ls",
     "less",
+    "login",
     "md5sum",
     "mkdir",
     "mktemp",
@@ -194,12 +197,39 @@ BUSYBOX_LINKS = (
     "whoami",
     "vi",
     "xargs",
+    "xxd",
     "xz",
     "xzcat",
     "yes",
     "zcat",
 )
-STRESS_LINKS = ("cputest", "memtest", "cachetest")
+STRESS_LINKS = (
+    "cputest",
+    "memtest",
+    "cachetest",
+    "preempttest",
+    "sleeptest",
+    "reaptest",
+    "nxtest",
+    "exhausttest",
+    "perftest",
+    "microbench",
+    "boottime",
+    "readelf-lite",
+)
+DIAGNOSTICS_SOURCES = (
+    ROOT / "user/diagnostics/liteos-stress.c",
+    ROOT / "user/diagnostics/elf-inspect.c",
+    ROOT / "user/diagnostics/elf-inspect.h",
+    ROOT / "user/diagnostics/exhaust.c",
+    ROOT / "user/diagnostics/exhaust.h",
+    ROOT / "user/diagnostics/bench.c",
+    ROOT / "user/diagnostics/bench.h",
+    ROOT / "user/diagnostics/boottime.c",
+    ROOT / "user/diagnostics/boottime.h",
+    ROOT / "user/diagnostics/perf.c",
+    ROOT / "user/diagnostics/perf.h",
+)
 
 
 def start_http_gate() -> tuple[subprocess.Popen[bytes], int]:
@@ -369,6 +399,22 @@ def install_phase57_script(image: Path, directory: Path) -> None:
     run([str(find_debugfs()), "-w", "-f", str(commands), str(image)], ROOT)
 
 
+def install_exhaust_script(image: Path, directory: Path) -> None:
+    """向 disposable runtime image 注入资源耗尽验收；rootfs 与内存填充不与交互 gate 共享 deadline。"""
+    fixture = directory / "exhaust.sh"
+    fixture.write_text(
+        "#!/bin/sh\nset -e\n"
+        "/bin/exhausttest\n"
+        "echo LITEOS_EXHAUST_$((7*7+4))\n"
+    )
+    commands = directory / "exhaust-debugfs.commands"
+    commands.write_text(
+        f"write {fixture} /run/exhaust.sh\n"
+        "set_inode_field /run/exhaust.sh mode 0100755\n"
+    )
+    run([str(find_debugfs()), "-w", "-f", str(commands), str(image)], ROOT)
+
+
 def install_phase55_script(image: Path, directory: Path) -> None:
     """向 disposable runtime image 注入进程发现与生命周期验收。"""
     fixture = directory / "phase55.sh"
@@ -1006,14 +1052,14 @@ def build_ui_assets() -> Path:
 
 
 def build_stress_tools(musl: MuslCachePaths) -> Path:
-    """构建 rootfs 单一 CPU/memory/page-cache 诊断程序。"""
-    source = ROOT / "user/diagnostics/liteos-stress.c"
+    """构建 rootfs 单一 CPU/memory/page-cache/ELF 诊断程序。"""
+    sources = DIAGNOSTICS_SOURCES
     payload = {
         "kind": "liteos-stress-tools",
-        "recipe_version": 1,
+        "recipe_version": 2,
         "musl_sysroot_fingerprint": musl.sysroot_fingerprint,
         "driver_sha256": sha256(ROOT / "scripts/musl_clang.py"),
-        "source_sha256": sha256(source),
+        "source_sha256": [sha256(source) for source in sources],
     }
     entry = WORK / "stress-tools" / fingerprint(payload)
     if manifest_matches(entry, payload, ("liteos-stress",)):
@@ -1032,7 +1078,7 @@ def build_stress_tools(musl: MuslCachePaths) -> Path:
             [
                 sys.executable,
                 str(ROOT / "scripts/musl_clang.py"),
-                str(source),
+                *(str(source) for source in sources if source.suffix == ".c"),
                 "-std=c11",
                 "-D_GNU_SOURCE",
                 "-Wall",
@@ -1109,6 +1155,8 @@ def create_image(
         "mkdir /var/empty",
         f"write {ROOT / 'user' / 'base' / 'passwd'} /etc/passwd",
         f"write {ROOT / 'user' / 'base' / 'group'} /etc/group",
+        f"write {ROOT / 'user' / 'base' / 'shadow'} /etc/shadow",
+        "set_inode_field /etc/shadow mode 0100600",
         f"write {ROOT / 'user' / 'base' / 'inittab'} /etc/inittab",
         f"write {ROOT / 'user' / 'base' / 'graphical-session'} /etc/init.d/graphical-session",
         "set_inode_field /etc/init.d/graphical-session mode 0100755",
@@ -1158,12 +1206,10 @@ def create_image(
         "set_inode_field /bin/terminal-session mode 0100755",
         f"write {stress_tools} /bin/liteos-stress",
         "set_inode_field /bin/liteos-stress mode 0100755",
-        "ln /bin/liteos-stress /bin/cputest",
-        "ln /bin/liteos-stress /bin/memtest",
-        "ln /bin/liteos-stress /bin/cachetest",
         f"set_inode_field /bin/liteos-stress links_count {len(STRESS_LINKS) + 1}",
         f"symlink {TARGET.musl_loader} /usr/lib/libc.so",
     ]
+    commands.extend(f"ln /bin/liteos-stress /bin/{name}" for name in STRESS_LINKS)
     commands.extend(f"ln /bin/init /bin/{applet}" for applet in BUSYBOX_LINKS)
     commands.append(f"set_inode_field /bin/init links_count {len(BUSYBOX_LINKS) + 1}")
     script_path: Path | None = None
@@ -1209,7 +1255,7 @@ def create_image(
     stress_metadata = run(
         [str(find_debugfs()), "-R", "stat /bin/liteos-stress", str(image)], ROOT
     )
-    if "Links: 4" not in stress_metadata:
+    if f"Links: {len(stress_commands)}" not in stress_metadata:
         raise RuntimeError("stress command inode link count does not match multicall names")
     temporary_directory_metadata = run(
         [str(find_debugfs()), "-R", "stat /tmp", str(image)], ROOT
@@ -1220,6 +1266,11 @@ def create_image(
     group = run([str(find_debugfs()), "-R", "cat /etc/group", str(image)], ROOT)
     if "root:x:0:0:root:/root:/bin/sh" not in passwd or "root:x:0:" not in group:
         raise RuntimeError("BusyBox rootfs lacks the canonical root identity records")
+    shadow = run([str(find_debugfs()), "-R", "cat /etc/shadow", str(image)], ROOT)
+    shadow_metadata = run([str(find_debugfs()), "-R", "stat /etc/shadow", str(image)], ROOT)
+    # root 只能经 UART 恢复 shell 进入；可登录的 shadow hash 不得随产品镜像发布。
+    if not shadow.startswith("root:*:") or "Mode:  0600" not in shadow_metadata:
+        raise RuntimeError("BusyBox rootfs /etc/shadow must lock root and have mode 0600")
     for fixture in ("dynamic-smoke", "liteos-script", "rust-std-smoke"):
         if fixture in entries:
             raise RuntimeError(f"product rootfs contains verification fixture: {fixture}")
@@ -1334,13 +1385,14 @@ def create_published_image(
         *alpine_keys,
         ROOT / "user/base/passwd",
         ROOT / "user/base/group",
+        ROOT / "user/base/shadow",
         ROOT / "user/base/inittab",
         ROOT / "user/base/graphical-session",
         ROOT / "user/Cargo.toml",
         ROOT / "user/Cargo.lock",
         *sorted((ROOT / "user").glob("*/Cargo.toml")),
         *sorted((ROOT / "user").glob("*/src/*.rs")),
-        ROOT / "user/diagnostics/liteos-stress.c",
+        *DIAGNOSTICS_SOURCES,
         ROOT / "assets/terminfo/l/liteos",
         ROOT / "assets/fonts/liteos-terminal.a8",
         ROOT / "assets/fonts/liteos-ui.a8p",
@@ -1445,6 +1497,7 @@ def main() -> int:
                 openssl.binary,
                 ROOT / "user/base/passwd",
                 ROOT / "user/base/group",
+                ROOT / "user/base/shadow",
                 ROOT / "user/base/inittab",
                 ROOT / "user/base/graphical-session",
                 ROOT / "user/Cargo.toml",
@@ -1490,15 +1543,19 @@ def main() -> int:
         install_phase55_script(runtime_image, runtime_path)
         install_phase56_script(runtime_image, runtime_path)
         install_phase57_script(runtime_image, runtime_path)
+        install_exhaust_script(runtime_image, runtime_path)
         phase55_image = runtime_path / "phase55.img"
         phase56_image = runtime_path / "phase56.img"
         phase57_image = runtime_path / "phase57.img"
+        exhaust_image = runtime_path / "exhaust.img"
         shutil.copyfile(runtime_image, phase55_image)
         shutil.copyfile(runtime_image, phase56_image)
         shutil.copyfile(runtime_image, phase57_image)
+        shutil.copyfile(runtime_image, exhaust_image)
         install_guest_gate_init(phase55_image, runtime_path, "/run/phase55.sh", "phase55")
         install_guest_gate_init(phase56_image, runtime_path, "/run/phase56.sh", "phase56")
         install_guest_gate_init(phase57_image, runtime_path, "/run/phase57.sh", "phase57")
+        install_guest_gate_init(exhaust_image, runtime_path, "/run/exhaust.sh", "exhaust")
         # 该组合 gate 串行覆盖 50+ 次 UART interaction、TLS、archive、editor、并发 VFS 与
         # job-control；90 秒只是不受 host 调度影响的 liveness bound，不是性能阈值。热路径
         # 性能由 release instruction/count gates 独立约束，不能从这里删 marker 或 workload。
@@ -1521,7 +1578,17 @@ def main() -> int:
                 "LITEOS_COMPRESSION_53",
                 "LITEOS_TOOLS_53",
                 "LITEOS_ARCHIVE_53",
+                "LITEOS_CPIO_53",
+                "LITEOS_REAPTEST_53",
+                "LITEOS_PREEMPTTEST_53",
+                "LITEOS_SLEEPTEST_53",
+                "LITEOS_NXTEST_53",
+                "LITEOS_PERFTEST_53",
+                "LITEOS_MICROBENCH_53",
+                "LITEOS_BOOTTIME_53",
+                "LITEOS_STRESS_53",
                 "LITEOS_VI_54",
+                "LITEOS_VI_REGEX_54",
                 "LITEOS_LESS_54",
                 "LITEOS_TEXT_DIAG_54",
                 "LITEOS_LS_42",
@@ -1540,6 +1607,7 @@ def main() -> int:
                 "LITEOS_LINKS_43",
                 "LITEOS_NAMESPACE_CONCURRENCY_43",
                 "LITEOS_BUSYBOX_CREDENTIALS_44",
+                "LITEOS_LOGIN_58",
                 "LITEOS_SYSTEM_IDENTITY_42",
                 "LITEOS_WALLCLOCK_42",
                 "LITEOS_EXEC_RECLAIM_42",
@@ -1627,6 +1695,46 @@ def main() -> int:
                 ),
                 (
                     "LITEOS_ARCHIVE_53",
+                    b"mkdir -p /phase53/cpio/in /phase53/cpio/out; printf cpio53 > /phase53/cpio/in/data; chmod 604 /phase53/cpio/in/data; sleep 1; ln -s data /phase53/cpio/in/link; (cd /phase53/cpio/in && find . | cpio -o -H newc --ignore-devno --renumber-inodes > /phase53/cpio/image.cpio) && (cd /phase53/cpio/out && cpio -i -d -m < /phase53/cpio/image.cpio) && cmp /phase53/cpio/in/data /phase53/cpio/out/data && [ \"$(stat -c '%a %Y' /phase53/cpio/out/data)\" = \"$(stat -c '%a %Y' /phase53/cpio/in/data)\" ] && [ \"$(readlink /phase53/cpio/out/link)\" = data ] && echo LITEOS_CPIO_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_CPIO_53",
+                    b"printf AB | xxd | grep -q '^00000000: 4142' && readelf-lite -h /bin/init | grep -q '^  Type:    DYN' && readelf-lite -d /bin/init | grep -q 'NEEDED .*libc' && readelf-lite -l /bin/init | grep -q 'interpreter: /lib/ld-musl-' && echo LITEOS_ELF_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_ELF_53",
+                    b"reaptest 4 && echo LITEOS_REAPTEST_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_REAPTEST_53",
+                    b"preempttest && echo LITEOS_PREEMPTTEST_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_PREEMPTTEST_53",
+                    b"sleeptest && echo LITEOS_SLEEPTEST_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_SLEEPTEST_53",
+                    b"nxtest && echo LITEOS_NXTEST_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_NXTEST_53",
+                    b"perftest && echo LITEOS_PERFTEST_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_PERFTEST_53",
+                    b"microbench && echo LITEOS_MICROBENCH_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_MICROBENCH_53",
+                    b"boottime && echo LITEOS_BOOTTIME_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_BOOTTIME_53",
+                    b"[ \"$(/bin/cut -d' ' -f1 /proc/bootlog | /bin/tr '\\n' ,)\" = board,memory,drivers,fs,task,smp, ] && echo LITEOS_STRESS_$((7*7+4))\n",
+                ),
+                (
+                    "LITEOS_STRESS_53",
                     b"printf 'alpha\\nbeta\\n' >/vi54; vi /vi54; [ \"$(tail -n1 /vi54)\" = OK ] && stty -a | grep -q echo && echo LITEOS_VI_$((9*6))\n",
                 ),
                 (
@@ -1635,6 +1743,15 @@ def main() -> int:
                 ),
                 (
                     "LITEOS_VI_54",
+                    b"printf 'alpha\\nbeta\\ngamma\\n' >/viregex54; vi /viregex54; [ \"$(tr '\\n' , </viregex54)\" = alpha,gamma, ] && echo LITEOS_VI_REGEX_$((9*6))\n",
+                ),
+                (
+                    # 只有 FEATURE_VI_REGEX_SEARCH 把 `^b.t` 当正则匹配 beta；字面搜索找不到时 dd 删掉 alpha。
+                    "- /viregex54 1/3 33%",
+                    b"/^b.t\ndd:wq\n",
+                ),
+                (
+                    "LITEOS_VI_REGEX_54",
                     b"seq 1 40 >/less54; less /less54; stty -a | grep -q echo && echo LITEOS_LESS_$((9*6))\n",
                 ),
                 (
@@ -1695,6 +1812,30 @@ def main() -> int:
                 ),
                 (
                     "LITEOS_BUSYBOX_CREDENTIALS_44",
+                    (
+                        "hash=$(/bin/cryptpw -m sha256 -S saltstring 'Hello world!'); "
+                        "[ \"$hash\" = '$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5' ] && "
+                        "secret=$(/bin/cryptpw -S guest58 guest-secret) && "
+                        "case $secret in '$5$guest58$'*) true;; *) false;; esac && /bin/mkdir -p /home/guest && "
+                        "printf 'guest:x:1001:1001:guest:/home/guest:/home/guest/check\\n' >>/etc/passwd && "
+                        "printf 'guest:x:1001:\\nstaff58:x:1002:guest\\n' >>/etc/group && "
+                        "printf 'guest:%s:::::::\\n' \"$secret\" >>/etc/shadow && "
+                        "printf staff58 >/home/guest/staff && /bin/chown 0:1002 /home/guest/staff && "
+                        "/bin/chmod 040 /home/guest/staff && printf '%s\\n' '#!/bin/sh' "
+                        "'[ \"$(/bin/id -u):$(/bin/id -g):$(/bin/pwd):$HOME:$USER\" = 1001:1001:/home/guest:/home/guest:guest ] || exit 1' "
+                        "'/bin/id -G | /bin/grep -qw 1002 && [ \"$(/bin/cat /home/guest/staff)\" = staff58 ] || exit 1' "
+                        "'if /bin/cat /etc/shadow >/dev/null 2>&1; then exit 1; fi' "
+                        "'echo LITEOS_LOGIN_$((2*29))' >/home/guest/check && "
+                        "/bin/chmod 755 /home/guest/check && /bin/chown 1001:1001 /home/guest && /bin/login guest\n"
+                    ).encode(),
+                ),
+                # 错误密码必须经 3 秒 failure pause 后回到用户名提示；正确 SHA-256 crypt 才能
+                # 经 initgroups/setgid/setuid 进入 guest shell，并只凭 supplementary group 读取 staff 文件。
+                ("Password: ", b"wrong-secret\n"),
+                ("liteos login: ", b"guest\n"),
+                ("Password: ", b"guest-secret\n"),
+                (
+                    "LITEOS_LOGIN_58",
                     (
                         "[ \"$(/bin/uname -s)\" = LiteOS ] && "
                         "[ \"$(/bin/uname -n)\" = liteos ] && "
@@ -1925,6 +2066,19 @@ def main() -> int:
             persistent_writes=True,
             timeout_seconds=90,
         )
+        # exhausttest 把 rootfs 填到 ENOSPC 并映射 2 倍 RAM 触发 OOM kill；独立 deadline 只约束该
+        # workload 的 liveness，不放宽交互 gate 的 90 秒 bound。
+        boot(
+            exhaust_image,
+            1,
+            (
+                *cpu_topology_markers(1),
+                "init started: BusyBox v1.37.0",
+                "LITEOS_EXHAUST_53",
+            ),
+            forbidden_markers=FORBIDDEN_BOOT_MARKERS,
+            timeout_seconds=90,
+        )
         boot(
             phase55_image,
             1,
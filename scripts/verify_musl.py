@@ -499,6 +499,14 @@ def smoke_payload(install: Path, compiler: Path, sysroot_fingerprint: str) -> di
         "compiler": compiler_identity(compiler),
         "source_sha256": sha256(ROOT / "scripts/fixtures/musl/musl-smoke.c"),
         "shared_sync_sha256": sha256(ROOT / "scripts/fixtures/musl/shared-sync.c"),
+        "splice_sha256": sha256(ROOT / "scripts/fixtures/musl/splice.c"),
+        "fifo_open_sha256": sha256(ROOT / "scripts/fixtures/musl/fifo-open.c"),
+        "chroot_sha256": sha256(ROOT / "scripts/fixtures/musl/chroot.c"),
+        "pid_namespace_sha256": sha256(ROOT / "scripts/fixtures/musl/pid-namespace.c"),
+        "audit_sha256": sha256(ROOT / "scripts/fixtures/musl/audit.c"),
+        "waitid_sha256": sha256(ROOT / "scripts/fixtures/musl/waitid.c"),
+        "keyring_sha256": sha256(ROOT / "scripts/fixtures/musl/keyring.c"),
+        "mdwe_sha256": sha256(ROOT / "scripts/fixtures/musl/mdwe.c"),
         "link_arguments": list(SMOKE_LINK_ARGUMENTS),
         "compiler_runtime": {
             "path": str(compiler_runtime),
@@ -547,6 +555,14 @@ def link_smoke(
                 str(install / "usr/lib" / "crti.o"),
                 str(ROOT / "scripts/fixtures/musl/musl-smoke.c"),
                 str(ROOT / "scripts/fixtures/musl/shared-sync.c"),
+                str(ROOT / "scripts/fixtures/musl/splice.c"),
+                str(ROOT / "scripts/fixtures/musl/fifo-open.c"),
+                str(ROOT / "scripts/fixtures/musl/chroot.c"),
+                str(ROOT / "scripts/fixtures/musl/pid-namespace.c"),
+                str(ROOT / "scripts/fixtures/musl/audit.c"),
+                str(ROOT / "scripts/fixtures/musl/waitid.c"),
+                str(ROOT / "scripts/fixtures/musl/keyring.c"),
+                str(ROOT / "scripts/fixtures/musl/mdwe.c"),
                 f"-L{install / 'usr/lib'}",
                 "-Wl,--start-group",
                 str(install / "usr/lib/libc.a"),
@@ -31,6 +31,7 @@ pub const SYSCALL_FTRUNCATE: usize = 46;
 pub const SYSCALL_FALLOCATE: usize = 47;
 pub const SYSCALL_CHDIR: usize = 49;
 pub const SYSCALL_FCHDIR: usize = 50;
+pub const SYSCALL_CHROOT: usize = 51;
 pub const SYSCALL_OPENAT: usize = 56;
 pub const SYSCALL_CLOSE: usize = 57;
 pub const SYSCALL_PIPE2: usize = 59;
@@ -47,6 +48,7 @@ pub const SYSCALL_PWRITEV: usize = 70;
 pub const SYSCALL_SENDFILE: usize = 71;
 pub const SYSCALL_PPOLL: usize = 73;
 pub const SYSCALL_PSELECT6: usize = 72;
+pub const SYSCALL_SPLICE: usize = 76;
 pub const SYSCALL_READLINKAT: usize = 78;
 pub const SYSCALL_NEWFSTATAT: usize = 79;
 pub const SYSCALL_FSTAT: usize = 80;
@@ -56,6 +58,7 @@ pub const SYSCALL_FDATASYNC: usize = 83;
 pub const SYSCALL_UTIMENSAT: usize = 88;
 pub const SYSCALL_EXIT: usize = 93;
 pub const SYSCALL_EXIT_GROUP: usize = 94;
+pub const SYSCALL_WAITID: usize = 95;
 pub const SYSCALL_SET_TID_ADDRESS: usize = 96;
 pub const SYSCALL_FUTEX: usize = 98;
 pub const SYSCALL_SET_ROBUST_LIST: usize = 99;
@@ -134,6 +137,9 @@ pub const SYSCALL_SHUTDOWN: usize = 210;
 pub const SYSCALL_SYSINFO: usize = 179;
 pub const SYSCALL_BRK: usize = 214;
 pub const SYSCALL_MUNMAP: usize = 215;
+pub const SYSCALL_ADD_KEY: usize = 217;
+pub const SYSCALL_REQUEST_KEY: usize = 218;
+pub const SYSCALL_KEYCTL: usize = 219;
 pub const SYSCALL_CLONE: usize = 220;
 pub const SYSCALL_EXECVE: usize = 221;
 pub const SYSCALL_MMAP: usize = 222;
@@ -147,6 +153,7 @@ pub const SYSCALL_MADVISE: usize = 233;
 pub const SYSCALL_WAIT4: usize = 260;
 pub const SYSCALL_PRLIMIT64: usize = 261;
 pub const SYSCALL_ACCEPT4: usize = 242;
+pub const SYSCALL_PERF_EVENT_OPEN: usize = 241;
 pub const SYSCALL_RISCV_HWPROBE: usize = 258;
 pub const SYSCALL_RENAMEAT2: usize = 276;
 
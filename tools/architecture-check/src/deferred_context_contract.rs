@@ -352,10 +352,11 @@ fn check_software_interrupt_acknowledgement(source: &SourceFile, errors: &mut Ve
     let expected = [
         "arch::interrupt::clear_software",
         "crate::task::complete_pending_memory_barrier",
+        "timer::rearm_boot_event",
     ];
     if calls != expected {
         errors.push(format!(
-            "{TRAP_SOURCE}: supervisor software interrupt must first acknowledge SSIP and then complete the synchronous memory barrier, with no deferred domain dispatch; found {calls:?}"
+            "{TRAP_SOURCE}: supervisor software interrupt must first acknowledge SSIP, complete the synchronous memory barrier and re-arm the boot timer event, with no deferred domain dispatch; found {calls:?}"
         ));
     }
 }
@@ -20,6 +20,7 @@ const KERNEL_MODULES: &[&str] = &[
     "id",
     "input",
     "ipc",
+    "keys",
     "lang_item",
     "log",
     "main",
@@ -41,11 +41,15 @@ pub(super) fn check(root: &Path, errors: &mut Vec<String>) {
 fn check_direct_pipe_wake_one(root: &Path) -> Result<(), String> {
     let preparation = read(root, WAIT_PREPARATION_SOURCE)?;
     let prepare_pipe = function_body(&preparation, "fn prepare_pipe(", WAIT_PREPARATION_SOURCE)?;
-    if prepare_pipe.contains("exclusive: true") {
+    // FIFO open 的对端等待按 Linux `wake_up_partner` 全部唤醒；data readiness 等待仍须独占。
+    if prepare_pipe.contains("exclusive: true")
+        || prepare_pipe
+            .contains("exclusive: !matches!(condition, PipeWaitCondition::Partner { .. })")
+    {
         Ok(())
     } else {
         Err(format!(
-            "{WAIT_PREPARATION_SOURCE}: direct pipe waits must be exclusive so one readiness transition wakes one waiter"
+            "{WAIT_PREPARATION_SOURCE}: direct pipe data waits must be exclusive so one readiness transition wakes one waiter"
         ))
     }
 }
@@ -1,4 +1,5 @@
 #[path = "../../../kernel/src/drivers/block.rs"]
+#[allow(unused_imports)]
 pub(crate) mod block;
 
 #[path = "../../../kernel/src/drivers/io_completion.rs"]
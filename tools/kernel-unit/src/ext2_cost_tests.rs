@@ -13,15 +13,15 @@ use alloc::sync::Arc;
 
 use crate::{
     InodeType,
-    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError},
+    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError, BlockRequest},
     fs::{
         CreateMetadata, DirectoryEntry, DirectoryVisit, DirectoryVisitor, FileSystem,
         FileSystemError,
         ext2::{
             Ext2FileSystem, TestMappedInode, clear_test_metadata_cache,
-            fail_next_test_metadata_owner, reset_test_allocation_attempts,
+            fail_next_test_metadata_owner, reset_test_allocation_attempts, reset_test_name_lookups,
             reset_test_stage_capacity, reset_test_write_costs, set_test_stage_capacity,
-            test_allocation_attempts, test_write_costs,
+            test_allocation_attempts, test_name_lookups, test_write_costs,
         },
     },
     regular_write_policy::regular_write_chunk,
@@ -106,6 +106,29 @@ impl BlockDevice for CountingImage {
         Ok(buf.len())
     }
 
+    fn submit_write(&self, block_id: usize, buf: &[u8]) -> Result<BlockRequest, BlockError> {
+        self.write_block(block_id, buf)?;
+        Ok(BlockRequest::new(0, 0))
+    }
+
+    fn submit_read(&self, block_id: usize) -> Result<BlockRequest, BlockError> {
+        // 同步 mock 不占用 slot；generation 携带 block id，complete_read 时再读取。
+        Ok(BlockRequest::new(0, block_id as u64))
+    }
+
+    fn complete_read(&self, request: BlockRequest, buf: &mut [u8]) -> Result<(), BlockError> {
+        self.read_block(request.generation() as usize, buf)
+            .map(|_| ())
+    }
+
+    fn complete(&self, _request: BlockRequest) -> Result<(), BlockError> {
+        Ok(())
+    }
+
+    fn queue_depth(&self) -> usize {
+        16
+    }
+
     fn flush(&self) -> Result<(), BlockError> {
         self.flushes.fetch_add(1, Ordering::Relaxed);
         if self.fail_next_flush.swap(false, Ordering::Relaxed) {
@@ -118,6 +141,11 @@ impl BlockDevice for CountingImage {
         BLOCK_SIZE
     }
 
+    fn block_count(&self) -> usize {
+        let length = self.image.lock().unwrap().metadata().unwrap().len();
+        (length / BLOCK_SIZE as u64) as usize
+    }
+
     fn dispatch_completions(&self) -> bool {
         false
     }
@@ -228,6 +256,18 @@ fn committed_rename_publishes_only_the_new_directory_image() {
     let root = fs.root_inode().unwrap();
     let original = root.find_child(b"bin").unwrap();
     let inode = original.metadata().unwrap().inode;
+    reset_test_name_lookups();
+    for _ in 0..2 {
+        assert!(matches!(
+            root.find_child(b"bin-cache-rename"),
+            Err(FileSystemError::NotFound)
+        ));
+    }
+    assert_eq!(
+        test_name_lookups(),
+        (1, 1),
+        "negative lookup must be cached"
+    );
     root.rename(b"bin", 2, b"bin-cache-rename", true).unwrap();
     assert_eq!(
         root.find_child(b"bin-cache-rename")
@@ -520,3 +560,41 @@ fn concurrent_truncate_and_indirect_write_publish_one_serial_order() {
     }
     file.write_storage(0, &[0x7f]).unwrap();
 }
+
+#[test]
+fn paged_storage_read_matches_contiguous_read() {
+    let _serial = COST_TEST_LOCK.lock().unwrap();
+    let (_image, fs) = mounted();
+    let root = fs.root_inode().unwrap();
+    let file = root
+        .create(
+            b"readahead-window",
+            InodeType::File,
+            CreateMetadata {
+                mode: 0o644,
+                uid: 0,
+                gid: 0,
+            },
+        )
+        .unwrap();
+    let page = crate::memory::PAGE_SIZE;
+    let input = (0..8 * page + 100)
+        .map(|index| (index % 251) as u8)
+        .collect::<Vec<_>>();
+    assert_eq!(file.write_storage(0, &input).unwrap(), input.len());
+    let mut contiguous = vec![0; input.len()];
+    assert_eq!(file.read_storage(0, &mut contiguous).unwrap(), input.len());
+    // 末页只有 EOF 前的有效前缀；独立 page buffer 模拟 readahead window 的非连续 frame。
+    let mut pages = vec![vec![0u8; page]; 8];
+    pages.push(vec![0u8; 100]);
+    let mut segments = pages
+        .iter_mut()
+        .map(|page| page.as_mut_slice())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        file.read_storage_pages(0, &mut segments).unwrap(),
+        input.len()
+    );
+    assert_eq!(pages.concat(), contiguous);
+    assert_eq!(contiguous, input);
+}
@@ -0,0 +1,373 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::PathBuf,
+    sync::{Mutex, PoisonError},
+};
+
+use alloc::sync::Arc;
+
+use crate::{
+    InodeType,
+    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError, BlockRequest},
+    fs::{
+        CreateMetadata, FileSystem, Inode,
+        ext2::{
+            Ext2FileSystem, clear_test_metadata_cache, drop_test_read_pipeline,
+            drop_test_write_pipeline, test_pipeline_depths,
+        },
+    },
+};
+
+use crate::ext2_cost_tests::COST_TEST_LOCK;
+
+/// virtio-blk 的 device-wide request slot 数。
+const SLOTS: usize = 16;
+/// 一条 ext2 流水可占用的 slot 份额。
+const PIPELINE_SHARE: usize = SLOTS / 4;
+
+enum Pending {
+    /// 模拟另一 caller 持有、本测试期间不归还的 slot。
+    Held,
+    Read(usize),
+    Write(usize, Vec<u8>),
+}
+
+#[derive(Default)]
+struct Slots {
+    requests: Vec<Option<(u64, Pending)>>,
+    generation: u64,
+    peak: usize,
+    exhausted: usize,
+    /// 流水 token 未归还时发起的同步 request 数；这类 request 可能等待本 task 自己持有的 slot。
+    synchronous_while_pipelined: usize,
+    submits_before_failure: Option<usize>,
+    completions_before_failure: Option<usize>,
+}
+
+impl Slots {
+    fn outstanding(&self) -> usize {
+        self.requests
+            .iter()
+            .flatten()
+            .filter(|(_, pending)| !matches!(pending, Pending::Held))
+            .count()
+    }
+}
+
+/// Fixed request slots with completion deferred until `complete`, like the virtio-blk adapter.
+///
+/// A submit that finds every slot taken would sleep forever on real hardware if the waiting task
+/// itself holds the slots; the mock records it as exhaustion and fails the request instead.
+struct SlotImage {
+    image: Mutex<File>,
+    overlay: Mutex<BTreeMap<usize, Vec<u8>>>,
+    slots: Mutex<Slots>,
+}
+
+impl SlotImage {
+    fn open() -> Arc<Self> {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../fs.img");
+        let mut slots = Slots::default();
+        slots.requests.resize_with(SLOTS, || None);
+        Arc::new(Self {
+            image: Mutex::new(File::open(path).expect("open repository ext image")),
+            overlay: Mutex::new(BTreeMap::new()),
+            slots: Mutex::new(slots),
+        })
+    }
+
+    fn hold_other_caller_slots(&self, count: usize) {
+        for _ in 0..count {
+            let _held = self.reserve(Pending::Held).unwrap();
+        }
+    }
+
+    fn reset_peak(&self) {
+        self.slots.lock().unwrap().peak = 0;
+    }
+
+    fn peak(&self) -> usize {
+        self.slots.lock().unwrap().peak
+    }
+
+    fn outstanding(&self) -> usize {
+        self.slots.lock().unwrap().outstanding()
+    }
+
+    fn exhausted(&self) -> usize {
+        self.slots.lock().unwrap().exhausted
+    }
+
+    fn synchronous_while_pipelined(&self) -> usize {
+        self.slots.lock().unwrap().synchronous_while_pipelined
+    }
+
+    fn record_synchronous_request(&self) {
+        let mut slots = self.slots.lock().unwrap();
+        if slots.outstanding() > 0 {
+            slots.synchronous_while_pipelined += 1;
+        }
+    }
+
+    fn fail_submit_after(&self, successes: usize) {
+        self.slots.lock().unwrap().submits_before_failure = Some(successes);
+    }
+
+    fn fail_completion_after(&self, successes: usize) {
+        self.slots.lock().unwrap().completions_before_failure = Some(successes);
+    }
+
+    fn reserve(&self, pending: Pending) -> Result<BlockRequest, BlockError> {
+        let mut slots = self.slots.lock().unwrap();
+        if let Some(remaining) = slots.submits_before_failure.as_mut() {
+            if *remaining == 0 {
+                slots.submits_before_failure = None;
+                return Err(BlockError::IoError);
+            }
+            *remaining -= 1;
+        }
+        let Some(slot) = slots.requests.iter().position(Option::is_none) else {
+            slots.exhausted += 1;
+            return Err(BlockError::DeviceError);
+        };
+        slots.generation += 1;
+        let generation = slots.generation;
+        slots.requests[slot] = Some((generation, pending));
+        slots.peak = slots.peak.max(slots.outstanding());
+        Ok(BlockRequest::new(slot as u16, generation))
+    }
+
+    fn release(&self, request: BlockRequest) -> Result<Pending, BlockError> {
+        let mut slots = self.slots.lock().unwrap();
+        let (generation, pending) = slots.requests[request.slot() as usize]
+            .take()
+            .expect("completed an idle slot");
+        assert_eq!(generation, request.generation(), "stale request token");
+        if let Some(remaining) = slots.completions_before_failure.as_mut() {
+            if *remaining == 0 {
+                slots.completions_before_failure = None;
+                return Err(BlockError::IoError);
+            }
+            *remaining -= 1;
+        }
+        Ok(pending)
+    }
+
+    fn read_image(&self, block_id: usize, buf: &mut [u8]) -> Result<(), BlockError> {
+        if let Some(block) = self.overlay.lock().unwrap().get(&block_id) {
+            buf.copy_from_slice(block);
+            return Ok(());
+        }
+        let mut image = self.image.lock().unwrap();
+        image
+            .seek(SeekFrom::Start(block_id as u64 * BLOCK_SIZE as u64))
+            .map_err(|_| BlockError::IoError)?;
+        image.read_exact(buf).map_err(|_| BlockError::IoError)
+    }
+}
+
+impl BlockDevice for SlotImage {
+    fn read_block(&self, block_id: usize, buf: &mut [u8]) -> Result<usize, BlockError> {
+        self.record_synchronous_request();
+        let request = self.submit_read(block_id)?;
+        self.complete_read(request, buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_block(&self, block_id: usize, buf: &[u8]) -> Result<usize, BlockError> {
+        self.record_synchronous_request();
+        let request = self.submit_write(block_id, buf)?;
+        self.complete(request)?;
+        Ok(buf.len())
+    }
+
+    fn submit_write(&self, block_id: usize, buf: &[u8]) -> Result<BlockRequest, BlockError> {
+        if buf.len() != BLOCK_SIZE {
+            return Err(BlockError::InvalidBlock);
+        }
+        self.reserve(Pending::Write(block_id, buf.to_vec()))
+    }
+
+    fn submit_read(&self, block_id: usize) -> Result<BlockRequest, BlockError> {
+        self.reserve(Pending::Read(block_id))
+    }
+
+    fn complete_read(&self, request: BlockRequest, buf: &mut [u8]) -> Result<(), BlockError> {
+        if buf.len() != BLOCK_SIZE {
+            return Err(BlockError::InvalidBlock);
+        }
+        match self.release(request)? {
+            Pending::Read(block_id) => self.read_image(block_id, buf),
+            _ => panic!("read completion for a non-read request"),
+        }
+    }
+
+    fn complete(&self, request: BlockRequest) -> Result<(), BlockError> {
+        match self.release(request)? {
+            Pending::Write(block_id, bytes) => {
+                self.overlay.lock().unwrap().insert(block_id, bytes);
+                Ok(())
+            }
+            _ => panic!("write completion for a non-write request"),
+        }
+    }
+
+    fn queue_depth(&self) -> usize {
+        SLOTS
+    }
+
+    fn flush(&self) -> Result<(), BlockError> {
+        Ok(())
+    }
+
+    fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+
+    fn block_count(&self) -> usize {
+        let length = self.image.lock().unwrap().metadata().unwrap().len();
+        (length / BLOCK_SIZE as u64) as usize
+    }
+
+    fn dispatch_completions(&self) -> bool {
+        false
+    }
+}
+
+fn mounted() -> (Arc<SlotImage>, Arc<Ext2FileSystem>) {
+    let image = SlotImage::open();
+    let fs = Ext2FileSystem::new(image.clone()).expect("mount repository ext image");
+    image.reset_peak();
+    (image, fs)
+}
+
+fn pattern(length: usize) -> Vec<u8> {
+    (0..length).map(|index| (index % 251) as u8).collect()
+}
+
+fn create_file(fs: &Ext2FileSystem, name: &[u8]) -> Arc<dyn Inode> {
+    let metadata = CreateMetadata {
+        mode: 0o644,
+        uid: 0,
+        gid: 0,
+    };
+    fs.root_inode()
+        .unwrap()
+        .create(name, InodeType::File, metadata)
+        .unwrap()
+}
+
+#[test]
+fn journal_commit_stays_within_its_slot_share_beside_another_caller() {
+    let _serial = COST_TEST_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (image, fs) = mounted();
+    image.hold_other_caller_slots(SLOTS - PIPELINE_SHARE);
+    let file = create_file(&fs, b"pipeline-share");
+    let input = pattern(64 * BLOCK_SIZE);
+
+    assert_eq!(file.write_storage(0, &input).unwrap(), input.len());
+    assert_eq!(image.exhausted(), 0);
+    assert_eq!(image.peak(), PIPELINE_SHARE);
+    assert_eq!(image.outstanding(), 0);
+}
+
+#[test]
+fn failed_write_completion_drains_every_outstanding_token() {
+    let _serial = COST_TEST_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (image, fs) = mounted();
+    let file = create_file(&fs, b"pipeline-completion-error");
+    image.reset_peak();
+    image.fail_completion_after(8);
+
+    assert!(file.write_storage(0, &pattern(32 * BLOCK_SIZE)).is_err());
+    assert!(image.peak() > 1);
+    assert_eq!(image.outstanding(), 0);
+}
+
+#[test]
+fn failed_write_submit_mid_stream_drains_every_outstanding_token() {
+    let _serial = COST_TEST_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (image, fs) = mounted();
+    let file = create_file(&fs, b"pipeline-submit-error");
+    image.reset_peak();
+    image.fail_submit_after(8);
+
+    assert!(file.write_storage(0, &pattern(32 * BLOCK_SIZE)).is_err());
+    assert!(image.peak() > 1);
+    assert_eq!(image.outstanding(), 0);
+}
+
+#[test]
+fn pipelined_read_resolves_block_map_and_partial_blocks_before_submitting() {
+    let _serial = COST_TEST_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (image, fs) = mounted();
+    let file = create_file(&fs, b"pipeline-indirect-read");
+    // 12 个 direct block 之后的数据经 single-indirect block 映射。
+    let input = pattern(48 * BLOCK_SIZE);
+    assert_eq!(file.write_storage(0, &input).unwrap(), input.len());
+    clear_test_metadata_cache(&fs);
+    image.reset_peak();
+
+    // 首尾为 partial block，中间完整块跨越 direct 与 indirect 映射。
+    let offset = BLOCK_SIZE / 2;
+    let mut output = vec![0; 40 * BLOCK_SIZE];
+    assert_eq!(
+        file.read_storage(offset as u64, &mut output).unwrap(),
+        output.len()
+    );
+    assert_eq!(output, input[offset..offset + output.len()]);
+    assert_eq!(image.synchronous_while_pipelined(), 0);
+    assert_eq!(image.exhausted(), 0);
+    assert_eq!(image.peak(), PIPELINE_SHARE);
+    assert_eq!(image.outstanding(), 0);
+}
+
+#[test]
+fn dropped_pipelines_complete_outstanding_tokens() {
+    let _serial = COST_TEST_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (image, fs) = mounted();
+    let blocks = (1..=8).collect::<Vec<u32>>();
+
+    let images = drop_test_read_pipeline(&fs, &blocks).unwrap();
+    assert_eq!(image.peak(), PIPELINE_SHARE);
+    assert_eq!(image.outstanding(), 0);
+    for (block, bytes) in blocks.iter().zip(&images) {
+        let mut expected = vec![0; BLOCK_SIZE];
+        image.read_image(*block as usize, &mut expected).unwrap();
+        assert_eq!(bytes, &expected);
+    }
+
+    image.reset_peak();
+    let writes = blocks.iter().copied().zip(images).collect::<Vec<_>>();
+    drop_test_write_pipeline(&fs, &writes);
+    assert_eq!(image.peak(), PIPELINE_SHARE);
+    assert_eq!(image.outstanding(), 0);
+    let overlay = image.overlay.lock().unwrap();
+    for (block, bytes) in &writes {
+        assert_eq!(overlay.get(&(*block as usize)), Some(bytes));
+    }
+}
+
+#[test]
+fn concurrent_pipelines_leave_a_slot_no_blocked_pipeline_holds() {
+    let _serial = COST_TEST_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (_image, fs) = mounted();
+
+    // 每条流水等待 slot 时至多持有 depth-1 个 token：5 × 3 = 15 < 16，之后的流水不再流水化。
+    assert_eq!(test_pipeline_depths(&fs, 7), [4, 4, 4, 4, 4, 1, 1]);
+    assert_eq!(test_pipeline_depths(&fs, 1), [4]);
+}
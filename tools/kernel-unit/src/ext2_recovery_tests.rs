@@ -14,7 +14,7 @@ use alloc::sync::Arc;
 use crate::ext2_cost_tests::COST_TEST_LOCK;
 use crate::{
     InodeType,
-    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError},
+    drivers::block::{BLOCK_SIZE, BlockDevice, BlockError, BlockRequest},
     fs::{
         CreateMetadata, FileSystem, FileSystemError,
         ext2::{
@@ -136,6 +136,29 @@ impl BlockDevice for RecoveryImage {
         Ok(buf.len())
     }
 
+    fn submit_write(&self, block_id: usize, buf: &[u8]) -> Result<BlockRequest, BlockError> {
+        self.write_block(block_id, buf)?;
+        Ok(BlockRequest::new(0, 0))
+    }
+
+    fn submit_read(&self, block_id: usize) -> Result<BlockRequest, BlockError> {
+        // 同步 mock 不占用 slot；generation 携带 block id，complete_read 时再读取。
+        Ok(BlockRequest::new(0, block_id as u64))
+    }
+
+    fn complete_read(&self, request: BlockRequest, buf: &mut [u8]) -> Result<(), BlockError> {
+        self.read_block(request.generation() as usize, buf)
+            .map(|_| ())
+    }
+
+    fn complete(&self, _request: BlockRequest) -> Result<(), BlockError> {
+        Ok(())
+    }
+
+    fn queue_depth(&self) -> usize {
+        16
+    }
+
     fn flush(&self) -> Result<(), BlockError> {
         let flush = self.flushes.fetch_add(1, Ordering::Relaxed) + 1;
         if self.descriptor_open.load(Ordering::Relaxed) {
@@ -151,6 +174,11 @@ impl BlockDevice for RecoveryImage {
         BLOCK_SIZE
     }
 
+    fn block_count(&self) -> usize {
+        let length = self.image.lock().unwrap().metadata().unwrap().len();
+        (length / BLOCK_SIZE as u64) as usize
+    }
+
     fn dispatch_completions(&self) -> bool {
         false
     }
@@ -65,6 +65,11 @@ pub(crate) trait Inode: Send + Sync {
     fn size(&self) -> u64;
     fn is_executable(&self) -> bool;
     fn read_storage(&self, offset: u64, bytes: &mut [u8]) -> Result<usize, FileSystemError>;
+    fn read_storage_pages(
+        &self,
+        offset: u64,
+        pages: &mut [&mut [u8]],
+    ) -> Result<usize, FileSystemError>;
     fn read_link(&self) -> Result<Vec<u8>, FileSystemError>;
     fn write_storage(&self, offset: u64, bytes: &[u8]) -> Result<usize, FileSystemError>;
     fn write_storage_batch(
@@ -0,0 +1,100 @@
+use core::{alloc::Layout, ptr::NonNull};
+
+#[path = "../../../kernel/src/memory/heap_allocator/size_class.rs"]
+mod size_class;
+
+use size_class::{MIN_CLASS_SIZE, class_size, slab_layout};
+
+#[path = "../../../kernel/src/memory/heap_allocator/sanitizer.rs"]
+mod sanitizer;
+
+use sanitizer::{Quarantine, poison_free_block, sanitize_allocation, sanitize_free};
+
+/// Host stand-in for one canonical slab block, aligned like the kernel's class blocks.
+#[repr(C, align(64))]
+struct Block([u8; 64]);
+
+impl Block {
+    fn free() -> Box<Self> {
+        let mut block = Box::new(Self([0; 64]));
+        poison_free_block(block.0.as_mut_ptr() as usize, 64);
+        block
+    }
+
+    fn pointer(&mut self) -> NonNull<u8> {
+        NonNull::from(&mut self.0).cast()
+    }
+}
+
+fn payload() -> Layout {
+    Layout::from_size_align(40, 8).unwrap()
+}
+
+#[test]
+fn redzone_survives_in_bounds_writes() {
+    let mut block = Block::free();
+    sanitize_allocation(block.pointer(), payload());
+    block.0[..40].fill(0x11);
+
+    sanitize_free(block.pointer(), payload());
+    sanitize_allocation(block.pointer(), payload());
+}
+
+#[test]
+#[should_panic(expected = "heap redzone overwritten: 40-byte allocation")]
+fn overflow_into_class_slack_panics_on_free() {
+    let mut block = Block::free();
+    sanitize_allocation(block.pointer(), payload());
+    block.0[40] = 0;
+
+    sanitize_free(block.pointer(), payload());
+}
+
+#[test]
+#[should_panic(expected = "modified at offset 16 while free")]
+fn write_after_free_panics_on_reuse() {
+    let mut block = Block::free();
+    sanitize_allocation(block.pointer(), payload());
+    sanitize_free(block.pointer(), payload());
+    block.0[16] = 0x42;
+
+    sanitize_allocation(block.pointer(), payload());
+}
+
+#[test]
+fn quarantine_evicts_blocks_in_free_order() {
+    let mut quarantine = Quarantine::new();
+    let mut blocks: Vec<_> = (0..66).map(|_| Block::free()).collect();
+    let pointers: Vec<_> = blocks.iter_mut().map(|block| block.pointer()).collect();
+
+    for &block in &pointers[..64] {
+        assert!(quarantine.admit(block, payload()).is_none());
+    }
+    let evicted = quarantine.admit(pointers[64], payload()).unwrap();
+    assert_eq!(evicted, (pointers[0], payload()));
+    let evicted = quarantine.admit(pointers[65], payload()).unwrap();
+    assert_eq!(evicted, (pointers[1], payload()));
+}
+
+#[test]
+fn quarantine_passes_direct_layouts_through() {
+    let mut quarantine = Quarantine::new();
+    let direct = Layout::from_size_align(4096, 8).unwrap();
+    let block = NonNull::<u8>::dangling();
+
+    assert_eq!(quarantine.admit(block, direct), Some((block, direct)));
+}
+
+#[test]
+#[should_panic(expected = "heap use-after-free: 64-byte block")]
+fn quarantine_eviction_checks_poison() {
+    let mut quarantine = Quarantine::new();
+    let mut blocks: Vec<_> = (0..65).map(|_| Block::free()).collect();
+    let pointers: Vec<_> = blocks.iter_mut().map(|block| block.pointer()).collect();
+    for &block in &pointers[..64] {
+        quarantine.admit(block, payload());
+    }
+    blocks[0].0[32] = 0;
+
+    quarantine.admit(pointers[64], payload());
+}
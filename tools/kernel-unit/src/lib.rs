@@ -15,6 +15,7 @@ pub(crate) enum InodeType {
     CharacterDevice,
     Fifo,
     Socket,
+    BlockDevice,
 }
 
 #[cfg(test)]
@@ -84,6 +85,9 @@ mod ext2_cost_tests;
 #[cfg(test)]
 mod ext2_recovery_tests;
 
+#[cfg(test)]
+mod ext2_pipeline_tests;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/fs/directory.rs"]
 #[allow(dead_code)]
@@ -114,9 +118,13 @@ mod inet_port_namespace_tests;
 
 #[cfg(test)]
 #[path = "../../../kernel/src/drivers/block.rs"]
-#[allow(dead_code)]
+#[allow(dead_code, unused_imports)]
 mod block_device;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/drivers/virtio_balloon/policy.rs"]
+mod virtio_balloon_policy;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/drivers/virtio_blk/policy.rs"]
 mod virtio_blk_policy;
@@ -146,6 +154,10 @@ mod journal_layout;
 #[path = "../../../kernel/src/fs/page_cache/writeback_batch.rs"]
 mod writeback_batch;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/fs/page_cache/readahead.rs"]
+mod page_cache_readahead;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/syscall/fs/io/write_limit.rs"]
 mod regular_write_policy;
@@ -165,6 +177,9 @@ mod fault_preflight;
 #[cfg(test)]
 mod memory_retire;
 
+#[cfg(test)]
+mod heap_sanitizer;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/task/model/user_context.rs"]
 mod task_user_context;
@@ -182,6 +197,14 @@ mod virtio_gpu_sequence_policy;
 #[path = "../../../kernel/src/timer/deadline.rs"]
 mod timer_deadline;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/platform/command_line.rs"]
+mod platform_command_line;
+
+#[cfg(test)]
+#[path = "../../../kernel/src/keys/store.rs"]
+mod key_store;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/platform/qemu_virt/riscv64/plic_policy.rs"]
 mod plic_policy;
@@ -221,6 +244,11 @@ mod user_iovec_tests;
 #[path = "../../../kernel/src/socket/message_limits.rs"]
 mod socket_message_limits;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/socket/audit/wire.rs"]
+#[allow(dead_code)]
+mod audit_wire;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/syscall/socket/receive_publication.rs"]
 mod socket_receive_publication;
@@ -269,6 +297,13 @@ mod getrandom_flags;
 #[cfg(test)]
 mod getrandom_flags_tests;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/syscall/perf_event_attr.rs"]
+mod perf_event_attr;
+
+#[cfg(test)]
+mod perf_event_attr_tests;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/fs/pty/input_notification.rs"]
 mod pty_input_notification;
@@ -320,10 +355,18 @@ mod thread_activation;
 #[path = "../../../kernel/src/task/task_manager/wait_publication.rs"]
 mod wait_publication;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/task/task_manager/out_of_memory/victim_policy.rs"]
+mod oom_victim_policy;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/task/task_manager/snapshot_staging.rs"]
 mod snapshot_staging;
 
+#[cfg(test)]
+#[path = "../../../kernel/src/task/task_manager/pid_namespace.rs"]
+mod pid_namespace;
+
 #[cfg(test)]
 #[path = "../../../kernel/src/task/task_manager/timer_queue/preparation_policy.rs"]
 mod timer_preparation_policy;
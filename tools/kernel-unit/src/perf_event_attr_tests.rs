@@ -0,0 +1,91 @@
+use crate::perf_event_attr::{
+    PERF_ATTR_SIZE_MAX, PERF_ATTR_SIZE_VER0, PerfAttrError, PerfCounterAttr, PerfHardwareEvent,
+    attr_copy_size, parse_counter_attr,
+};
+
+fn attr(kind: u32, config: u64, flags: u64) -> [u8; PERF_ATTR_SIZE_VER0] {
+    let mut bytes = [0u8; PERF_ATTR_SIZE_VER0];
+    bytes[0..4].copy_from_slice(&kind.to_ne_bytes());
+    bytes[4..8].copy_from_slice(&(PERF_ATTR_SIZE_VER0 as u32).to_ne_bytes());
+    bytes[8..16].copy_from_slice(&config.to_ne_bytes());
+    bytes[40..48].copy_from_slice(&flags.to_ne_bytes());
+    bytes
+}
+
+#[test]
+fn attr_size_zero_means_ver0_and_bounds_are_e2big() {
+    assert_eq!(attr_copy_size(0), Ok(PERF_ATTR_SIZE_VER0));
+    assert_eq!(attr_copy_size(136), Ok(136));
+    assert_eq!(attr_copy_size(32), Err(PerfAttrError::TooBig));
+    assert_eq!(
+        attr_copy_size(PERF_ATTR_SIZE_MAX as u32 + 1),
+        Err(PerfAttrError::TooBig)
+    );
+}
+
+#[test]
+fn hardware_cycles_and_instructions_are_counting_events() {
+    assert_eq!(
+        parse_counter_attr(&attr(0, 0, 0)),
+        Ok(PerfCounterAttr {
+            event: PerfHardwareEvent::Cycles,
+            disabled: false,
+        })
+    );
+    // disabled | exclude_kernel | exclude_hv：非特权 perf 工具的典型 counting 请求。
+    assert_eq!(
+        parse_counter_attr(&attr(0, 1, 0b110_0001)),
+        Ok(PerfCounterAttr {
+            event: PerfHardwareEvent::Instructions,
+            disabled: true,
+        })
+    );
+}
+
+#[test]
+fn unmodelled_events_report_no_pmu_event() {
+    // cache-misses 与 software task-clock 都没有 LiteOS PMU 事件。
+    assert_eq!(
+        parse_counter_attr(&attr(0, 3, 0)),
+        Err(PerfAttrError::NoEvent)
+    );
+    assert_eq!(
+        parse_counter_attr(&attr(1, 1, 0)),
+        Err(PerfAttrError::NoEvent)
+    );
+}
+
+#[test]
+fn sampling_and_user_exclusion_are_unsupported() {
+    let mut sampling = attr(0, 0, 0);
+    sampling[16..24].copy_from_slice(&4096u64.to_ne_bytes());
+    assert_eq!(
+        parse_counter_attr(&sampling),
+        Err(PerfAttrError::Unsupported)
+    );
+    assert_eq!(
+        parse_counter_attr(&attr(0, 0, 1 << 4)),
+        Err(PerfAttrError::Unsupported)
+    );
+    assert_eq!(
+        parse_counter_attr(&attr(0, 0, 1 << 1)),
+        Err(PerfAttrError::Unsupported)
+    );
+    let mut formatted = attr(0, 0, 0);
+    formatted[32..40].copy_from_slice(&1u64.to_ne_bytes());
+    assert_eq!(
+        parse_counter_attr(&formatted),
+        Err(PerfAttrError::Unsupported)
+    );
+}
+
+#[test]
+fn reserved_bits_are_invalid() {
+    assert_eq!(
+        parse_counter_attr(&attr(0, 0, 1 << 38)),
+        Err(PerfAttrError::Invalid)
+    );
+    let mut formatted = attr(0, 0, 0);
+    formatted[32..40].copy_from_slice(&(1u64 << 5).to_ne_bytes());
+    assert_eq!(parse_counter_attr(&formatted), Err(PerfAttrError::Invalid));
+}
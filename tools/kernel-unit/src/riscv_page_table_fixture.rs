@@ -16,6 +16,9 @@ mod mmu {
     }
 
     pub(crate) fn release_address_space_id_after_global_fence(_address_space_id: usize) {}
+    pub(crate) fn address_space_cpus(_address_space_id: usize) -> usize {
+        usize::MAX
+    }
     pub(crate) fn activate_kernel(_token: AddressSpaceToken) {}
 }
 
@@ -2,7 +2,7 @@ use crate::{plic_policy, sv39, timer_deadline};
 
 #[cfg(test)]
 mod timer_deadline_tests {
-    use super::timer_deadline::next;
+    use super::timer_deadline::{counter_at, next};
 
     #[test]
     fn first_deadline_starts_one_interval_after_now() {
@@ -26,6 +26,19 @@ mod timer_deadline_tests {
         assert_eq!(next(100, 100, 0), None);
         assert_eq!(next(0, u64::MAX, 1), None);
     }
+
+    #[test]
+    fn event_counter_never_fires_before_the_deadline() {
+        assert_eq!(counter_at(1_000, 10_000_000), 10);
+        assert_eq!(counter_at(1_001, 10_000_000), 11);
+        assert_eq!(counter_at(0, 62_500_000), 0);
+    }
+
+    #[test]
+    fn absent_or_unrepresentable_event_saturates() {
+        assert_eq!(counter_at(u64::MAX, 1_000_000_000), u64::MAX);
+        assert_eq!(counter_at(u64::MAX, 10_000_000_000), u64::MAX);
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{socket_message_limits, unix_datagram_queue, user_iovec};
+use crate::{audit_wire, socket_message_limits, unix_datagram_queue, user_iovec};
 
 #[cfg(test)]
 mod socket_message_limit_tests {
@@ -15,8 +15,8 @@ mod socket_message_limit_tests {
         assert!(validate_send_length(MessageProtocol::Ipv4Udp, 65_508).is_err());
         assert!(validate_send_length(MessageProtocol::Ipv4Raw, 65_515).is_ok());
         assert!(validate_send_length(MessageProtocol::Ipv4Packet, 1_501).is_err());
-        assert!(validate_send_length(MessageProtocol::NetlinkUevent, 65_535).is_ok());
-        assert!(validate_send_length(MessageProtocol::NetlinkUevent, 65_536).is_err());
+        assert!(validate_send_length(MessageProtocol::Netlink, 65_535).is_ok());
+        assert!(validate_send_length(MessageProtocol::Netlink, 65_536).is_err());
         assert!(validate_send_length(MessageProtocol::Unsupported, 1).is_err());
     }
 
@@ -401,3 +401,148 @@ mod unix_datagram_queue_tests {
         assert!(!peer_identity_changed::<()>(&None, &None));
     }
 }
+
+#[cfg(test)]
+mod audit_wire_tests {
+    use super::audit_wire::{
+        AuditError, AuditRecord, RECORD_CAPACITY, RULE_BYTES, RuleSubject, SyscallFacts,
+        next_message, parse_rule, parse_status_change,
+    };
+
+    const ARCH_RISCV64: u32 = 0xc000_00f3;
+
+    fn rule_payload(action: u32, fields: &[(u32, u32, u32)]) -> [u8; RULE_BYTES] {
+        let mut bytes = [0u8; RULE_BYTES];
+        let mut put = |index: usize, value: u32| {
+            bytes[index * 4..index * 4 + 4].copy_from_slice(&value.to_ne_bytes());
+        };
+        put(0, 0x04);
+        put(1, action);
+        put(2, fields.len() as u32);
+        put(3 + 173 / 32, 1 << (173 % 32));
+        for (index, (field, value, operator)) in fields.iter().enumerate() {
+            put(3 + 64 + index, *field);
+            put(3 + 128 + index, *value);
+            put(3 + 192 + index, *operator);
+        }
+        bytes
+    }
+
+    #[test]
+    fn audit_exit_rule_matches_syscall_and_every_field() {
+        let payload = rule_payload(2, &[(11, ARCH_RISCV64, 0x4000_0000), (1, 0, 0x3000_0000)]);
+        let (rule, prepend) = parse_rule(&payload).unwrap();
+        assert!(!prepend);
+        assert_eq!(&rule.encode()[..], &payload[..]);
+        let subject = RuleSubject {
+            arch: ARCH_RISCV64,
+            syscall: 173,
+            pid: 7,
+            uid: 1000,
+        };
+        assert_eq!(rule.decide(subject), Some(true));
+        assert_eq!(rule.decide(RuleSubject { uid: 0, ..subject }), None);
+        assert_eq!(
+            rule.decide(RuleSubject {
+                syscall: 172,
+                ..subject
+            }),
+            None
+        );
+        assert_eq!(
+            rule.decide(RuleSubject {
+                syscall: 1 << 20,
+                ..subject
+            }),
+            None
+        );
+
+        // string 字段、未知 action 与非 exit filter 都不会被静默接受。
+        let unsupported = rule_payload(2, &[(13, 0, 0x4000_0000)]);
+        assert_eq!(parse_rule(&unsupported), Err(AuditError::Invalid));
+        assert_eq!(parse_rule(&rule_payload(1, &[])), Err(AuditError::Invalid));
+        assert_eq!(
+            parse_rule(&payload[..RULE_BYTES - 1]),
+            Err(AuditError::Invalid)
+        );
+    }
+
+    #[test]
+    fn audit_status_change_accepts_only_declared_mask_bits() {
+        let status = |words: &[u32]| -> Vec<u8> {
+            words.iter().flat_map(|word| word.to_ne_bytes()).collect()
+        };
+        let change = parse_status_change(&status(&[0x15, 1, 0, 42, 0, 8])).unwrap();
+        assert_eq!(change.enabled, Some(true));
+        assert_eq!(change.daemon, Some(42));
+        assert_eq!(change.backlog_limit, Some(8));
+        assert_eq!(
+            parse_status_change(&status(&[0x1, 2])),
+            Err(AuditError::Invalid)
+        );
+        assert_eq!(
+            parse_status_change(&status(&[0x2, 0, 2])),
+            Err(AuditError::Invalid)
+        );
+        assert_eq!(
+            parse_status_change(&status(&[0x10, 0, 0, 0, 0, 65])),
+            Err(AuditError::Invalid)
+        );
+        assert_eq!(parse_status_change(&[]).unwrap().enabled, None);
+    }
+
+    #[test]
+    fn audit_syscall_record_uses_linux_field_order() {
+        let record = AuditRecord::syscall(SyscallFacts {
+            seconds: 1_700_000_000,
+            milliseconds: 7,
+            serial: 3,
+            arch: ARCH_RISCV64,
+            syscall: 56,
+            result: -2,
+            arguments: [0xffff_ff9c, 0x1000, 0, 0],
+            pid: 12,
+            uid: 0,
+            euid: 0,
+            gid: 5,
+        });
+        assert_eq!(
+            core::str::from_utf8(record.text()).unwrap(),
+            "audit(1700000000.007:3): arch=c00000f3 syscall=56 success=no exit=-2 \
+             a0=ffffff9c a1=1000 a2=0 a3=0 items=0 pid=12 uid=0 gid=5 euid=0"
+        );
+    }
+
+    #[test]
+    fn audit_syscall_record_fits_every_maximum_width_field() {
+        let record = AuditRecord::syscall(SyscallFacts {
+            seconds: u64::MAX,
+            milliseconds: 999,
+            serial: u32::MAX,
+            arch: u32::MAX,
+            syscall: usize::MAX,
+            result: isize::MIN,
+            arguments: [usize::MAX; 4],
+            pid: u32::MAX,
+            uid: u32::MAX,
+            euid: u32::MAX,
+            gid: u32::MAX,
+        });
+        let text = core::str::from_utf8(record.text()).unwrap();
+        assert!(text.contains(" exit=-9223372036854775808 a0=ffffffffffffffff"));
+        assert!(text.ends_with(" euid=4294967295"));
+        assert_eq!(text.len(), 273);
+        assert!(text.len() <= RECORD_CAPACITY);
+    }
+
+    #[test]
+    fn netlink_iteration_stops_at_malformed_length() {
+        let mut datagram = [0u8; 36];
+        datagram[..4].copy_from_slice(&18u32.to_ne_bytes());
+        datagram[4..6].copy_from_slice(&1000u16.to_ne_bytes());
+        datagram[20..24].copy_from_slice(&64u32.to_ne_bytes());
+        let (header, payload, next) = next_message(&datagram, 0).unwrap();
+        assert_eq!((header.kind, payload.len(), next), (1000, 2, 20));
+        assert!(next_message(&datagram, next).is_none());
+    }
+}
@@ -4,6 +4,11 @@ use crate::{
     clone_errno::{clone_resource_errno, process_clone_memory_errno, thread_clone_memory_errno},
     clone_tid_store::store_clone_tid_values,
     console_batch::{CONSOLE_WAKE_BATCH, ConsoleWakeBatch},
+    oom_victim_policy::{
+        LargestResident, PendingVictim, PublishedVictim, VICTIM_EXIT_TIMEOUT_NS, VICTIM_RECHECK_NS,
+        pending_victim, recheck_deadline,
+    },
+    pid_namespace::{PidNamespace, PidNamespaceSlots},
     pty_input_notification::{PtyInputActions, pty_input_actions},
     snapshot_staging::{SnapshotCapacity, snapshot_capacity},
     terminal_input_batch::{
@@ -187,6 +192,61 @@ fn child_wait_final_recheck_precedes_staging_oom() {
     );
 }
 
+#[test]
+fn oom_selects_the_largest_other_process_and_keeps_the_first_on_ties() {
+    let mut largest = LargestResident::new();
+    assert_eq!(largest.victim(2), None);
+    largest.offer(2, 40);
+    largest.offer(3, 90);
+    largest.offer(4, 90);
+    largest.offer(5, 10);
+    assert_eq!(largest.victim(2), Some((3, 90)));
+    // caller 自身最大时不牺牲其他 Process。
+    assert_eq!(largest.victim(3), None);
+}
+
+#[test]
+fn oom_waits_for_a_live_victim_until_its_exit_deadline() {
+    let victim = PublishedVictim::new(7, 1_000);
+    assert_eq!(victim.deadline_ns, 1_000 + VICTIM_EXIT_TIMEOUT_NS);
+    assert_eq!(
+        pending_victim(2, Some(victim), true, 1_000),
+        PendingVictim::Wait
+    );
+    assert_eq!(
+        pending_victim(7, Some(victim), true, 1_000),
+        PendingVictim::KillCurrent
+    );
+    assert_eq!(
+        pending_victim(2, Some(victim), true, victim.deadline_ns),
+        PendingVictim::KillCurrent
+    );
+    assert_eq!(
+        recheck_deadline(victim, 1_000),
+        Some(1_000 + VICTIM_RECHECK_NS)
+    );
+    assert_eq!(
+        recheck_deadline(victim, victim.deadline_ns - 1),
+        Some(victim.deadline_ns)
+    );
+    assert_eq!(recheck_deadline(victim, victim.deadline_ns), None);
+}
+
+#[test]
+fn oom_reselects_once_the_published_victim_exits() {
+    let victim = PublishedVictim::new(7, 0);
+    assert_eq!(pending_victim(2, None, false, 0), PendingVictim::Reselect);
+    assert_eq!(
+        pending_victim(2, Some(victim), false, 0),
+        PendingVictim::Reselect
+    );
+    // 已退出的 victim 即便超时也不再决定 caller 的命运。
+    assert_eq!(
+        pending_victim(7, Some(victim), false, victim.deadline_ns),
+        PendingVictim::Reselect
+    );
+}
+
 #[test]
 fn graph_snapshot_retries_when_final_owner_observes_growth() {
     assert_eq!(
@@ -220,3 +280,49 @@ fn posix_timer_id_collision_retargets_the_prepared_node() {
     );
     assert_eq!(posix_create_action(false), PosixCreateAction::Commit);
 }
+
+#[test]
+fn pid_namespace_numbers_are_monotonic_and_released_after_reap() {
+    let mut namespace = PidNamespace::new(40, PidNamespaceSlots::try_new().unwrap());
+    assert_eq!(namespace.local(40), Some(1));
+    assert_eq!(namespace.global(1), Some(40));
+    let local = namespace.allocate();
+    assert_eq!(local, 2);
+    namespace.publish(local, 43, PidNamespaceSlots::try_new().unwrap());
+    namespace.add_process();
+    assert_eq!(namespace.last_allocated(), 2);
+    assert_eq!(namespace.global(2), Some(43));
+    assert_eq!(namespace.local(41), None);
+    // cursor 按全局编号推进，杀死成员时不会重复或遗漏 cursor 之后发布的编号。
+    assert_eq!(namespace.member_after(0), Some(40));
+    assert_eq!(namespace.member_after(40), Some(43));
+    assert_eq!(namespace.member_after(43), None);
+    assert!(!namespace.init_exited());
+    namespace.mark_init_exited();
+    assert!(namespace.init_exited());
+    assert!(!namespace.remove_process());
+    // 回收后的编号移出双向表，但 local 编号不复用。
+    assert!(namespace.release(43));
+    assert!(!namespace.release(43));
+    assert_eq!(namespace.global(2), None);
+    assert_eq!(namespace.member_after(40), None);
+    assert_eq!(namespace.allocate(), 3);
+    assert_eq!(namespace.global(1), Some(40));
+    assert!(namespace.remove_process());
+}
+
+#[test]
+fn pid_namespace_fork_and_reap_loop_keeps_maps_bounded() {
+    let mut namespace = PidNamespace::new(100, PidNamespaceSlots::try_new().unwrap());
+    for global in 101..1_101 {
+        let local = namespace.allocate();
+        namespace.publish(local, global, PidNamespaceSlots::try_new().unwrap());
+        namespace.add_process();
+        assert_eq!(namespace.published(), 2);
+        assert!(!namespace.remove_process());
+        assert!(namespace.release(global));
+        assert_eq!(namespace.published(), 1);
+    }
+    assert_eq!(namespace.last_allocated(), 1_001);
+    assert_eq!(namespace.local(100), Some(1));
+}
@@ -4,6 +4,7 @@ use std::{
     io,
     os::fd::{AsFd, BorrowedFd},
     path::PathBuf,
+    time::Instant,
 };
 
 use display_proto::PointerPhase;
@@ -18,6 +19,9 @@ const ABS_Y: u16 = 1;
 const BTN_LEFT: u16 = 272;
 const BTN_RIGHT: u16 = 273;
 const BTN_MIDDLE: u16 = 274;
+const KEY_F12: u16 = 88;
+/// Ctrl+Alt modifier bits (see `update_modifier`) that turn F12 into the overlay chord.
+const OVERLAY_MODIFIERS: u32 = 2 | 4;
 const EVENT_CAPACITY: usize = 64;
 
 pub struct Input {
@@ -34,6 +38,14 @@ pub struct Input {
     buttons: u32,
     modifiers: u32,
     serial: u64,
+    /// Set by a Ctrl+Alt+F12 press and consumed by [`Self::take_overlay_toggle`].
+    overlay_toggle: bool,
+    /// F12 went down as the overlay chord; its repeats and release stay compositor-owned
+    /// even if Ctrl or Alt is released first.
+    overlay_key_held: bool,
+    /// Earliest key press/repeat or button event routed to a client since the
+    /// last [`Self::take_accepted`].
+    accepted: Option<Instant>,
 }
 
 impl Input {
@@ -62,6 +74,9 @@ impl Input {
             buttons: 0,
             modifiers: 0,
             serial: 1,
+            overlay_toggle: false,
+            overlay_key_held: false,
+            accepted: None,
         }
     }
 
@@ -76,6 +91,19 @@ impl Input {
         (self.x, self.y)
     }
 
+    /// Reports (once) whether the frame-timing overlay chord was pressed.
+    pub fn take_overlay_toggle(&mut self) -> bool {
+        std::mem::take(&mut self.overlay_toggle)
+    }
+
+    /// Reports (once) when the earliest pending key or button event was routed.
+    ///
+    /// Pointer motion and key releases are excluded: they often change no pixels,
+    /// so they would leave a stale timestamp for an unrelated later scene.
+    pub fn take_accepted(&mut self) -> Option<Instant> {
+        self.accepted.take()
+    }
+
     /// Borrows the evdev descriptors so the main loop can wait on input readiness
     /// inside the same `poll` as the display sockets.
     ///
@@ -103,7 +131,22 @@ impl Input {
                 continue;
             }
             update_modifier(&mut self.modifiers, event.code(), event.value());
+            // The chord is compositor-owned: neither its press, repeat nor release
+            // reaches the focused client. The release is matched by the held flag,
+            // not the modifiers, because Ctrl or Alt may already be up by then.
+            if event.code() == KEY_F12
+                && (self.overlay_key_held
+                    || event.value() == 1
+                        && self.modifiers & OVERLAY_MODIFIERS == OVERLAY_MODIFIERS)
+            {
+                self.overlay_toggle ^= event.value() == 1;
+                self.overlay_key_held = event.value() != 0;
+                continue;
+            }
             session.route_key(u32::from(event.code()), event.value(), self.modifiers)?;
+            if event.value() != 0 {
+                self.accepted.get_or_insert_with(Instant::now);
+            }
         }
         Ok(())
     }
@@ -182,7 +225,9 @@ impl Input {
             button,
             self.buttons,
             self.take_serial(),
-        )
+        )?;
+        self.accepted.get_or_insert_with(Instant::now);
+        Ok(())
     }
 
     fn take_serial(&mut self) -> u64 {
@@ -6,6 +6,7 @@
 mod boot;
 mod cursor;
 mod input;
+mod overlay;
 mod scanout;
 mod session;
 
@@ -51,18 +52,30 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     // repeatedly; gating on elapsed time keeps the pre-desktop animation at a
     // steady 30 Hz instead of racing ahead on every stray event.
     let mut last_boot = Instant::now() - FRAME;
+    let mut stats = overlay::FrameStats::new();
+    // Acceptance time of the earliest routed input not yet answered by a flip.
+    let mut input_accepted: Option<Instant> = None;
     loop {
         // 1. Wait once on display sockets and evdev together. The wake borrow is
         //    scoped so `input` is free for mutable pumping below.
+        let waiting = Instant::now();
         let activity = {
             let wake = input.wake_fds();
             session.poll(&wake, FRAME)?
         };
-        // 2. A newly accepted scene is composed without the cursor, then the cursor
-        //    is overlaid and the whole frame flipped.
+        let woke = Instant::now();
+        stats.record_wake(woke - waiting, FRAME);
+        // 2. A newly accepted scene is composed without the cursor, then the
+        //    frame-timing overlay and the cursor are drawn and the whole frame flipped.
+        //    The first flip after routed input completes that input's latency.
         if let Some(scene) = activity.scene {
             scanout.compose(&scene, session.buffers())?;
+            stats.record_compose(woke.elapsed());
+            scanout.draw_stats(&stats);
             let event = scanout.present_scene(scene.revision, input.position())?;
+            if let Some(accepted) = input_accepted.take() {
+                stats.record_input_to_flip(accepted.elapsed(), FRAME);
+            }
             session.presented(&scene, event)?;
         } else if !session.desktop_ready() && last_boot.elapsed() >= FRAME {
             scanout.render_boot(boot_offset)?;
@@ -72,9 +85,16 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         }
         // 3. Drain evdev whenever it signalled (also clears its readability so the
         //    next poll can block). A pure pointer move updates only the cursor via
-        //    DIRTYFB, avoiding a scene recompose and page flip.
+        //    DIRTYFB, avoiding a scene recompose and page flip. The overlay chord
+        //    takes effect with the next presented scene.
         if activity.input {
             let moved = input.poll(&mut session)?;
+            if let Some(accepted) = input.take_accepted() {
+                input_accepted.get_or_insert(accepted);
+            }
+            if input.take_overlay_toggle() {
+                stats.toggle();
+            }
             if moved && session.desktop_ready() {
                 scanout.move_cursor(input.position())?;
             }
@@ -0,0 +1,244 @@
+//! Development frame-timing overlay toggled by Ctrl+Alt+F12.
+//!
+//! The compositor measures its own frame path and renders three lines into the
+//! composed back buffer before the cursor overlay:
+//!
+//! 1. `COMPOSE`: CPU time spent composing the accepted scene (last and window max).
+//! 2. `WAKE`: how late the idle `poll` timeout returned past its deadline, i.e. the
+//!    scheduler wake-up latency the compositor task actually observed.
+//! 3. `LATE`: flips whose input-to-flip latency exceeded the frame cadence, out of
+//!    all flips that answered input. The latency runs from routing the earliest
+//!    pending key or button event to the completion of the next page flip.
+//!
+//! Counters keep running while the overlay is hidden, so enabling it shows the
+//! history of the current session instead of starting from zero.
+
+use std::{fmt::Write, time::Duration};
+
+use linux_uapi::drm::DumbBuffer;
+
+/// Samples retained for the window maximum (~2 s at the 30 Hz cadence).
+const WINDOW: usize = 64;
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+const SCALE: usize = 2;
+const ADVANCE: usize = (GLYPH_WIDTH + 1) * SCALE;
+const LINE_HEIGHT: usize = (GLYPH_HEIGHT + 2) * SCALE;
+const LINE_CAPACITY: usize = 40;
+const MARGIN: usize = 8;
+const PADDING: usize = 6;
+const BACKGROUND: u32 = 0x0010_1010;
+const FOREGROUND: u32 = 0x0040_ff40;
+
+/// Fixed-capacity overlay text line; steady compositor frames must not allocate.
+struct Line {
+    bytes: [u8; LINE_CAPACITY],
+    len: usize,
+}
+
+impl Line {
+    fn text(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Write for Line {
+    /// Truncates silently at capacity; the overlay alphabet is ASCII-only.
+    fn write_str(&mut self, text: &str) -> std::fmt::Result {
+        let take = text.len().min(LINE_CAPACITY - self.len);
+        self.bytes[self.len..self.len + take].copy_from_slice(&text.as_bytes()[..take]);
+        self.len += take;
+        Ok(())
+    }
+}
+
+/// Fixed-size ring of microsecond samples.
+struct Window {
+    samples: [u32; WINDOW],
+    next: usize,
+    filled: usize,
+}
+
+impl Window {
+    const fn new() -> Self {
+        Self {
+            samples: [0; WINDOW],
+            next: 0,
+            filled: 0,
+        }
+    }
+
+    fn record(&mut self, duration: Duration) {
+        self.samples[self.next] = u32::try_from(duration.as_micros()).unwrap_or(u32::MAX);
+        self.next = (self.next + 1) % WINDOW;
+        self.filled = (self.filled + 1).min(WINDOW);
+    }
+
+    fn last(&self) -> u32 {
+        if self.filled == 0 {
+            return 0;
+        }
+        self.samples[(self.next + WINDOW - 1) % WINDOW]
+    }
+
+    fn max(&self) -> u32 {
+        self.samples[..self.filled]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Compositor-owned frame statistics and overlay visibility.
+pub struct FrameStats {
+    visible: bool,
+    compose: Window,
+    wake: Window,
+    late: u64,
+    answered: u64,
+}
+
+impl FrameStats {
+    pub const fn new() -> Self {
+        Self {
+            visible: false,
+            compose: Window::new(),
+            wake: Window::new(),
+            late: 0,
+            answered: 0,
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    pub fn record_compose(&mut self, duration: Duration) {
+        self.compose.record(duration);
+    }
+
+    /// Records how far a timed-out wait overshot its requested timeout.
+    pub fn record_wake(&mut self, waited: Duration, timeout: Duration) {
+        if let Some(latency) = waited.checked_sub(timeout) {
+            self.wake.record(latency);
+        }
+    }
+
+    /// Records one input-to-flip latency and whether it missed the frame `budget`.
+    pub fn record_input_to_flip(&mut self, latency: Duration, budget: Duration) {
+        self.answered += 1;
+        if latency > budget {
+            self.late += 1;
+        }
+    }
+
+    fn lines(&self) -> [Line; 3] {
+        let mut lines = [(); 3].map(|()| Line {
+            bytes: [0; LINE_CAPACITY],
+            len: 0,
+        });
+        let (compose, wake) = (&self.compose, &self.wake);
+        let _ = write!(
+            lines[0],
+            "COMPOSE {} US MAX {} US",
+            compose.last(),
+            compose.max()
+        );
+        let _ = write!(lines[1], "WAKE {} US MAX {} US", wake.last(), wake.max());
+        let _ = write!(lines[2], "LATE {} OF {}", self.late, self.answered);
+        lines
+    }
+
+    /// Draws the overlay into the top-left corner of `target` when visible.
+    pub fn draw(&self, target: &mut DumbBuffer) {
+        if !self.visible {
+            return;
+        }
+        let lines = self.lines();
+        let columns = lines.iter().map(|line| line.len).max().unwrap_or(0);
+        let width = (columns * ADVANCE + 2 * PADDING).min(target.width().saturating_sub(MARGIN));
+        let height =
+            (lines.len() * LINE_HEIGHT + 2 * PADDING).min(target.height().saturating_sub(MARGIN));
+        for row in MARGIN..MARGIN + height {
+            let pixels = target.row_mut(row);
+            pixels[MARGIN..MARGIN + width].fill(BACKGROUND);
+        }
+        for (index, line) in lines.iter().enumerate() {
+            let y = MARGIN + PADDING + index * LINE_HEIGHT;
+            for (column, character) in line.text().iter().enumerate() {
+                let x = MARGIN + PADDING + column * ADVANCE;
+                draw_glyph(
+                    target,
+                    x,
+                    y,
+                    glyph(*character),
+                    MARGIN + width,
+                    MARGIN + height,
+                );
+            }
+        }
+    }
+}
+
+/// Paints one scaled glyph, clipped to the overlay box `(right, bottom)`.
+fn draw_glyph(
+    target: &mut DumbBuffer,
+    x: usize,
+    y: usize,
+    rows: [u8; GLYPH_HEIGHT],
+    right: usize,
+    bottom: usize,
+) {
+    for (glyph_row, bits) in rows.into_iter().enumerate() {
+        for dy in 0..SCALE {
+            let row = y + glyph_row * SCALE + dy;
+            if row >= bottom {
+                return;
+            }
+            let pixels = target.row_mut(row);
+            for glyph_column in 0..GLYPH_WIDTH {
+                if bits & (0x10 >> glyph_column) == 0 {
+                    continue;
+                }
+                let start = x + glyph_column * SCALE;
+                let end = (start + SCALE).min(right);
+                if start < end {
+                    pixels[start..end].fill(FOREGROUND);
+                }
+            }
+        }
+    }
+}
+
+/// 5x7 bitmap rows (bit 4 is the leftmost column) for the overlay alphabet only;
+/// every other byte renders as a blank cell.
+fn glyph(character: u8) -> [u8; GLYPH_HEIGHT] {
+    match character {
+        b'0' => [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e],
+        b'1' => [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        b'2' => [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f],
+        b'3' => [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e],
+        b'4' => [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02],
+        b'5' => [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e],
+        b'6' => [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e],
+        b'7' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        b'8' => [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e],
+        b'9' => [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c],
+        b'A' => [0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        b'C' => [0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e],
+        b'E' => [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f],
+        b'F' => [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10],
+        b'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        b'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f],
+        b'M' => [0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11],
+        b'O' => [0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        b'P' => [0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10],
+        b'S' => [0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e],
+        b'T' => [0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        b'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        b'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a],
+        b'X' => [0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11],
+        _ => [0; GLYPH_HEIGHT],
+    }
+}
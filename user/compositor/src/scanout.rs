@@ -8,6 +8,7 @@ use linux_uapi::drm::{DrmDevice, DumbBuffer, FlipEvent, Topology};
 use crate::{
     boot::Canvas,
     cursor::Cursor,
+    overlay::FrameStats,
     session::{Buffers, Scene},
 };
 
@@ -143,6 +144,11 @@ impl Scanout {
         Ok(())
     }
 
+    /// Draws the frame-timing overlay (when enabled) into the composed back buffer.
+    pub fn draw_stats(&mut self, stats: &FrameStats) {
+        stats.draw(&mut self.targets[1 - self.front].buffer);
+    }
+
     /// Overlays the cursor into the freshly composed back buffer and flips.
     ///
     /// 1. Rasterizes the cursor into the back buffer, saving the clean scene pixels
@@ -0,0 +1,232 @@
+#include <errno.h>
+#include <fcntl.h>
+#include <inttypes.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/mman.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "bench.h"
+#include "perf.h"
+
+#define CHUNK_BYTES (64UL * 1024UL)
+#define PAGE_BYTES 4096UL
+#define BLOCK_PATH "/microbench.dat"
+
+struct bench {
+	const char *name;
+	const char *unit;
+	uint64_t ops;
+	int (*run)(uint64_t ops);
+};
+
+static char chunk[CHUNK_BYTES];
+
+static int syscall_bench(uint64_t ops)
+{
+	for (uint64_t index = 0; index < ops; ++index)
+		(void)getppid();
+	return 0;
+}
+
+/* 父子各持一端 pipe 回送 1 byte；每个 op 是一次往返，即两次跨进程 context switch。 */
+static int switch_bench(uint64_t ops)
+{
+	int forward[2];
+	int backward[2];
+	char byte = 0;
+	int status;
+	pid_t child;
+
+	if (pipe(forward) != 0 || pipe(backward) != 0)
+		return -1;
+	child = fork();
+	if (child < 0)
+		return -1;
+	if (child == 0) {
+		close(forward[1]);
+		close(backward[0]);
+		while (read(forward[0], &byte, 1) == 1 && write(backward[1], &byte, 1) == 1)
+			;
+		_exit(0);
+	}
+	close(forward[0]);
+	close(backward[1]);
+	for (uint64_t index = 0; index < ops; ++index) {
+		if (write(forward[1], &byte, 1) != 1 || read(backward[0], &byte, 1) != 1)
+			return -1;
+	}
+	close(forward[1]);
+	close(backward[0]);
+	return waitpid(child, &status, 0) == child && WIFEXITED(status) ? 0 : -1;
+}
+
+static int pipe_bench(uint64_t ops)
+{
+	int descriptors[2];
+	uint64_t received = 0;
+	int status;
+	ssize_t count;
+	pid_t child;
+
+	if (pipe(descriptors) != 0)
+		return -1;
+	child = fork();
+	if (child < 0)
+		return -1;
+	if (child == 0) {
+		close(descriptors[0]);
+		for (uint64_t sent = 0; sent < ops; sent += CHUNK_BYTES) {
+			if (write(descriptors[1], chunk, CHUNK_BYTES) != (ssize_t)CHUNK_BYTES)
+				_exit(1);
+		}
+		_exit(0);
+	}
+	close(descriptors[1]);
+	while ((count = read(descriptors[0], chunk, CHUNK_BYTES)) > 0)
+		received += (uint64_t)count;
+	close(descriptors[0]);
+	if (waitpid(child, &status, 0) != child || !WIFEXITED(status) || WEXITSTATUS(status) != 0)
+		return -1;
+	return count == 0 && received == ops ? 0 : -1;
+}
+
+static int spawn_bench(uint64_t ops)
+{
+	for (uint64_t index = 0; index < ops; ++index) {
+		int status;
+		pid_t child = fork();
+
+		if (child < 0)
+			return -1;
+		if (child == 0) {
+			execl("/bin/true", "true", (char *)NULL);
+			_exit(127);
+		}
+		if (waitpid(child, &status, 0) != child || !WIFEXITED(status) ||
+		    WEXITSTATUS(status) != 0)
+			return -1;
+	}
+	return 0;
+}
+
+static int fault_bench(uint64_t ops)
+{
+	size_t length = (size_t)ops * PAGE_BYTES;
+	volatile unsigned char *pages =
+		mmap(NULL, length, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+
+	if (pages == MAP_FAILED)
+		return -1;
+	for (uint64_t index = 0; index < ops; ++index)
+		pages[index * PAGE_BYTES] = (unsigned char)index;
+	return munmap((void *)pages, length);
+}
+
+/* 计时覆盖 write 与 fsync，度量的是写回到 block device 的带宽而不只是 page cache copy。 */
+static int block_bench(uint64_t ops)
+{
+	int descriptor = open(BLOCK_PATH, O_WRONLY | O_CREAT | O_TRUNC | O_CLOEXEC, 0600);
+	int result = 0;
+
+	if (descriptor < 0)
+		return -1;
+	for (uint64_t written = 0; written < ops && result == 0; written += CHUNK_BYTES) {
+		if (write(descriptor, chunk, CHUNK_BYTES) != (ssize_t)CHUNK_BYTES)
+			result = -1;
+	}
+	if (result == 0 && fsync(descriptor) != 0)
+		result = -1;
+	close(descriptor);
+	unlink(BLOCK_PATH);
+	return result;
+}
+
+static const struct bench BENCHES[] = {
+	{ "syscall", "call", 20000, syscall_bench },
+	{ "switch", "roundtrip", 2000, switch_bench },
+	{ "pipe", "byte", 4 * 1024 * 1024, pipe_bench },
+	{ "spawn", "exec", 20, spawn_bench },
+	{ "fault", "page", 1024, fault_bench },
+	{ "block", "byte", 1024 * 1024, block_bench },
+};
+
+#define BENCH_COUNT (sizeof(BENCHES) / sizeof(BENCHES[0]))
+
+static uint64_t monotonic_ns(void)
+{
+	struct timespec now;
+
+	clock_gettime(CLOCK_MONOTONIC, &now);
+	return (uint64_t)now.tv_sec * UINT64_C(1000000000) + (uint64_t)now.tv_nsec;
+}
+
+static int read_cycles(int counter, uint64_t *value)
+{
+	return counter >= 0 && read(counter, value, sizeof(*value)) == (ssize_t)sizeof(*value);
+}
+
+/* cycles 只计调用 Thread；switch/pipe/spawn 的对端进程不在该 counter 内。 */
+static int measure(const struct bench *bench, int counter)
+{
+	uint64_t cycles_before = 0;
+	uint64_t cycles_after = 0;
+	int counted = read_cycles(counter, &cycles_before);
+	uint64_t started = monotonic_ns();
+	uint64_t elapsed;
+
+	if (bench->run(bench->ops) != 0) {
+		fprintf(stderr, "microbench: %s: %s\n", bench->name, strerror(errno));
+		return -1;
+	}
+	elapsed = monotonic_ns() - started;
+	counted = counted && read_cycles(counter, &cycles_after);
+	printf("microbench name=%s unit=%s ops=%" PRIu64 " ns=%" PRIu64, bench->name, bench->unit,
+	       bench->ops, elapsed);
+	if (counted)
+		printf(" cycles=%" PRIu64 "\n", cycles_after - cycles_before);
+	else
+		printf(" cycles=unavailable\n");
+	return 0;
+}
+
+static int bench_index(const char *name)
+{
+	for (size_t index = 0; index < BENCH_COUNT; ++index) {
+		if (strcmp(name, BENCHES[index].name) == 0)
+			return (int)index;
+	}
+	return -1;
+}
+
+int run_microbench(int argc, char **argv)
+{
+	int selected[BENCH_COUNT];
+	int counter;
+	int result = 0;
+
+	for (size_t index = 0; index < BENCH_COUNT; ++index)
+		selected[index] = argc < 2;
+	for (int argument = 1; argument < argc; ++argument) {
+		int index = bench_index(argv[argument]);
+
+		if (index < 0) {
+			fprintf(stderr, "microbench: unknown benchmark %s\n", argv[argument]);
+			return 2;
+		}
+		selected[index] = 1;
+	}
+	memset(chunk, 0x5a, sizeof(chunk));
+	counter = perf_cycles_open();
+	for (size_t index = 0; index < BENCH_COUNT && result == 0; ++index) {
+		if (selected[index])
+			result = measure(&BENCHES[index], counter);
+	}
+	if (counter >= 0)
+		close(counter);
+	return result == 0 ? 0 : 1;
+}
@@ -0,0 +1,6 @@
+#ifndef LITEOS_BENCH_H
+#define LITEOS_BENCH_H
+
+int run_microbench(int argc, char **argv);
+
+#endif
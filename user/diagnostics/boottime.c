@@ -0,0 +1,51 @@
+#include <inttypes.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "boottime.h"
+
+#define BOOTLOG_PATH "/proc/bootlog"
+#define STAGE_NAME_BYTES 16
+
+/* 与 kernel BootStage 顺序一致；缺少任一 stage 表示 kernel 未完成该 boot 阶段。 */
+static const char *const STAGES[] = { "board", "memory", "drivers", "fs", "task", "smp" };
+
+#define STAGE_COUNT (sizeof(STAGES) / sizeof(STAGES[0]))
+
+int run_boottime(int argc, char **argv)
+{
+	char name[STAGE_NAME_BYTES];
+	uint64_t previous = 0;
+	uint64_t timestamp;
+	size_t next = 0;
+	FILE *bootlog;
+
+	(void)argv;
+	if (argc > 1) {
+		fprintf(stderr, "boottime: no arguments expected\n");
+		return 2;
+	}
+	bootlog = fopen(BOOTLOG_PATH, "re");
+	if (bootlog == NULL) {
+		perror("boottime: open " BOOTLOG_PATH);
+		return 1;
+	}
+	/* 每行形如 "<stage> <timestamp_us>"，timestamp 自 timebase 零点起算。 */
+	while (next < STAGE_COUNT && fscanf(bootlog, "%15s %" SCNu64, name, &timestamp) == 2) {
+		if (strcmp(name, STAGES[next]) != 0)
+			break;
+		/* 首个 stage 从 timebase 零点起算，包含 firmware 与 bootloader 时间。 */
+		printf("boottime stage=%s at_us=%" PRIu64 " duration_us=%" PRIu64 "\n", STAGES[next],
+		       timestamp, timestamp - previous);
+		previous = timestamp;
+		++next;
+	}
+	fclose(bootlog);
+	if (next != STAGE_COUNT) {
+		fprintf(stderr, "boottime: boot stage %s record missing\n", STAGES[next]);
+		return 1;
+	}
+	printf("boottime total_us=%" PRIu64 "\n", previous);
+	return 0;
+}
@@ -0,0 +1,6 @@
+#ifndef LITEOS_BOOTTIME_H
+#define LITEOS_BOOTTIME_H
+
+int run_boottime(int argc, char **argv);
+
+#endif
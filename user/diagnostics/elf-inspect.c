@@ -0,0 +1,374 @@
+#include <elf.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <inttypes.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/stat.h>
+#include <unistd.h>
+
+#include "elf-inspect.h"
+
+/* 只检查 rootfs 内的 LiteOS binary；上限避免误传块设备或巨型文件时耗尽诊断进程内存。 */
+#define MAX_ELF_BYTES (256UL * 1024UL * 1024UL)
+
+#define SHOW_HEADER 1U
+#define SHOW_PROGRAM 2U
+#define SHOW_SECTIONS 4U
+#define SHOW_DYNAMIC 8U
+
+struct elf_image {
+	const uint8_t *bytes;
+	size_t size;
+	const Elf64_Ehdr *header;
+};
+
+struct name_entry {
+	uint64_t value;
+	const char *name;
+};
+
+static const struct name_entry elf_types[] = {
+	{ ET_NONE, "NONE" }, { ET_REL, "REL" }, { ET_EXEC, "EXEC" },
+	{ ET_DYN, "DYN" }, { ET_CORE, "CORE" },
+};
+
+static const struct name_entry machines[] = {
+	{ EM_AARCH64, "AArch64" }, { EM_RISCV, "RISC-V" }, { EM_X86_64, "x86-64" },
+};
+
+static const struct name_entry program_types[] = {
+	{ PT_NULL, "NULL" }, { PT_LOAD, "LOAD" }, { PT_DYNAMIC, "DYNAMIC" },
+	{ PT_INTERP, "INTERP" }, { PT_NOTE, "NOTE" }, { PT_PHDR, "PHDR" },
+	{ PT_TLS, "TLS" }, { PT_GNU_EH_FRAME, "GNU_EH_FRAME" },
+	{ PT_GNU_STACK, "GNU_STACK" }, { PT_GNU_RELRO, "GNU_RELRO" },
+};
+
+static const struct name_entry section_types[] = {
+	{ SHT_NULL, "NULL" }, { SHT_PROGBITS, "PROGBITS" }, { SHT_SYMTAB, "SYMTAB" },
+	{ SHT_STRTAB, "STRTAB" }, { SHT_RELA, "RELA" }, { SHT_HASH, "HASH" },
+	{ SHT_DYNAMIC, "DYNAMIC" }, { SHT_NOTE, "NOTE" }, { SHT_NOBITS, "NOBITS" },
+	{ SHT_REL, "REL" }, { SHT_DYNSYM, "DYNSYM" }, { SHT_INIT_ARRAY, "INIT_ARRAY" },
+	{ SHT_FINI_ARRAY, "FINI_ARRAY" }, { SHT_GNU_HASH, "GNU_HASH" },
+	{ SHT_GNU_versym, "VERSYM" }, { SHT_GNU_verdef, "VERDEF" },
+	{ SHT_GNU_verneed, "VERNEED" },
+};
+
+static const struct name_entry dynamic_tags[] = {
+	{ DT_NEEDED, "NEEDED" }, { DT_PLTRELSZ, "PLTRELSZ" }, { DT_PLTGOT, "PLTGOT" },
+	{ DT_HASH, "HASH" }, { DT_STRTAB, "STRTAB" }, { DT_SYMTAB, "SYMTAB" },
+	{ DT_RELA, "RELA" }, { DT_RELASZ, "RELASZ" }, { DT_RELAENT, "RELAENT" },
+	{ DT_STRSZ, "STRSZ" }, { DT_SYMENT, "SYMENT" }, { DT_INIT, "INIT" },
+	{ DT_FINI, "FINI" }, { DT_SONAME, "SONAME" }, { DT_RPATH, "RPATH" },
+	{ DT_SYMBOLIC, "SYMBOLIC" }, { DT_PLTREL, "PLTREL" }, { DT_DEBUG, "DEBUG" },
+	{ DT_TEXTREL, "TEXTREL" }, { DT_JMPREL, "JMPREL" }, { DT_BIND_NOW, "BIND_NOW" },
+	{ DT_INIT_ARRAY, "INIT_ARRAY" }, { DT_FINI_ARRAY, "FINI_ARRAY" },
+	{ DT_INIT_ARRAYSZ, "INIT_ARRAYSZ" }, { DT_FINI_ARRAYSZ, "FINI_ARRAYSZ" },
+	{ DT_RUNPATH, "RUNPATH" }, { DT_FLAGS, "FLAGS" }, { DT_GNU_HASH, "GNU_HASH" },
+	{ DT_RELACOUNT, "RELACOUNT" }, { DT_FLAGS_1, "FLAGS_1" },
+	{ DT_VERSYM, "VERSYM" }, { DT_VERDEF, "VERDEF" }, { DT_VERDEFNUM, "VERDEFNUM" },
+	{ DT_VERNEED, "VERNEED" }, { DT_VERNEEDNUM, "VERNEEDNUM" },
+};
+
+#define NAME_OF(table, value) lookup_name(table, sizeof(table) / sizeof(table[0]), value)
+
+static const char *lookup_name(const struct name_entry *table, size_t count, uint64_t value)
+{
+	for (size_t index = 0; index < count; ++index) {
+		if (table[index].value == value)
+			return table[index].name;
+	}
+	return NULL;
+}
+
+static void print_name(const char *name, uint64_t value, int width)
+{
+	if (name != NULL)
+		printf("%-*s", width, name);
+	else
+		printf("0x%-*" PRIx64, width - 2, value);
+}
+
+/* 返回 [offset, offset + length) 的 file view；溢出或越界返回 NULL，调用方不得继续解码。 */
+static const void *image_range(const struct elf_image *image, uint64_t offset, uint64_t length)
+{
+	if (offset > image->size || length > image->size - offset)
+		return NULL;
+	return image->bytes + offset;
+}
+
+/* string table 中的 NUL 必须落在 file 内；否则返回 NULL，避免读越 image 尾部。 */
+static const char *image_string(const struct elf_image *image, uint64_t offset)
+{
+	const char *start = image_range(image, offset, 1);
+
+	if (start == NULL || memchr(start, '\0', image->size - offset) == NULL)
+		return NULL;
+	return start;
+}
+
+static const Elf64_Phdr *program_header(const struct elf_image *image, unsigned index)
+{
+	const Elf64_Ehdr *header = image->header;
+
+	return image_range(image, header->e_phoff + (uint64_t)index * sizeof(Elf64_Phdr),
+			   sizeof(Elf64_Phdr));
+}
+
+static const Elf64_Shdr *section_header(const struct elf_image *image, unsigned index)
+{
+	const Elf64_Ehdr *header = image->header;
+
+	return image_range(image, header->e_shoff + (uint64_t)index * sizeof(Elf64_Shdr),
+			   sizeof(Elf64_Shdr));
+}
+
+static int load_image(const char *path, struct elf_image *image)
+{
+	struct stat status;
+	uint8_t *bytes;
+	size_t loaded = 0;
+	int fd = open(path, O_RDONLY | O_CLOEXEC);
+
+	if (fd < 0 || fstat(fd, &status) != 0) {
+		perror(path);
+		if (fd >= 0)
+			close(fd);
+		return -1;
+	}
+	if (!S_ISREG(status.st_mode) || status.st_size < (off_t)sizeof(Elf64_Ehdr) ||
+	    (uint64_t)status.st_size > MAX_ELF_BYTES) {
+		fprintf(stderr, "%s: not a regular ELF64 file within %lu bytes\n", path,
+			MAX_ELF_BYTES);
+		close(fd);
+		return -1;
+	}
+	bytes = malloc((size_t)status.st_size);
+	if (bytes == NULL) {
+		perror("readelf-lite: malloc");
+		close(fd);
+		return -1;
+	}
+	while (loaded < (size_t)status.st_size) {
+		ssize_t count = read(fd, bytes + loaded, (size_t)status.st_size - loaded);
+
+		if (count < 0 && errno == EINTR)
+			continue;
+		if (count <= 0) {
+			fprintf(stderr, "%s: short read\n", path);
+			free(bytes);
+			close(fd);
+			return -1;
+		}
+		loaded += (size_t)count;
+	}
+	close(fd);
+	image->bytes = bytes;
+	image->size = loaded;
+	image->header = (const Elf64_Ehdr *)bytes;
+	if (memcmp(image->header->e_ident, ELFMAG, SELFMAG) != 0 ||
+	    image->header->e_ident[EI_CLASS] != ELFCLASS64 ||
+	    image->header->e_ident[EI_DATA] != ELFDATA2LSB) {
+		fprintf(stderr, "%s: not a little-endian ELF64 file\n", path);
+		free(bytes);
+		return -1;
+	}
+	return 0;
+}
+
+static void show_header(const struct elf_image *image)
+{
+	const Elf64_Ehdr *header = image->header;
+
+	printf("ELF Header:\n");
+	printf("  Type:    ");
+	print_name(NAME_OF(elf_types, header->e_type), header->e_type, 0);
+	printf("\n  Machine: ");
+	print_name(NAME_OF(machines, header->e_machine), header->e_machine, 0);
+	printf("\n  Entry:   0x%" PRIx64 "\n", header->e_entry);
+	printf("  Flags:   0x%" PRIx32 "\n", header->e_flags);
+	printf("  Program headers: %u at offset %" PRIu64 "\n", header->e_phnum,
+	       header->e_phoff);
+	printf("  Section headers: %u at offset %" PRIu64 " (names in %u)\n",
+	       header->e_shnum, header->e_shoff, header->e_shstrndx);
+}
+
+static int show_program_headers(const struct elf_image *image)
+{
+	printf("Program Headers:\n");
+	printf("  %-14s %-18s %-18s %-10s %-10s %-3s %s\n", "Type", "Offset", "VirtAddr",
+	       "FileSiz", "MemSiz", "Flg", "Align");
+	for (unsigned index = 0; index < image->header->e_phnum; ++index) {
+		const Elf64_Phdr *segment = program_header(image, index);
+
+		if (segment == NULL) {
+			fprintf(stderr, "readelf-lite: program header %u out of file\n", index);
+			return -1;
+		}
+		printf("  ");
+		print_name(NAME_OF(program_types, segment->p_type), segment->p_type, 14);
+		printf(" 0x%016" PRIx64 " 0x%016" PRIx64 " 0x%08" PRIx64 " 0x%08" PRIx64
+		       " %c%c%c 0x%" PRIx64 "\n",
+		       segment->p_offset, segment->p_vaddr, segment->p_filesz, segment->p_memsz,
+		       segment->p_flags & PF_R ? 'R' : '-', segment->p_flags & PF_W ? 'W' : '-',
+		       segment->p_flags & PF_X ? 'X' : '-', segment->p_align);
+		if (segment->p_type == PT_INTERP) {
+			const char *interpreter = image_string(image, segment->p_offset);
+
+			printf("      [interpreter: %s]\n",
+			       interpreter != NULL ? interpreter : "<out of file>");
+		}
+	}
+	return 0;
+}
+
+static int show_sections(const struct elf_image *image)
+{
+	const Elf64_Shdr *names = section_header(image, image->header->e_shstrndx);
+
+	printf("Section Headers:\n");
+	printf("  [Nr] %-20s %-12s %-18s %-10s %s\n", "Name", "Type", "Address", "Offset",
+	       "Size");
+	for (unsigned index = 0; index < image->header->e_shnum; ++index) {
+		const Elf64_Shdr *section = section_header(image, index);
+		const char *name = NULL;
+
+		if (section == NULL) {
+			fprintf(stderr, "readelf-lite: section header %u out of file\n", index);
+			return -1;
+		}
+		if (names != NULL)
+			name = image_string(image, names->sh_offset + section->sh_name);
+		printf("  [%2u] %-20s ", index, name != NULL ? name : "<unnamed>");
+		print_name(NAME_OF(section_types, section->sh_type), section->sh_type, 12);
+		printf(" 0x%016" PRIx64 " 0x%08" PRIx64 " 0x%" PRIx64 "\n", section->sh_addr,
+		       section->sh_offset, section->sh_size);
+	}
+	return 0;
+}
+
+/* DT_STRTAB 是 virtual address；只通过覆盖它的 PT_LOAD file range 换算，section header 可被 strip。 */
+static int virtual_to_offset(const struct elf_image *image, uint64_t address, uint64_t *offset)
+{
+	for (unsigned index = 0; index < image->header->e_phnum; ++index) {
+		const Elf64_Phdr *segment = program_header(image, index);
+
+		if (segment == NULL || segment->p_type != PT_LOAD)
+			continue;
+		if (address >= segment->p_vaddr && address - segment->p_vaddr < segment->p_filesz) {
+			*offset = segment->p_offset + (address - segment->p_vaddr);
+			return 0;
+		}
+	}
+	return -1;
+}
+
+static int show_dynamic(const struct elf_image *image)
+{
+	const Elf64_Phdr *dynamic = NULL;
+	const Elf64_Dyn *entries;
+	uint64_t count;
+	uint64_t strings = 0;
+	int have_strings = 0;
+
+	for (unsigned index = 0; index < image->header->e_phnum; ++index) {
+		const Elf64_Phdr *segment = program_header(image, index);
+
+		if (segment != NULL && segment->p_type == PT_DYNAMIC)
+			dynamic = segment;
+	}
+	if (dynamic == NULL) {
+		printf("There is no dynamic section in this file.\n");
+		return 0;
+	}
+	entries = image_range(image, dynamic->p_offset, dynamic->p_filesz);
+	if (entries == NULL) {
+		fprintf(stderr, "readelf-lite: PT_DYNAMIC out of file\n");
+		return -1;
+	}
+	count = dynamic->p_filesz / sizeof(Elf64_Dyn);
+	for (uint64_t index = 0; index < count && entries[index].d_tag != DT_NULL; ++index) {
+		if (entries[index].d_tag == DT_STRTAB)
+			have_strings =
+				virtual_to_offset(image, entries[index].d_un.d_ptr, &strings) == 0;
+	}
+	printf("Dynamic section at offset 0x%" PRIx64 ":\n", dynamic->p_offset);
+	for (uint64_t index = 0; index < count && entries[index].d_tag != DT_NULL; ++index) {
+		const Elf64_Dyn *entry = &entries[index];
+		const char *text = NULL;
+
+		printf("  ");
+		print_name(NAME_OF(dynamic_tags, (uint64_t)entry->d_tag), (uint64_t)entry->d_tag,
+			   14);
+		switch (entry->d_tag) {
+		case DT_NEEDED:
+		case DT_SONAME:
+		case DT_RPATH:
+		case DT_RUNPATH:
+			if (have_strings)
+				text = image_string(image, strings + entry->d_un.d_val);
+			printf(" [%s]\n", text != NULL ? text : "<out of file>");
+			break;
+		default:
+			printf(" 0x%" PRIx64 "\n", entry->d_un.d_val);
+			break;
+		}
+	}
+	return 0;
+}
+
+static void readelf_usage(void)
+{
+	fprintf(stderr, "usage: readelf-lite [-a|-hlSd] FILE\n");
+}
+
+int run_readelf(int argc, char **argv)
+{
+	struct elf_image image;
+	unsigned selection = 0;
+	int option;
+	int status = 0;
+
+	while ((option = getopt(argc, argv, "ahlSd")) != -1) {
+		switch (option) {
+		case 'a':
+			selection |= SHOW_HEADER | SHOW_PROGRAM | SHOW_SECTIONS | SHOW_DYNAMIC;
+			break;
+		case 'h':
+			selection |= SHOW_HEADER;
+			break;
+		case 'l':
+			selection |= SHOW_PROGRAM;
+			break;
+		case 'S':
+			selection |= SHOW_SECTIONS;
+			break;
+		case 'd':
+			selection |= SHOW_DYNAMIC;
+			break;
+		default:
+			readelf_usage();
+			return 2;
+		}
+	}
+	if (optind + 1 != argc) {
+		readelf_usage();
+		return 2;
+	}
+	if (selection == 0)
+		selection = SHOW_HEADER | SHOW_PROGRAM | SHOW_SECTIONS | SHOW_DYNAMIC;
+	if (load_image(argv[optind], &image) != 0)
+		return 1;
+	if (selection & SHOW_HEADER)
+		show_header(&image);
+	if (status == 0 && (selection & SHOW_PROGRAM))
+		status = show_program_headers(&image);
+	if (status == 0 && (selection & SHOW_SECTIONS))
+		status = show_sections(&image);
+	if (status == 0 && (selection & SHOW_DYNAMIC))
+		status = show_dynamic(&image);
+	free((void *)image.bytes);
+	return status == 0 ? 0 : 1;
+}
@@ -0,0 +1,6 @@
+#ifndef LITEOS_ELF_INSPECT_H
+#define LITEOS_ELF_INSPECT_H
+
+int run_readelf(int argc, char **argv);
+
+#endif
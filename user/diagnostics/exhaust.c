@@ -0,0 +1,265 @@
+#include <errno.h>
+#include <fcntl.h>
+#include <signal.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/mman.h>
+#include <sys/resource.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "exhaust.h"
+
+#define PAGE_BYTES 4096UL
+#define MIB (1024UL * 1024UL)
+/* BusyBox rootfs 的 nobody；RLIMIT_NPROC 对 real UID 0 不生效，fork bomb 必须先降权。 */
+#define UNPRIVILEGED_ID 65534
+#define PROCESS_LIMIT 64
+/* 没有 ENOSPC 的 disk fill 在该上限处判失败，避免错误的容量统计让诊断无限写入。 */
+#define DISK_CAP_MIB 8192UL
+#define DISK_PATH "/exhausttest.fill"
+
+/* 每个 stressor 在独立 child 中耗尽资源；exit status 0 表示观察到预期错误且 child 内已恢复。 */
+static int run_isolated(int (*stressor)(void), int *status)
+{
+	pid_t child = fork();
+
+	if (child < 0)
+		return -1;
+	if (child == 0) {
+		int result = stressor();
+		fflush(stdout);
+		_exit(result);
+	}
+	return waitpid(child, status, 0) == child ? 0 : -1;
+}
+
+static int exhaust_fds(void)
+{
+	int last = -1;
+	int descriptor;
+
+	while ((descriptor = open("/dev/null", O_RDONLY | O_CLOEXEC)) >= 0)
+		last = descriptor;
+	if (errno != EMFILE) {
+		perror("exhausttest: fds: open did not fail with EMFILE");
+		return 1;
+	}
+	for (int index = STDERR_FILENO + 1; index <= last; ++index)
+		close(index);
+	descriptor = open("/dev/null", O_RDONLY | O_CLOEXEC);
+	if (descriptor < 0 || close(descriptor) != 0) {
+		perror("exhausttest: fds: reopen after release");
+		return 1;
+	}
+	printf("exhausttest: fds: EMFILE after %d descriptors, recovered\n", last - STDERR_FILENO);
+	return 0;
+}
+
+static int exhaust_pids(void)
+{
+	const struct rlimit limit = { PROCESS_LIMIT, PROCESS_LIMIT };
+	pid_t children[PROCESS_LIMIT];
+	int forked = 0;
+	int error = 0;
+
+	if (setgid(UNPRIVILEGED_ID) != 0 || setuid(UNPRIVILEGED_ID) != 0 ||
+	    setrlimit(RLIMIT_NPROC, &limit) != 0) {
+		perror("exhausttest: pids: drop privileges");
+		return 1;
+	}
+	while (forked < PROCESS_LIMIT) {
+		pid_t child = fork();
+		if (child == 0) {
+			for (;;)
+				pause();
+		}
+		if (child < 0) {
+			error = errno;
+			break;
+		}
+		children[forked++] = child;
+	}
+	for (int index = 0; index < forked; ++index)
+		kill(children[index], SIGKILL);
+	while (waitpid(-1, NULL, 0) > 0)
+		;
+	if (error != EAGAIN) {
+		fprintf(stderr, "exhausttest: pids: fork bomb stopped at %d without EAGAIN\n", forked);
+		return 1;
+	}
+	pid_t probe = fork();
+	if (probe == 0)
+		_exit(0);
+	int status = 0;
+	if (probe < 0 || waitpid(probe, &status, 0) != probe || !WIFEXITED(status)) {
+		perror("exhausttest: pids: fork after release");
+		return 1;
+	}
+	printf("exhausttest: pids: EAGAIN after %d children under RLIMIT_NPROC %d, recovered\n",
+	       forked, PROCESS_LIMIT);
+	return 0;
+}
+
+/* 映射两倍物理内存并逐页写入；无 swap 时唯一合法结局是 OOM killer 以 SIGKILL 结束本进程。 */
+static int exhaust_memory(void)
+{
+	long pages = sysconf(_SC_PHYS_PAGES);
+	size_t bytes = (pages > 0 ? (size_t)pages : 65536UL) * PAGE_BYTES * 2;
+	uint8_t *memory = mmap(NULL, bytes, PROT_READ | PROT_WRITE,
+			       MAP_PRIVATE | MAP_ANONYMOUS | MAP_NORESERVE, -1, 0);
+
+	if (memory == MAP_FAILED) {
+		perror("exhausttest: memory: mmap");
+		return 1;
+	}
+	for (size_t offset = 0; offset < bytes; offset += PAGE_BYTES)
+		memory[offset] = (uint8_t)(offset >> 12);
+	fprintf(stderr, "exhausttest: memory: touched %zu MiB without OOM kill\n", bytes / MIB);
+	return 1;
+}
+
+static int probe_memory(void)
+{
+	size_t bytes = 16 * MIB;
+	uint8_t *memory = mmap(NULL, bytes, PROT_READ | PROT_WRITE,
+			       MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+
+	if (memory == MAP_FAILED) {
+		perror("exhausttest: memory: mmap after OOM kill");
+		return 1;
+	}
+	for (size_t offset = 0; offset < bytes; offset += PAGE_BYTES)
+		memory[offset] = 1;
+	return munmap(memory, bytes) == 0 ? 0 : 1;
+}
+
+static int exhaust_disk(void)
+{
+	static uint8_t chunk[MIB];
+	int descriptor = open(DISK_PATH, O_CREAT | O_TRUNC | O_WRONLY | O_CLOEXEC, 0600);
+	unsigned long written = 0;
+	int error = 0;
+
+	if (descriptor < 0) {
+		perror("exhausttest: disk: create");
+		return 1;
+	}
+	memset(chunk, 0xa5, sizeof(chunk));
+	while (written < DISK_CAP_MIB * MIB) {
+		ssize_t result = write(descriptor, chunk, sizeof(chunk));
+		if (result < 0) {
+			error = errno;
+			break;
+		}
+		written += (unsigned long)result;
+	}
+	if (close(descriptor) != 0 && error == 0)
+		error = errno;
+	if (unlink(DISK_PATH) != 0) {
+		perror("exhausttest: disk: unlink");
+		return 1;
+	}
+	if (error != ENOSPC) {
+		fprintf(stderr, "exhausttest: disk: stopped after %lu MiB without ENOSPC (%s)\n",
+			written / MIB, strerror(error));
+		return 1;
+	}
+	descriptor = open(DISK_PATH, O_CREAT | O_TRUNC | O_WRONLY | O_CLOEXEC, 0600);
+	if (descriptor < 0 || write(descriptor, chunk, PAGE_BYTES) != (ssize_t)PAGE_BYTES ||
+	    close(descriptor) != 0 || unlink(DISK_PATH) != 0) {
+		perror("exhausttest: disk: write after release");
+		return 1;
+	}
+	printf("exhausttest: disk: ENOSPC after %lu MiB, recovered\n", written / MIB);
+	return 0;
+}
+
+/* /proc/meminfo KernelStack 只随 live task 变化；全部 stressor 回收后不得高于基线，否则 kernel stack 泄漏。 */
+static long kernel_stack_kib(void)
+{
+	char line[128];
+	long value = -1;
+	FILE *meminfo = fopen("/proc/meminfo", "re");
+
+	if (meminfo == NULL)
+		return -1;
+	while (fgets(line, sizeof(line), meminfo) != NULL) {
+		if (sscanf(line, "KernelStack: %ld kB", &value) == 1)
+			break;
+	}
+	fclose(meminfo);
+	return value;
+}
+
+static int check_kernel_stacks(long baseline)
+{
+	long current = kernel_stack_kib();
+	struct timespec delay = { .tv_sec = 0, .tv_nsec = 10000000L };
+
+	/* 被回收 task 的 stack 在 reaper 释放最后一个引用时归还，允许短暂收敛窗口。 */
+	for (int attempt = 0; attempt < 100 && current > baseline; ++attempt) {
+		nanosleep(&delay, NULL);
+		current = kernel_stack_kib();
+	}
+	if (current < 0 || current > baseline) {
+		fprintf(stderr, "exhausttest: KernelStack %ld kB after recovery, baseline %ld kB\n",
+			current, baseline);
+		return 1;
+	}
+	return 0;
+}
+
+static int run_stressor(const char *name)
+{
+	int status = 0;
+
+	if (strcmp(name, "memory") == 0) {
+		if (run_isolated(exhaust_memory, &status) != 0 || !WIFSIGNALED(status) ||
+		    WTERMSIG(status) != SIGKILL) {
+			fprintf(stderr, "exhausttest: memory: hog was not OOM-killed\n");
+			return 1;
+		}
+		if (run_isolated(probe_memory, &status) != 0 || !WIFEXITED(status) ||
+		    WEXITSTATUS(status) != 0)
+			return 1;
+		printf("exhausttest: memory: hog OOM-killed, recovered\n");
+		return 0;
+	}
+	int (*stressor)(void) = strcmp(name, "fds") == 0 ? exhaust_fds :
+				strcmp(name, "pids") == 0 ? exhaust_pids :
+				strcmp(name, "disk") == 0 ? exhaust_disk : NULL;
+	if (stressor == NULL) {
+		fprintf(stderr, "exhausttest: unknown resource %s (fds|pids|memory|disk)\n", name);
+		return 2;
+	}
+	if (run_isolated(stressor, &status) != 0 || !WIFEXITED(status))
+		return 1;
+	return WEXITSTATUS(status);
+}
+
+int run_exhausttest(int argc, char **argv)
+{
+	static char *all[] = { "fds", "pids", "memory", "disk" };
+	char **resources = argc > 1 ? argv + 1 : all;
+	int count = argc > 1 ? argc - 1 : (int)(sizeof(all) / sizeof(all[0]));
+	long baseline = kernel_stack_kib();
+
+	if (baseline < 0) {
+		fprintf(stderr, "exhausttest: /proc/meminfo has no KernelStack field\n");
+		return 1;
+	}
+	for (int index = 0; index < count; ++index) {
+		int result = run_stressor(resources[index]);
+		if (result != 0)
+			return result;
+	}
+	if (check_kernel_stacks(baseline) != 0)
+		return 1;
+	printf("exhausttest ok: kernel survived %d resource exhaustion scenarios, KernelStack %ld kB\n",
+	       count, baseline);
+	return 0;
+}
@@ -0,0 +1,6 @@
+#ifndef LITEOS_EXHAUST_H
+#define LITEOS_EXHAUST_H
+
+int run_exhausttest(int argc, char **argv);
+
+#endif
@@ -2,6 +2,9 @@
 #include <fcntl.h>
 #include <inttypes.h>
 #include <pthread.h>
+#include <signal.h>
+#include <stdatomic.h>
+#include <stdbool.h>
 #include <stdint.h>
 #include <stdio.h>
 #include <stdlib.h>
@@ -12,10 +15,24 @@
 #include <time.h>
 #include <unistd.h>
 
+#include "bench.h"
+#include "boottime.h"
+#include "elf-inspect.h"
+#include "exhaust.h"
+#include "perf.h"
+
 #define MIB (1024UL * 1024UL)
 #define PAGE_BYTES 4096UL
 #define MAX_THREADS 64UL
 #define MAX_MIB 96UL
+#define MAX_DEPTH 32UL
+#define REAP_TIMEOUT_MS 5000L
+#define PREEMPT_PERIOD_NS 2000000L
+#define PREEMPT_LIMIT_US 200000L
+#define SLEEP_PERIOD_NS 500000L
+/* 100 Hz scheduler tick；median overshoot 达到一个 tick 说明 sleep 又被量化到 tick 边界。 */
+#define SLEEP_TICK_US 10000L
+#define MAX_SLEEP_SAMPLES 1000UL
 
 struct cpu_job {
 	uint64_t iterations;
@@ -120,6 +137,125 @@ static int run_cputest(int argc, char **argv)
 	return 0;
 }
 
+static atomic_bool preempt_stop;
+
+static void *spin_worker(void *argument)
+{
+	uint64_t value = (uint64_t)(uintptr_t)argument;
+
+	/* 纯用户态循环不进入 syscall；只有 timer interrupt 能把 CPU 让给采样线程。 */
+	while (!atomic_load_explicit(&preempt_stop, memory_order_relaxed))
+		value = mix(value);
+	return (void *)(uintptr_t)value;
+}
+
+static long elapsed_micros(const struct timespec *start, const struct timespec *end)
+{
+	return (long)(end->tv_sec - start->tv_sec) * 1000000L +
+	       (end->tv_nsec - start->tv_nsec) / 1000L;
+}
+
+static int run_preempttest(int argc, char **argv)
+{
+	long online = sysconf(_SC_NPROCESSORS_ONLN);
+	unsigned long default_spinners = online > 0 ? (unsigned long)online * 2 : 2;
+	unsigned long spinners = parse_value(argc > 1 ? argv[1] : NULL,
+					     default_spinners < MAX_THREADS ? default_spinners
+									    : MAX_THREADS,
+					     MAX_THREADS);
+	unsigned long samples = parse_value(argc > 2 ? argv[2] : NULL, 100, 10000);
+	const struct timespec period = { .tv_sec = 0, .tv_nsec = PREEMPT_PERIOD_NS };
+	pthread_t workers[MAX_THREADS];
+	long worst = 0;
+	long total = 0;
+
+	atomic_store(&preempt_stop, false);
+	for (unsigned long index = 0; index < spinners; ++index) {
+		int error = pthread_create(&workers[index], NULL, spin_worker,
+					   (void *)(uintptr_t)(index + 1));
+		if (error != 0) {
+			errno = error;
+			perror("preempttest: pthread_create");
+			return 1;
+		}
+	}
+	for (unsigned long sample = 0; sample < samples; ++sample) {
+		struct timespec start;
+		struct timespec end;
+
+		if (clock_gettime(CLOCK_MONOTONIC, &start) != 0 || nanosleep(&period, NULL) != 0 ||
+		    clock_gettime(CLOCK_MONOTONIC, &end) != 0) {
+			perror("preempttest: timed sleep");
+			return 1;
+		}
+		long latency = elapsed_micros(&start, &end) - PREEMPT_PERIOD_NS / 1000L;
+		total += latency;
+		if (latency > worst)
+			worst = latency;
+	}
+	atomic_store(&preempt_stop, true);
+	for (unsigned long index = 0; index < spinners; ++index) {
+		int error = pthread_join(workers[index], NULL);
+		if (error != 0) {
+			errno = error;
+			perror("preempttest: pthread_join");
+			return 1;
+		}
+	}
+	if (worst > PREEMPT_LIMIT_US) {
+		fprintf(stderr, "preempttest: worst wake latency %ld us exceeds %ld us\n", worst,
+			PREEMPT_LIMIT_US);
+		return 1;
+	}
+	printf("preempttest ok: %lu spinners, %lu samples, wake latency avg %ld us max %ld us\n",
+	       spinners, samples, total / (long)samples, worst);
+	return 0;
+}
+
+static int compare_long(const void *left, const void *right)
+{
+	long a = *(const long *)left;
+	long b = *(const long *)right;
+
+	return (a > b) - (a < b);
+}
+
+/* 空闲系统上测 sub-tick nanosleep 的 overshoot 分布；负载下的唤醒上界由 preempttest 负责。 */
+static int run_sleeptest(int argc, char **argv)
+{
+	static long overshoot[MAX_SLEEP_SAMPLES];
+	unsigned long samples = parse_value(argc > 1 ? argv[1] : NULL, 200, MAX_SLEEP_SAMPLES);
+	const struct timespec period = { .tv_sec = 0, .tv_nsec = SLEEP_PERIOD_NS };
+
+	for (unsigned long sample = 0; sample < samples; ++sample) {
+		struct timespec start;
+		struct timespec end;
+
+		if (clock_gettime(CLOCK_MONOTONIC, &start) != 0 || nanosleep(&period, NULL) != 0 ||
+		    clock_gettime(CLOCK_MONOTONIC, &end) != 0) {
+			perror("sleeptest: timed sleep");
+			return 1;
+		}
+		long elapsed_ns = (long)(end.tv_sec - start.tv_sec) * 1000000000L +
+				  (end.tv_nsec - start.tv_nsec);
+		if (elapsed_ns < SLEEP_PERIOD_NS) {
+			fprintf(stderr, "sleeptest: woke %ld ns early\n", SLEEP_PERIOD_NS - elapsed_ns);
+			return 1;
+		}
+		overshoot[sample] = (elapsed_ns - SLEEP_PERIOD_NS) / 1000L;
+	}
+	qsort(overshoot, samples, sizeof(overshoot[0]), compare_long);
+	if (overshoot[samples / 2] >= SLEEP_TICK_US) {
+		fprintf(stderr, "sleeptest: median overshoot %ld us reaches the %ld us tick\n",
+			overshoot[samples / 2], SLEEP_TICK_US);
+		return 1;
+	}
+	printf("sleeptest ok: %lu x %ld us sleeps, overshoot min %ld us median %ld us max %ld us\n",
+	       samples, SLEEP_PERIOD_NS / 1000L, overshoot[0], overshoot[samples / 2],
+	       overshoot[samples - 1]);
+	return 0;
+}
+
 static void fill_pages(uint8_t *memory, size_t bytes, uint64_t salt)
 {
 	for (size_t offset = 0; offset < bytes; offset += PAGE_BYTES)
@@ -234,10 +370,170 @@ static int run_cachetest(int argc, char **argv)
 	return 0;
 }
 
+static void sleep_millis(long milliseconds)
+{
+	struct timespec delay = { .tv_sec = 0, .tv_nsec = milliseconds * 1000000L };
+
+	nanosleep(&delay, NULL);
+}
+
+static int wait_until_reaped(pid_t pid)
+{
+	for (long waited = 0; waited < REAP_TIMEOUT_MS; waited += 10) {
+		if (kill(pid, 0) != 0 && errno == ESRCH)
+			return 0;
+		sleep_millis(10);
+	}
+	return -1;
+}
+
+/* 每层先上报自身 PID 再 fork 下一层；叶子等待 init 收养后上报一个结果字节。 */
+static void run_reap_chain(int report, unsigned long depth)
+{
+	for (unsigned long level = 1;; ++level) {
+		pid_t self = getpid();
+
+		if (write(report, &self, sizeof(self)) != (ssize_t)sizeof(self))
+			_exit(1);
+		if (level == depth)
+			break;
+		pid_t child = fork();
+		if (child < 0)
+			_exit(1);
+		if (child > 0) {
+			for (;;)
+				pause();
+		}
+	}
+	char adopted = 0;
+	for (long waited = 0; waited < REAP_TIMEOUT_MS && !adopted; waited += 10) {
+		adopted = getppid() == 1;
+		if (!adopted)
+			sleep_millis(10);
+	}
+	_exit(write(report, &adopted, 1) == 1 ? 0 : 1);
+}
+
+static int run_reaptest(int argc, char **argv)
+{
+	unsigned long depth = parse_value(argc > 1 ? argv[1] : NULL, 8, MAX_DEPTH);
+	pid_t chain[MAX_DEPTH];
+	int report[2];
+
+	if (depth < 2) {
+		fprintf(stderr, "reaptest: depth must be at least 2\n");
+		return 2;
+	}
+	if (pipe(report) != 0) {
+		perror("reaptest: pipe");
+		return 1;
+	}
+	pid_t root = fork();
+	if (root < 0) {
+		perror("reaptest: fork");
+		return 1;
+	}
+	if (root == 0) {
+		close(report[0]);
+		run_reap_chain(report[1], depth);
+	}
+	close(report[1]);
+	for (unsigned long level = 0; level < depth; ++level) {
+		if (read(report[0], &chain[level], sizeof(pid_t)) != (ssize_t)sizeof(pid_t)) {
+			fprintf(stderr, "reaptest: chain level %lu did not report\n", level);
+			return 1;
+		}
+	}
+
+	/* 1. 杀死叶子之外的全部中间 parent；直接 child 由本进程回收，其余 zombie 必须由 init 收养回收。
+	 * 2. 叶子只有在 getppid()==1 时报告成功，随后退出并同样交给 init 回收。 */
+	for (unsigned long level = 0; level + 1 < depth; ++level)
+		kill(chain[level], SIGKILL);
+	int status = 0;
+	if (waitpid(root, &status, 0) != root || !WIFSIGNALED(status) || WTERMSIG(status) != SIGKILL) {
+		fprintf(stderr, "reaptest: chain root did not die by SIGKILL\n");
+		return 1;
+	}
+	char adopted = 0;
+	if (read(report[0], &adopted, 1) != 1 || !adopted) {
+		fprintf(stderr, "reaptest: leaf %d was not reparented to init\n", chain[depth - 1]);
+		return 1;
+	}
+	close(report[0]);
+	for (unsigned long level = 1; level < depth; ++level) {
+		if (wait_until_reaped(chain[level]) != 0) {
+			fprintf(stderr, "reaptest: orphan %d was not reaped by init\n", chain[level]);
+			return 1;
+		}
+	}
+	printf("reaptest ok: %lu-level chain, %lu orphans reparented to and reaped by init\n",
+	       depth, depth - 1);
+	return 0;
+}
+
+/* 单条 `ret` 指令；写在栈上后调用，NX 栈必须以 SIGSEGV 结束 child。 */
+#if defined(__riscv)
+static const uint32_t RETURN_INSTRUCTION = 0x00008067U;
+#elif defined(__aarch64__)
+static const uint32_t RETURN_INSTRUCTION = 0xd65f03c0U;
+#else
+static const uint32_t RETURN_INSTRUCTION = 0x000000c3U;
+#endif
+
+static void execute_stack(void)
+{
+	volatile uint32_t code[4] = { RETURN_INSTRUCTION, RETURN_INSTRUCTION };
+	void (*function)(void) = (void (*)(void))(uintptr_t)code;
+
+	function();
+	_exit(1);
+}
+
+static int run_nxtest(void)
+{
+	/* W^X 与 Linux 一致只在 PR_SET_MDWE 后强制（musl smoke 覆盖）；默认 JIT 路径必须可用。 */
+	void *mapping = mmap(NULL, PAGE_BYTES, PROT_READ | PROT_WRITE | PROT_EXEC,
+			     MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+
+	if (mapping == MAP_FAILED) {
+		perror("nxtest: writable executable mmap");
+		return 1;
+	}
+	munmap(mapping, PAGE_BYTES);
+	mapping = mmap(NULL, PAGE_BYTES, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+	if (mapping == MAP_FAILED) {
+		perror("nxtest: mmap");
+		return 1;
+	}
+	if (mprotect(mapping, PAGE_BYTES, PROT_READ | PROT_EXEC) != 0) {
+		perror("nxtest: mprotect to R|X");
+		return 1;
+	}
+	munmap(mapping, PAGE_BYTES);
+	pid_t child = fork();
+	if (child < 0) {
+		perror("nxtest: fork");
+		return 1;
+	}
+	if (child == 0)
+		execute_stack();
+	int status = 0;
+	if (waitpid(child, &status, 0) != child || !WIFSIGNALED(status) || WTERMSIG(status) != SIGSEGV) {
+		fprintf(stderr, "nxtest: stack execution did not raise SIGSEGV\n");
+		return 1;
+	}
+	printf("nxtest ok: W+X mapping allowed without MDWE, stack execution killed by SIGSEGV\n");
+	return 0;
+}
+
 static void usage(const char *name)
 {
 	fprintf(stderr,
-		"usage: %s {cputest [threads [M-iterations]]|memtest [MiB]|cachetest [MiB]}\n",
+		"usage: %s {cputest [threads [M-iterations]]|memtest [MiB]|cachetest [MiB]|\n"
+		"preempttest [spinners [samples]]|sleeptest [samples]|reaptest [depth]|nxtest|\n"
+		"exhausttest [fds|pids|memory|disk]...|perftest [iterations]|\n"
+		"microbench [syscall|switch|pipe|spawn|fault|block]...|boottime|\n"
+		"readelf-lite [-a|-hlSd] FILE}\n",
 		name);
 }
 
@@ -260,6 +556,24 @@ int main(int argc, char **argv)
 		return run_memtest(argc, argv);
 	if (strcmp(name, "cachetest") == 0)
 		return run_cachetest(argc, argv);
+	if (strcmp(name, "preempttest") == 0)
+		return run_preempttest(argc, argv);
+	if (strcmp(name, "sleeptest") == 0)
+		return run_sleeptest(argc, argv);
+	if (strcmp(name, "reaptest") == 0)
+		return run_reaptest(argc, argv);
+	if (strcmp(name, "nxtest") == 0)
+		return run_nxtest();
+	if (strcmp(name, "exhausttest") == 0)
+		return run_exhausttest(argc, argv);
+	if (strcmp(name, "perftest") == 0)
+		return run_perftest(argc, argv);
+	if (strcmp(name, "microbench") == 0)
+		return run_microbench(argc, argv);
+	if (strcmp(name, "boottime") == 0)
+		return run_boottime(argc, argv);
+	if (strcmp(name, "readelf-lite") == 0)
+		return run_readelf(argc, argv);
 	usage(program_name(argv[0]));
 	return 2;
 }
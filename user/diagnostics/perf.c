@@ -0,0 +1,177 @@
+#include <errno.h>
+#include <inttypes.h>
+#include <sched.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/ioctl.h>
+#include <sys/syscall.h>
+#include <sys/utsname.h>
+#include <unistd.h>
+
+#include "perf.h"
+
+/* musl sysroot 不带 Linux UAPI header；VER0 layout 与 ioctl 编号按 perf_event.h 固定。 */
+struct perf_attr_ver0 {
+	uint32_t type;
+	uint32_t size;
+	uint64_t config;
+	uint64_t sample_period;
+	uint64_t sample_type;
+	uint64_t read_format;
+	uint64_t flags;
+	uint32_t wakeup_events;
+	uint32_t bp_type;
+	uint64_t config1;
+};
+_Static_assert(sizeof(struct perf_attr_ver0) == 64, "PERF_ATTR_SIZE_VER0");
+
+#define PERF_TYPE_HARDWARE 0
+#define PERF_COUNT_HW_CPU_CYCLES 0
+#define PERF_COUNT_HW_INSTRUCTIONS 1
+#define PERF_COUNT_HW_CACHE_MISSES 3
+#define PERF_ATTR_DISABLED 1ULL
+#define PERF_ATTR_EXCLUDE_HV (1ULL << 6)
+#define PERF_FLAG_FD_CLOEXEC 8UL
+#define PERF_EVENT_IOC_ENABLE 0x2400
+#define PERF_EVENT_IOC_DISABLE 0x2401
+#define PERF_EVENT_IOC_RESET 0x2403
+
+static int open_counter(uint64_t config)
+{
+	struct perf_attr_ver0 attr;
+
+	memset(&attr, 0, sizeof(attr));
+	attr.type = PERF_TYPE_HARDWARE;
+	attr.size = sizeof(attr);
+	attr.config = config;
+	attr.flags = PERF_ATTR_DISABLED | PERF_ATTR_EXCLUDE_HV;
+	return (int)syscall(SYS_perf_event_open, &attr, 0, -1, -1, PERF_FLAG_FD_CLOEXEC);
+}
+
+static int read_counter(int descriptor, uint64_t *value)
+{
+	return read(descriptor, value, sizeof(*value)) == (ssize_t)sizeof(*value) ? 0 : -1;
+}
+
+static int control(const int *descriptors, int count, unsigned long request)
+{
+	for (int index = 0; index < count; ++index) {
+		if (ioctl(descriptors[index], request, 0) != 0)
+			return -1;
+	}
+	return 0;
+}
+
+static int read_counters(const int *descriptors, int count, uint64_t *values)
+{
+	for (int index = 0; index < count; ++index) {
+		if (read_counter(descriptors[index], &values[index]) != 0)
+			return -1;
+	}
+	return 0;
+}
+
+/* AArch64 只开放 PMUv3 cycle counter，且 HVF guest 可能没有 PMUv3；riscv64 firmware 保证两者。 */
+static int counters_optional(void)
+{
+	struct utsname name;
+
+	return uname(&name) == 0 && strcmp(name.machine, "aarch64") == 0;
+}
+
+/* 每 1/8 工作量主动 yield，使计数跨越 scheduler slice 边界而不只覆盖单个 slice。 */
+static void workload(unsigned long iterations)
+{
+	volatile unsigned long sink = 0;
+
+	for (unsigned long index = 0; index < iterations; ++index) {
+		sink += index;
+		if (index % (iterations / 8) == 0)
+			sched_yield();
+	}
+}
+
+int perf_cycles_open(void)
+{
+	int descriptor = open_counter(PERF_COUNT_HW_CPU_CYCLES);
+
+	if (descriptor >= 0 && ioctl(descriptor, PERF_EVENT_IOC_ENABLE, 0) != 0) {
+		close(descriptor);
+		return -1;
+	}
+	return descriptor;
+}
+
+int run_perftest(int argc, char **argv)
+{
+	unsigned long iterations = argc > 1 ? strtoul(argv[1], NULL, 10) : 1000000UL;
+	int optional = counters_optional();
+	int counters[2] = { open_counter(PERF_COUNT_HW_CPU_CYCLES),
+			    open_counter(PERF_COUNT_HW_INSTRUCTIONS) };
+	int count = 2;
+	uint64_t counted[2] = { 0, 0 };
+	uint64_t frozen[2] = { 0, 0 };
+	uint8_t short_buffer[4];
+
+	if (iterations < 8) {
+		fprintf(stderr, "perftest: iterations must be at least 8\n");
+		return 2;
+	}
+	if (optional && counters[0] < 0 && errno == ENOENT) {
+		printf("perftest skipped: no PMUv3 cycle counter\n");
+		return 0;
+	}
+	if (optional && counters[0] >= 0 && counters[1] < 0 && errno == ENOENT)
+		count = 1;
+	if (counters[0] < 0 || (count == 2 && counters[1] < 0)) {
+		perror("perftest: perf_event_open cycles/instructions");
+		return 1;
+	}
+	if (control(counters, count, PERF_EVENT_IOC_RESET) != 0 ||
+	    control(counters, count, PERF_EVENT_IOC_ENABLE) != 0) {
+		perror("perftest: enable");
+		return 1;
+	}
+	workload(iterations);
+	if (control(counters, count, PERF_EVENT_IOC_DISABLE) != 0 ||
+	    read_counters(counters, count, counted) != 0) {
+		perror("perftest: disable/read");
+		return 1;
+	}
+	if (counted[0] == 0 || (count == 2 && counted[1] < iterations)) {
+		fprintf(stderr, "perftest: %lu iterations counted %" PRIu64 " cycles, %" PRIu64
+			" instructions\n", iterations, counted[0], counted[1]);
+		return 1;
+	}
+	/* disabled counter 必须冻结，即使 Thread 继续运行并经过 context switch。 */
+	workload(iterations);
+	if (read_counters(counters, count, frozen) != 0 || frozen[0] != counted[0] ||
+	    frozen[1] != counted[1]) {
+		fprintf(stderr, "perftest: disabled counters advanced\n");
+		return 1;
+	}
+	if (control(counters, count, PERF_EVENT_IOC_RESET) != 0 ||
+	    read_counter(counters[0], &frozen[0]) != 0 || frozen[0] != 0) {
+		fprintf(stderr, "perftest: reset did not clear the cycle count\n");
+		return 1;
+	}
+	if (read(counters[0], short_buffer, sizeof(short_buffer)) != -1 || errno != ENOSPC) {
+		fprintf(stderr, "perftest: short read did not fail with ENOSPC\n");
+		return 1;
+	}
+	if (open_counter(PERF_COUNT_HW_CACHE_MISSES) != -1 || errno != ENOENT) {
+		fprintf(stderr, "perftest: unsupported cache-miss event did not fail with ENOENT\n");
+		return 1;
+	}
+	for (int index = 0; index < count; ++index)
+		close(counters[index]);
+	if (count == 1)
+		printf("perftest ok: %lu iterations: %" PRIu64 " cycles, instructions unavailable\n",
+		       iterations, counted[0]);
+	else
+		printf("perftest ok: %lu iterations: %" PRIu64 " cycles, %" PRIu64 " instructions\n",
+		       iterations, counted[0], counted[1]);
+	return 0;
+}
@@ -0,0 +1,8 @@
+#ifndef LITEOS_PERF_H
+#define LITEOS_PERF_H
+
+int run_perftest(int argc, char **argv);
+/* 打开已 enable 的调用 Thread cycle counter；不可用时返回 -1 并保留 errno。 */
+int perf_cycles_open(void);
+
+#endif